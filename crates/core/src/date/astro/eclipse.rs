@@ -0,0 +1,496 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+
+use super::{
+    delta_t, from_julian_day, julian_day,
+    location::Location,
+    moon::{mean_lunation, Moon, LUNATION_BASE_JDE},
+    sidereal_time, sun, EARTH_RADIUS, SUNRISE_ALTITUDE, SYNODIC_MONTH,
+};
+
+// 日月食按 Meeus《天文算法》第 54 章推算: 先由朔望序数 k 判断月亮是否靠近黄白交点,
+// 再求食甚时刻、γ(月影轴到地心的最近距离, 以地球半径计)与食分
+
+// 天文单位对应的地球赤道半径数
+const AU_IN_EARTH_RADII: f64 = 23454.78;
+// 月亮半径与地球赤道半径之比
+const MOON_RADIUS_RATIO: f64 = 0.272481;
+// 日心距离 1 天文单位时的太阳视半径(度)
+const SUN_SEMIDIAMETER: f64 = 959.63 / 3600.0;
+// 当地食况在食甚前后搜索的范围(小时)
+const LOCAL_WINDOW_HOURS: f64 = 4.0;
+
+// 日食或月食
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EclipseTarget {
+    Solar,
+    Lunar,
+}
+
+// 食的类型: 全环食只见于日食, 半影食只见于月食
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EclipseKind {
+    Total,
+    Annular,
+    Hybrid,
+    Partial,
+    Penumbral,
+}
+
+impl EclipseKind {
+    pub fn alias(&self) -> &'static str {
+        match self {
+            EclipseKind::Total => "全食",
+            EclipseKind::Annular => "环食",
+            EclipseKind::Hybrid => "全环食",
+            EclipseKind::Partial => "偏食",
+            EclipseKind::Penumbral => "半影食",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eclipse {
+    target: EclipseTarget,
+    kind: EclipseKind,
+    // 食甚的儒略日(世界时)
+    jd: f64,
+    gamma: f64,
+    magnitude: Option<f64>,
+    tz: FixedOffset,
+}
+
+impl Eclipse {
+    // 当地日期 start 至 end(含)之间的全部日月食, 按食甚时刻排序
+    pub fn between(start: NaiveDate, end: NaiveDate, tz: FixedOffset) -> Vec<Eclipse> {
+        let midnight = |date: NaiveDate| {
+            julian_day(
+                &Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap()),
+                &tz,
+            )
+        };
+        let (from, to) = (midnight(start), midnight(end) + 1.0);
+        let first = ((from - LUNATION_BASE_JDE) / SYNODIC_MONTH).floor() - 1.0;
+        let last = ((to - LUNATION_BASE_JDE) / SYNODIC_MONTH).ceil() + 1.0;
+        // 整数 k 为朔, 半整数为望
+        (0..=((last - first) * 2.0) as i64)
+            .filter_map(|i| Self::at_lunation(first + i as f64 * 0.5, tz))
+            .filter(|eclipse| from <= eclipse.jd && eclipse.jd < to)
+            .collect()
+    }
+
+    // 第 k 个朔望是否发生日月食
+    fn at_lunation(k: f64, tz: FixedOffset) -> Option<Eclipse> {
+        let t = k / 1236.85;
+        let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+        let jde = mean_lunation(k);
+        let m = (2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3).to_radians();
+        let mp = (201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3
+            - 0.000000058 * t4)
+            .to_radians();
+        let f = (160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4)
+            .to_radians();
+        // 月亮远离交点时不会发生食
+        if f.sin().abs() > 0.36 {
+            return None;
+        }
+        let omega = (124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3).to_radians();
+        let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+        let f1 = f - (0.02665 * omega.sin()).to_radians();
+        let a1 = (299.77 + 0.107408 * k - 0.009173 * t2).to_radians();
+        let target = if k.fract() == 0.0 {
+            EclipseTarget::Solar
+        } else {
+            EclipseTarget::Lunar
+        };
+
+        let mut jde = jde
+            + match target {
+                EclipseTarget::Solar => -0.4075 * mp.sin() + 0.1721 * e * m.sin(),
+                EclipseTarget::Lunar => -0.4065 * mp.sin() + 0.1727 * e * m.sin(),
+            };
+        jde += 0.0161 * (2.0 * mp).sin() - 0.0097 * (2.0 * f1).sin() + 0.0073 * e * (mp - m).sin()
+            - 0.0050 * e * (mp + m).sin()
+            - 0.0023 * (mp - 2.0 * f1).sin()
+            + 0.0021 * e * (2.0 * m).sin()
+            + 0.0012 * (mp + 2.0 * f1).sin()
+            + 0.0006 * e * (2.0 * mp + m).sin()
+            - 0.0004 * (3.0 * mp).sin()
+            - 0.0003 * e * (m + 2.0 * f1).sin()
+            + 0.0003 * a1.sin()
+            - 0.0002 * e * (m - 2.0 * f1).sin()
+            - 0.0002 * e * (2.0 * mp - m).sin()
+            - 0.0002 * omega.sin();
+
+        let p = 0.2070 * e * m.sin() + 0.0024 * e * (2.0 * m).sin() - 0.0392 * mp.sin()
+            + 0.0116 * (2.0 * mp).sin()
+            - 0.0073 * e * (mp + m).sin()
+            + 0.0067 * e * (mp - m).sin()
+            + 0.0118 * (2.0 * f1).sin();
+        let q = 5.2207 - 0.0048 * e * m.cos() + 0.0020 * e * (2.0 * m).cos()
+            - 0.3299 * mp.cos()
+            - 0.0060 * e * (mp + m).cos()
+            + 0.0041 * e * (mp - m).cos();
+        let gamma = (p * f1.cos() + q * f1.sin()) * (1.0 - 0.0048 * f1.cos().abs());
+        let u = 0.0059 + 0.0046 * e * m.cos() - 0.0182 * mp.cos() + 0.0004 * (2.0 * mp).cos()
+            - 0.0005 * (m + mp).cos();
+
+        let (kind, magnitude) = match target {
+            EclipseTarget::Solar => Self::solar_kind(gamma, u)?,
+            EclipseTarget::Lunar => Self::lunar_kind(gamma, u)?,
+        };
+        Some(Eclipse {
+            target,
+            kind,
+            jd: jde - delta_t(jde),
+            gamma,
+            magnitude,
+            tz,
+        })
+    }
+
+    // 日食类型与偏食的食分; u 为月亮本影在基本面上的半径
+    fn solar_kind(gamma: f64, u: f64) -> Option<(EclipseKind, Option<f64>)> {
+        let g = gamma.abs();
+        if g > 1.5433 + u {
+            return None;
+        }
+        // 月影轴穿过地球: 中心食
+        if g < 0.9972 {
+            let kind = if u < 0.0 {
+                EclipseKind::Total
+            } else if u > 0.0047 || u >= 0.00464 * (1.0 - gamma * gamma).sqrt() {
+                EclipseKind::Annular
+            } else {
+                EclipseKind::Hybrid
+            };
+            return Some((kind, None));
+        }
+        let magnitude = (1.5433 + u - g) / (0.5461 + 2.0 * u);
+        Some((EclipseKind::Partial, Some(magnitude)))
+    }
+
+    // 月食类型与食分: 本影食分不足零时为半影食, 给出半影食分
+    fn lunar_kind(gamma: f64, u: f64) -> Option<(EclipseKind, Option<f64>)> {
+        let g = gamma.abs();
+        let penumbral = (1.5573 + u - g) / 0.5450;
+        let umbral = (1.0128 - u - g) / 0.5450;
+        if penumbral <= 0.0 {
+            None
+        } else if umbral <= 0.0 {
+            Some((EclipseKind::Penumbral, Some(penumbral)))
+        } else if umbral < 1.0 {
+            Some((EclipseKind::Partial, Some(umbral)))
+        } else {
+            Some((EclipseKind::Total, Some(umbral)))
+        }
+    }
+
+    pub fn target(&self) -> EclipseTarget {
+        self.target
+    }
+
+    pub fn kind(&self) -> EclipseKind {
+        self.kind
+    }
+
+    pub fn is_solar(&self) -> bool {
+        self.target == EclipseTarget::Solar
+    }
+
+    pub fn is_lunar(&self) -> bool {
+        self.target == EclipseTarget::Lunar
+    }
+
+    // 食甚的当地时间
+    pub fn maximum(&self) -> DateTime<Utc> {
+        from_julian_day(self.jd, &self.tz)
+    }
+
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    // 偏食与月食的食分, 中心日食没有统一的食分
+    pub fn magnitude(&self) -> Option<f64> {
+        self.magnitude
+    }
+
+    // 日食在某地的食况, 月食或该地看不到食时为 None
+    pub fn local_circumstances(&self, location: &Location) -> Option<LocalEclipse> {
+        if self.is_lunar() {
+            return None;
+        }
+        let window = LOCAL_WINDOW_HOURS / 24.0;
+        let step = 1.0 / 1440.0;
+        let overlap = |jd: f64| LocalState::new(jd, location).overlap();
+
+        // 逐分钟找出日月圆面重叠最多的时刻, 再三分细化
+        let samples = (2.0 * window / step) as i64;
+        let best = (0..=samples)
+            .map(|i| self.jd - window + i as f64 * step)
+            .max_by(|a, b| overlap(*a).total_cmp(&overlap(*b)))?;
+        let (mut lo, mut hi) = (best - step, best + step);
+        while (hi - lo) * 86400.0 > 1.0 {
+            let (a, b) = (lo + (hi - lo) / 3.0, hi - (hi - lo) / 3.0);
+            if overlap(a) < overlap(b) {
+                lo = a;
+            } else {
+                hi = b;
+            }
+        }
+        let maximum = (lo + hi) / 2.0;
+        let state = LocalState::new(maximum, location);
+        if state.overlap() <= 0.0 {
+            return None;
+        }
+
+        // 初亏与复圆: 两圆面外切的时刻
+        let contact = |mut outside: f64, mut inside: f64| {
+            while (outside - inside).abs() * 86400.0 > 1.0 {
+                let mid = (outside + inside) / 2.0;
+                if overlap(mid) > 0.0 {
+                    inside = mid;
+                } else {
+                    outside = mid;
+                }
+            }
+            (outside + inside) / 2.0
+        };
+        let begin = contact(self.jd - window, maximum);
+        let end = contact(self.jd + window, maximum);
+        // 食的过程中太阳至少有一刻在地平线上才算可见
+        let visible = (0..=((end - begin) / step) as i64)
+            .map(|i| begin + i as f64 * step)
+            .chain([end])
+            .any(|jd| LocalState::new(jd, location).sun_altitude > SUNRISE_ALTITUDE);
+        if !visible {
+            return None;
+        }
+
+        let kind = if state.separation < (state.moon_radius - state.sun_radius).abs() {
+            if state.moon_radius > state.sun_radius {
+                EclipseKind::Total
+            } else {
+                EclipseKind::Annular
+            }
+        } else {
+            EclipseKind::Partial
+        };
+        Some(LocalEclipse {
+            kind,
+            begin: from_julian_day(begin, &self.tz),
+            maximum: from_julian_day(maximum, &self.tz),
+            end: from_julian_day(end, &self.tz),
+            magnitude: state.overlap() / (2.0 * state.sun_radius),
+            sun_altitude: state.sun_altitude,
+        })
+    }
+}
+
+// 某地所见的日食, 时刻为当地时间
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalEclipse {
+    kind: EclipseKind,
+    begin: DateTime<Utc>,
+    maximum: DateTime<Utc>,
+    end: DateTime<Utc>,
+    magnitude: f64,
+    sun_altitude: f64,
+}
+
+impl LocalEclipse {
+    pub fn kind(&self) -> EclipseKind {
+        self.kind
+    }
+
+    // 初亏
+    pub fn begin(&self) -> DateTime<Utc> {
+        self.begin
+    }
+
+    // 食甚
+    pub fn maximum(&self) -> DateTime<Utc> {
+        self.maximum
+    }
+
+    // 复圆
+    pub fn end(&self) -> DateTime<Utc> {
+        self.end
+    }
+
+    // 食分: 太阳直径被月亮遮住的比例
+    pub fn magnitude(&self) -> f64 {
+        self.magnitude
+    }
+
+    // 食甚时太阳的高度角(度), 为负表示带食日出或日落
+    pub fn sun_altitude(&self) -> f64 {
+        self.sun_altitude
+    }
+}
+
+// 某地某时日月的站心视位置, 角度均以度为单位
+struct LocalState {
+    separation: f64,
+    sun_radius: f64,
+    moon_radius: f64,
+    sun_altitude: f64,
+}
+
+impl LocalState {
+    fn new(jd: f64, location: &Location) -> LocalState {
+        // 历表使用力学时, 恒星时使用世界时
+        let jde = jd + delta_t(jd);
+        let (sun_ra, sun_dec) = sun::equatorial(jde);
+        let sun_distance = sun::distance(jde);
+        let moon = Moon::from_julian_day(jde);
+        let (moon_ra, moon_dec) = moon.equatorial();
+
+        // 观测者在地心赤道坐标中的位置(地球半径)
+        let lat = location.latitude().to_radians();
+        let height = location.elevation() / (EARTH_RADIUS * 1000.0);
+        let u = (0.99664719 * lat.tan()).atan();
+        let rho_sin = 0.99664719 * u.sin() + height * lat.sin();
+        let rho_cos = u.cos() + height * lat.cos();
+        let theta = (sidereal_time(jd) + location.longitude()).to_radians();
+        let observer = [rho_cos * theta.cos(), rho_cos * theta.sin(), rho_sin];
+
+        let topocentric = |ra: f64, dec: f64, r: f64| {
+            let (ra, dec) = (ra.to_radians(), dec.to_radians());
+            [
+                r * dec.cos() * ra.cos() - observer[0],
+                r * dec.cos() * ra.sin() - observer[1],
+                r * dec.sin() - observer[2],
+            ]
+        };
+        let sun = topocentric(sun_ra, sun_dec, sun_distance * AU_IN_EARTH_RADII);
+        let moon = topocentric(moon_ra, moon_dec, moon.distance() / EARTH_RADIUS);
+        let zenith = [lat.cos() * theta.cos(), lat.cos() * theta.sin(), lat.sin()];
+
+        let norm = |v: [f64; 3]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+        let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+        let (sun_norm, moon_norm) = (norm(sun), norm(moon));
+        let cos_sep = (dot(sun, moon) / (sun_norm * moon_norm)).clamp(-1.0, 1.0);
+        LocalState {
+            separation: cos_sep.acos().to_degrees(),
+            sun_radius: SUN_SEMIDIAMETER / sun_distance,
+            moon_radius: (MOON_RADIUS_RATIO / moon_norm).asin().to_degrees(),
+            sun_altitude: (dot(sun, zenith) / sun_norm).asin().to_degrees(),
+        }
+    }
+
+    // 日月圆面重叠的宽度(度), 为负表示未相接
+    fn overlap(&self) -> f64 {
+        self.sun_radius + self.moon_radius - self.separation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn utc() -> FixedOffset {
+        FixedOffset::east_opt(0).unwrap()
+    }
+
+    fn hm(t: DateTime<Utc>) -> String {
+        t.format("%Y-%m-%d %H:%M").to_string()
+    }
+
+    #[test]
+    fn test_eclipses_between() {
+        let got: Vec<_> = Eclipse::between(date(2022, 1, 1), date(2024, 12, 31), utc())
+            .iter()
+            .map(|e| (e.target(), e.kind(), hm(e.maximum())))
+            .collect();
+        let (solar, lunar) = (EclipseTarget::Solar, EclipseTarget::Lunar);
+        let want = vec![
+            (solar, EclipseKind::Partial, "2022-04-30 20:41"),
+            (lunar, EclipseKind::Total, "2022-05-16 04:11"),
+            (solar, EclipseKind::Partial, "2022-10-25 11:00"),
+            (lunar, EclipseKind::Total, "2022-11-08 10:59"),
+            (solar, EclipseKind::Hybrid, "2023-04-20 04:16"),
+            (lunar, EclipseKind::Penumbral, "2023-05-05 17:22"),
+            (solar, EclipseKind::Annular, "2023-10-14 17:59"),
+            (lunar, EclipseKind::Partial, "2023-10-28 20:14"),
+            (lunar, EclipseKind::Penumbral, "2024-03-25 07:13"),
+            (solar, EclipseKind::Total, "2024-04-08 18:17"),
+            (lunar, EclipseKind::Partial, "2024-09-18 02:44"),
+            (solar, EclipseKind::Annular, "2024-10-02 18:45"),
+        ];
+        let want: Vec<_> = want
+            .into_iter()
+            .map(|(target, kind, t)| (target, kind, t.to_string()))
+            .collect();
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn test_eclipse_details() {
+        let eclipses = Eclipse::between(date(2024, 4, 8), date(2024, 4, 8), utc());
+        assert_eq!(eclipses.len(), 1);
+        let total = &eclipses[0];
+        assert!(total.is_solar());
+        assert!((total.gamma() - 0.3431).abs() < 0.001, "{}", total.gamma());
+        assert_eq!(total.magnitude(), None);
+
+        let lunar = Eclipse::between(date(2022, 11, 8), date(2022, 11, 8), utc());
+        let magnitude = lunar[0].magnitude().unwrap();
+        assert!((magnitude - 1.359).abs() < 0.01, "{}", magnitude);
+
+        // 以北京时间划分日期, 2024-04-08 的日全食落在 4 月 9 日
+        let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+        assert!(Eclipse::between(date(2024, 4, 8), date(2024, 4, 8), beijing).is_empty());
+        let eclipses = Eclipse::between(date(2024, 4, 9), date(2024, 4, 9), beijing);
+        assert_eq!(hm(eclipses[0].maximum()), "2024-04-09 02:17");
+        assert_eq!(eclipses[0].kind().alias(), "全食");
+    }
+
+    #[test]
+    fn test_local_circumstances() {
+        let eclipse = Eclipse::between(date(2024, 4, 8), date(2024, 4, 8), utc()).remove(0);
+        // 与 NASA 公布的初亏、食甚、复圆时刻相差不超过一分钟
+        let tests = vec![
+            // 达拉斯位于全食带内
+            (
+                "dallas",
+                Location::new(32.7767, -96.7970).unwrap(),
+                EclipseKind::Total,
+                [(17, 23, 20), (18, 42, 39), (20, 2, 41)],
+            ),
+            // 纽约只见偏食, 食分约 0.9
+            (
+                "new_york",
+                Location::new(40.7128, -74.0060).unwrap(),
+                EclipseKind::Partial,
+                [(18, 10, 28), (19, 25, 28), (20, 36, 34)],
+            ),
+        ];
+        for (name, location, kind, contacts) in tests {
+            let local = eclipse.local_circumstances(&location).unwrap();
+            assert_eq!(local.kind(), kind, "{} kind", name);
+            let got = [local.begin(), local.maximum(), local.end()];
+            for (got, (h, m, s)) in got.into_iter().zip(contacts) {
+                let want = Utc.with_ymd_and_hms(2024, 4, 8, h, m, s).unwrap();
+                assert!((got - want).num_seconds().abs() < 60, "{} {}", name, got);
+            }
+            assert!(local.sun_altitude() > 40.0, "{} altitude", name);
+        }
+        let new_york = Location::new(40.7128, -74.0060).unwrap();
+        let magnitude = eclipse.local_circumstances(&new_york).unwrap().magnitude();
+        assert!((magnitude - 0.90).abs() < 0.02, "{}", magnitude);
+
+        // 北京在夜间, 伦敦不在月影范围内
+        let beijing = Location::new(39.9042, 116.4074).unwrap();
+        assert_eq!(eclipse.local_circumstances(&beijing), None);
+        let london = Location::new(51.5074, -0.1278).unwrap();
+        assert_eq!(eclipse.local_circumstances(&london), None);
+
+        let lunar = Eclipse::between(date(2022, 11, 8), date(2022, 11, 8), utc()).remove(0);
+        assert_eq!(lunar.local_circumstances(&beijing), None);
+    }
+}
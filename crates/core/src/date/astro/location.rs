@@ -0,0 +1,77 @@
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 地理位置: 纬度北正南负, 经度东正西负, 海拔以米计
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    latitude: f64,
+    longitude: f64,
+    elevation: f64,
+}
+
+impl Location {
+    pub fn new(latitude: f64, longitude: f64) -> IkigaiResult<Self> {
+        Self::with_elevation(latitude, longitude, 0.0)
+    }
+
+    pub fn with_elevation(latitude: f64, longitude: f64, elevation: f64) -> IkigaiResult<Self> {
+        if !(-90.0..=90.0).contains(&latitude)
+            || !(-180.0..=180.0).contains(&longitude)
+            || !elevation.is_finite()
+        {
+            return Err(IkigaiError::InvalidLocation {
+                latitude,
+                longitude,
+            });
+        }
+        Ok(Location {
+            latitude,
+            longitude,
+            elevation,
+        })
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    pub fn elevation(&self) -> f64 {
+        self.elevation
+    }
+
+    // 海拔升高后地平线下沉的角度(度), 低于海平面不作修正
+    pub(crate) fn horizon_dip(&self) -> f64 {
+        2.076 * self.elevation.max(0.0).sqrt() / 60.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_new() {
+        let tests = vec![
+            ("beijing", 39.9042, 116.4074, true),
+            ("north_pole", 90.0, 0.0, true),
+            ("date_line", -10.0, -180.0, true),
+            ("bad_latitude", 91.0, 0.0, false),
+            ("bad_longitude", 0.0, 181.0, false),
+            ("nan", f64::NAN, 0.0, false),
+        ];
+        for (name, latitude, longitude, ok) in tests {
+            assert_eq!(
+                Location::new(latitude, longitude).is_ok(),
+                ok,
+                "{} failed",
+                name
+            );
+        }
+        assert!(Location::with_elevation(0.0, 0.0, f64::INFINITY).is_err());
+        let peak = Location::with_elevation(27.98, 86.92, 8848.0).unwrap();
+        assert!((peak.horizon_dip() - 3.26).abs() < 0.01);
+    }
+}
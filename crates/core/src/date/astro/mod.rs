@@ -0,0 +1,161 @@
+pub mod eclipse;
+pub mod location;
+pub mod moon;
+pub mod season;
+pub mod sun;
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 天体位置采用 Meeus《天文算法》中的简化公式, 精度约为一分钟
+
+// 1970-01-01 00:00 UTC 的儒略日
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+// J2000.0 的儒略日
+pub(crate) const J2000_JD: f64 = 2451545.0;
+// chrono 的 num_days_from_ce 与儒略日数之差
+pub(crate) const CE_TO_JDN: i64 = 1721425;
+// 平均朔望月长度(日)
+pub(crate) const SYNODIC_MONTH: f64 = 29.530588861;
+// 地球赤道半径(千米)
+pub(crate) const EARTH_RADIUS: f64 = 6378.14;
+// 日出日落时太阳中心的高度角: 大气折射 34' 加太阳视半径 16'
+pub(crate) const SUNRISE_ALTITUDE: f64 = -0.833;
+
+// 公历日期的儒略日数
+pub(crate) fn jdn(date: NaiveDate) -> i64 {
+    date.num_days_from_ce() as i64 + CE_TO_JDN
+}
+
+// 儒略日数对应的公历日期
+pub(crate) fn from_jdn(jdn: i64) -> IkigaiResult<NaiveDate> {
+    i32::try_from(jdn - CE_TO_JDN)
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .ok_or(IkigaiError::InvalidDate)
+}
+
+// 以 Utc 承载的当地时间(时区 tz)所对应的儒略日
+pub(crate) fn julian_day(t: &DateTime<Utc>, tz: &FixedOffset) -> f64 {
+    (t.timestamp() - tz.local_minus_utc() as i64) as f64 / 86400.0 + UNIX_EPOCH_JD
+}
+
+// 儒略日对应的当地时间, 精确到秒
+pub(crate) fn from_julian_day(jd: f64, tz: &FixedOffset) -> DateTime<Utc> {
+    let seconds = ((jd - UNIX_EPOCH_JD) * 86400.0).round() as i64 + tz.local_minus_utc() as i64;
+    Utc.timestamp_opt(0, 0).unwrap() + Duration::seconds(seconds)
+}
+
+// 自 J2000.0 起的儒略世纪数
+pub(crate) fn julian_century(jd: f64) -> f64 {
+    (jd - J2000_JD) / 36525.0
+}
+
+// 力学时与世界时之差(日), 采用 Espenak 与 Meeus 的分段多项式
+pub(crate) fn delta_t(jd: f64) -> f64 {
+    let year = 2000.0 + (jd - J2000_JD) / 365.25;
+    let t = year - 2000.0;
+    let seconds = if (1986.0..2005.0).contains(&year) {
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+            + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if (2005.0..2050.0).contains(&year) {
+        62.92 + 0.32217 * t + 0.005589 * t * t
+    } else {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+    seconds / 86400.0
+}
+
+// 格林尼治平恒星时(度)
+pub(crate) fn sidereal_time(jd: f64) -> f64 {
+    let t = julian_century(jd);
+    (280.46061837 + 360.98564736629 * (jd - J2000_JD) + 0.000387933 * t * t).rem_euclid(360.0)
+}
+
+// 黄赤交角(度)
+pub(crate) fn obliquity(jd: f64) -> f64 {
+    let t = julian_century(jd);
+    23.439291 - 0.0130042 * t
+}
+
+// 黄道坐标(度)转为赤经、赤纬(度)
+pub(crate) fn ecliptic_to_equatorial(longitude: f64, latitude: f64, jd: f64) -> (f64, f64) {
+    let (l, b, e) = (
+        longitude.to_radians(),
+        latitude.to_radians(),
+        obliquity(jd).to_radians(),
+    );
+    let ra = (l.sin() * e.cos() - b.tan() * e.sin()).atan2(l.cos());
+    let dec = (b.sin() * e.cos() + b.cos() * e.sin() * l.sin()).asin();
+    (ra.to_degrees().rem_euclid(360.0), dec.to_degrees())
+}
+
+// 地平坐标, 高度角与方位角均以度为单位, 方位角自正北顺时针计
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Horizontal {
+    pub altitude: f64,
+    pub azimuth: f64,
+}
+
+impl Horizontal {
+    // 赤经、赤纬(度)在某地某时的地平坐标
+    pub(crate) fn from_equatorial(
+        ra: f64,
+        dec: f64,
+        jd: f64,
+        location: &location::Location,
+    ) -> Horizontal {
+        let hour_angle = (sidereal_time(jd) + location.longitude() - ra).to_radians();
+        let (lat, dec) = (location.latitude().to_radians(), dec.to_radians());
+        let altitude = (lat.sin() * dec.sin() + lat.cos() * dec.cos() * hour_angle.cos()).asin();
+        let azimuth =
+            (-hour_angle.sin()).atan2(dec.tan() * lat.cos() - lat.sin() * hour_angle.cos());
+        Horizontal {
+            altitude: altitude.to_degrees(),
+            azimuth: azimuth.to_degrees().rem_euclid(360.0),
+        }
+    }
+}
+
+// 天体在某一高度角上的升落
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Crossing {
+    // 当日升起与落下的当地时刻
+    RiseSet {
+        rise: DateTime<Utc>,
+        set: DateTime<Utc>,
+    },
+    // 全天都在该高度之上(如极昼)
+    AlwaysAbove,
+    // 全天都在该高度之下(如极夜)
+    AlwaysBelow,
+}
+
+impl Crossing {
+    pub fn rise(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Crossing::RiseSet { rise, .. } => Some(*rise),
+            _ => None,
+        }
+    }
+
+    pub fn set(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Crossing::RiseSet { set, .. } => Some(*set),
+            _ => None,
+        }
+    }
+
+    // 位于该高度之上的时长
+    pub fn duration(&self) -> Duration {
+        match self {
+            Crossing::RiseSet { rise, set } => *set - *rise,
+            Crossing::AlwaysAbove => Duration::days(1),
+            Crossing::AlwaysBelow => Duration::zero(),
+        }
+    }
+}
@@ -0,0 +1,535 @@
+use std::f64::consts::PI;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+
+use super::{
+    delta_t, ecliptic_to_equatorial, from_julian_day, julian_century, julian_day,
+    location::Location, sun, Horizontal, EARTH_RADIUS, SYNODIC_MONTH,
+};
+
+// 1900-01-01 的儒略日, 用作 new_moon 朔望月计数的起点
+pub(crate) const NEW_MOON_EPOCH_JD: f64 = 2415021.076998695;
+// Meeus 第 49 章 k = 0 的朔(2000-01-06, 力学时), 用作 mean_lunation 的计数起点
+pub(crate) const LUNATION_BASE_JDE: f64 = 2451550.09766;
+// 满月时地心距离小于此值视为超级月亮, 大于 MICROMOON_DISTANCE 视为微型月亮
+const SUPERMOON_DISTANCE: f64 = 360_000.0;
+const MICROMOON_DISTANCE: f64 = 405_000.0;
+// 距望(日月黄经差 180°)在此角度以内视为满月, 约为月亮一天的行程
+const FULL_MOON_TOLERANCE: f64 = 12.2;
+// 搜索月出月落的步长(分钟)
+const SCAN_MINUTES: i64 = 10;
+
+// 月亮黄经与距离的周期项(Meeus 表 47.A): D、M、M'、F 的系数,
+// 黄经振幅(10⁻⁶ 度), 距离振幅(米)
+const LONGITUDE_DISTANCE_TERMS: [(i32, i32, i32, i32, i32, i32); 60] = [
+    (0, 0, 1, 0, 6288774, -20905355),
+    (2, 0, -1, 0, 1274027, -3699111),
+    (2, 0, 0, 0, 658314, -2955968),
+    (0, 0, 2, 0, 213618, -569925),
+    (0, 1, 0, 0, -185116, 48888),
+    (0, 0, 0, 2, -114332, -3149),
+    (2, 0, -2, 0, 58793, 246158),
+    (2, -1, -1, 0, 57066, -152138),
+    (2, 0, 1, 0, 53322, -170733),
+    (2, -1, 0, 0, 45758, -204586),
+    (0, 1, -1, 0, -40923, -129620),
+    (1, 0, 0, 0, -34720, 108743),
+    (0, 1, 1, 0, -30383, 104755),
+    (2, 0, 0, -2, 15327, 10321),
+    (0, 0, 1, 2, -12528, 0),
+    (0, 0, 1, -2, 10980, 79661),
+    (4, 0, -1, 0, 10675, -34782),
+    (0, 0, 3, 0, 10034, -23210),
+    (4, 0, -2, 0, 8548, -21636),
+    (2, 1, -1, 0, -7888, 24208),
+    (2, 1, 0, 0, -6766, 30824),
+    (1, 0, -1, 0, -5163, -8379),
+    (1, 1, 0, 0, 4987, -16675),
+    (2, -1, 1, 0, 4036, -12831),
+    (2, 0, 2, 0, 3994, -10445),
+    (4, 0, 0, 0, 3861, -11650),
+    (2, 0, -3, 0, 3665, 14403),
+    (0, 1, -2, 0, -2689, -7003),
+    (2, 0, -1, 2, -2602, 0),
+    (2, -1, -2, 0, 2390, 10056),
+    (1, 0, 1, 0, -2348, 6322),
+    (2, -2, 0, 0, 2236, -9884),
+    (0, 1, 2, 0, -2120, 5751),
+    (0, 2, 0, 0, -2069, 0),
+    (2, -2, -1, 0, 2048, -4950),
+    (2, 0, 1, -2, -1773, 4130),
+    (2, 0, 0, 2, -1595, 0),
+    (4, -1, -1, 0, 1215, -3958),
+    (0, 0, 2, 2, -1110, 0),
+    (3, 0, -1, 0, -892, 3258),
+    (2, 1, 1, 0, -810, 2616),
+    (4, -1, -2, 0, 759, -1897),
+    (0, 2, -1, 0, -713, -2117),
+    (2, 2, -1, 0, -700, 2354),
+    (2, 1, -2, 0, 691, 0),
+    (2, -1, 0, -2, 596, 0),
+    (4, 0, 1, 0, 549, -1423),
+    (0, 0, 4, 0, 537, -1117),
+    (4, -1, 0, 0, 520, -1571),
+    (1, 0, -2, 0, -487, -1739),
+    (2, 1, 0, -2, -399, 0),
+    (0, 0, 2, -2, -381, -4421),
+    (1, 1, 1, 0, 351, 0),
+    (3, 0, -2, 0, -340, 0),
+    (4, 0, -3, 0, 330, 0),
+    (2, -1, 2, 0, 327, 0),
+    (0, 2, 1, 0, -323, 1165),
+    (1, 1, -1, 0, 299, 0),
+    (2, 0, 3, 0, 294, 0),
+    (2, 0, -1, -2, 0, 8752),
+];
+
+// 月亮黄纬的周期项(Meeus 表 47.B): D、M、M'、F 的系数, 振幅(10⁻⁶ 度)
+const LATITUDE_TERMS: [(i32, i32, i32, i32, i32); 60] = [
+    (0, 0, 0, 1, 5128122),
+    (0, 0, 1, 1, 280602),
+    (0, 0, 1, -1, 277693),
+    (2, 0, 0, -1, 173237),
+    (2, 0, -1, 1, 55413),
+    (2, 0, -1, -1, 46271),
+    (2, 0, 0, 1, 32573),
+    (0, 0, 2, 1, 17198),
+    (2, 0, 1, -1, 9266),
+    (0, 0, 2, -1, 8822),
+    (2, -1, 0, -1, 8216),
+    (2, 0, -2, -1, 4324),
+    (2, 0, 1, 1, 4200),
+    (2, 1, 0, -1, -3359),
+    (2, -1, -1, 1, 2463),
+    (2, -1, 0, 1, 2211),
+    (2, -1, -1, -1, 2065),
+    (0, 1, -1, -1, -1870),
+    (4, 0, -1, -1, 1828),
+    (0, 1, 0, 1, -1794),
+    (0, 0, 0, 3, -1749),
+    (0, 1, -1, 1, -1565),
+    (1, 0, 0, 1, -1491),
+    (0, 1, 1, 1, -1475),
+    (0, 1, 1, -1, -1410),
+    (0, 1, 0, -1, -1344),
+    (1, 0, 0, -1, -1335),
+    (0, 0, 3, 1, 1107),
+    (4, 0, 0, -1, 1021),
+    (4, 0, -1, 1, 833),
+    (0, 0, 1, -3, 777),
+    (4, 0, -2, 1, 671),
+    (2, 0, 0, -3, 607),
+    (2, 0, 2, -1, 596),
+    (2, -1, 1, -1, 491),
+    (2, 0, -2, 1, -451),
+    (0, 0, 3, -1, 439),
+    (2, 0, 2, 1, 422),
+    (2, 0, -3, -1, 421),
+    (2, 1, -1, 1, -366),
+    (2, 1, 0, 1, -351),
+    (4, 0, 0, 1, 331),
+    (2, -1, 1, 1, 315),
+    (2, -2, 0, -1, 302),
+    (0, 0, 1, 3, -283),
+    (2, 1, 1, -1, -229),
+    (1, 1, 0, -1, 223),
+    (1, 1, 0, 1, 223),
+    (0, 1, -2, -1, -220),
+    (2, 1, -1, -1, -220),
+    (1, 0, 1, 1, -185),
+    (2, -1, -2, -1, 181),
+    (0, 1, 2, 1, -177),
+    (4, 0, -2, -1, 176),
+    (4, -1, -1, -1, 166),
+    (1, 0, 1, -1, -164),
+    (4, 0, 1, -1, 132),
+    (1, 0, -1, -1, -119),
+    (4, -1, 0, -1, 115),
+    (2, -2, 0, 1, 107),
+];
+
+// 第 k 个朔(自 1900-01-01 起)的儒略日(世界时), 采用胡玉德(Hồ Ngọc Đức)整理的 Meeus 简化公式
+pub(crate) fn new_moon(k: i64) -> f64 {
+    let k = k as f64;
+    let t = k / 1236.85;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let dr = PI / 180.0;
+    let mut jd1 = 2415020.75933 + 29.53058868 * k + 0.0001178 * t2 - 0.000000155 * t3;
+    jd1 += 0.00033 * ((166.56 + 132.87 * t - 0.009173 * t2) * dr).sin();
+    let m = 359.2242 + 29.10535608 * k - 0.0000333 * t2 - 0.00000347 * t3;
+    let mpr = 306.0253 + 385.81691806 * k + 0.0107306 * t2 + 0.00001236 * t3;
+    let f = 21.2964 + 390.67050646 * k - 0.0016528 * t2 - 0.00000239 * t3;
+    let mut c1 = (0.1734 - 0.000393 * t) * (m * dr).sin() + 0.0021 * (2.0 * dr * m).sin();
+    c1 = c1 - 0.4068 * (mpr * dr).sin() + 0.0161 * (dr * 2.0 * mpr).sin();
+    c1 -= 0.0004 * (dr * 3.0 * mpr).sin();
+    c1 = c1 + 0.0104 * (dr * 2.0 * f).sin() - 0.0051 * (dr * (m + mpr)).sin();
+    c1 = c1 - 0.0074 * (dr * (m - mpr)).sin() + 0.0004 * (dr * (2.0 * f + m)).sin();
+    c1 = c1 - 0.0004 * (dr * (2.0 * f - m)).sin() - 0.0006 * (dr * (2.0 * f + mpr)).sin();
+    c1 = c1 + 0.0010 * (dr * (2.0 * f - mpr)).sin() + 0.0005 * (dr * (2.0 * mpr + m)).sin();
+    // 力学时换算为世界时
+    let jde = jd1 + c1;
+    jde - delta_t(jde)
+}
+
+// 不晚于儒略日 jd 的最近一个朔(儒略日)
+pub(crate) fn last_new_moon(jd: f64) -> f64 {
+    let mut k = ((jd - NEW_MOON_EPOCH_JD) / SYNODIC_MONTH).floor() as i64 + 1;
+    while new_moon(k) > jd {
+        k -= 1;
+    }
+    new_moon(k)
+}
+
+// Meeus 第 49 章的第 k 个平朔望(整数为朔, 半整数为望)的儒略日(力学时)
+pub(crate) fn mean_lunation(k: f64) -> f64 {
+    let t = k / 1236.85;
+    LUNATION_BASE_JDE + SYNODIC_MONTH * k + 0.00015437 * t * t - 0.000000150 * t.powi(3)
+        + 0.00000000073 * t.powi(4)
+}
+
+// 某一时刻月亮的地心位置, 按 Meeus 第 47 章的主要周期项推算
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Moon {
+    jd: f64,
+    longitude: f64,
+    latitude: f64,
+    distance: f64,
+}
+
+impl Moon {
+    // t 为时区 tz 的当地时间
+    pub fn new(t: &DateTime<Utc>, tz: &FixedOffset) -> Moon {
+        Self::from_julian_day(julian_day(t, tz))
+    }
+
+    pub(crate) fn from_julian_day(jd: f64) -> Moon {
+        let t = julian_century(jd);
+        let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+        let l =
+            218.3164477 + 481267.88123421 * t - 0.0015786 * t2 + t3 / 538841.0 - t4 / 65194000.0;
+        let d =
+            297.8501921 + 445267.1114034 * t - 0.0018819 * t2 + t3 / 545868.0 - t4 / 113065000.0;
+        let m = 357.5291092 + 35999.0502909 * t - 0.0001536 * t2 + t3 / 24490000.0;
+        let mp = 134.9633964 + 477198.8675055 * t + 0.0087414 * t2 + t3 / 69699.0 - t4 / 14712000.0;
+        let f =
+            93.2720950 + 483202.0175233 * t - 0.0036539 * t2 - t3 / 3526000.0 + t4 / 863310000.0;
+        // 地球轨道偏心率减小对含 M 项的修正
+        let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+        let arg = |cd: i32, cm: i32, cmp: i32, cf: i32| {
+            let angle =
+                (cd as f64 * d + cm as f64 * m + cmp as f64 * mp + cf as f64 * f).to_radians();
+            (angle, e.powi(cm.abs()))
+        };
+
+        let (mut sl, mut sr, mut sb) = (0.0, 0.0, 0.0);
+        for (cd, cm, cmp, cf, l, r) in LONGITUDE_DISTANCE_TERMS {
+            let (angle, factor) = arg(cd, cm, cmp, cf);
+            sl += l as f64 * factor * angle.sin();
+            sr += r as f64 * factor * angle.cos();
+        }
+        for (cd, cm, cmp, cf, b) in LATITUDE_TERMS {
+            let (angle, factor) = arg(cd, cm, cmp, cf);
+            sb += b as f64 * factor * angle.sin();
+        }
+        // 金星、木星摄动与地球扁率的附加项
+        let sin = |x: f64| x.to_radians().sin();
+        let a1 = 119.75 + 131.849 * t;
+        let a2 = 53.09 + 479264.290 * t;
+        let a3 = 313.45 + 481266.484 * t;
+        sl += 3958.0 * sin(a1) + 1962.0 * sin(l - f) + 318.0 * sin(a2);
+        sb += -2235.0 * sin(l)
+            + 382.0 * sin(a3)
+            + 175.0 * sin(a1 - f)
+            + 175.0 * sin(a1 + f)
+            + 127.0 * sin(l - mp)
+            - 115.0 * sin(l + mp);
+        // 黄经章动, 使结果与太阳的视黄经一致
+        let omega = 125.04452 - 1934.136261 * t;
+        let nutation = (-17.20 * sin(omega)
+            - 1.32 * sin(2.0 * (280.4665 + 36000.7698 * t))
+            - 0.23 * sin(2.0 * l)
+            + 0.21 * sin(2.0 * omega))
+            / 3600.0;
+        Moon {
+            jd,
+            longitude: (l + sl / 1e6 + nutation).rem_euclid(360.0),
+            latitude: sb / 1e6,
+            distance: 385000.56 + sr / 1000.0,
+        }
+    }
+
+    // 地心黄经(度)
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    // 地心黄纬(度)
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    // 地心距离(千米)
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    // 地平视差(度)
+    pub fn parallax(&self) -> f64 {
+        (EARTH_RADIUS / self.distance).asin().to_degrees()
+    }
+
+    // 月龄: 距上一个朔的天数
+    pub fn age(&self) -> f64 {
+        self.jd - last_new_moon(self.jd)
+    }
+
+    // 日月黄经差(度), 朔为 0, 望为 180
+    pub fn elongation(&self) -> f64 {
+        (self.longitude - sun::apparent_longitude(self.jd)).rem_euclid(360.0)
+    }
+
+    // 被照亮部分的比例
+    pub fn illumination(&self) -> f64 {
+        (1.0 - self.elongation().to_radians().cos()) / 2.0
+    }
+
+    pub fn is_full(&self) -> bool {
+        (self.elongation() - 180.0).abs() < FULL_MOON_TOLERANCE
+    }
+
+    // 满月恰逢近地点附近
+    pub fn is_supermoon(&self) -> bool {
+        self.is_full() && self.distance < SUPERMOON_DISTANCE
+    }
+
+    // 满月恰逢远地点附近
+    pub fn is_micromoon(&self) -> bool {
+        self.is_full() && self.distance > MICROMOON_DISTANCE
+    }
+
+    // 地心赤经、赤纬(度)
+    pub(crate) fn equatorial(&self) -> (f64, f64) {
+        ecliptic_to_equatorial(self.longitude, self.latitude, self.jd)
+    }
+
+    // 站心地平坐标, 高度角已扣除视差
+    pub fn horizontal(&self, location: &Location) -> Horizontal {
+        let (ra, dec) = self.equatorial();
+        let mut horizontal = Horizontal::from_equatorial(ra, dec, self.jd, location);
+        horizontal.altitude -= self.parallax() * horizontal.altitude.to_radians().cos();
+        horizontal
+    }
+
+    // 地心高度角与月出月落所用高度角之差, 为正表示月亮在地平线上
+    fn above_horizon(jd: f64, location: &Location) -> f64 {
+        let moon = Self::from_julian_day(jd);
+        let (ra, dec) = moon.equatorial();
+        let altitude = Horizontal::from_equatorial(ra, dec, jd, location).altitude;
+        // 月面上缘与地平相切: 视差与月亮视半径、大气折射抵消后的近似值
+        altitude - (0.7275 * moon.parallax() - 0.5667 - location.horizon_dip())
+    }
+}
+
+// 某地某日的月出月落, 时刻为时区 tz 的当地时间; 月亮每天约迟 50 分钟升起,
+// 每月总有一天没有月出或月落
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoonTimes {
+    date: NaiveDate,
+    rise: Option<DateTime<Utc>>,
+    set: Option<DateTime<Utc>>,
+    always_above: bool,
+}
+
+impl MoonTimes {
+    pub fn new(date: NaiveDate, location: &Location, tz: FixedOffset) -> MoonTimes {
+        let midnight = Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap());
+        let start = julian_day(&midnight, &tz);
+        let step = SCAN_MINUTES as f64 / 1440.0;
+        let (mut rise, mut set) = (None, None);
+        let mut prev = Moon::above_horizon(start, location);
+        let always_above = prev > 0.0;
+        for i in 1..=(1440 / SCAN_MINUTES) {
+            let jd = start + i as f64 * step;
+            let value = Moon::above_horizon(jd, location);
+            if (prev > 0.0) != (value > 0.0) {
+                let t = Self::bisect(jd - step, jd, location);
+                if value > 0.0 {
+                    rise.get_or_insert(from_julian_day(t, &tz));
+                } else {
+                    set.get_or_insert(from_julian_day(t, &tz));
+                }
+            }
+            prev = value;
+        }
+        MoonTimes {
+            date,
+            rise,
+            set,
+            always_above: always_above && rise.is_none() && set.is_none(),
+        }
+    }
+
+    // 在升落所在的区间内二分到一秒以内
+    fn bisect(mut lo: f64, mut hi: f64, location: &Location) -> f64 {
+        let rising = Moon::above_horizon(hi, location) > 0.0;
+        while (hi - lo) * 86400.0 > 1.0 {
+            let mid = (lo + hi) / 2.0;
+            if (Moon::above_horizon(mid, location) > 0.0) == rising {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        (lo + hi) / 2.0
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn moonrise(&self) -> Option<DateTime<Utc>> {
+        self.rise
+    }
+
+    pub fn moonset(&self) -> Option<DateTime<Utc>> {
+        self.set
+    }
+
+    // 全天都在地平线上
+    pub fn is_always_above(&self) -> bool {
+        self.always_above
+    }
+
+    // 全天都在地平线下
+    pub fn is_always_below(&self) -> bool {
+        !self.always_above && self.rise.is_none() && self.set.is_none()
+    }
+
+    // 当日月亮在地平线上的时长
+    pub fn duration_above(&self) -> Option<Duration> {
+        match (self.rise, self.set) {
+            (Some(rise), Some(set)) if rise < set => Some(set - rise),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc() -> FixedOffset {
+        FixedOffset::east_opt(0).unwrap()
+    }
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_moon_age() {
+        let tests = vec![
+            // 2024-04-08 18:21 UTC 为朔(日全食)
+            ("new_moon", at(2024, 4, 8, 18, 30), 0.0),
+            ("full_moon", at(2024, 4, 23, 23, 49), 15.23),
+            ("before_new_moon", at(2024, 4, 8, 18, 0), 29.35),
+        ];
+        for (name, t, want) in tests {
+            let age = Moon::new(&t, &utc()).age();
+            assert!((age - want).abs() < 0.1, "{} age {}", name, age);
+        }
+        // 以北京时间表示同一时刻, 月龄不变
+        let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+        let got = Moon::new(&at(2024, 4, 24, 7, 49), &beijing).age();
+        assert!((got - 15.23).abs() < 0.1, "beijing age {}", got);
+    }
+
+    #[test]
+    fn test_moon_phase_and_distance() {
+        let tests = vec![
+            // 2024-10-17 满月距地约 357,400 千米
+            ("supermoon", at(2024, 10, 17, 11, 26), true, false),
+            // 2025-04-13 满月距地约 406,000 千米
+            ("micromoon", at(2025, 4, 13, 0, 22), false, true),
+            // 2024-04-08 为朔, 虽近近地点但不是满月
+            ("new_moon", at(2024, 4, 8, 18, 21), false, false),
+        ];
+        for (name, t, supermoon, micromoon) in tests {
+            let moon = Moon::new(&t, &utc());
+            assert_eq!(moon.is_supermoon(), supermoon, "{} {:?}", name, moon);
+            assert_eq!(moon.is_micromoon(), micromoon, "{} {:?}", name, moon);
+        }
+        let full = Moon::new(&at(2024, 10, 17, 11, 26), &utc());
+        assert!(full.is_full() && full.illumination() > 0.999);
+        assert!((full.distance() - 357_400.0).abs() < 500.0, "{:?}", full);
+        let new = Moon::new(&at(2024, 4, 8, 18, 21), &utc());
+        assert!(
+            new.elongation() < 0.5 || new.elongation() > 359.5,
+            "{:?}",
+            new
+        );
+        assert!(new.illumination() < 0.001);
+    }
+
+    #[test]
+    fn test_moon_horizontal() {
+        // 2024-04-08 日全食期间, 达拉斯看到月亮与太阳重合
+        let dallas = Location::new(32.7767, -96.7970).unwrap();
+        let t = at(2024, 4, 8, 18, 42);
+        let moon = Moon::new(&t, &utc()).horizontal(&dallas);
+        let sun = sun::position(&t, &utc(), &dallas);
+        assert!(
+            (moon.altitude - sun.altitude).abs() < 0.1,
+            "{:?} {:?}",
+            moon,
+            sun
+        );
+        assert!(
+            (moon.azimuth - sun.azimuth).abs() < 0.2,
+            "{:?} {:?}",
+            moon,
+            sun
+        );
+    }
+
+    #[test]
+    fn test_moon_times() {
+        let beijing = Location::new(39.9042, 116.4074).unwrap();
+        let tz = FixedOffset::east_opt(8 * 3600).unwrap();
+        let date = |d| NaiveDate::from_ymd_opt(2024, 10, d).unwrap();
+
+        // 满月前后月亮约在日落时升起
+        let full = MoonTimes::new(date(17), &beijing, tz);
+        let sunset = sun::SunTimes::new(date(17), &beijing, tz).sunset().unwrap();
+        let rise = full.moonrise().unwrap();
+        assert!(
+            (rise - sunset).num_minutes().abs() < 60,
+            "{} {}",
+            rise,
+            sunset
+        );
+        // 升起时上缘与地平相切, 未计折射的月心约在地平线下 0.83°
+        let altitude = Moon::new(&rise, &tz).horizontal(&beijing).altitude;
+        assert!((altitude + 0.83).abs() < 0.1, "altitude {}", altitude);
+
+        // 一个月内恰有一天没有月出, 一天没有月落
+        let days: Vec<_> = (1..=31)
+            .map(|d| MoonTimes::new(date(d), &beijing, tz))
+            .collect();
+        assert_eq!(days.iter().filter(|d| d.moonrise().is_none()).count(), 1);
+        assert_eq!(days.iter().filter(|d| d.moonset().is_none()).count(), 1);
+        // 月出每天推迟
+        let (a, b) = (days[0].moonrise().unwrap(), days[1].moonrise().unwrap());
+        assert!(b - a > Duration::hours(24) + Duration::minutes(20));
+
+        // 高纬度地区会出现月亮整日不落或不升
+        let tromso = Location::new(69.6496, 18.9560).unwrap();
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        let days: Vec<_> = (1..=28)
+            .map(|d| MoonTimes::new(NaiveDate::from_ymd_opt(2024, 2, d).unwrap(), &tromso, cet))
+            .collect();
+        assert!(days.iter().any(|d| d.is_always_above()));
+        assert!(days.iter().any(|d| d.is_always_below()));
+    }
+}
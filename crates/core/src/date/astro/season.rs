@@ -0,0 +1,367 @@
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Utc};
+
+use crate::{
+    date::chinese::solarterm::{self, Solarterm, SOLARTERM_FROM_YEAR},
+    ikigai_error::IkigaiResult,
+};
+
+// 春分、夏至、秋分、冬至在一年节气中的序号(小寒为 0)
+const MARCH_EQUINOX: i64 = 5;
+const JUNE_SOLSTICE: i64 = 11;
+const SEPTEMBER_EQUINOX: i64 = 17;
+const DECEMBER_SOLSTICE: i64 = 23;
+// 节气表采用北京时间
+const BEIJING_OFFSET: i32 = 8 * 3600;
+
+// 南北半球, 赤道归入北半球
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Hemisphere {
+    #[default]
+    Northern,
+    Southern,
+}
+
+impl Hemisphere {
+    pub fn from_latitude(latitude: f64) -> Hemisphere {
+        if latitude < 0.0 {
+            Hemisphere::Southern
+        } else {
+            Hemisphere::Northern
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub const ALL: [Season; 4] = [
+        Season::Spring,
+        Season::Summer,
+        Season::Autumn,
+        Season::Winter,
+    ];
+
+    pub fn alias(&self) -> &'static str {
+        match self {
+            Season::Spring => "春",
+            Season::Summer => "夏",
+            Season::Autumn => "秋",
+            Season::Winter => "冬",
+        }
+    }
+
+    pub fn next(&self) -> Season {
+        Self::ALL[(*self as usize + 1) % 4]
+    }
+
+    // 另一半球同一时刻的季节
+    pub fn opposite(&self) -> Season {
+        Self::ALL[(*self as usize + 2) % 4]
+    }
+
+    // 天文季节: 以分至点为界, t 为北京时间
+    pub fn astronomical(t: &DateTime<Utc>, hemisphere: Hemisphere) -> IkigaiResult<Season> {
+        Self::astronomical_in(t, beijing(), hemisphere)
+    }
+
+    // 天文季节, t 为时区 tz 的当地时间
+    pub fn astronomical_in(
+        t: &DateTime<Utc>,
+        tz: FixedOffset,
+        hemisphere: Hemisphere,
+    ) -> IkigaiResult<Season> {
+        let seasons = Seasons::new_in(t.year() as i64, tz)?;
+        // 春分之前仍是上一年冬至开始的季节
+        let season = Season::ALL
+            .into_iter()
+            .rev()
+            .find(|&season| seasons.start(season, Hemisphere::Northern) <= *t)
+            .unwrap_or(Season::Winter);
+        Ok(match hemisphere {
+            Hemisphere::Northern => season,
+            Hemisphere::Southern => season.opposite(),
+        })
+    }
+
+    // 气象季节: 北半球以 3-5 月为春, 6-8 月为夏, 9-11 月为秋, 12-2 月为冬
+    pub fn meteorological(date: &impl Datelike, hemisphere: Hemisphere) -> Season {
+        let season = Self::ALL[(date.month0() as usize + 10) / 3 % 4];
+        match hemisphere {
+            Hemisphere::Northern => season,
+            Hemisphere::Southern => season.opposite(),
+        }
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+fn beijing() -> FixedOffset {
+    FixedOffset::east_opt(BEIJING_OFFSET).unwrap()
+}
+
+// 某年的两分两至, 取自节气表, 时刻为时区 tz 的当地时间
+#[derive(Debug, Clone, PartialEq)]
+pub struct Seasons {
+    year: i64,
+    tz: FixedOffset,
+    // 当年的春分、夏至、秋分、冬至
+    instants: [DateTime<Utc>; 4],
+}
+
+impl Seasons {
+    // 北京时间的两分两至
+    pub fn new(year: i64) -> IkigaiResult<Self> {
+        Self::new_in(year, beijing())
+    }
+
+    pub fn new_in(year: i64, tz: FixedOffset) -> IkigaiResult<Self> {
+        solarterm::check_year(year)?;
+        let shift = Duration::seconds((tz.local_minus_utc() - BEIJING_OFFSET) as i64);
+        let instant = |k: i64| -> IkigaiResult<DateTime<Utc>> {
+            let term = Solarterm::new(24 * (year - SOLARTERM_FROM_YEAR) + k)?;
+            Ok(term.time()? + shift)
+        };
+        Ok(Seasons {
+            year,
+            tz,
+            instants: [
+                instant(MARCH_EQUINOX)?,
+                instant(JUNE_SOLSTICE)?,
+                instant(SEPTEMBER_EQUINOX)?,
+                instant(DECEMBER_SOLSTICE)?,
+            ],
+        })
+    }
+
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    pub fn march_equinox(&self) -> DateTime<Utc> {
+        self.instants[0]
+    }
+
+    pub fn june_solstice(&self) -> DateTime<Utc> {
+        self.instants[1]
+    }
+
+    pub fn september_equinox(&self) -> DateTime<Utc> {
+        self.instants[2]
+    }
+
+    pub fn december_solstice(&self) -> DateTime<Utc> {
+        self.instants[3]
+    }
+
+    // 按时间先后排列的两分两至
+    pub fn instants(&self) -> [DateTime<Utc>; 4] {
+        self.instants
+    }
+
+    // 当年某季开始的时刻, 南半球的春季始于秋分
+    pub fn start(&self, season: Season, hemisphere: Hemisphere) -> DateTime<Utc> {
+        self.instants[Self::position(season, hemisphere)]
+    }
+
+    // 当年开始的某季的长度, 北半球冬季延续到次年春分, 次年须在节气表内
+    pub fn length(&self, season: Season, hemisphere: Hemisphere) -> IkigaiResult<Duration> {
+        let i = Self::position(season, hemisphere);
+        let end = match self.instants.get(i + 1) {
+            Some(&end) => end,
+            None => Seasons::new_in(self.year + 1, self.tz)?.march_equinox(),
+        };
+        Ok(end - self.instants[i])
+    }
+
+    fn position(season: Season, hemisphere: Hemisphere) -> usize {
+        match hemisphere {
+            Hemisphere::Northern => season as usize,
+            Hemisphere::Southern => season.opposite() as usize,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone};
+
+    use super::*;
+    use crate::ikigai_error::IkigaiError;
+
+    fn fmt(t: DateTime<Utc>) -> String {
+        t.format("%Y-%m-%d %H:%M").to_string()
+    }
+
+    #[test]
+    fn test_seasons() {
+        let seasons = Seasons::new(2024).unwrap();
+        let got: Vec<String> = seasons.instants().into_iter().map(fmt).collect();
+        assert_eq!(
+            got,
+            vec![
+                "2024-03-20 11:06",
+                "2024-06-21 04:50",
+                "2024-09-22 20:43",
+                "2024-12-21 17:20",
+            ]
+        );
+        assert_eq!(seasons.year(), 2024);
+        assert_eq!(fmt(seasons.june_solstice()), "2024-06-21 04:50");
+
+        // 换算到 UTC, 三月分点为 03:06
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let seasons = Seasons::new_in(2024, utc).unwrap();
+        assert_eq!(fmt(seasons.march_equinox()), "2024-03-20 03:06");
+        assert_eq!(fmt(seasons.december_solstice()), "2024-12-21 09:20");
+
+        assert!(Seasons::new(1800).is_err());
+    }
+
+    #[test]
+    fn test_seasons_range() {
+        // 2200 年之后的节气表与前后衔接
+        let north = Hemisphere::Northern;
+        let seasons = Seasons::new(2201).unwrap();
+        for instant in seasons.instants() {
+            assert_eq!(instant.year(), 2201);
+        }
+        let days = seasons.length(Season::Spring, north).unwrap().num_days();
+        assert!((92..=93).contains(&days));
+        let t = Utc.with_ymd_and_hms(2201, 6, 1, 0, 0, 0).unwrap();
+        assert_eq!(Season::astronomical(&t, north), Ok(Season::Spring));
+
+        // 节气表的最后一年, 两分两至都在当年, 但冬季延续到表外
+        let seasons = Seasons::new(solarterm::SOLARTERM_TO_YEAR).unwrap();
+        for instant in seasons.instants() {
+            assert_eq!(instant.year() as i64, solarterm::SOLARTERM_TO_YEAR);
+        }
+        assert_eq!(seasons.march_equinox().month(), 3);
+        assert_eq!(seasons.december_solstice().month(), 12);
+        assert!(seasons.length(Season::Autumn, north).is_ok());
+        assert!(seasons.length(Season::Winter, north).is_err());
+
+        let year = solarterm::SOLARTERM_TO_YEAR + 1;
+        assert_eq!(
+            Seasons::new(year),
+            Err(IkigaiError::YearOutOfRange {
+                year,
+                min: SOLARTERM_FROM_YEAR,
+                max: solarterm::SOLARTERM_TO_YEAR,
+            })
+        );
+    }
+
+    #[test]
+    fn test_season_start_and_length() {
+        let seasons = Seasons::new(2024).unwrap();
+        let (north, south) = (Hemisphere::Northern, Hemisphere::Southern);
+        assert_eq!(
+            seasons.start(Season::Spring, north),
+            seasons.march_equinox()
+        );
+        assert_eq!(
+            seasons.start(Season::Spring, south),
+            seasons.september_equinox()
+        );
+        assert_eq!(
+            seasons.start(Season::Summer, south),
+            seasons.december_solstice()
+        );
+
+        // 北半球夏季最长(近日点在一月), 冬季最短
+        let days = |season| seasons.length(season, north).unwrap().num_minutes() as f64 / 1440.0;
+        let tests = vec![
+            (Season::Spring, 92.76),
+            (Season::Summer, 93.65),
+            (Season::Autumn, 89.85),
+            (Season::Winter, 88.99),
+        ];
+        for (season, want) in tests {
+            assert!(
+                (days(season) - want).abs() < 0.05,
+                "{} {}",
+                season,
+                days(season)
+            );
+        }
+        assert_eq!(
+            seasons.length(Season::Winter, south).unwrap(),
+            seasons.length(Season::Summer, north).unwrap()
+        );
+        let total: Duration = Season::ALL
+            .iter()
+            .map(|&s| seasons.length(s, north).unwrap())
+            .sum();
+        assert!((total.num_minutes() as f64 / 1440.0 - 365.24).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_astronomical_season() {
+        let at = |y, m, d, h, min| Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap();
+        let tests = vec![
+            ("new_year", at(2024, 1, 1, 0, 0), Season::Winter),
+            ("before_equinox", at(2024, 3, 20, 11, 5), Season::Winter),
+            ("after_equinox", at(2024, 3, 20, 11, 7), Season::Spring),
+            ("summer", at(2024, 7, 1, 0, 0), Season::Summer),
+            ("autumn", at(2024, 10, 1, 0, 0), Season::Autumn),
+            ("winter", at(2024, 12, 25, 0, 0), Season::Winter),
+        ];
+        for (name, t, want) in tests {
+            let got = Season::astronomical(&t, Hemisphere::Northern).unwrap();
+            assert_eq!(got, want, "{} failed", name);
+            let got = Season::astronomical(&t, Hemisphere::Southern).unwrap();
+            assert_eq!(got, want.opposite(), "{} southern failed", name);
+        }
+        // 悉尼(UTC+10) 2024-03-20 13:05 尚未过分点, 仍是夏季
+        let sydney = FixedOffset::east_opt(10 * 3600).unwrap();
+        let south = Hemisphere::from_latitude(-33.87);
+        let got = Season::astronomical_in(&at(2024, 3, 20, 13, 5), sydney, south).unwrap();
+        assert_eq!(got, Season::Summer);
+        let got = Season::astronomical_in(&at(2024, 3, 20, 13, 7), sydney, south).unwrap();
+        assert_eq!(got, Season::Autumn);
+    }
+
+    #[test]
+    fn test_meteorological_season() {
+        let tests = vec![
+            (1, Season::Winter),
+            (2, Season::Winter),
+            (3, Season::Spring),
+            (5, Season::Spring),
+            (6, Season::Summer),
+            (8, Season::Summer),
+            (9, Season::Autumn),
+            (11, Season::Autumn),
+            (12, Season::Winter),
+        ];
+        for (month, want) in tests {
+            let date = NaiveDate::from_ymd_opt(2024, month, 15).unwrap();
+            assert_eq!(
+                Season::meteorological(&date, Hemisphere::Northern),
+                want,
+                "month {}",
+                month
+            );
+            assert_eq!(
+                Season::meteorological(&date, Hemisphere::Southern),
+                want.opposite(),
+                "southern month {}",
+                month
+            );
+        }
+        assert_eq!(Season::Winter.next(), Season::Spring);
+        assert_eq!(Season::Summer.alias(), "夏");
+    }
+}
@@ -0,0 +1,311 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+
+use super::{
+    ecliptic_to_equatorial, from_julian_day, julian_century, julian_day, location::Location,
+    sidereal_time, Crossing, Horizontal, SUNRISE_ALTITUDE,
+};
+
+// 恒星时每日转过的角度
+const SIDEREAL_DEGREES_PER_DAY: f64 = 360.98564736629;
+
+// 太阳的视黄经(度)
+pub(crate) fn apparent_longitude(jd: f64) -> f64 {
+    let t = julian_century(jd);
+    let l0 = 280.46646 + 36000.76983 * t + 0.0003032 * t * t;
+    let m = (357.52911 + 35999.05029 * t - 0.0001537 * t * t).to_radians();
+    let c = (1.914602 - 0.004817 * t - 0.000014 * t * t) * m.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * m).sin()
+        + 0.000289 * (3.0 * m).sin();
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    (l0 + c - 0.00569 - 0.00478 * omega.sin()).rem_euclid(360.0)
+}
+
+// 日地距离(天文单位)
+pub(crate) fn distance(jd: f64) -> f64 {
+    let t = julian_century(jd);
+    let m = (357.52911 + 35999.05029 * t - 0.0001537 * t * t).to_radians();
+    let e = 0.016708634 - 0.000042037 * t - 0.0000001267 * t * t;
+    let c = (1.914602 - 0.004817 * t - 0.000014 * t * t) * m.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * m).sin()
+        + 0.000289 * (3.0 * m).sin();
+    1.000001018 * (1.0 - e * e) / (1.0 + e * (m + c.to_radians()).cos())
+}
+
+// 太阳的赤经、赤纬(度)
+pub(crate) fn equatorial(jd: f64) -> (f64, f64) {
+    ecliptic_to_equatorial(apparent_longitude(jd), 0.0, jd)
+}
+
+// 太阳在某地某时的地平坐标, t 为时区 tz 的当地时间
+pub fn position(t: &DateTime<Utc>, tz: &FixedOffset, location: &Location) -> Horizontal {
+    let jd = julian_day(t, tz);
+    let (ra, dec) = equatorial(jd);
+    Horizontal::from_equatorial(ra, dec, jd, location)
+}
+
+// 太阳的时角(度), 归入 (-180, 180]
+fn hour_angle(jd: f64, location: &Location) -> f64 {
+    let (ra, _) = equatorial(jd);
+    let h = (sidereal_time(jd) + location.longitude() - ra).rem_euclid(360.0);
+    if h > 180.0 {
+        h - 360.0
+    } else {
+        h
+    }
+}
+
+// 太阳中心到达某一高度角时的时角(度); 全天在其上返回 Err(true), 全天在其下返回 Err(false)
+fn crossing_hour_angle(dec: f64, altitude: f64, location: &Location) -> Result<f64, bool> {
+    let (lat, dec) = (location.latitude().to_radians(), dec.to_radians());
+    let cos_h = (altitude.to_radians().sin() - lat.sin() * dec.sin()) / (lat.cos() * dec.cos());
+    if cos_h < -1.0 {
+        Err(true)
+    } else if cos_h > 1.0 {
+        Err(false)
+    } else {
+        Ok(cos_h.acos().to_degrees())
+    }
+}
+
+// 晨昏蒙影的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Twilight {
+    // 民用晨昏蒙影, 太阳在地平线下 6°
+    Civil,
+    // 航海晨昏蒙影, 地平线下 12°
+    Nautical,
+    // 天文晨昏蒙影, 地平线下 18°
+    Astronomical,
+}
+
+impl Twilight {
+    pub const ALL: [Twilight; 3] = [Twilight::Civil, Twilight::Nautical, Twilight::Astronomical];
+
+    // 太阳中心的高度角(度)
+    pub fn altitude(&self) -> f64 {
+        match self {
+            Twilight::Civil => -6.0,
+            Twilight::Nautical => -12.0,
+            Twilight::Astronomical => -18.0,
+        }
+    }
+}
+
+// 某地某日的日出、日落、正午与晨昏蒙影, 时刻均为时区 tz 的当地时间
+#[derive(Debug, Clone, PartialEq)]
+pub struct SunTimes {
+    date: NaiveDate,
+    solar_noon: DateTime<Utc>,
+    daylight: Crossing,
+    twilights: [Crossing; 3],
+}
+
+impl SunTimes {
+    pub fn new(date: NaiveDate, location: &Location, tz: FixedOffset) -> SunTimes {
+        let noon = Self::transit(date, location, &tz);
+        let sunrise = SUNRISE_ALTITUDE - location.horizon_dip();
+        SunTimes {
+            date,
+            solar_noon: from_julian_day(noon, &tz),
+            daylight: Self::crossing(noon, sunrise, location, &tz),
+            twilights: Twilight::ALL
+                .map(|kind| Self::crossing(noon, kind.altitude(), location, &tz)),
+        }
+    }
+
+    // 太阳上中天的儒略日, 自当地正午起按时角迭代
+    fn transit(date: NaiveDate, location: &Location, tz: &FixedOffset) -> f64 {
+        let t = Utc.from_utc_datetime(&date.and_hms_opt(12, 0, 0).unwrap());
+        let mut jd = julian_day(&t, tz);
+        for _ in 0..3 {
+            jd -= hour_angle(jd, location) / SIDEREAL_DEGREES_PER_DAY;
+        }
+        jd
+    }
+
+    // 太阳经过某一高度角的升落, 极昼极夜按正午的赤纬判断
+    fn crossing(noon: f64, altitude: f64, location: &Location, tz: &FixedOffset) -> Crossing {
+        let (_, dec) = equatorial(noon);
+        let h = match crossing_hour_angle(dec, altitude, location) {
+            Ok(h) => h,
+            Err(true) => return Crossing::AlwaysAbove,
+            Err(false) => return Crossing::AlwaysBelow,
+        };
+        let event = |sign: f64| {
+            let mut jd = noon + sign * h / SIDEREAL_DEGREES_PER_DAY;
+            for _ in 0..3 {
+                let (_, dec) = equatorial(jd);
+                // 临近极昼极夜时升落时刻的赤纬可能越界, 此时取最接近的时角
+                let target = match crossing_hour_angle(dec, altitude, location) {
+                    Ok(h) => h,
+                    Err(true) => 180.0,
+                    Err(false) => 0.0,
+                };
+                jd += (sign * target - hour_angle(jd, location)) / SIDEREAL_DEGREES_PER_DAY;
+            }
+            from_julian_day(jd, tz)
+        };
+        Crossing::RiseSet {
+            rise: event(-1.0),
+            set: event(1.0),
+        }
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    // 太阳上中天(真太阳时正午)
+    pub fn solar_noon(&self) -> DateTime<Utc> {
+        self.solar_noon
+    }
+
+    pub fn sunrise(&self) -> Option<DateTime<Utc>> {
+        self.daylight.rise()
+    }
+
+    pub fn sunset(&self) -> Option<DateTime<Utc>> {
+        self.daylight.set()
+    }
+
+    pub fn daylight(&self) -> Crossing {
+        self.daylight
+    }
+
+    // 昼长, 极昼为 24 小时, 极夜为 0
+    pub fn day_length(&self) -> Duration {
+        self.daylight.duration()
+    }
+
+    // 晨昏蒙影: rise 为晨光始, set 为昏影终
+    pub fn twilight(&self, kind: Twilight) -> Crossing {
+        self.twilights[kind as usize]
+    }
+
+    pub fn dawn(&self, kind: Twilight) -> Option<DateTime<Utc>> {
+        self.twilight(kind).rise()
+    }
+
+    pub fn dusk(&self, kind: Twilight) -> Option<DateTime<Utc>> {
+        self.twilight(kind).set()
+    }
+
+    pub fn is_polar_day(&self) -> bool {
+        self.daylight == Crossing::AlwaysAbove
+    }
+
+    pub fn is_polar_night(&self) -> bool {
+        self.daylight == Crossing::AlwaysBelow
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn hm(t: Option<DateTime<Utc>>) -> String {
+        t.map(|t| t.format("%H:%M").to_string()).unwrap_or_default()
+    }
+
+    fn tz(hours: i32) -> FixedOffset {
+        FixedOffset::east_opt(hours * 3600).unwrap()
+    }
+
+    #[test]
+    fn test_sun_times() {
+        let beijing = Location::new(39.9042, 116.4074).unwrap();
+        let tests = vec![
+            (
+                "summer_solstice",
+                date(2024, 6, 21),
+                ("04:46", "12:16", "19:46"),
+            ),
+            (
+                "winter_solstice",
+                date(2024, 12, 21),
+                ("07:32", "12:12", "16:52"),
+            ),
+            ("equinox", date(2024, 3, 20), ("06:17", "12:21", "18:26")),
+        ];
+        for (name, d, (rise, noon, set)) in tests {
+            let times = SunTimes::new(d, &beijing, tz(8));
+            assert_eq!(times.date(), d);
+            assert_eq!(hm(times.sunrise()), rise, "{} sunrise", name);
+            assert_eq!(hm(Some(times.solar_noon())), noon, "{} noon", name);
+            assert_eq!(hm(times.sunset()), set, "{} sunset", name);
+        }
+    }
+
+    #[test]
+    fn test_twilight() {
+        let beijing = Location::new(39.9042, 116.4074).unwrap();
+        let times = SunTimes::new(date(2024, 6, 21), &beijing, tz(8));
+        let mut prev = times.sunrise().unwrap();
+        for kind in Twilight::ALL {
+            let dawn = times.dawn(kind).unwrap();
+            assert!(dawn < prev, "{:?} dawn", kind);
+            assert!(times.dusk(kind).unwrap() > times.sunset().unwrap());
+            prev = dawn;
+        }
+        assert_eq!(hm(times.dawn(Twilight::Civil)), "04:13");
+        assert_eq!(hm(times.dusk(Twilight::Civil)), "20:19");
+    }
+
+    #[test]
+    fn test_polar_day_and_night() {
+        let tromso = Location::new(69.6496, 18.9560).unwrap();
+        let summer = SunTimes::new(date(2024, 6, 21), &tromso, tz(2));
+        assert!(summer.is_polar_day());
+        assert_eq!(summer.sunrise(), None);
+        assert_eq!(summer.day_length(), Duration::days(1));
+
+        let winter = SunTimes::new(date(2024, 12, 21), &tromso, tz(1));
+        assert!(winter.is_polar_night());
+        assert_eq!(winter.day_length(), Duration::zero());
+        // 极夜时仍有民用晨昏蒙影
+        assert!(winter.dawn(Twilight::Civil).is_some());
+
+        // 夏至前后高纬度地区整夜不会进入天文晨昏蒙影以下
+        let london = Location::new(51.5074, -0.1278).unwrap();
+        let june = SunTimes::new(date(2024, 6, 21), &london, tz(1));
+        assert_eq!(june.twilight(Twilight::Astronomical), Crossing::AlwaysAbove);
+    }
+
+    #[test]
+    fn test_day_length_and_elevation() {
+        let equator = Location::new(0.0, 0.0).unwrap();
+        let length = SunTimes::new(date(2024, 3, 20), &equator, tz(0)).day_length();
+        assert!((length.num_minutes() - 727).abs() <= 2, "{}", length);
+
+        let sea = SunTimes::new(date(2024, 6, 21), &equator, tz(0));
+        let peak = Location::with_elevation(0.0, 0.0, 2000.0).unwrap();
+        let high = SunTimes::new(date(2024, 6, 21), &peak, tz(0));
+        assert!(high.sunrise().unwrap() < sea.sunrise().unwrap());
+        assert!(high.day_length() > sea.day_length());
+    }
+
+    #[test]
+    fn test_sun_position() {
+        let beijing = Location::new(39.9042, 116.4074).unwrap();
+        let times = SunTimes::new(date(2024, 6, 21), &beijing, tz(8));
+        let noon = position(&times.solar_noon(), &tz(8), &beijing);
+        // 夏至正午高度角约为 90 - 39.9 + 23.44
+        assert!((noon.altitude - 73.53).abs() < 0.05, "{:?}", noon);
+        assert!((noon.azimuth - 180.0).abs() < 0.5, "{:?}", noon);
+        let sunrise = position(&times.sunrise().unwrap(), &tz(8), &beijing);
+        assert!(
+            (sunrise.altitude - SUNRISE_ALTITUDE).abs() < 0.05,
+            "{:?}",
+            sunrise
+        );
+        assert!(
+            sunrise.azimuth > 55.0 && sunrise.azimuth < 65.0,
+            "{:?}",
+            sunrise
+        );
+    }
+}
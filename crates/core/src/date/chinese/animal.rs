@@ -1,6 +1,10 @@
+use super::{locale::Locale, variant::CalendarVariant};
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct Animal {
     order: i64,
+    variant: CalendarVariant,
 }
 
 const ANIMAL_ALIAS: [&str; 12] = [
@@ -8,17 +12,45 @@ const ANIMAL_ALIAS: [&str; 12] = [
 ];
 
 impl Animal {
-    pub fn new(order: i64) -> Option<Animal> {
+    pub fn new(order: i64) -> IkigaiResult<Animal> {
+        Self::new_in(order, CalendarVariant::Chinese)
+    }
+
+    // 指定历法变体的生肖, 越南以猫代兔
+    pub fn new_in(order: i64, variant: CalendarVariant) -> IkigaiResult<Animal> {
         if !Self::is_supported(order) {
-            return None;
+            return Err(IkigaiError::OrderOutOfRange {
+                name: "Animal",
+                order,
+                max: 12,
+            });
         }
-        Some(Animal { order })
+        Ok(Animal { order, variant })
     }
 
     pub fn alias(&self) -> &str {
+        if self.is_cat() {
+            return Locale::ZhHans.cat();
+        }
         ANIMAL_ALIAS[((self.order - 1) % 12) as usize]
     }
 
+    // 指定语言的生肖名称
+    pub fn name(&self, locale: Locale) -> &'static str {
+        if self.is_cat() {
+            return locale.cat();
+        }
+        locale.animal(self.order)
+    }
+
+    pub fn order(&self) -> i64 {
+        self.order
+    }
+
+    fn is_cat(&self) -> bool {
+        self.variant == CalendarVariant::Vietnamese && self.order == 4
+    }
+
     fn is_supported(order: i64) -> bool {
         (1..=12).contains(&order)
     }
@@ -29,10 +61,10 @@ mod tests {
 
     #[test]
     fn test_new_animal() {
-        assert!(Animal::new(1).is_some());
-        assert!(Animal::new(12).is_some());
-        assert!(Animal::new(13).is_none());
-        assert!(Animal::new(0).is_none());
+        assert!(Animal::new(1).is_ok());
+        assert!(Animal::new(12).is_ok());
+        assert!(Animal::new(13).is_err());
+        assert!(Animal::new(0).is_err());
     }
 
     #[test]
@@ -44,6 +76,17 @@ mod tests {
         assert_eq!(animal_12.alias(), "猪");
 
         let animal_13 = Animal::new(13);
-        assert!(animal_13.is_none());
+        assert!(animal_13.is_err());
+    }
+
+    #[test]
+    fn test_vietnamese_animal() {
+        let cat = Animal::new_in(4, CalendarVariant::Vietnamese).unwrap();
+        assert_eq!(cat.alias(), "猫");
+        assert_eq!(cat.name(Locale::Vi), "Mèo");
+        assert_eq!(Animal::new(4).unwrap().name(Locale::Vi), "Thỏ");
+
+        let dragon = Animal::new_in(5, CalendarVariant::Vietnamese).unwrap();
+        assert_eq!(dragon.name(Locale::Vi), "Rồng");
     }
 }
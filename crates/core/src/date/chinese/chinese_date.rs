@@ -0,0 +1,204 @@
+use std::fmt::{self, Write};
+
+use chrono::{DateTime, Utc};
+
+use super::{gan_zhi::Ganzhi, lunar::Lunar, solar::Solar, solarterm::Solarterm};
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// Display 使用的格式
+pub const DEFAULT_FORMAT: &str = "%Y-%m-%d 农历%LM%LD %GY年 %GM月 %GD日";
+
+// 同一时刻的公历、农历与干支
+#[derive(Debug, PartialEq)]
+pub struct ChineseDate {
+    t: DateTime<Utc>,
+    solar: Solar,
+    lunar: Lunar,
+    ganzhi: Ganzhi,
+}
+
+impl ChineseDate {
+    pub fn new(t: DateTime<Utc>) -> IkigaiResult<Self> {
+        Ok(ChineseDate {
+            t,
+            solar: Solar::new(t)?,
+            lunar: Lunar::new(t)?,
+            ganzhi: Ganzhi::new(t)?,
+        })
+    }
+
+    pub fn time(&self) -> DateTime<Utc> {
+        self.t
+    }
+
+    pub fn solar(&self) -> &Solar {
+        &self.solar
+    }
+
+    pub fn lunar(&self) -> &Lunar {
+        &self.lunar
+    }
+
+    pub fn ganzhi(&self) -> &Ganzhi {
+        &self.ganzhi
+    }
+
+    // 当天所处的节气: 当天交节则为该节气, 否则为上一个节气
+    pub fn solarterm(&self) -> &Solarterm {
+        self.solar
+            .current_solarterm()
+            .unwrap_or_else(|| self.solar.prev_solarterm())
+    }
+
+    // 按格式输出, 除下列农历说明符外, 其余交给 chrono 的 strftime 处理:
+    //
+    // %LY 农历年(二零二四)   %Ly 农历年(2024)
+    // %LM 农历月(闰二月)     %Lm 农历月(2)
+    // %LD 农历日(初一)       %Ld 农历日(1)
+    // %GY %GM %GD %GH 年、月、日、时柱(甲辰)
+    // %ST 节气(立春)         %Z 生肖(龙)
+    // %DY 韩国檀纪年(4357)
+    pub fn format(&self, spec: &str) -> IkigaiResult<String> {
+        let mut out = String::new();
+        // 尚未交给 chrono 的部分
+        let mut pending = String::new();
+        let mut rest = spec;
+        while let Some(pos) = rest.find('%') {
+            pending.push_str(&rest[..pos]);
+            rest = &rest[pos..];
+            if let Some((value, len)) = self.specifier(&rest[1..]) {
+                self.flush(&mut out, &mut pending)?;
+                out.push_str(&value);
+                rest = &rest[1 + len..];
+            } else {
+                // 原样保留 `%%` 及 chrono 的说明符
+                let len = if rest.starts_with("%%") { 2 } else { 1 };
+                pending.push_str(&rest[..len]);
+                rest = &rest[len..];
+            }
+        }
+        pending.push_str(rest);
+        self.flush(&mut out, &mut pending)?;
+        Ok(out)
+    }
+
+    // 农历说明符(不含 `%`)的取值及长度
+    fn specifier(&self, s: &str) -> Option<(String, usize)> {
+        let lunar = &self.lunar;
+        if s.starts_with('Z') {
+            return Some((lunar.animal().alias().to_string(), 1));
+        }
+        let value = match s.get(..2)? {
+            "LY" => lunar.year_alias(),
+            "Ly" => lunar.get_year().to_string(),
+            "LM" => lunar.month_alias(),
+            "Lm" => lunar.get_month().to_string(),
+            "LD" => lunar.day_alias(),
+            "Ld" => lunar.get_day().to_string(),
+            "GY" => self.ganzhi.year_pillar().to_string(),
+            "GM" => self.ganzhi.month_pillar().to_string(),
+            "GD" => self.ganzhi.day_pillar().to_string(),
+            "GH" => self.ganzhi.hour_pillar().to_string(),
+            "ST" => self.solarterm().alias().to_string(),
+            "DY" => self.solar.dangi_year().to_string(),
+            _ => return None,
+        };
+        Some((value, 2))
+    }
+
+    fn flush(&self, out: &mut String, pending: &mut String) -> IkigaiResult<()> {
+        if pending.is_empty() {
+            return Ok(());
+        }
+        write!(out, "{}", self.t.format(pending))
+            .map_err(|_| IkigaiError::InvalidFormat(pending.clone()))?;
+        pending.clear();
+        Ok(())
+    }
+}
+
+impl fmt::Display for ChineseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(DEFAULT_FORMAT).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_chinese_date_format() {
+        let t = Utc.with_ymd_and_hms(2024, 2, 10, 9, 30, 0).unwrap();
+        let date = ChineseDate::new(t).unwrap();
+
+        let tests = vec![
+            ("lunar", "%LY年%LM%LD", "二零二四年正月初一"),
+            ("lunar_numeric", "%Ly-%Lm-%Ld", "2024-1-1"),
+            (
+                "pillars",
+                "%GY年%GM月%GD日%GH时",
+                "甲辰年丙寅月甲辰日己巳时",
+            ),
+            ("solarterm", "%ST", "立春"),
+            ("animal", "%Z年", "龙年"),
+            ("dangi", "단기 %DY년", "단기 4357년"),
+            ("chrono", "%Y-%m-%d %H:%M", "2024-02-10 09:30"),
+            ("mixed", "%m月%d日 %LM%LD(%a)", "02月10日 正月初一(Sat)"),
+            ("percent", "100%% %%LY", "100% %LY"),
+            ("plain", "农历", "农历"),
+            ("empty", "", ""),
+        ];
+        for (name, spec, want) in tests {
+            assert_eq!(date.format(spec).unwrap(), want, "{} failed", name);
+        }
+    }
+
+    #[test]
+    fn test_chinese_date_format_error() {
+        let t = Utc.with_ymd_and_hms(2024, 2, 10, 0, 0, 0).unwrap();
+        let date = ChineseDate::new(t).unwrap();
+        assert!(matches!(
+            date.format("%LY %Q"),
+            Err(IkigaiError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_chinese_date_solarterm() {
+        let tests = vec![
+            (
+                "on_lichun",
+                Utc.with_ymd_and_hms(2024, 2, 4, 0, 0, 0).unwrap(),
+                "立春",
+            ),
+            (
+                "after_lichun",
+                Utc.with_ymd_and_hms(2024, 2, 10, 0, 0, 0).unwrap(),
+                "立春",
+            ),
+            (
+                "on_yushui",
+                Utc.with_ymd_and_hms(2024, 2, 19, 0, 0, 0).unwrap(),
+                "雨水",
+            ),
+        ];
+        for (name, t, want) in tests {
+            let date = ChineseDate::new(t).unwrap();
+            assert_eq!(date.solarterm().alias(), want, "{} failed", name);
+        }
+    }
+
+    #[test]
+    fn test_chinese_date_display() {
+        let t = Utc.with_ymd_and_hms(2024, 2, 10, 0, 0, 0).unwrap();
+        let date = ChineseDate::new(t).unwrap();
+        assert_eq!(
+            date.to_string(),
+            "2024-02-10 农历正月初一 甲辰年 丙寅月 甲辰日"
+        );
+        assert!(ChineseDate::new(Utc.with_ymd_and_hms(1800, 1, 1, 0, 0, 0).unwrap()).is_err());
+    }
+}
@@ -1,41 +1,109 @@
-use chrono::{DateTime, Datelike, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, Utc};
+
+use super::{
+    locale::Locale,
+    solarterm::{self, Solarterm, SOLARTERM_FROM_YEAR},
+    zodiac_sign::ZodiacSign,
+    zodiac_system::{ZodiacSystem, OPHIUCHUS_ORDER},
+};
+use crate::{
+    date::astro::{julian_day, sun::apparent_longitude},
+    ikigai_error::IkigaiResult,
+};
+
+// 春分(白羊 0°)在一年节气中的序号, 其后每隔一个中气进入下一星座
+const ARIES_SOLARTERM: i64 = 5;
+// 星座时刻按北京时间(UTC+8)计
+const BEIJING_OFFSET: i32 = 8 * 3600;
+
 #[derive(Debug, PartialEq)]
 pub struct Constellation {
     t: DateTime<Utc>,
+    system: ZodiacSystem,
 }
 
 impl Constellation {
     pub fn new(t: DateTime<Utc>) -> Constellation {
-        Constellation { t }
-    }
-
-    pub fn alias(&self) -> Option<&'static str> {
-        match (self.t.month(), self.t.day()) {
-            (1, day) if day >= 20 => Some("水瓶"),
-            (2, day) if day <= 18 => Some("水瓶"),
-            (2, day) if day >= 19 => Some("双鱼"),
-            (3, day) if day <= 20 => Some("双鱼"),
-            (3, day) if day >= 21 => Some("白羊"),
-            (4, day) if day <= 19 => Some("白羊"),
-            (4, day) if day >= 20 => Some("金牛"),
-            (5, day) if day <= 20 => Some("金牛"),
-            (5, day) if day >= 21 => Some("双子"),
-            (6, day) if day <= 21 => Some("双子"),
-            (6, day) if day >= 22 => Some("巨蟹"),
-            (7, day) if day <= 22 => Some("巨蟹"),
-            (7, day) if day >= 23 => Some("狮子"),
-            (8, day) if day <= 22 => Some("狮子"),
-            (8, day) if day >= 23 => Some("处女"),
-            (9, day) if day <= 22 => Some("处女"),
-            (9, day) if day >= 23 => Some("天秤"),
-            (10, day) if day <= 23 => Some("天秤"),
-            (10, day) if day >= 24 => Some("天蝎"),
-            (11, day) if day <= 22 => Some("天蝎"),
-            (11, day) if day >= 23 => Some("射手"),
-            (12, day) if day <= 21 => Some("射手"),
-            (12, day) if day >= 22 => Some("摩羯"),
-            (1, day) if day <= 19 => Some("摩羯"),
-            _ => None,
+        Self::new_in(t, ZodiacSystem::Tropical)
+    }
+
+    // 按指定的星座划分方式创建
+    pub fn new_in(t: DateTime<Utc>, system: ZodiacSystem) -> Constellation {
+        Constellation { t, system }
+    }
+
+    pub fn system(&self) -> ZodiacSystem {
+        self.system
+    }
+
+    pub fn alias(&self) -> &'static str {
+        self.name(Locale::ZhHans)
+    }
+
+    // 指定语言的星座名称
+    pub fn name(&self, locale: Locale) -> &'static str {
+        match self.sign() {
+            Some(sign) => sign.name(locale),
+            None => locale.ophiuchus(),
+        }
+    }
+
+    // 对应的黄道十二星座, 天文十三星座中太阳位于蛇夫座时为 None
+    pub fn sign(&self) -> Option<ZodiacSign> {
+        let order = self.order();
+        (order != OPHIUCHUS_ORDER).then(|| ZodiacSign::from_order(order))
+    }
+
+    pub fn is_ophiuchus(&self) -> bool {
+        self.order() == OPHIUCHUS_ORDER
+    }
+
+    pub fn time(&self) -> DateTime<Utc> {
+        self.t
+    }
+
+    // 一年中太阳进入各回归黄道星座的时刻(即十二中气), 自大寒进入水瓶起
+    pub fn ingresses(year: i64) -> IkigaiResult<Vec<Constellation>> {
+        solarterm::check_year(year)?;
+        (0..12)
+            .map(|i| {
+                let term = Solarterm::new(24 * (year - SOLARTERM_FROM_YEAR) + 2 * i + 1)?;
+                Ok(Constellation::new(term.time()?))
+            })
+            .collect()
+    }
+
+    // 星座序数, 白羊为 1, 蛇夫为 13;
+    // 回归黄道由太阳所在的中气区间决定, 超出节气表时按固定日期推算
+    pub fn order(&self) -> i64 {
+        match self.system {
+            ZodiacSystem::Tropical => self
+                .order_by_solarterm()
+                .unwrap_or_else(|| self.order_by_date()),
+            system => {
+                let jd = julian_day(&self.t, &FixedOffset::east_opt(BEIJING_OFFSET).unwrap());
+                system.order_by_longitude(apparent_longitude(jd), jd)
+            }
+        }
+    }
+
+    fn order_by_solarterm(&self) -> Option<i64> {
+        let (term, _) = Solarterm::calc_solarterm(&self.t).ok()?;
+        // 节令(偶数序号)归入前一个中气
+        let index = term.index() - (term.index() % 2 == 0) as i64;
+        Some((index % 24 - ARIES_SOLARTERM).rem_euclid(24) / 2 + 1)
+    }
+
+    // 每月交替星座的日期, 当日起进入下一星座
+    fn order_by_date(&self) -> i64 {
+        const CUSP_DAYS: [u32; 12] = [20, 19, 21, 20, 21, 22, 23, 23, 23, 24, 23, 22];
+        let month = self.t.month() as i64;
+        // 一月上旬为摩羯(10), 其后每月顺延一宫
+        let order = (month + 8) % 12 + 1;
+        if self.t.day() >= CUSP_DAYS[month as usize - 1] {
+            order % 12 + 1
+        } else {
+            order
         }
     }
 }
@@ -45,6 +113,7 @@ mod tests {
     use chrono::TimeZone;
 
     use super::*;
+    use crate::{date::chinese::solarterm::SOLARTERM_TO_YEAR, ikigai_error::IkigaiError};
     fn now() -> DateTime<Utc> {
         Utc::now()
     }
@@ -57,10 +126,194 @@ mod tests {
     fn test_new_constellation() {
         let now = now();
         let constellation = Constellation::new(now);
+        assert_eq!(constellation.alias(), Constellation::new(now).alias());
+    }
+
+    #[test]
+    fn test_constellation_cusp() {
+        // 2024 年春分为北京时间 3-20 11:06, 固定日期表会误判为双鱼
+        let tests = vec![
+            (
+                "before_equinox",
+                Utc.with_ymd_and_hms(2024, 3, 20, 11, 0, 0).unwrap(),
+                "双鱼",
+            ),
+            (
+                "after_equinox",
+                Utc.with_ymd_and_hms(2024, 3, 20, 11, 10, 0).unwrap(),
+                "白羊",
+            ),
+            (
+                "winter_solstice",
+                Utc.with_ymd_and_hms(2024, 12, 21, 18, 0, 0).unwrap(),
+                "摩羯",
+            ),
+            (
+                "before_solstice",
+                Utc.with_ymd_and_hms(2024, 12, 21, 17, 0, 0).unwrap(),
+                "射手",
+            ),
+            (
+                "out_of_table",
+                Utc.with_ymd_and_hms(1900, 3, 25, 0, 0, 0).unwrap(),
+                "白羊",
+            ),
+        ];
+        for (name, t, want) in tests {
+            assert_eq!(Constellation::new(t).alias(), want, "{} failed", name);
+        }
+    }
+
+    #[test]
+    fn test_constellation_ingresses() {
+        let ingresses = Constellation::ingresses(2024).unwrap();
+        assert_eq!(ingresses.len(), 12);
+        assert_eq!(ingresses[0].alias(), "水瓶");
+        assert_eq!(ingresses[11].alias(), "摩羯");
+        let equinox = &ingresses[2];
+        assert_eq!(equinox.alias(), "白羊");
         assert_eq!(
-            constellation.alias().unwrap(),
-            Constellation::new(now).alias().unwrap()
+            equinox.time().format("%Y-%m-%d %H:%M").to_string(),
+            "2024-03-20 11:06"
         );
+        // 交节时刻本身已属新星座
+        for ingress in &ingresses {
+            assert_eq!(Constellation::new(ingress.time()).alias(), ingress.alias());
+        }
+        assert!(Constellation::ingresses(1800).is_err());
+
+        // 2200 年之后直到节气表的最后一年, 各交节时刻均落在当年
+        for year in [2201, 2202, 2500, SOLARTERM_TO_YEAR] {
+            let ingresses = Constellation::ingresses(year).unwrap();
+            assert_eq!(ingresses[11].alias(), "摩羯");
+            for ingress in &ingresses {
+                assert_eq!(ingress.time().year() as i64, year, "{} failed", year);
+            }
+        }
+        assert!(matches!(
+            Constellation::ingresses(SOLARTERM_TO_YEAR + 1),
+            Err(IkigaiError::YearOutOfRange {
+                max: SOLARTERM_TO_YEAR,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_constellation_sign() {
+        let t = Utc.with_ymd_and_hms(2024, 3, 20, 11, 10, 0).unwrap();
+        let sign = Constellation::new(t).sign();
+        assert_eq!(sign, Some(ZodiacSign::Aries));
+        assert_eq!(Constellation::new(t).name(Locale::En), "Aries");
+
+        // 节气表之外逐日按固定日期推算, 与节气表范围内最多相差一天
+        let mut day = Utc.with_ymd_and_hms(1900, 1, 1, 12, 0, 0).unwrap();
+        let mut prev = Constellation::new(day).order();
+        while day.year() == 1900 {
+            let order = Constellation::new(day).order();
+            assert!(order == prev || order == prev % 12 + 1, "{} failed", day);
+            let table = Constellation::new(day.with_year(2000).unwrap()).order();
+            let near = |d: i64| {
+                Constellation::new(day.with_year(2000).unwrap() + chrono::Duration::days(d)).order()
+            };
+            assert!(
+                order == table || order == near(-1) || order == near(1),
+                "{} failed",
+                day
+            );
+            prev = order;
+            day += chrono::Duration::days(1);
+        }
+    }
+
+    #[test]
+    fn test_constellation_systems() {
+        let at = |y, m, d, h| Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap();
+        let tests = vec![
+            // 2024 年 Mesha Sankranti 为北京时间 4-13 深夜
+            (
+                "sidereal_pisces",
+                ZodiacSystem::Sidereal,
+                at(2024, 4, 13, 12),
+                "双鱼",
+            ),
+            (
+                "sidereal_aries",
+                ZodiacSystem::Sidereal,
+                at(2024, 4, 14, 12),
+                "白羊",
+            ),
+            // Makar Sankranti 为北京时间 1-15 凌晨
+            (
+                "sidereal_sagittarius",
+                ZodiacSystem::Sidereal,
+                at(2024, 1, 14, 12),
+                "射手",
+            ),
+            (
+                "sidereal_capricorn",
+                ZodiacSystem::Sidereal,
+                at(2024, 1, 15, 12),
+                "摩羯",
+            ),
+            (
+                "astronomical_pisces",
+                ZodiacSystem::Astronomical,
+                at(2024, 4, 1, 0),
+                "双鱼",
+            ),
+            (
+                "astronomical_aries",
+                ZodiacSystem::Astronomical,
+                at(2024, 4, 25, 0),
+                "白羊",
+            ),
+            (
+                "scorpius",
+                ZodiacSystem::Astronomical,
+                at(2024, 11, 26, 0),
+                "天蝎",
+            ),
+            (
+                "ophiuchus",
+                ZodiacSystem::Astronomical,
+                at(2024, 12, 1, 0),
+                "蛇夫",
+            ),
+            (
+                "sagittarius",
+                ZodiacSystem::Astronomical,
+                at(2024, 12, 20, 0),
+                "射手",
+            ),
+            (
+                "tropical",
+                ZodiacSystem::Tropical,
+                at(2024, 12, 1, 0),
+                "射手",
+            ),
+        ];
+        for (name, system, t, want) in tests {
+            let constellation = Constellation::new_in(t, system);
+            assert_eq!(constellation.system(), system, "{} failed", name);
+            assert_eq!(constellation.alias(), want, "{} failed", name);
+        }
+
+        let ophiuchus = Constellation::new_in(at(2024, 12, 1, 0), ZodiacSystem::Astronomical);
+        assert!(ophiuchus.is_ophiuchus());
+        assert_eq!(ophiuchus.order(), OPHIUCHUS_ORDER);
+        assert_eq!(ophiuchus.sign(), None);
+        assert_eq!(ophiuchus.name(Locale::En), "Ophiuchus");
+
+        // 太阳黄经推算的回归黄道星座与节气表一致
+        let beijing = FixedOffset::east_opt(BEIJING_OFFSET).unwrap();
+        let mut t = at(2024, 1, 1, 0);
+        while t.year() == 2024 {
+            let by_longitude = ZodiacSystem::Tropical
+                .order_by_longitude(apparent_longitude(julian_day(&t, &beijing)), 0.0);
+            assert_eq!(by_longitude, Constellation::new(t).order(), "{} failed", t);
+            t += chrono::Duration::days(1);
+        }
     }
 
     #[test]
@@ -82,12 +335,7 @@ mod tests {
 
         for (name, time, expected) in tests {
             let constellation = Constellation::new(time);
-            assert_eq!(
-                constellation.alias().unwrap(),
-                expected,
-                "{} test failed",
-                name
-            );
+            assert_eq!(constellation.alias(), expected, "{} test failed", name);
         }
     }
 }
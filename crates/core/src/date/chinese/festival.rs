@@ -0,0 +1,227 @@
+use chrono::{DateTime, Datelike, Duration, Utc};
+
+use super::{lunar::Lunar, variant::CalendarVariant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FestivalKind {
+    Lunar,
+    Solar,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Festival {
+    kind: FestivalKind,
+    month: i64,
+    day: i64,
+    alias: &'static str,
+}
+
+// 农历节日(月, 日, 名称), 日为 -1 表示当月最后一天
+const LUNAR_FESTIVALS: [(i64, i64, &str); 11] = [
+    (1, 1, "春节"),
+    (1, 15, "元宵节"),
+    (2, 2, "龙抬头"),
+    (5, 5, "端午节"),
+    (7, 7, "七夕节"),
+    (7, 15, "中元节"),
+    (8, 15, "中秋节"),
+    (9, 9, "重阳节"),
+    (12, 8, "腊八节"),
+    (12, 23, "小年"),
+    (12, -1, "除夕"),
+];
+
+// 公历节日(月, 日, 名称)
+const SOLAR_FESTIVALS: [(i64, i64, &str); 12] = [
+    (1, 1, "元旦"),
+    (2, 14, "情人节"),
+    (3, 8, "妇女节"),
+    (3, 12, "植树节"),
+    (5, 1, "劳动节"),
+    (5, 4, "青年节"),
+    (6, 1, "儿童节"),
+    (7, 1, "建党节"),
+    (8, 1, "建军节"),
+    (9, 10, "教师节"),
+    (10, 1, "国庆节"),
+    (12, 25, "圣诞节"),
+];
+
+// 韩国农历节日, 설날、추석 连同前后各一天为三天假期
+const KOREAN_LUNAR_FESTIVALS: [(i64, i64, &str); 7] = [
+    (1, 1, "설날"),
+    (1, 2, "설날 연휴"),
+    (4, 8, "부처님오신날"),
+    (8, 14, "추석 연휴"),
+    (8, 15, "추석"),
+    (8, 16, "추석 연휴"),
+    (12, -1, "설날 연휴"),
+];
+
+// 韩国公历节日
+const KOREAN_SOLAR_FESTIVALS: [(i64, i64, &str); 8] = [
+    (1, 1, "신정"),
+    (3, 1, "삼일절"),
+    (5, 5, "어린이날"),
+    (6, 6, "현충일"),
+    (8, 15, "광복절"),
+    (10, 3, "개천절"),
+    (10, 9, "한글날"),
+    (12, 25, "성탄절"),
+];
+
+impl Festival {
+    // 获取某一天的全部节日, 农历节日在前
+    pub fn from_solar(t: &DateTime<Utc>) -> Vec<Festival> {
+        Self::from_solar_in(t, CalendarVariant::Chinese)
+    }
+
+    // 按历法变体获取某一天的全部节日, 越南沿用中国的农历节日
+    pub fn from_solar_in(t: &DateTime<Utc>, variant: CalendarVariant) -> Vec<Festival> {
+        let (lunar_festivals, solar_festivals): (&[_], &[_]) = match variant {
+            CalendarVariant::Chinese => (&LUNAR_FESTIVALS, &SOLAR_FESTIVALS),
+            CalendarVariant::Vietnamese => (&LUNAR_FESTIVALS, &[]),
+            CalendarVariant::Japanese => (&[], &[]),
+            CalendarVariant::Korean => (&KOREAN_LUNAR_FESTIVALS, &KOREAN_SOLAR_FESTIVALS),
+        };
+        let mut result = Vec::new();
+        if let Some(lunar) = Self::lunar_of(t, variant) {
+            // 除夕是正月初一的前一天, 不论腊月大小
+            let is_eve = Self::lunar_of(&(*t + Duration::days(1)), variant)
+                .is_some_and(|next| next.get_month() == 1 && next.get_day() == 1);
+            for &(month, day, alias) in lunar_festivals {
+                let matched = if day < 0 {
+                    is_eve
+                } else {
+                    !lunar.is_leap_month() && lunar.get_month() == month && lunar.get_day() == day
+                };
+                if matched {
+                    result.push(Festival {
+                        kind: FestivalKind::Lunar,
+                        month,
+                        day,
+                        alias,
+                    });
+                }
+            }
+        }
+        for &(month, day, alias) in solar_festivals {
+            if t.month() as i64 == month && t.day() as i64 == day {
+                result.push(Festival {
+                    kind: FestivalKind::Solar,
+                    month,
+                    day,
+                    alias,
+                });
+            }
+        }
+        result
+    }
+
+    // 返回节日名称(春节、中秋节...)
+    pub fn alias(&self) -> &str {
+        self.alias
+    }
+
+    pub fn kind(&self) -> FestivalKind {
+        self.kind
+    }
+
+    pub fn is_lunar(&self) -> bool {
+        self.kind == FestivalKind::Lunar
+    }
+
+    // 返回稳定的 ASCII 标识, 如 `lunar-0815`、`solar-1001`、`lunar-12-eve`
+    pub fn id(&self) -> String {
+        let kind = match self.kind {
+            FestivalKind::Lunar => "lunar",
+            FestivalKind::Solar => "solar",
+        };
+        if self.day < 0 {
+            format!("{}-{:02}-eve", kind, self.month)
+        } else {
+            format!("{}-{:02}{:02}", kind, self.month, self.day)
+        }
+    }
+
+    fn lunar_of(t: &DateTime<Utc>, variant: CalendarVariant) -> Option<Lunar> {
+        Lunar::new_in(*t, variant).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn aliases(y: i32, m: u32, d: u32) -> Vec<String> {
+        let t = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+        Festival::from_solar(&t)
+            .iter()
+            .map(|f| f.alias().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_festival_from_solar() {
+        let tests = vec![
+            ("chuxi", (2024, 2, 9), vec!["除夕"]),
+            ("chuxi_small_month", (2025, 1, 28), vec!["除夕"]),
+            ("chunjie", (2024, 2, 10), vec!["春节"]),
+            ("duanwu", (2024, 6, 10), vec!["端午节"]),
+            ("zhongqiu_guoqing", (2020, 10, 1), vec!["中秋节", "国庆节"]),
+            ("yuandan", (2024, 1, 1), vec!["元旦"]),
+            ("none", (2024, 3, 1), vec![]),
+        ];
+
+        for (name, (y, m, d), want) in tests {
+            assert_eq!(aliases(y, m, d), want, "{} failed", name);
+        }
+    }
+
+    #[test]
+    fn test_festival_leap_month() {
+        // 2023 年闰二月初二不是龙抬头
+        assert_eq!(aliases(2023, 2, 21), vec!["龙抬头"]);
+        assert!(aliases(2023, 3, 23).is_empty());
+    }
+
+    #[test]
+    fn test_korean_festival() {
+        let korean = |y, m, d| -> Vec<String> {
+            let t = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+            Festival::from_solar_in(&t, CalendarVariant::Korean)
+                .iter()
+                .map(|f| f.alias().to_string())
+                .collect()
+        };
+        let tests = vec![
+            ("seollal_eve", (2024, 2, 9), vec!["설날 연휴"]),
+            ("seollal", (2024, 2, 10), vec!["설날"]),
+            ("seollal_after", (2024, 2, 11), vec!["설날 연휴"]),
+            ("chuseok", (2024, 9, 17), vec!["추석"]),
+            ("chuseok_span", (2024, 9, 16), vec!["추석 연휴"]),
+            ("buddha", (2024, 5, 15), vec!["부처님오신날"]),
+            ("hangul", (2024, 10, 9), vec!["한글날"]),
+            // 1997 年韩国설날比中国春节晚一天
+            ("seollal_1997", (1997, 2, 8), vec!["설날"]),
+            ("eve_1997", (1997, 2, 7), vec!["설날 연휴"]),
+            ("none", (2024, 3, 2), vec![]),
+        ];
+        for (name, (y, m, d), want) in tests {
+            assert_eq!(korean(y, m, d), want, "{} failed", name);
+        }
+        assert_eq!(aliases(1997, 2, 7), vec!["春节"]);
+    }
+
+    #[test]
+    fn test_festival_id() {
+        let t = Utc.with_ymd_and_hms(2024, 2, 9, 0, 0, 0).unwrap();
+        assert_eq!(Festival::from_solar(&t)[0].id(), "lunar-12-eve");
+
+        let t = Utc.with_ymd_and_hms(2020, 10, 1, 0, 0, 0).unwrap();
+        let ids: Vec<String> = Festival::from_solar(&t).iter().map(|f| f.id()).collect();
+        assert_eq!(ids, vec!["lunar-0815", "solar-1001"]);
+    }
+}
@@ -1,4 +1,9 @@
-#[derive(Debug, PartialEq)]
+use std::{fmt, str::FromStr};
+
+use super::locale::Locale;
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Gan {
     order: i64,
 }
@@ -6,17 +11,26 @@ pub struct Gan {
 const GAN_ALIAS: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
 
 impl Gan {
-    pub fn new(order: i64) -> Option<Gan> {
+    pub fn new(order: i64) -> IkigaiResult<Gan> {
         if !Self::is_supported(order) {
-            return None;
+            return Err(IkigaiError::OrderOutOfRange {
+                name: "Gan",
+                order,
+                max: 10,
+            });
         }
-        Some(Gan { order })
+        Ok(Gan { order })
     }
 
     pub fn alias(&self) -> &str {
         GAN_ALIAS[(self.order as usize - 1) % 10]
     }
 
+    // 指定语言的天干名称
+    pub fn name(&self, locale: Locale) -> &'static str {
+        locale.gan(self.order)
+    }
+
     pub fn order(&self) -> i64 {
         self.order
     }
@@ -26,16 +40,44 @@ impl Gan {
     }
 }
 
+impl fmt::Display for Gan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.alias())
+    }
+}
+
+impl FromStr for Gan {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        let order = GAN_ALIAS
+            .iter()
+            .position(|alias| *alias == s)
+            .ok_or_else(|| IkigaiError::ParseError {
+                input: s.to_string(),
+                kind: "Gan",
+            })?;
+        Gan::new(order as i64 + 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_new_gan() {
-        assert!(Gan::new(1).is_some());
-        assert!(Gan::new(10).is_some());
-        assert!(Gan::new(0).is_none());
-        assert!(Gan::new(11).is_none());
+        assert!(Gan::new(1).is_ok());
+        assert!(Gan::new(10).is_ok());
+        assert!(Gan::new(0).is_err());
+        assert_eq!(
+            Gan::new(11),
+            Err(IkigaiError::OrderOutOfRange {
+                name: "Gan",
+                order: 11,
+                max: 10
+            })
+        );
     }
 
     #[test]
@@ -47,6 +89,17 @@ mod tests {
         assert_eq!(gan.alias(), "癸");
     }
 
+    #[test]
+    fn test_gan_from_str() {
+        for order in 1..=10 {
+            let gan = Gan::new(order).unwrap();
+            assert_eq!(gan.to_string().parse::<Gan>().unwrap(), gan);
+        }
+        assert_eq!("丙".parse::<Gan>().unwrap().order(), 3);
+        assert!("子".parse::<Gan>().is_err());
+        assert!("甲乙".parse::<Gan>().is_err());
+    }
+
     #[test]
     fn test_gan_order() {
         let gan = Gan::new(1).unwrap();
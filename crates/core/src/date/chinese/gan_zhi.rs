@@ -1,12 +1,18 @@
-use chrono::{DateTime, Datelike, TimeZone, Timelike, Utc};
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
 
 use super::{
     animal::Animal,
     gan::Gan,
+    lunar_date::LunarDate,
+    pillar::Pillar,
     solarterm::{self, Solarterm, SOLARTERM_FROM_YEAR},
     utils,
+    variant::CalendarVariant,
     zhi::Zhi,
 };
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
 #[derive(Debug, PartialEq)]
 pub struct Ganzhi {
     t: DateTime<Utc>,
@@ -20,46 +26,46 @@ pub struct Ganzhi {
     pub hour_zhi: Zhi,
     pub prev_solarterm: Solarterm,
     pub next_solarterm: Solarterm,
+    variant: CalendarVariant,
 }
 
 impl Ganzhi {
-    pub fn new(t: DateTime<Utc>) -> Option<Self> {
+    pub fn new(t: DateTime<Utc>) -> IkigaiResult<Self> {
         let mut year = t.year() as i64;
-        if !Self::is_supported(year) {
-            return None;
-        }
+        solarterm::check_year(year)?;
         if t.timestamp() < Solarterm::spring_timestamp(year)? {
             year -= 1;
         }
         let year_gan = Gan::new(utils::order_mod(year - 3, 10))?;
         let year_zhi = Zhi::new(utils::order_mod(year - 3, 12))?;
 
-        let (p, n) = Solarterm::calc_solarterm(&t);
-        let p = p?;
-        let n = n?;
-        let mut i = p.index();
-        if n.index() - p.index() == 2 {
-            i += 1;
+        let (mut p, n) = Solarterm::calc_solarterm(&t)?;
+        let i = utils::order_mod((p.index() % 24) / 2, 12);
+        // 恰在交节时刻, prev_solarterm 取再前一个节气
+        if p.timestamp()? == t.timestamp() {
+            p = p.prev().unwrap_or(p);
         }
-        i = utils::order_mod((i % 24) / 2, 12);
 
         let month_zhi = Zhi::new(utils::order_mod(i + 2, 12))?;
         let month_gan = Gan::new(utils::order_mod(i + year_gan.order() * 2, 10))?;
 
-        let begin = Utc.with_ymd_and_hms(SOLARTERM_FROM_YEAR as i32, 1, 1,0, 0, 0).latest()?;
+        let begin = Utc
+            .with_ymd_and_hms(SOLARTERM_FROM_YEAR as i32, 1, 1, 0, 0, 0)
+            .latest()
+            .ok_or(IkigaiError::InvalidDate)?;
         let seconds = t.signed_duration_since(begin).num_seconds();
         let day_order = utils::order_mod(seconds / 86400 + 31, 60);
 
         let day_gan = Gan::new(utils::order_mod(day_order, 10))?;
         let day_zhi = Zhi::new(utils::order_mod(day_order, 12))?;
 
-        let hour_zhi = Zhi::new(utils::order_mod(((t.hour() + 1) / 2) as i64 + 1, 12))?;
+        let hour_zhi = Zhi::new(utils::order_mod(t.hour().div_ceil(2) as i64 + 1, 12))?;
         let hour_gan = Gan::new(utils::order_mod(
             hour_zhi.order() - 2 + day_gan.order() * 2,
             10,
         ))?;
 
-        Some(Ganzhi {
+        Ok(Ganzhi {
             t,
             year_gan,
             year_zhi,
@@ -71,12 +77,41 @@ impl Ganzhi {
             hour_zhi,
             prev_solarterm: p,
             next_solarterm: n,
+            variant: CalendarVariant::Chinese,
         })
     }
 
+    // 指定历法变体的干支, t 为该地区的当地时间; 节气按北京时间换算后比较.
+    // 越南以农历年定年柱, 以农历月(正月建寅, 闰月同本月)定月柱
+    pub fn new_in(t: DateTime<Utc>, variant: CalendarVariant) -> IkigaiResult<Self> {
+        let mut ganzhi = Self::new(t)?;
+        let shift = variant.utc_offset() - CalendarVariant::Chinese.utc_offset();
+        if shift != 0 {
+            let beijing = Self::new(t - Duration::hours(shift))?;
+            ganzhi.year_gan = beijing.year_gan;
+            ganzhi.year_zhi = beijing.year_zhi;
+            ganzhi.month_gan = beijing.month_gan;
+            ganzhi.month_zhi = beijing.month_zhi;
+            ganzhi.prev_solarterm = beijing.prev_solarterm;
+            ganzhi.next_solarterm = beijing.next_solarterm;
+        }
+        if variant == CalendarVariant::Vietnamese {
+            let lunar = LunarDate::from_naive_date_in(t.date_naive(), variant)?;
+            ganzhi.year_gan = Gan::new(utils::order_mod(lunar.year - 3, 10))?;
+            ganzhi.year_zhi = Zhi::new(utils::order_mod(lunar.year - 3, 12))?;
+            ganzhi.month_zhi = Zhi::new(utils::order_mod(lunar.month + 2, 12))?;
+            ganzhi.month_gan = Gan::new(utils::order_mod(
+                lunar.month + ganzhi.year_gan.order() * 2,
+                10,
+            ))?;
+        }
+        ganzhi.variant = variant;
+        Ok(ganzhi)
+    }
+
     // Animal returns the animal of the year
     pub fn animal(&self) -> Animal {
-        Animal::new(self.year_zhi.order()).unwrap()
+        Animal::new_in(self.year_zhi.order(), self.variant).unwrap()
     }
 
     // YearGanzhiAlias returns the alias of the year (Jiazi, Yichou...)
@@ -99,6 +134,22 @@ impl Ganzhi {
         format!("{}{}", self.hour_gan.alias(), self.hour_zhi.alias())
     }
 
+    pub fn year_pillar(&self) -> Pillar {
+        Pillar::new(self.year_gan, self.year_zhi).unwrap()
+    }
+
+    pub fn month_pillar(&self) -> Pillar {
+        Pillar::new(self.month_gan, self.month_zhi).unwrap()
+    }
+
+    pub fn day_pillar(&self) -> Pillar {
+        Pillar::new(self.day_gan, self.day_zhi).unwrap()
+    }
+
+    pub fn hour_pillar(&self) -> Pillar {
+        Pillar::new(self.hour_gan, self.hour_zhi).unwrap()
+    }
+
     // YearGanzhiOrder returns the order of the year (1,2...)
     pub fn year_ganzhi_order(&self) -> i64 {
         Self::ganzhi_order(self.year_gan.order(), self.year_zhi.order())
@@ -120,7 +171,7 @@ impl Ganzhi {
     }
 
     pub fn is_supported(year: i64) -> bool {
-        (solarterm::SOLARTERM_FROM_YEAR..solarterm::SOLARTERM_TO_YEAR).contains(&year)
+        solarterm::check_year(year).is_ok()
     }
 
     pub fn ganzhi_order(gan_order: i64, zhi_order: i64) -> i64 {
@@ -128,12 +179,25 @@ impl Ganzhi {
     }
 }
 
+// 四柱, 形如 `戊戌年 甲寅月 戊辰日 壬子时`
+impl fmt::Display for Ganzhi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}年 {}月 {}日 {}时",
+            self.year_pillar(),
+            self.month_pillar(),
+            self.day_pillar(),
+            self.hour_pillar()
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use chrono::Duration;
-
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     fn maker(
         t: DateTime<Utc>,
         yg: i64,
@@ -159,6 +223,7 @@ mod tests {
             hour_zhi: Zhi::new(hz).unwrap(),
             prev_solarterm: Solarterm::new(p).unwrap(),
             next_solarterm: Solarterm::new(n).unwrap(),
+            variant: CalendarVariant::Chinese,
         }
     }
 
@@ -185,7 +250,7 @@ mod tests {
         for (t, expected) in test_cases {
             let got = Ganzhi::new(t);
             match got {
-                Some(got) => {
+                Ok(got) => {
                     let expected = expected.unwrap();
                     assert_eq!(got.year_gan, expected.year_gan, "For yg: {}", t);
                     assert_eq!(got.year_zhi, expected.year_zhi, "For yz: {}", t);
@@ -208,7 +273,14 @@ mod tests {
                         t
                     );
                 }
-                None => assert_eq!(got, expected, "For date: {}", t),
+                Err(err) => {
+                    assert!(expected.is_none(), "For date: {}", t);
+                    assert!(
+                        matches!(err, IkigaiError::YearOutOfRange { .. }),
+                        "For date: {}",
+                        t
+                    );
+                }
             }
         }
     }
@@ -394,6 +466,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ganzhi_display() {
+        let t = Utc.with_ymd_and_hms(2018, 2, 5, 0, 0, 0).unwrap();
+        let gz = Ganzhi::new(t).unwrap();
+        assert_eq!(gz.to_string(), "戊戌年 甲寅月 戊辰日 壬子时");
+        assert_eq!(gz.year_pillar().order(), gz.year_ganzhi_order());
+        assert_eq!(
+            gz.day_pillar().to_string().parse::<Pillar>(),
+            Ok(gz.day_pillar())
+        );
+    }
+
     #[test]
     fn test_ganzhi_equals() {
         let t1 = Utc::now();
@@ -408,4 +492,70 @@ mod tests {
             assert_eq!(gz == gz2, want, "Ganzhi::equals() failed");
         }
     }
+
+    #[test]
+    fn test_new_ganzhi_vietnamese() {
+        let vi = CalendarVariant::Vietnamese;
+        // 1985-01-21 越南已是乙丑年正月, 中国仍在甲子年; 1985 年越南闰二月
+        let tests = vec![
+            (
+                "tet_1985",
+                Utc.with_ymd_and_hms(1985, 1, 21, 12, 0, 0).unwrap(),
+                "乙丑年 戊寅月",
+                "牛",
+            ),
+            (
+                "leap_1985",
+                Utc.with_ymd_and_hms(1985, 4, 10, 12, 0, 0).unwrap(),
+                "乙丑年 己卯月",
+                "牛",
+            ),
+            (
+                "cat_2023",
+                Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap(),
+                "癸卯年 丁巳月",
+                "猫",
+            ),
+        ];
+        for (name, t, want, animal) in tests {
+            let ganzhi = Ganzhi::new_in(t, vi).unwrap();
+            let got = format!("{}年 {}月", ganzhi.year_pillar(), ganzhi.month_pillar());
+            assert_eq!(got, want, "{} failed", name);
+            assert_eq!(ganzhi.animal().alias(), animal, "{} failed", name);
+            // 日柱、时柱与中国相同
+            let china = Ganzhi::new(t).unwrap();
+            assert_eq!(ganzhi.day_pillar(), china.day_pillar(), "{} failed", name);
+            assert_eq!(ganzhi.hour_pillar(), china.hour_pillar(), "{} failed", name);
+        }
+
+        let t = Utc.with_ymd_and_hms(1985, 1, 21, 12, 0, 0).unwrap();
+        assert_eq!(Ganzhi::new(t).unwrap().year_pillar().to_string(), "甲子");
+    }
+
+    #[test]
+    fn test_new_ganzhi_korean() {
+        // 2018 年立春为北京时间 2-4 05:28:29, 即首尔时间 06:28:29
+        let ko = CalendarVariant::Korean;
+        let before = Utc.with_ymd_and_hms(2018, 2, 4, 6, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2018, 2, 4, 6, 30, 0).unwrap();
+        assert_eq!(
+            Ganzhi::new_in(before, ko)
+                .unwrap()
+                .year_pillar()
+                .to_string(),
+            "丁酉"
+        );
+        assert_eq!(
+            Ganzhi::new_in(after, ko).unwrap().year_pillar().to_string(),
+            "戊戌"
+        );
+        assert_eq!(
+            Ganzhi::new(before).unwrap().year_pillar().to_string(),
+            "戊戌"
+        );
+        assert_eq!(
+            Ganzhi::new_in(before, ko).unwrap().day_pillar(),
+            Ganzhi::new(before).unwrap().day_pillar()
+        );
+    }
 }
@@ -0,0 +1,699 @@
+use std::{fmt, str::FromStr};
+
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 名称的语言: 简体、繁体、英文、拼音、日文、韩文、越南文
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    ZhHans,
+    ZhHant,
+    En,
+    Pinyin,
+    Ja,
+    Ko,
+    Vi,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 7] = [
+        Locale::ZhHans,
+        Locale::ZhHant,
+        Locale::En,
+        Locale::Pinyin,
+        Locale::Ja,
+        Locale::Ko,
+        Locale::Vi,
+    ];
+
+    // BCP 47 语言标签
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::ZhHans => "zh-Hans",
+            Locale::ZhHant => "zh-Hant",
+            Locale::En => "en",
+            Locale::Pinyin => "zh-Latn-pinyin",
+            Locale::Ja => "ja",
+            Locale::Ko => "ko",
+            Locale::Vi => "vi",
+        }
+    }
+
+    // 干支两字之间的分隔符
+    pub fn separator(&self) -> &'static str {
+        match self {
+            Locale::En | Locale::Pinyin | Locale::Vi => " ",
+            _ => "",
+        }
+    }
+
+    pub fn gan(&self, order: i64) -> &'static str {
+        GAN_NAMES[self.index()][(order - 1).rem_euclid(10) as usize]
+    }
+
+    pub fn zhi(&self, order: i64) -> &'static str {
+        ZHI_NAMES[self.index()][(order - 1).rem_euclid(12) as usize]
+    }
+
+    pub fn animal(&self, order: i64) -> &'static str {
+        ANIMAL_NAMES[self.index()][(order - 1).rem_euclid(12) as usize]
+    }
+
+    // 越南生肖中代替兔的猫
+    pub fn cat(&self) -> &'static str {
+        CAT_NAMES[self.index()]
+    }
+
+    // 天文十三星座中的蛇夫座
+    pub fn ophiuchus(&self) -> &'static str {
+        OPHIUCHUS_NAMES[self.index()]
+    }
+
+    // 农历月份名称, 含闰月前后缀
+    pub fn lunar_month(&self, month: i64, is_leap: bool) -> String {
+        let name = LUNAR_MONTH_NAMES[self.index()][(month - 1).rem_euclid(12) as usize];
+        if !is_leap {
+            return name.to_string();
+        }
+        match self {
+            Locale::ZhHans => format!("闰{}", name),
+            Locale::ZhHant | Locale::Ja => format!("閏{}", name),
+            Locale::En => format!("Leap {}", name),
+            Locale::Pinyin => format!("rùn {}", name),
+            Locale::Ko => format!("윤{}", name),
+            Locale::Vi => format!("{} nhuận", name),
+        }
+    }
+
+    // 节气名称, 序号 0 为小寒
+    pub fn solarterm(&self, index: i64) -> &'static str {
+        SOLARTERM_NAMES[self.index()][index.rem_euclid(24) as usize]
+    }
+
+    // 星座名称, 序号 1 为白羊
+    pub fn constellation(&self, order: i64) -> &'static str {
+        CONSTELLATION_NAMES[self.index()][(order - 1).rem_euclid(12) as usize]
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        let locale = match s.to_ascii_lowercase().replace('_', "-").as_str() {
+            "zh" | "zh-hans" | "zh-cn" | "zh-sg" => Locale::ZhHans,
+            "zh-hant" | "zh-tw" | "zh-hk" | "zh-mo" => Locale::ZhHant,
+            "en" | "en-us" | "en-gb" => Locale::En,
+            "pinyin" | "zh-latn" | "zh-latn-pinyin" => Locale::Pinyin,
+            "ja" | "ja-jp" => Locale::Ja,
+            "ko" | "ko-kr" => Locale::Ko,
+            "vi" | "vi-vn" => Locale::Vi,
+            _ => {
+                return Err(IkigaiError::ParseError {
+                    input: s.to_string(),
+                    kind: "Locale",
+                })
+            }
+        };
+        Ok(locale)
+    }
+}
+
+const GAN_NAMES: [[&str; 10]; 7] = [
+    ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"],
+    ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"],
+    [
+        "Jia", "Yi", "Bing", "Ding", "Wu", "Ji", "Geng", "Xin", "Ren", "Gui",
+    ],
+    [
+        "jiǎ", "yǐ", "bǐng", "dīng", "wù", "jǐ", "gēng", "xīn", "rén", "guǐ",
+    ],
+    [
+        "甲(きのえ)",
+        "乙(きのと)",
+        "丙(ひのえ)",
+        "丁(ひのと)",
+        "戊(つちのえ)",
+        "己(つちのと)",
+        "庚(かのえ)",
+        "辛(かのと)",
+        "壬(みずのえ)",
+        "癸(みずのと)",
+    ],
+    ["갑", "을", "병", "정", "무", "기", "경", "신", "임", "계"],
+    [
+        "Giáp", "Ất", "Bính", "Đinh", "Mậu", "Kỷ", "Canh", "Tân", "Nhâm", "Quý",
+    ],
+];
+
+const ZHI_NAMES: [[&str; 12]; 7] = [
+    [
+        "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+    ],
+    [
+        "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+    ],
+    [
+        "Zi", "Chou", "Yin", "Mao", "Chen", "Si", "Wu", "Wei", "Shen", "You", "Xu", "Hai",
+    ],
+    [
+        "zǐ", "chǒu", "yín", "mǎo", "chén", "sì", "wǔ", "wèi", "shēn", "yǒu", "xū", "hài",
+    ],
+    [
+        "子(ね)",
+        "丑(うし)",
+        "寅(とら)",
+        "卯(う)",
+        "辰(たつ)",
+        "巳(み)",
+        "午(うま)",
+        "未(ひつじ)",
+        "申(さる)",
+        "酉(とり)",
+        "戌(いぬ)",
+        "亥(い)",
+    ],
+    [
+        "자", "축", "인", "묘", "진", "사", "오", "미", "신", "유", "술", "해",
+    ],
+    [
+        "Tý", "Sửu", "Dần", "Mão", "Thìn", "Tỵ", "Ngọ", "Mùi", "Thân", "Dậu", "Tuất", "Hợi",
+    ],
+];
+
+const ANIMAL_NAMES: [[&str; 12]; 7] = [
+    [
+        "鼠", "牛", "虎", "兔", "龙", "蛇", "马", "羊", "猴", "鸡", "狗", "猪",
+    ],
+    [
+        "鼠", "牛", "虎", "兔", "龍", "蛇", "馬", "羊", "猴", "雞", "狗", "豬",
+    ],
+    [
+        "Rat", "Ox", "Tiger", "Rabbit", "Dragon", "Snake", "Horse", "Goat", "Monkey", "Rooster",
+        "Dog", "Pig",
+    ],
+    [
+        "shǔ", "niú", "hǔ", "tù", "lóng", "shé", "mǎ", "yáng", "hóu", "jī", "gǒu", "zhū",
+    ],
+    [
+        "鼠(ねずみ)",
+        "牛(うし)",
+        "虎(とら)",
+        "兎(うさぎ)",
+        "竜(たつ)",
+        "蛇(へび)",
+        "馬(うま)",
+        "羊(ひつじ)",
+        "猿(さる)",
+        "鶏(にわとり)",
+        "犬(いぬ)",
+        "猪(いのしし)",
+    ],
+    [
+        "쥐",
+        "소",
+        "호랑이",
+        "토끼",
+        "용",
+        "뱀",
+        "말",
+        "양",
+        "원숭이",
+        "닭",
+        "개",
+        "돼지",
+    ],
+    [
+        "Chuột", "Trâu", "Hổ", "Thỏ", "Rồng", "Rắn", "Ngựa", "Dê", "Khỉ", "Gà", "Chó", "Lợn",
+    ],
+];
+
+const CAT_NAMES: [&str; 7] = ["猫", "貓", "Cat", "māo", "猫(ねこ)", "고양이", "Mèo"];
+
+const OPHIUCHUS_NAMES: [&str; 7] = [
+    "蛇夫",
+    "蛇夫",
+    "Ophiuchus",
+    "shéfū",
+    "蛇遣座(へびつかいざ)",
+    "뱀주인자리",
+    "Xà Phu",
+];
+
+const LUNAR_MONTH_NAMES: [[&str; 12]; 7] = [
+    [
+        "正月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "冬月",
+        "腊月",
+    ],
+    [
+        "正月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "冬月",
+        "臘月",
+    ],
+    [
+        "First Month",
+        "Second Month",
+        "Third Month",
+        "Fourth Month",
+        "Fifth Month",
+        "Sixth Month",
+        "Seventh Month",
+        "Eighth Month",
+        "Ninth Month",
+        "Tenth Month",
+        "Eleventh Month",
+        "Twelfth Month",
+    ],
+    [
+        "zhēngyuè",
+        "èryuè",
+        "sānyuè",
+        "sìyuè",
+        "wǔyuè",
+        "liùyuè",
+        "qīyuè",
+        "bāyuè",
+        "jiǔyuè",
+        "shíyuè",
+        "dōngyuè",
+        "làyuè",
+    ],
+    // 和风月名
+    [
+        "睦月(むつき)",
+        "如月(きさらぎ)",
+        "弥生(やよい)",
+        "卯月(うづき)",
+        "皐月(さつき)",
+        "水無月(みなづき)",
+        "文月(ふみづき)",
+        "葉月(はづき)",
+        "長月(ながつき)",
+        "神無月(かんなづき)",
+        "霜月(しもつき)",
+        "師走(しわす)",
+    ],
+    [
+        "정월",
+        "이월",
+        "삼월",
+        "사월",
+        "오월",
+        "유월",
+        "칠월",
+        "팔월",
+        "구월",
+        "시월",
+        "동짓달",
+        "섣달",
+    ],
+    [
+        "Tháng Giêng",
+        "Tháng Hai",
+        "Tháng Ba",
+        "Tháng Tư",
+        "Tháng Năm",
+        "Tháng Sáu",
+        "Tháng Bảy",
+        "Tháng Tám",
+        "Tháng Chín",
+        "Tháng Mười",
+        "Tháng Mười Một",
+        "Tháng Chạp",
+    ],
+];
+
+const SOLARTERM_NAMES: [[&str; 24]; 7] = [
+    [
+        "小寒", "大寒", "立春", "雨水", "惊蛰", "春分", "清明", "谷雨", "立夏", "小满", "芒种",
+        "夏至", "小暑", "大暑", "立秋", "处暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪",
+        "大雪", "冬至",
+    ],
+    [
+        "小寒", "大寒", "立春", "雨水", "驚蟄", "春分", "清明", "穀雨", "立夏", "小滿", "芒種",
+        "夏至", "小暑", "大暑", "立秋", "處暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪",
+        "大雪", "冬至",
+    ],
+    [
+        "Minor Cold",
+        "Major Cold",
+        "Start of Spring",
+        "Rain Water",
+        "Awakening of Insects",
+        "Spring Equinox",
+        "Pure Brightness",
+        "Grain Rain",
+        "Start of Summer",
+        "Grain Buds",
+        "Grain in Ear",
+        "Summer Solstice",
+        "Minor Heat",
+        "Major Heat",
+        "Start of Autumn",
+        "End of Heat",
+        "White Dew",
+        "Autumn Equinox",
+        "Cold Dew",
+        "Frost's Descent",
+        "Start of Winter",
+        "Minor Snow",
+        "Major Snow",
+        "Winter Solstice",
+    ],
+    [
+        "xiǎohán",
+        "dàhán",
+        "lìchūn",
+        "yǔshuǐ",
+        "jīngzhé",
+        "chūnfēn",
+        "qīngmíng",
+        "gǔyǔ",
+        "lìxià",
+        "xiǎomǎn",
+        "mángzhòng",
+        "xiàzhì",
+        "xiǎoshǔ",
+        "dàshǔ",
+        "lìqiū",
+        "chǔshǔ",
+        "báilù",
+        "qiūfēn",
+        "hánlù",
+        "shuāngjiàng",
+        "lìdōng",
+        "xiǎoxuě",
+        "dàxuě",
+        "dōngzhì",
+    ],
+    [
+        "小寒(しょうかん)",
+        "大寒(だいかん)",
+        "立春(りっしゅん)",
+        "雨水(うすい)",
+        "啓蟄(けいちつ)",
+        "春分(しゅんぶん)",
+        "清明(せいめい)",
+        "穀雨(こくう)",
+        "立夏(りっか)",
+        "小満(しょうまん)",
+        "芒種(ぼうしゅ)",
+        "夏至(げし)",
+        "小暑(しょうしょ)",
+        "大暑(たいしょ)",
+        "立秋(りっしゅう)",
+        "処暑(しょしょ)",
+        "白露(はくろ)",
+        "秋分(しゅうぶん)",
+        "寒露(かんろ)",
+        "霜降(そうこう)",
+        "立冬(りっとう)",
+        "小雪(しょうせつ)",
+        "大雪(たいせつ)",
+        "冬至(とうじ)",
+    ],
+    [
+        "소한", "대한", "입춘", "우수", "경칩", "춘분", "청명", "곡우", "입하", "소만", "망종",
+        "하지", "소서", "대서", "입추", "처서", "백로", "추분", "한로", "상강", "입동", "소설",
+        "대설", "동지",
+    ],
+    [
+        "Tiểu hàn",
+        "Đại hàn",
+        "Lập xuân",
+        "Vũ thủy",
+        "Kinh trập",
+        "Xuân phân",
+        "Thanh minh",
+        "Cốc vũ",
+        "Lập hạ",
+        "Tiểu mãn",
+        "Mang chủng",
+        "Hạ chí",
+        "Tiểu thử",
+        "Đại thử",
+        "Lập thu",
+        "Xử thử",
+        "Bạch lộ",
+        "Thu phân",
+        "Hàn lộ",
+        "Sương giáng",
+        "Lập đông",
+        "Tiểu tuyết",
+        "Đại tuyết",
+        "Đông chí",
+    ],
+];
+
+const CONSTELLATION_NAMES: [[&str; 12]; 7] = [
+    [
+        "白羊", "金牛", "双子", "巨蟹", "狮子", "处女", "天秤", "天蝎", "射手", "摩羯", "水瓶",
+        "双鱼",
+    ],
+    [
+        "白羊", "金牛", "雙子", "巨蟹", "獅子", "處女", "天秤", "天蠍", "射手", "摩羯", "水瓶",
+        "雙魚",
+    ],
+    [
+        "Aries",
+        "Taurus",
+        "Gemini",
+        "Cancer",
+        "Leo",
+        "Virgo",
+        "Libra",
+        "Scorpio",
+        "Sagittarius",
+        "Capricorn",
+        "Aquarius",
+        "Pisces",
+    ],
+    [
+        "báiyáng",
+        "jīnniú",
+        "shuāngzǐ",
+        "jùxiè",
+        "shīzi",
+        "chǔnǚ",
+        "tiānchèng",
+        "tiānxiē",
+        "shèshǒu",
+        "mójié",
+        "shuǐpíng",
+        "shuāngyú",
+    ],
+    [
+        "牡羊座(おひつじざ)",
+        "牡牛座(おうしざ)",
+        "双子座(ふたござ)",
+        "蟹座(かにざ)",
+        "獅子座(ししざ)",
+        "乙女座(おとめざ)",
+        "天秤座(てんびんざ)",
+        "蠍座(さそりざ)",
+        "射手座(いてざ)",
+        "山羊座(やぎざ)",
+        "水瓶座(みずがめざ)",
+        "魚座(うおざ)",
+    ],
+    [
+        "양자리",
+        "황소자리",
+        "쌍둥이자리",
+        "게자리",
+        "사자자리",
+        "처녀자리",
+        "천칭자리",
+        "전갈자리",
+        "궁수자리",
+        "염소자리",
+        "물병자리",
+        "물고기자리",
+    ],
+    [
+        "Bạch Dương",
+        "Kim Ngưu",
+        "Song Tử",
+        "Cự Giải",
+        "Sư Tử",
+        "Xử Nữ",
+        "Thiên Bình",
+        "Thiên Yết",
+        "Nhân Mã",
+        "Ma Kết",
+        "Bảo Bình",
+        "Song Ngư",
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::date::chinese::{
+        animal::Animal, gan::Gan, lunar_date::LunarDate, pillar::Pillar, solarterm::Solarterm,
+        zhi::Zhi,
+    };
+
+    // 每个语言的每张表都须完整且无重复
+    fn check_table(name: &str, table: &[&str]) {
+        assert!(
+            table.iter().all(|s| !s.trim().is_empty()),
+            "{} has empty names",
+            name
+        );
+        let unique: HashSet<_> = table.iter().collect();
+        assert_eq!(unique.len(), table.len(), "{} has duplicate names", name);
+    }
+
+    #[test]
+    fn test_tables_complete() {
+        for locale in Locale::ALL {
+            let i = locale.index();
+            check_table(&format!("{} gan", locale), &GAN_NAMES[i]);
+            check_table(&format!("{} zhi", locale), &ZHI_NAMES[i]);
+            check_table(&format!("{} animal", locale), &ANIMAL_NAMES[i]);
+            assert!(!locale.cat().is_empty());
+            assert!(!locale.ophiuchus().is_empty());
+            check_table(&format!("{} lunar month", locale), &LUNAR_MONTH_NAMES[i]);
+            check_table(&format!("{} solarterm", locale), &SOLARTERM_NAMES[i]);
+            check_table(
+                &format!("{} constellation", locale),
+                &CONSTELLATION_NAMES[i],
+            );
+        }
+        assert_eq!(Locale::ALL.len(), GAN_NAMES.len());
+        assert_eq!(Locale::ALL.len(), ZHI_NAMES.len());
+        assert_eq!(Locale::ALL.len(), ANIMAL_NAMES.len());
+        assert_eq!(Locale::ALL.len(), CAT_NAMES.len());
+        assert_eq!(Locale::ALL.len(), OPHIUCHUS_NAMES.len());
+        assert_eq!(Locale::ALL.len(), LUNAR_MONTH_NAMES.len());
+        assert_eq!(Locale::ALL.len(), SOLARTERM_NAMES.len());
+        assert_eq!(Locale::ALL.len(), CONSTELLATION_NAMES.len());
+    }
+
+    #[test]
+    fn test_zh_hans_matches_alias() {
+        for order in 1..=10 {
+            let gan = Gan::new(order).unwrap();
+            assert_eq!(gan.name(Locale::ZhHans), gan.alias());
+        }
+        for order in 1..=12 {
+            let zhi = Zhi::new(order).unwrap();
+            assert_eq!(zhi.name(Locale::ZhHans), zhi.alias());
+            let animal = Animal::new(order).unwrap();
+            assert_eq!(animal.name(Locale::ZhHans), animal.alias());
+            let date = LunarDate {
+                year: 2024,
+                month: order,
+                day: 1,
+                is_leap: false,
+            };
+            assert_eq!(date.month_name(Locale::ZhHans), date.month_alias());
+        }
+        for index in 0..24 {
+            let term = Solarterm::new(index).unwrap();
+            assert_eq!(term.name(Locale::ZhHans), term.alias());
+        }
+    }
+
+    #[test]
+    fn test_pillar_names() {
+        let pillar = Pillar::from_order(41).unwrap();
+        let tests = vec![
+            (Locale::ZhHans, "甲辰"),
+            (Locale::En, "Jia Chen"),
+            (Locale::Pinyin, "jiǎ chén"),
+            (Locale::Ko, "갑진"),
+            (Locale::Vi, "Giáp Thìn"),
+        ];
+        for (locale, want) in tests {
+            assert_eq!(pillar.name(locale), want, "{} failed", locale);
+        }
+    }
+
+    #[test]
+    fn test_order_wraps() {
+        // 序号按周期取模, 0 与负数不越界
+        let tests = vec![
+            ("gan_0", Locale::ZhHans.gan(0), "癸"),
+            ("gan_11", Locale::ZhHans.gan(11), "甲"),
+            ("zhi_0", Locale::ZhHans.zhi(0), "亥"),
+            ("zhi_negative", Locale::ZhHans.zhi(-1), "戌"),
+            ("animal_0", Locale::ZhHans.animal(0), "猪"),
+            ("constellation_0", Locale::ZhHans.constellation(0), "双鱼"),
+            ("solarterm_negative", Locale::ZhHans.solarterm(-1), "冬至"),
+        ];
+        for (name, got, want) in tests {
+            assert_eq!(got, want, "{} failed", name);
+        }
+        assert_eq!(Locale::ZhHans.lunar_month(0, false), "腊月");
+    }
+
+    #[test]
+    fn test_solarterm_names() {
+        // 谷雨
+        let tests = vec![
+            (Locale::ZhHans, "谷雨"),
+            (Locale::ZhHant, "穀雨"),
+            (Locale::En, "Grain Rain"),
+            (Locale::Pinyin, "gǔyǔ"),
+            (Locale::Ja, "穀雨(こくう)"),
+            (Locale::Ko, "곡우"),
+            (Locale::Vi, "Cốc vũ"),
+        ];
+        for (locale, want) in tests {
+            assert_eq!(locale.solarterm(7), want, "{} failed", locale);
+        }
+    }
+
+    #[test]
+    fn test_lunar_month_names() {
+        let tests = vec![
+            (Locale::ZhHans, 2, true, "闰二月"),
+            (Locale::ZhHant, 12, false, "臘月"),
+            (Locale::En, 2, true, "Leap Second Month"),
+            (Locale::Pinyin, 1, false, "zhēngyuè"),
+            (Locale::Ja, 6, true, "閏水無月(みなづき)"),
+            (Locale::Ko, 6, true, "윤유월"),
+            (Locale::Vi, 2, true, "Tháng Hai nhuận"),
+        ];
+        for (locale, month, is_leap, want) in tests {
+            assert_eq!(
+                locale.lunar_month(month, is_leap),
+                want,
+                "{} failed",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn test_locale_from_str() {
+        for locale in Locale::ALL {
+            assert_eq!(locale.code().parse::<Locale>(), Ok(locale));
+        }
+        let tests = vec![
+            ("zh_CN", Locale::ZhHans),
+            ("zh-TW", Locale::ZhHant),
+            ("EN", Locale::En),
+            ("pinyin", Locale::Pinyin),
+            ("ko-KR", Locale::Ko),
+        ];
+        for (s, want) in tests {
+            assert_eq!(s.parse::<Locale>(), Ok(want), "{} failed", s);
+        }
+        assert!("fr".parse::<Locale>().is_err());
+        assert_eq!(Locale::default(), Locale::ZhHans);
+    }
+}
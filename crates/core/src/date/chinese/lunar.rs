@@ -1,6 +1,15 @@
-use chrono::{Datelike, TimeZone, Utc};
+use std::{fmt, str::FromStr};
 
-use super::animal::Animal;
+use chrono::{Datelike, NaiveTime, TimeZone, Utc};
+
+use super::{animal::Animal, lunar_date::LunarDate, lunisolar, utils, variant::CalendarVariant};
+use crate::{
+    date::japanese::rokuyo::Rokuyo,
+    ikigai_error::{IkigaiError, IkigaiResult},
+};
+
+pub const LUNAR_FROM_YEAR: i64 = 1900;
+pub const LUNAR_TO_YEAR: i64 = 2100;
 
 #[derive(Debug, PartialEq)]
 pub struct Lunar {
@@ -9,15 +18,8 @@ pub struct Lunar {
     month: i64,
     day: i64,
     month_is_leap: bool,
+    variant: CalendarVariant,
 }
-const NUMBER_ALIAS: [&str; 10] = ["零", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
-
-const DATE_ALIAS: [&str; 4] = ["初", "十", "廿", "卅"];
-
-const LUNAR_MONTH_ALIAS: [&str; 12] = [
-    "正", "二", "三", "四", "五", "六", "七", "八", "九", "十", "冬", "腊",
-];
-
 const LUNARS: [i64; 201] = [
     0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0,
     0x055d2, // 1900-1909
@@ -63,28 +65,64 @@ const LUNARS: [i64; 201] = [
 ];
 
 impl Lunar {
-    pub fn new(t: chrono::DateTime<chrono::Utc>) -> Option<Lunar> {
+    pub fn new(t: chrono::DateTime<chrono::Utc>) -> IkigaiResult<Lunar> {
         let (year, month, day, is_leap) = Self::from_solar_timestamp(t.timestamp())?;
-        Some(Lunar {
+        Ok(Lunar {
             t,
             year,
             month,
             day,
             month_is_leap: is_leap,
+            variant: CalendarVariant::Chinese,
         })
     }
 
-    pub fn from_solar_timestamp(ts: i64) -> Option<(i64, i64, i64, bool)> {
+    // 指定历法变体的农历, t 为该地区的当地时间
+    pub fn new_in(
+        t: chrono::DateTime<chrono::Utc>,
+        variant: CalendarVariant,
+    ) -> IkigaiResult<Lunar> {
+        let date = LunarDate::from_naive_date_in(t.date_naive(), variant)?;
+        Ok(Lunar {
+            t,
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            month_is_leap: date.is_leap,
+            variant,
+        })
+    }
+
+    pub fn variant(&self) -> CalendarVariant {
+        self.variant
+    }
+
+    pub fn from_solar_timestamp(ts: i64) -> IkigaiResult<(i64, i64, i64, bool)> {
         // Get date from input Unix timestamp
-        let dt = Utc.timestamp_opt(ts, 0).latest()?;
+        let dt = Utc
+            .timestamp_opt(ts, 0)
+            .latest()
+            .ok_or(IkigaiError::InvalidDate)?;
         // Create a new Utc datetime with the same date but time at zero
         let dt1 = Utc
             .with_ymd_and_hms(dt.year(), dt.month(), dt.day(), 0, 0, 0)
-            .latest()?;
+            .latest()
+            .ok_or(IkigaiError::InvalidDate)?;
         // Create a new Utc datetime for 1900-01-31 at zero time
-        let dt2 = Utc.with_ymd_and_hms(1900, 1, 31, 0, 0, 0).latest()?;
+        let dt2 = Utc
+            .with_ymd_and_hms(1900, 1, 31, 0, 0, 0)
+            .latest()
+            .ok_or(IkigaiError::InvalidDate)?;
         // Calculate the difference in days
         let mut offset = (dt1.timestamp() - dt2.timestamp()) / 86400;
+        let out_of_range = IkigaiError::YearOutOfRange {
+            year: dt.year() as i64,
+            min: LUNAR_FROM_YEAR,
+            max: LUNAR_TO_YEAR,
+        };
+        if offset < 0 {
+            return Err(out_of_range);
+        }
 
         // // 1900-01-31 in Unix timestamp
         // let start_timestamp: i64 = -2206425600;
@@ -102,6 +140,9 @@ impl Lunar {
             i -= 1;
         }
         let lunar_year = i;
+        if lunar_year > LUNAR_TO_YEAR {
+            return Err(out_of_range);
+        }
 
         let leap = Self::leap_month(i);
         let mut is_leap = false;
@@ -126,8 +167,9 @@ impl Lunar {
             }
             month_counter += 1;
         }
-        // If offset is 0, and the month just calculated is a leap month, correct
-        if offset == 0 && leap > 0 && month_counter == leap + 1 {
+        // If offset is 0, the date is the first day of the month after the one just
+        // calculated; correct it when that month is the leap month or the one following it
+        if offset == 0 && leap > 0 && month_counter == leap {
             if is_leap {
                 is_leap = false;
             } else {
@@ -142,7 +184,7 @@ impl Lunar {
         let lunar_month = month_counter + 1;
         let lunar_day = offset + 1;
         let lunar_month_is_leap = is_leap;
-        Some((lunar_year, lunar_month, lunar_day, lunar_month_is_leap))
+        Ok((lunar_year, lunar_month, lunar_day, lunar_month_is_leap))
     }
 
     pub fn to_solar_timestamp(
@@ -153,20 +195,22 @@ impl Lunar {
         minute: i64,
         second: i64,
         is_leap_month: bool,
-    ) -> i64 {
+    ) -> IkigaiResult<i64> {
         let mut offset = 0;
 
         // validity check
-        if !(1900..=2100).contains(&year) {
-            return 0;
+        Self::check_year(year)?;
+        if !(1..=12).contains(&month) {
+            return Err(IkigaiError::InvalidLunarMonth { year, month });
         }
 
-        let m = Self::leap_month(year);
-        let is_leap_month = is_leap_month && (m == month);
+        if is_leap_month && Self::leap_month(year) != month {
+            return Err(IkigaiError::LeapMonthNotInYear { year, month });
+        }
 
         // beyond the max boundary
-        if (year == 2100 && month == 12 && day > 1) || (year == 1900 && month == 1 && day < 31) {
-            return 0;
+        if year == 2100 && month == 12 && day > 1 {
+            return Err(IkigaiError::InvalidDate);
         }
 
         let days = Self::lunar_days(year, month);
@@ -178,8 +222,13 @@ impl Lunar {
         }
 
         // validity check
-        if day > max_days {
-            return 0;
+        if !(1..=max_days).contains(&day) {
+            return Err(IkigaiError::InvalidLunarDay {
+                year,
+                month,
+                day,
+                max: max_days,
+            });
         }
 
         // calculate lunar offset
@@ -208,11 +257,27 @@ impl Lunar {
         // 1900-01-30 in Unix timestamp
         let start_timestamp: i64 = -2206512000;
 
-        (offset + day) * 86400 + start_timestamp + hour * 3600 + minute * 60 + second
+        Ok((offset + day) * 86400 + start_timestamp + hour * 3600 + minute * 60 + second)
+    }
+
+    // 检查年份是否在农历数据表的范围内
+    pub fn check_year(year: i64) -> IkigaiResult<()> {
+        if (LUNAR_FROM_YEAR..=LUNAR_TO_YEAR).contains(&year) {
+            Ok(())
+        } else {
+            Err(IkigaiError::YearOutOfRange {
+                year,
+                min: LUNAR_FROM_YEAR,
+                max: LUNAR_TO_YEAR,
+            })
+        }
     }
 
     pub fn is_leap(&self) -> bool {
-        Self::leap_month(self.year) != 0
+        match self.variant {
+            CalendarVariant::Chinese => Self::leap_month(self.year) != 0,
+            variant => lunisolar::leap_month(self.year, variant.utc_offset()).is_ok_and(|m| m != 0),
+        }
     }
 
     pub fn is_leap_month(&self) -> bool {
@@ -231,6 +296,11 @@ impl Lunar {
         self.day
     }
 
+    // 不含时刻的农历日期
+    pub fn date(&self) -> LunarDate {
+        LunarDate::from(self)
+    }
+
     pub fn days_of_lunar_year(year: i64) -> i64 {
         let mut sum = 29 * 12;
         let mut mask = 0x8000;
@@ -267,46 +337,52 @@ impl Lunar {
             29
         }
     }
+
+    // 六曜, 总按日本旧历(CalendarVariant::Japanese)的月日推算, 与本农历的变体无关
+    pub fn rokuyo(&self) -> IkigaiResult<Rokuyo> {
+        let date = match self.variant {
+            CalendarVariant::Japanese => self.date(),
+            _ => LunarDate::from_naive_date_in(self.t.date_naive(), CalendarVariant::Japanese)?,
+        };
+        Ok(Rokuyo::from_lunar(date.month, date.day))
+    }
+
     // 获取生肖
     pub fn animal(&self) -> Animal {
-        let index = (self.year - 3) % 12;
-        Animal::new(index).unwrap()
+        let index = utils::order_mod(self.year - 3, 12);
+        Animal::new_in(index, self.variant).unwrap()
     }
 
     // 汉字表示年份
     pub fn year_alias(&self) -> String {
-        let mut result = self.year.to_string();
-        for (i, alias) in NUMBER_ALIAS.iter().enumerate() {
-            result = result.replace(&i.to_string(), alias);
-        }
-        result
+        self.date().year_alias()
     }
 
     // 汉字表示月份
     pub fn month_alias(&self) -> String {
-        let mut alias = String::new();
-        if self.month_is_leap {
-            alias += "闰";
-        }
-        alias += LUNAR_MONTH_ALIAS[self.month as usize - 1];
-        alias += "月";
-        alias
+        self.date().month_alias()
     }
 
     // 汉字表示日期
     pub fn day_alias(&self) -> String {
-        let mut alias = String::new();
-        match self.day {
-            10 => alias += "初十",
-            20 => alias += "二十",
-            30 => alias += "三十",
-            _ => {
-                let n = (self.day / 10) as usize;
-                alias += DATE_ALIAS[n];
-                alias += NUMBER_ALIAS[(self.day % 10) as usize];
-            }
-        }
-        alias
+        self.date().day_alias()
+    }
+}
+
+// 形如 `二零二四年正月初一`
+impl fmt::Display for Lunar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.date().fmt(f)
+    }
+}
+
+// 解析出的农历日期取北京时间零点
+impl FromStr for Lunar {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        let date = s.parse::<LunarDate>()?;
+        Lunar::new(date.to_datetime(&Utc, NaiveTime::MIN)?)
     }
 }
 
@@ -322,6 +398,10 @@ mod tests {
             ("test_2", 1522422690, 2018, 2, 14, false),
             ("test_1", 1502769600, 2017, 6, 24, true),
             ("test_3", 1699025232, 2023, 9, 20, false),
+            ("first_day_of_leap_month", 1679443200, 2023, 2, 1, true),
+            ("first_day_after_leap_month", 1681948800, 2023, 3, 1, false),
+            ("first_day_of_next_month", 1684454400, 2023, 4, 1, false),
+            ("first_day_of_leap_month_2017", 1500768000, 2017, 6, 1, true),
         ];
 
         for (
@@ -362,15 +442,119 @@ mod tests {
 
     #[test]
     fn test_to_solar_timestamp() {
+        let out_of_range = |year| IkigaiError::YearOutOfRange {
+            year,
+            min: LUNAR_FROM_YEAR,
+            max: LUNAR_TO_YEAR,
+        };
         let test_cases = vec![
-            ("test_1", 2017, 6, 24, 12, 0, 0, true, 1502769600),
-            ("test_2", 2018, 2, 14, 23, 11, 30, true, 1522422690),
-            ("test_3", 2018, 2, 14, 23, 11, 30, false, 1522422690),
-            ("test_4", 1900, 1, 14, 23, 11, 30, false, 0),
-            ("test_5", 2100, 12, 14, 23, 11, 30, false, 0),
-            ("test_6", 1900 - 1, 1, 14, 23, 11, 30, false, 0),
-            ("test_7", 2100 + 1, 12, 14, 23, 11, 30, false, 0),
-            ("test_8", 1900, 2, 100000, 23, 11, 30, false, 0),
+            ("test_1", 2017, 6, 24, 12, 0, 0, true, Ok(1502769600)),
+            (
+                "test_2",
+                2018,
+                2,
+                14,
+                23,
+                11,
+                30,
+                true,
+                Err(IkigaiError::LeapMonthNotInYear {
+                    year: 2018,
+                    month: 2,
+                }),
+            ),
+            ("test_3", 2018, 2, 14, 23, 11, 30, false, Ok(1522422690)),
+            (
+                "test_4",
+                1900,
+                1,
+                14,
+                23,
+                11,
+                30,
+                false,
+                // 北京时间 1900-02-13 23:11:30
+                Ok(-2205218910),
+            ),
+            (
+                "test_5",
+                2100,
+                12,
+                14,
+                23,
+                11,
+                30,
+                false,
+                Err(IkigaiError::InvalidDate),
+            ),
+            (
+                "test_6",
+                1900 - 1,
+                1,
+                14,
+                23,
+                11,
+                30,
+                false,
+                Err(out_of_range(1899)),
+            ),
+            (
+                "test_7",
+                2100 + 1,
+                12,
+                14,
+                23,
+                11,
+                30,
+                false,
+                Err(out_of_range(2101)),
+            ),
+            (
+                "test_8",
+                1900,
+                2,
+                100000,
+                23,
+                11,
+                30,
+                false,
+                Err(IkigaiError::InvalidLunarDay {
+                    year: 1900,
+                    month: 2,
+                    day: 100000,
+                    max: 30,
+                }),
+            ),
+            (
+                "test_9",
+                2018,
+                13,
+                1,
+                0,
+                0,
+                0,
+                false,
+                Err(IkigaiError::InvalidLunarMonth {
+                    year: 2018,
+                    month: 13,
+                }),
+            ),
+            (
+                "test_10",
+                2018,
+                2,
+                0,
+                0,
+                0,
+                0,
+                false,
+                Err(IkigaiError::InvalidLunarDay {
+                    year: 2018,
+                    month: 2,
+                    day: 0,
+                    max: 30,
+                }),
+            ),
         ];
 
         for case in test_cases {
@@ -384,6 +568,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_lunar_out_of_range() {
+        let t1 = Utc.with_ymd_and_hms(1900, 1, 30, 0, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2101, 6, 1, 0, 0, 0).unwrap();
+        let t3 = Utc.with_ymd_and_hms(1900, 1, 31, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            Lunar::new(t1),
+            Err(IkigaiError::YearOutOfRange {
+                year: 1900,
+                min: LUNAR_FROM_YEAR,
+                max: LUNAR_TO_YEAR
+            })
+        );
+        assert_eq!(
+            Lunar::new(t2),
+            Err(IkigaiError::YearOutOfRange {
+                year: 2101,
+                min: LUNAR_FROM_YEAR,
+                max: LUNAR_TO_YEAR
+            })
+        );
+        assert_eq!(
+            Lunar::from_solar_timestamp(t3.timestamp()),
+            Ok((1900, 1, 1, false))
+        );
+    }
+
     #[test]
     fn test_new_lunar() {
         let t1 = Utc.with_ymd_and_hms(2017, 8, 15,12, 0, 0).unwrap();
@@ -399,6 +611,7 @@ mod tests {
                     month: 6,
                     day: 24,
                     month_is_leap: true,
+                    variant: CalendarVariant::Chinese,
                 },
             ),
             (
@@ -410,6 +623,7 @@ mod tests {
                     month: 2,
                     day: 14,
                     month_is_leap: false,
+                    variant: CalendarVariant::Chinese,
                 },
             ),
         ];
@@ -443,6 +657,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_new_lunar_vietnamese() {
+        // 1985-01-21 越南已是乙丑年正月初一(牛年), 中国仍在甲子年腊月
+        let t = Utc.with_ymd_and_hms(1985, 1, 21, 12, 0, 0).unwrap();
+        let lunar = Lunar::new_in(t, CalendarVariant::Vietnamese).unwrap();
+        assert_eq!(lunar.date(), LunarDate::new(1985, 1, 1, false).unwrap());
+        assert_eq!(lunar.variant(), CalendarVariant::Vietnamese);
+        assert!(lunar.is_leap());
+        assert_eq!(lunar.animal().alias(), "牛");
+
+        let china = Lunar::new(t).unwrap();
+        assert_eq!(china.date(), LunarDate::new(1984, 12, 1, false).unwrap());
+        assert!(china.is_leap());
+        assert_eq!(china.animal().alias(), "鼠");
+
+        // 中国 1984 年闰十月, 越南 1984 年无闰月
+        let t = Utc.with_ymd_and_hms(1984, 12, 22, 0, 0, 0).unwrap();
+        let lunar = Lunar::new_in(t, CalendarVariant::Vietnamese).unwrap();
+        assert_eq!(lunar.date(), LunarDate::new(1984, 12, 1, false).unwrap());
+        assert!(!lunar.is_leap());
+
+        let t = Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            Lunar::new_in(t, CalendarVariant::Vietnamese)
+                .unwrap()
+                .animal()
+                .alias(),
+            "猫"
+        );
+        assert_eq!(Lunar::new(t).unwrap().animal().alias(), "兔");
+    }
+
     #[test]
     fn test_lunar_is_leap() {
         let t1 = Utc.with_ymd_and_hms(2018, 6, 1,0, 0, 0).unwrap();
@@ -492,11 +738,13 @@ mod tests {
         let t1 = Utc.with_ymd_and_hms(2018, 5, 1,0, 0, 0).unwrap();
         let t2 = Utc.with_ymd_and_hms(2017, 6, 15,0, 0, 0).unwrap();
         let t3 = Utc.with_ymd_and_hms(2017, 8, 15,0, 0, 0).unwrap();
+        let t4 = Utc.with_ymd_and_hms(2019, 6, 1, 0, 0, 0).unwrap();
 
         let test_cases = vec![
             ("test_1", Lunar::new(t1), Animal::new(11)),
             ("test_2", Lunar::new(t2), Animal::new(10)),
             ("test_3", Lunar::new(t3), Animal::new(10)),
+            ("test_4", Lunar::new(t4), Animal::new(12)),
         ];
 
         for case in test_cases {
@@ -651,6 +899,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_lunar_display() {
+        let tests = vec![
+            (
+                "test_1",
+                Utc.with_ymd_and_hms(2024, 2, 10, 0, 0, 0).unwrap(),
+                "二零二四年正月初一",
+            ),
+            (
+                "test_2",
+                Utc.with_ymd_and_hms(2023, 3, 22, 0, 0, 0).unwrap(),
+                "二零二三年闰二月初一",
+            ),
+            (
+                "test_3",
+                Utc.with_ymd_and_hms(2017, 8, 21, 0, 0, 0).unwrap(),
+                "二零一七年闰六月三十",
+            ),
+        ];
+
+        for (name, t, want) in tests {
+            let lunar = Lunar::new(t).unwrap();
+            assert_eq!(lunar.to_string(), want, "{} failed", name);
+            assert_eq!(want.parse::<Lunar>(), Ok(lunar), "{} failed", name);
+        }
+        assert!("二零二四年闰正月初一".parse::<Lunar>().is_err());
+    }
+
     #[test]
     fn test_lunar_days() {
         let test_cases = vec![
@@ -0,0 +1,469 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone};
+
+use super::{locale::Locale, lunar::Lunar, lunisolar, parser, variant::CalendarVariant};
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 农历数据表以北京时间划分日期
+const CHINA_UTC_OFFSET: i64 = 8 * 3600;
+
+const NUMBER_ALIAS: [&str; 10] = ["零", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+const DATE_ALIAS: [&str; 4] = ["初", "十", "廿", "卅"];
+
+const LUNAR_MONTH_ALIAS: [&str; 12] = [
+    "正", "二", "三", "四", "五", "六", "七", "八", "九", "十", "冬", "腊",
+];
+
+// 农历日期(年、月、日、是否闰月), 不含时刻
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunarDate {
+    pub year: i64,
+    pub month: i64,
+    pub day: i64,
+    pub is_leap: bool,
+}
+
+impl LunarDate {
+    // 创建并校验农历日期
+    pub fn new(year: i64, month: i64, day: i64, is_leap: bool) -> IkigaiResult<Self> {
+        let date = LunarDate {
+            year,
+            month,
+            day,
+            is_leap,
+        };
+        date.to_naive_date()?;
+        Ok(date)
+    }
+
+    // 按历法变体创建并校验农历日期
+    pub fn new_in(
+        year: i64,
+        month: i64,
+        day: i64,
+        is_leap: bool,
+        variant: CalendarVariant,
+    ) -> IkigaiResult<Self> {
+        let date = LunarDate {
+            year,
+            month,
+            day,
+            is_leap,
+        };
+        date.to_naive_date_in(variant)?;
+        Ok(date)
+    }
+
+    // 按历法变体将公历日期转为农历
+    pub fn from_naive_date_in(date: NaiveDate, variant: CalendarVariant) -> IkigaiResult<Self> {
+        match variant {
+            CalendarVariant::Chinese => Self::from_naive_date(date),
+            _ => {
+                let lunar = lunisolar::from_naive_date(date, variant.utc_offset())?;
+                Lunar::check_year(lunar.year)?;
+                Ok(lunar)
+            }
+        }
+    }
+
+    // 按历法变体将农历转为公历日期
+    pub fn to_naive_date_in(&self, variant: CalendarVariant) -> IkigaiResult<NaiveDate> {
+        match variant {
+            CalendarVariant::Chinese => self.to_naive_date(),
+            _ => {
+                Lunar::check_year(self.year)?;
+                lunisolar::to_naive_date(self, variant.utc_offset())
+            }
+        }
+    }
+
+    // 公历日期转农历
+    pub fn from_naive_date(date: NaiveDate) -> IkigaiResult<Self> {
+        let ts = date.and_time(NaiveTime::MIN).and_utc().timestamp();
+        let (year, month, day, is_leap) = Lunar::from_solar_timestamp(ts)?;
+        Ok(LunarDate {
+            year,
+            month,
+            day,
+            is_leap,
+        })
+    }
+
+    // 农历转公历日期
+    pub fn to_naive_date(&self) -> IkigaiResult<NaiveDate> {
+        // to_solar_timestamp 返回北京时间零点对应的时间戳
+        let ts = Lunar::to_solar_timestamp(self.year, self.month, self.day, 0, 0, 0, self.is_leap)?;
+        DateTime::from_timestamp(ts + CHINA_UTC_OFFSET, 0)
+            .map(|t| t.date_naive())
+            .ok_or(IkigaiError::InvalidDate)
+    }
+
+    // 该农历日期在指定时区的某一时刻
+    pub fn to_datetime<Tz: TimeZone>(
+        &self,
+        tz: &Tz,
+        time: NaiveTime,
+    ) -> IkigaiResult<DateTime<Tz>> {
+        let naive = self.to_naive_date()?.and_time(time);
+        tz.from_local_datetime(&naive)
+            .earliest()
+            .ok_or(IkigaiError::InvalidDate)
+    }
+
+    // 汉字表示年份(二零二四)
+    pub fn year_alias(&self) -> String {
+        let mut result = self.year.to_string();
+        for (i, alias) in NUMBER_ALIAS.iter().enumerate() {
+            result = result.replace(&i.to_string(), alias);
+        }
+        result
+    }
+
+    // 汉字表示月份(正月、闰二月...)
+    pub fn month_alias(&self) -> String {
+        let mut alias = String::new();
+        if self.is_leap {
+            alias += "闰";
+        }
+        alias += LUNAR_MONTH_ALIAS[self.month as usize - 1];
+        alias += "月";
+        alias
+    }
+
+    // 指定语言的月份名称(閏二月、Leap Second Month...)
+    pub fn month_name(&self, locale: Locale) -> String {
+        locale.lunar_month(self.month, self.is_leap)
+    }
+
+    // 汉字表示日期(初一、十五、廿九...)
+    pub fn day_alias(&self) -> String {
+        let mut alias = String::new();
+        match self.day {
+            10 => alias += "初十",
+            20 => alias += "二十",
+            30 => alias += "三十",
+            _ => {
+                let n = (self.day / 10) as usize;
+                alias += DATE_ALIAS[n];
+                alias += NUMBER_ALIAS[(self.day % 10) as usize];
+            }
+        }
+        alias
+    }
+
+    fn parse_month(s: &str) -> Option<(i64, bool)> {
+        let (s, is_leap) = match s.strip_prefix('闰') {
+            Some(rest) => (rest, true),
+            None => (s, false),
+        };
+        let month = LUNAR_MONTH_ALIAS.iter().position(|a| *a == s)? as i64 + 1;
+        Some((month, is_leap))
+    }
+
+    fn parse_day(s: &str) -> Option<i64> {
+        match s {
+            "初十" => return Some(10),
+            "二十" => return Some(20),
+            "三十" => return Some(30),
+            _ => {}
+        }
+        let mut chars = s.chars();
+        let (Some(tens), Some(ones), None) = (chars.next(), chars.next(), chars.next()) else {
+            return None;
+        };
+        let tens = DATE_ALIAS.iter().position(|a| a.starts_with(tens))? as i64;
+        let ones = NUMBER_ALIAS.iter().position(|a| a.starts_with(ones))? as i64;
+        if ones == 0 {
+            return None;
+        }
+        Some(tens * 10 + ones)
+    }
+}
+
+// 形如 `二零二四年闰二月初一`
+impl fmt::Display for LunarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}年{}{}",
+            self.year_alias(),
+            self.month_alias(),
+            self.day_alias()
+        )
+    }
+}
+
+impl FromStr for LunarDate {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        let err = || IkigaiError::ParseError {
+            input: s.to_string(),
+            kind: "LunarDate",
+        };
+        let (year, rest) = s.split_once('年').ok_or_else(err)?;
+        let (month, day) = rest.split_once('月').ok_or_else(err)?;
+        let year = parser::parse_year_number(year).ok_or_else(err)?;
+        let (month, is_leap) = Self::parse_month(month).ok_or_else(err)?;
+        let day = Self::parse_day(day).ok_or_else(err)?;
+        LunarDate::new(year, month, day, is_leap)
+    }
+}
+
+impl From<&Lunar> for LunarDate {
+    fn from(lunar: &Lunar) -> Self {
+        LunarDate {
+            year: lunar.get_year(),
+            month: lunar.get_month(),
+            day: lunar.get_day(),
+            is_leap: lunar.is_leap_month(),
+        }
+    }
+}
+
+impl TryFrom<NaiveDate> for LunarDate {
+    type Error = IkigaiError;
+
+    fn try_from(date: NaiveDate) -> IkigaiResult<Self> {
+        LunarDate::from_naive_date(date)
+    }
+}
+
+impl TryFrom<LunarDate> for NaiveDate {
+    type Error = IkigaiError;
+
+    fn try_from(date: LunarDate) -> IkigaiResult<Self> {
+        date.to_naive_date()
+    }
+}
+
+// 闰月排在同号的常规月份之后
+impl Ord for LunarDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.is_leap, self.day).cmp(&(
+            other.year,
+            other.month,
+            other.is_leap,
+            other.day,
+        ))
+    }
+}
+
+impl PartialOrd for LunarDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, Utc};
+
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn lunar(year: i64, month: i64, day: i64, is_leap: bool) -> LunarDate {
+        LunarDate {
+            year,
+            month,
+            day,
+            is_leap,
+        }
+    }
+
+    #[test]
+    fn test_lunar_date_round_trip() {
+        let tests = vec![
+            (
+                "spring_festival",
+                date(2024, 2, 10),
+                lunar(2024, 1, 1, false),
+            ),
+            ("new_year_eve", date(2024, 2, 9), lunar(2023, 12, 30, false)),
+            ("leap_month", date(2023, 3, 22), lunar(2023, 2, 1, true)),
+            (
+                "after_leap_month",
+                date(2023, 4, 20),
+                lunar(2023, 3, 1, false),
+            ),
+            (
+                "leap_month_2017",
+                date(2017, 8, 15),
+                lunar(2017, 6, 24, true),
+            ),
+            ("first_day", date(1900, 1, 31), lunar(1900, 1, 1, false)),
+        ];
+        for (name, solar, want) in tests {
+            let got = LunarDate::from_naive_date(solar).unwrap();
+            assert_eq!(got, want, "{} failed", name);
+            assert_eq!(want.to_naive_date().unwrap(), solar, "{} failed", name);
+            assert_eq!(NaiveDate::try_from(want).unwrap(), solar, "{} failed", name);
+            assert_eq!(LunarDate::try_from(solar).unwrap(), want, "{} failed", name);
+        }
+    }
+
+    #[test]
+    fn test_new_lunar_date() {
+        assert!(LunarDate::new(2023, 2, 29, true).is_ok());
+        assert_eq!(
+            LunarDate::new(2024, 2, 1, true),
+            Err(IkigaiError::LeapMonthNotInYear {
+                year: 2024,
+                month: 2
+            })
+        );
+        assert_eq!(
+            LunarDate::new(2024, 1, 31, false),
+            Err(IkigaiError::InvalidLunarDay {
+                year: 2024,
+                month: 1,
+                day: 31,
+                max: 29
+            })
+        );
+        assert!(matches!(
+            LunarDate::new(2200, 1, 1, false),
+            Err(IkigaiError::YearOutOfRange { year: 2200, .. })
+        ));
+        assert!(LunarDate::from_naive_date(date(1899, 6, 1)).is_err());
+    }
+
+    #[test]
+    fn test_lunar_date_to_datetime() {
+        let date = lunar(2024, 1, 1, false);
+        let time = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+
+        let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+        let got = date.to_datetime(&beijing, time).unwrap();
+        assert_eq!(got.to_rfc3339(), "2024-02-10T09:30:00+08:00");
+
+        let got = date.to_datetime(&Utc, time).unwrap();
+        assert_eq!(got.to_rfc3339(), "2024-02-10T09:30:00+00:00");
+    }
+
+    #[test]
+    fn test_lunar_date_from_str() {
+        let tests = vec![
+            ("display", "二零二四年正月初一", lunar(2024, 1, 1, false)),
+            ("leap", "二零二三年闰二月十五", lunar(2023, 2, 15, true)),
+            ("digits", "2024年腊月廿九", lunar(2024, 12, 29, false)),
+            ("ling", "二〇二四年冬月二十", lunar(2024, 11, 20, false)),
+        ];
+        for (name, s, want) in tests {
+            assert_eq!(s.parse::<LunarDate>(), Ok(want), "{} failed", name);
+        }
+        for day in 1..=29 {
+            let date = lunar(2023, 2, day, true);
+            assert_eq!(date.to_string().parse::<LunarDate>(), Ok(date));
+        }
+
+        let errors = vec![
+            ("no_year", "正月初一"),
+            ("bad_month", "二零二四年十三月初一"),
+            ("bad_day", "二零二四年正月初零"),
+            ("not_leap", "二零二四年闰正月初一"),
+            ("too_long", "二零二四年正月初一日"),
+            ("overflow", "99999999999999999999年正月初一"),
+        ];
+        for (name, s) in errors {
+            assert!(s.parse::<LunarDate>().is_err(), "{} should fail", name);
+        }
+    }
+
+    #[test]
+    fn test_vietnamese_variant() {
+        let vi = CalendarVariant::Vietnamese;
+        // 越南与中国春节不同的年份
+        let tests = vec![
+            ("1968", 1968, date(1968, 1, 29), date(1968, 1, 30)),
+            ("1985", 1985, date(1985, 1, 21), date(1985, 2, 20)),
+            ("2007", 2007, date(2007, 2, 17), date(2007, 2, 18)),
+            ("2024", 2024, date(2024, 2, 10), date(2024, 2, 10)),
+        ];
+        for (name, year, vietnam, china) in tests {
+            let tet = lunar(year, 1, 1, false);
+            assert_eq!(
+                tet.to_naive_date_in(vi).unwrap(),
+                vietnam,
+                "{} failed",
+                name
+            );
+            assert_eq!(
+                tet.to_naive_date_in(CalendarVariant::Chinese).unwrap(),
+                china,
+                "{} failed",
+                name
+            );
+            assert_eq!(
+                LunarDate::from_naive_date_in(vietnam, vi).unwrap(),
+                tet,
+                "{} failed",
+                name
+            );
+        }
+
+        // 中国 1984 年闰十月, 越南则闰在 1985 年二月, 两国春节相差一个月
+        assert_eq!(
+            LunarDate::from_naive_date_in(date(1985, 1, 21), CalendarVariant::Chinese).unwrap(),
+            lunar(1984, 12, 1, false)
+        );
+        assert_eq!(
+            LunarDate::from_naive_date_in(date(1984, 12, 22), vi).unwrap(),
+            lunar(1984, 12, 1, false)
+        );
+        assert!(LunarDate::new_in(1985, 2, 1, true, vi).is_ok());
+        assert!(LunarDate::new_in(1985, 2, 1, true, CalendarVariant::Chinese).is_err());
+        assert!(LunarDate::new_in(1984, 10, 1, true, vi).is_err());
+        assert!(LunarDate::new_in(2200, 1, 1, false, vi).is_err());
+        assert!(LunarDate::from_naive_date_in(date(1850, 1, 1), vi).is_err());
+    }
+
+    #[test]
+    fn test_korean_variant() {
+        let ko = CalendarVariant::Korean;
+        let tests = vec![
+            ("1997", 1997, date(1997, 2, 8), date(1997, 2, 7)),
+            ("2027", 2027, date(2027, 2, 7), date(2027, 2, 6)),
+            ("2024", 2024, date(2024, 2, 10), date(2024, 2, 10)),
+        ];
+        for (name, year, korea, china) in tests {
+            let seollal = lunar(year, 1, 1, false);
+            assert_eq!(
+                seollal.to_naive_date_in(ko).unwrap(),
+                korea,
+                "{} failed",
+                name
+            );
+            assert_eq!(seollal.to_naive_date().unwrap(), china, "{} failed", name);
+        }
+        // 2012 年韩国闰三月, 中国闰四月
+        assert!(LunarDate::new_in(2012, 3, 1, true, ko).is_ok());
+        assert!(LunarDate::new_in(2012, 4, 1, true, ko).is_err());
+        assert!(LunarDate::new(2012, 4, 1, true).is_ok());
+    }
+
+    #[test]
+    fn test_lunar_date_order() {
+        let mut dates = vec![
+            lunar(2023, 3, 1, false),
+            lunar(2023, 2, 1, true),
+            lunar(2022, 12, 30, false),
+            lunar(2023, 2, 30, false),
+        ];
+        dates.sort();
+        assert_eq!(
+            dates,
+            vec![
+                lunar(2022, 12, 30, false),
+                lunar(2023, 2, 30, false),
+                lunar(2023, 2, 1, true),
+                lunar(2023, 3, 1, false),
+            ]
+        );
+    }
+}
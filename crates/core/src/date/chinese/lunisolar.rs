@@ -0,0 +1,229 @@
+use chrono::{Datelike, NaiveDate};
+
+use super::lunar_date::LunarDate;
+use crate::{
+    date::astro::{
+        from_jdn, jdn,
+        moon::{new_moon, NEW_MOON_EPOCH_JD},
+        sun::apparent_longitude,
+        SYNODIC_MONTH,
+    },
+    ikigai_error::{IkigaiError, IkigaiResult},
+};
+
+// 按指定时区天文推算农历, 置月规则参照胡玉德(Hồ Ngọc Đức)的阴历算法;
+// 朔的时刻(沿用其 Meeus 简化公式)、力学时改正与判断中气的太阳视黄经均取自 astro 模块,
+// 原算法的几何黄经未计光行差与章动, 中气临近子夜时会差出一日, 如 2017 年韩国的闰五月
+
+// 第 k 个朔在当地的儒略日数
+fn new_moon_day(k: i64, tz: i64) -> i64 {
+    (new_moon(k) + 0.5 + tz as f64 / 24.0).floor() as i64
+}
+
+// 当地某日零点太阳所在的中气区间(0..12), 冬至为 9
+fn sun_sector(day: i64, tz: i64) -> i64 {
+    (apparent_longitude(day as f64 - 0.5 - tz as f64 / 24.0) / 30.0).floor() as i64
+}
+
+// 公历某年冬至所在农历月(十一月)的朔日
+fn month11(year: i64, tz: i64) -> IkigaiResult<i64> {
+    let dec31 = NaiveDate::from_ymd_opt(year as i32, 12, 31).ok_or(IkigaiError::InvalidDate)?;
+    let k = ((jdn(dec31) as f64 - 2415021.0) / SYNODIC_MONTH).floor() as i64;
+    let nm = new_moon_day(k, tz);
+    if sun_sector(nm, tz) >= 9 {
+        return Ok(new_moon_day(k - 1, tz));
+    }
+    Ok(nm)
+}
+
+// 闰月相对于十一月的偏移: 首个不含中气的月份
+fn leap_month_offset(a11: i64, tz: i64) -> i64 {
+    let k = ((a11 as f64 - NEW_MOON_EPOCH_JD) / SYNODIC_MONTH + 0.5).floor() as i64;
+    let mut i = 1;
+    let mut arc = sun_sector(new_moon_day(k + i, tz), tz);
+    loop {
+        let last = arc;
+        i += 1;
+        arc = sun_sector(new_moon_day(k + i, tz), tz);
+        if arc == last || i >= 14 {
+            break;
+        }
+    }
+    i - 1
+}
+
+// 公历日期转农历
+pub fn from_naive_date(date: NaiveDate, tz: i64) -> IkigaiResult<LunarDate> {
+    let day = jdn(date);
+    let year = date.year() as i64;
+    // 取不晚于当天的最近一个朔日
+    let mut k = ((day as f64 - NEW_MOON_EPOCH_JD) / SYNODIC_MONTH).floor() as i64 + 1;
+    let mut month_start = new_moon_day(k, tz);
+    while month_start > day {
+        k -= 1;
+        month_start = new_moon_day(k, tz);
+    }
+    let mut a11 = month11(year, tz)?;
+    let mut b11 = a11;
+    let mut lunar_year;
+    if a11 >= month_start {
+        lunar_year = year;
+        a11 = month11(year - 1, tz)?;
+    } else {
+        lunar_year = year + 1;
+        b11 = month11(year + 1, tz)?;
+    }
+    let diff = (month_start - a11) / 29;
+    let mut is_leap = false;
+    let mut month = diff + 11;
+    if b11 - a11 > 365 {
+        let leap_diff = leap_month_offset(a11, tz);
+        if diff >= leap_diff {
+            month = diff + 10;
+            is_leap = diff == leap_diff;
+        }
+    }
+    if month > 12 {
+        month -= 12;
+    }
+    if month >= 11 && diff < 4 {
+        lunar_year -= 1;
+    }
+    Ok(LunarDate {
+        year: lunar_year,
+        month,
+        day: day - month_start + 1,
+        is_leap,
+    })
+}
+
+// 农历月份的朔日(儒略日数), 闰月不存在时返回错误
+fn month_start(date: &LunarDate, tz: i64) -> IkigaiResult<i64> {
+    let (a11, b11) = if date.month < 11 {
+        (month11(date.year - 1, tz)?, month11(date.year, tz)?)
+    } else {
+        (month11(date.year, tz)?, month11(date.year + 1, tz)?)
+    };
+    let mut offset = (date.month - 11).rem_euclid(12);
+    let mut has_leap = false;
+    if b11 - a11 > 365 {
+        let leap_offset = leap_month_offset(a11, tz);
+        let leap_month = (leap_offset - 2).rem_euclid(12);
+        has_leap = leap_month == date.month;
+        if date.is_leap || offset >= leap_offset {
+            offset += 1;
+        }
+    }
+    if date.is_leap && !has_leap {
+        return Err(IkigaiError::LeapMonthNotInYear {
+            year: date.year,
+            month: date.month,
+        });
+    }
+    let k = (0.5 + (a11 as f64 - NEW_MOON_EPOCH_JD) / SYNODIC_MONTH).floor() as i64;
+    Ok(new_moon_day(k + offset, tz))
+}
+
+// 农历转公历日期, 同时校验月份与日期
+pub fn to_naive_date(date: &LunarDate, tz: i64) -> IkigaiResult<NaiveDate> {
+    if !(1..=12).contains(&date.month) {
+        return Err(IkigaiError::InvalidLunarMonth {
+            year: date.year,
+            month: date.month,
+        });
+    }
+    let start = month_start(date, tz)?;
+    let max = month_days(start, tz);
+    if !(1..=max).contains(&date.day) {
+        return Err(IkigaiError::InvalidLunarDay {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            max,
+        });
+    }
+    from_jdn(start + date.day - 1)
+}
+
+// 以朔日计算的月长
+fn month_days(start: i64, tz: i64) -> i64 {
+    let k = ((start as f64 - NEW_MOON_EPOCH_JD) / SYNODIC_MONTH + 0.5).floor() as i64;
+    new_moon_day(k + 1, tz) - start
+}
+
+// 农历年的闰月, 无闰月返回 0
+pub fn leap_month(year: i64, tz: i64) -> IkigaiResult<i64> {
+    // 闰十一、十二月落在 [本年十一月, 次年十一月) 区间内, 其余落在上一区间
+    for (from, months) in [(year - 1, 1..=10), (year, 11..=12)] {
+        let a11 = month11(from, tz)?;
+        if month11(from + 1, tz)? - a11 > 365 {
+            let month = (leap_month_offset(a11, tz) - 2).rem_euclid(12);
+            if months.contains(&month) {
+                return Ok(month);
+            }
+        }
+    }
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::chinese::lunar::{Lunar, LUNAR_TO_YEAR};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_matches_chinese_table() {
+        // UTC+8 下的天文推算与农历数据表的春节、闰月一致;
+        // 1929 年以前中国以北京地方平时划分日期, 不在比较之列
+        for year in 1929..=LUNAR_TO_YEAR {
+            let new_year = LunarDate {
+                year,
+                month: 1,
+                day: 1,
+                is_leap: false,
+            };
+            assert_eq!(
+                to_naive_date(&new_year, 8).unwrap(),
+                new_year.to_naive_date().unwrap(),
+                "{} failed",
+                year
+            );
+            assert_eq!(
+                leap_month(year, 8).unwrap(),
+                Lunar::leap_month(year),
+                "{} failed",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn test_korean_leap_month() {
+        // 2017 年韩国闰五月(6 月 24 日朔), 中国闰六月
+        assert_eq!(leap_month(2017, 9), Ok(5));
+        assert_eq!(
+            from_naive_date(date(2017, 6, 24), 9),
+            Ok(LunarDate {
+                year: 2017,
+                month: 5,
+                day: 1,
+                is_leap: true,
+            })
+        );
+        assert_eq!(leap_month(2017, 8), Ok(6));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut day = date(1980, 1, 1);
+        while day < date(1990, 1, 1) {
+            let lunar = from_naive_date(day, 7).unwrap();
+            assert_eq!(to_naive_date(&lunar, 7).unwrap(), day, "{} failed", day);
+            day += chrono::Duration::days(1);
+        }
+    }
+}
@@ -1,9 +1,21 @@
 pub mod animal;
+pub mod chinese_date;
 pub mod constellation;
+pub mod festival;
 pub mod gan;
 pub mod gan_zhi;
+pub mod locale;
 pub mod lunar;
+pub mod lunar_date;
+pub mod lunisolar;
+pub mod month_grid;
+pub mod parser;
+pub mod pillar;
+pub mod reign;
 pub mod solar;
 pub mod solarterm;
 pub mod utils;
+pub mod variant;
 pub mod zhi;
+pub mod zodiac_sign;
+pub mod zodiac_system;
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use chrono::{Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
+
+use super::{
+    festival::Festival,
+    lunar::{Lunar, LUNAR_TO_YEAR},
+    solarterm::{Solarterm, SOLARTERM_FROM_YEAR},
+};
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 月历中的一格
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayCell {
+    pub date: NaiveDate,
+    pub lunar_year: i64,
+    pub lunar_month: i64,
+    pub lunar_day: i64,
+    pub is_leap_month: bool,
+    // 农历日期标签, 初一显示月份(正月、闰二月...), 其余显示日期(初二、十五...)
+    pub lunar_label: String,
+    // 当天交节的节气
+    pub solarterm: Option<Solarterm>,
+    pub festivals: Vec<Festival>,
+    pub is_weekend: bool,
+    // 是否属于本月(首尾补齐的格子为上月或下月的日期)
+    pub in_month: bool,
+}
+
+// 按周排列的月历, 每周 7 格, 首尾用相邻月份的日期补齐
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthGrid {
+    year: i32,
+    month: u32,
+    week_start: Weekday,
+    weeks: Vec<Vec<DayCell>>,
+}
+
+impl MonthGrid {
+    pub fn new(year: i32, month: u32, week_start: Weekday) -> IkigaiResult<Self> {
+        if !Self::is_supported(year as i64) {
+            return Err(IkigaiError::YearOutOfRange {
+                year: year as i64,
+                min: SOLARTERM_FROM_YEAR,
+                max: LUNAR_TO_YEAR,
+            });
+        }
+        let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or(IkigaiError::InvalidDate)?;
+        let last = first
+            .checked_add_months(chrono::Months::new(1))
+            .ok_or(IkigaiError::InvalidDate)?
+            - Duration::days(1);
+
+        let lead = first.weekday().days_since(week_start) as i64;
+        let start = first - Duration::days(lead);
+        let trail = 6 - last.weekday().days_since(week_start) as i64;
+        let end = last + Duration::days(trail);
+
+        let terms = Self::solarterms_between(start, end);
+        let mut cells = Vec::new();
+        let mut date = start;
+        while date <= end {
+            cells.push(Self::cell(date, month, terms.get(&date).cloned())?);
+            date += Duration::days(1);
+        }
+        let weeks = cells.chunks(7).map(|week| week.to_vec()).collect();
+
+        Ok(MonthGrid {
+            year,
+            month,
+            week_start,
+            weeks,
+        })
+    }
+
+    // 一年 12 个月的月历
+    pub fn year_grids(year: i32, week_start: Weekday) -> IkigaiResult<Vec<MonthGrid>> {
+        (1..=12).map(|m| Self::new(year, m, week_start)).collect()
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    pub fn weeks(&self) -> &[Vec<DayCell>] {
+        &self.weeks
+    }
+
+    // 表头的星期顺序
+    pub fn weekdays(&self) -> [Weekday; 7] {
+        let mut result = [self.week_start; 7];
+        for i in 1..7 {
+            result[i] = result[i - 1].succ();
+        }
+        result
+    }
+
+    // 本月的全部日期(不含补齐的格子)
+    pub fn days(&self) -> impl Iterator<Item = &DayCell> {
+        self.weeks.iter().flatten().filter(|cell| cell.in_month)
+    }
+
+    pub fn is_supported(year: i64) -> bool {
+        (SOLARTERM_FROM_YEAR..=LUNAR_TO_YEAR).contains(&year)
+    }
+
+    fn cell(date: NaiveDate, month: u32, solarterm: Option<Solarterm>) -> IkigaiResult<DayCell> {
+        let t = Utc.from_utc_datetime(&date.and_time(Default::default()));
+        let lunar = Lunar::new(t)?;
+        let lunar_label = if lunar.get_day() == 1 {
+            lunar.month_alias()
+        } else {
+            lunar.day_alias()
+        };
+        Ok(DayCell {
+            date,
+            lunar_year: lunar.get_year(),
+            lunar_month: lunar.get_month(),
+            lunar_day: lunar.get_day(),
+            is_leap_month: lunar.is_leap_month(),
+            lunar_label,
+            solarterm,
+            festivals: Festival::from_solar(&t),
+            is_weekend: matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+            in_month: date.month() == month,
+        })
+    }
+
+    // 区间内各节气按北京时间所在日期索引
+    fn solarterms_between(start: NaiveDate, end: NaiveDate) -> HashMap<NaiveDate, Solarterm> {
+        let first = 24 * (start.year() as i64 - SOLARTERM_FROM_YEAR).max(0);
+        let last = 24 * (end.year() as i64 - SOLARTERM_FROM_YEAR + 1);
+        (first..last)
+            .filter_map(|index| Solarterm::new(index).ok())
+            .filter_map(|term| Some((term.time().ok()?.date_naive(), term)))
+            .filter(|(date, _)| (start..=end).contains(date))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn find(grid: &MonthGrid, d: NaiveDate) -> &DayCell {
+        grid.weeks()
+            .iter()
+            .flatten()
+            .find(|cell| cell.date == d)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_new_month_grid() {
+        let tests = vec![
+            (
+                "sunday",
+                Weekday::Sun,
+                date(2024, 1, 28),
+                date(2024, 3, 2),
+                5,
+            ),
+            (
+                "monday",
+                Weekday::Mon,
+                date(2024, 1, 29),
+                date(2024, 3, 3),
+                5,
+            ),
+            (
+                "thursday",
+                Weekday::Thu,
+                date(2024, 2, 1),
+                date(2024, 3, 6),
+                5,
+            ),
+        ];
+        for (name, week_start, first, last, weeks) in tests {
+            let grid = MonthGrid::new(2024, 2, week_start).unwrap();
+            assert_eq!(grid.weeks().len(), weeks, "{} failed", name);
+            assert_eq!(grid.weeks()[0][0].date, first, "{} failed", name);
+            assert_eq!(grid.weeks()[weeks - 1][6].date, last, "{} failed", name);
+            assert_eq!(grid.weekdays()[0], week_start, "{} failed", name);
+            assert_eq!(grid.days().count(), 29, "{} failed", name);
+        }
+
+        assert_eq!(
+            MonthGrid::new(1800, 1, Weekday::Sun),
+            Err(IkigaiError::YearOutOfRange {
+                year: 1800,
+                min: SOLARTERM_FROM_YEAR,
+                max: LUNAR_TO_YEAR
+            })
+        );
+        assert_eq!(
+            MonthGrid::new(2024, 13, Weekday::Sun),
+            Err(IkigaiError::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn test_month_grid_cells() {
+        let grid = MonthGrid::new(2024, 2, Weekday::Mon).unwrap();
+
+        let padding = find(&grid, date(2024, 1, 29));
+        assert!(!padding.in_month);
+        assert_eq!(padding.lunar_label, "十九");
+
+        let lichun = find(&grid, date(2024, 2, 4));
+        assert_eq!(lichun.solarterm.as_ref().unwrap().alias(), "立春");
+        assert!(lichun.is_weekend);
+
+        let chuxi = find(&grid, date(2024, 2, 9));
+        assert_eq!(chuxi.lunar_label, "三十");
+        assert_eq!(chuxi.festivals[0].alias(), "除夕");
+        assert!(!chuxi.is_weekend);
+
+        let chunjie = find(&grid, date(2024, 2, 10));
+        assert_eq!(chunjie.lunar_label, "正月");
+        assert_eq!(
+            (chunjie.lunar_year, chunjie.lunar_month, chunjie.lunar_day),
+            (2024, 1, 1)
+        );
+        assert_eq!(chunjie.festivals[0].alias(), "春节");
+        assert!(chunjie.solarterm.is_none());
+
+        let terms: Vec<&str> = grid
+            .days()
+            .filter_map(|cell| cell.solarterm.as_ref().map(|t| t.alias()))
+            .collect();
+        assert_eq!(terms, vec!["立春", "雨水"]);
+    }
+
+    #[test]
+    fn test_month_grid_leap_month_label() {
+        let grid = MonthGrid::new(2023, 3, Weekday::Sun).unwrap();
+        let cell = find(&grid, date(2023, 3, 22));
+        assert_eq!(cell.lunar_label, "闰二月");
+        assert!(cell.is_leap_month);
+    }
+
+    #[test]
+    fn test_year_grids() {
+        let grids = MonthGrid::year_grids(2024, Weekday::Sun).unwrap();
+        assert_eq!(grids.len(), 12);
+        let days: usize = grids.iter().map(|g| g.days().count()).sum();
+        assert_eq!(days, 366);
+        let terms = grids
+            .iter()
+            .flat_map(|g| g.days())
+            .filter(|cell| cell.solarterm.is_some())
+            .count();
+        assert_eq!(terms, 24);
+    }
+}
@@ -0,0 +1,432 @@
+use std::ops::RangeInclusive;
+
+use chrono::NaiveDate;
+
+use super::{lunar_date::LunarDate, pillar::Pillar, solarterm::Solarterm, utils};
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+const DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseOptions {
+    // 文本中没有年份时使用的年份
+    pub default_year: Option<i64>,
+    // 干支纪年在此范围内查找对应的农历年, 有多个匹配时报错
+    pub ganzhi_window: RangeInclusive<i64>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            default_year: None,
+            // 以 1984 甲子年起的一个甲子
+            ganzhi_window: 1984..=2043,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsedDate {
+    Lunar(LunarDate),
+    Solar(NaiveDate),
+}
+
+impl ParsedDate {
+    pub fn to_naive_date(&self) -> IkigaiResult<NaiveDate> {
+        match self {
+            ParsedDate::Lunar(date) => date.to_naive_date(),
+            ParsedDate::Solar(date) => Ok(*date),
+        }
+    }
+
+    pub fn to_lunar_date(&self) -> IkigaiResult<LunarDate> {
+        match self {
+            ParsedDate::Lunar(date) => Ok(*date),
+            ParsedDate::Solar(date) => LunarDate::from_naive_date(*date),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Calendar {
+    Lunar,
+    Solar,
+}
+
+enum Year {
+    Number(i64),
+    Ganzhi(Pillar),
+}
+
+// 解析中文日期文本, 如 `二〇二三年腊月廿三`、`农历闰六月初八`、`甲辰年正月初一`、
+// `2024年清明`、`2024年3月5日`
+//
+// 带 `农历`/`阴历` 或 `公历`/`阳历` 前缀时按前缀确定历法; 否则月份为正、冬、腊或闰月,
+// 或日期以初、廿、卅开头时为农历, 以 `日`/`号` 结尾时为公历, 其余按农历处理.
+// 节气名称解析为该年交节当天(北京时间)的公历日期.
+pub fn parse(text: &str, options: &ParseOptions) -> IkigaiResult<ParsedDate> {
+    let err = |reason: &str| IkigaiError::InvalidDateText {
+        input: text.to_string(),
+        reason: reason.to_string(),
+    };
+
+    let s = text.trim();
+    let (forced, s) = if let Some(rest) = s.strip_prefix("农历").or_else(|| s.strip_prefix("阴历"))
+    {
+        (Some(Calendar::Lunar), rest)
+    } else if let Some(rest) = s.strip_prefix("公历").or_else(|| s.strip_prefix("阳历")) {
+        (Some(Calendar::Solar), rest)
+    } else {
+        (None, s)
+    };
+
+    let (year, rest) = match s.split_once('年') {
+        Some((year, rest)) => (
+            Some(parse_year(year).ok_or_else(|| err("invalid year"))?),
+            rest,
+        ),
+        None => (None, s),
+    };
+    let number_year = |year: Option<Year>| match year {
+        Some(Year::Number(year)) => Ok(year),
+        Some(Year::Ganzhi(_)) => Err(err("ganzhi years are only supported for lunar dates")),
+        None => options.default_year.ok_or_else(|| err("missing year")),
+    };
+
+    // 节气
+    if rest.chars().count() == 2 && !rest.contains('月') {
+        if forced == Some(Calendar::Lunar) {
+            return Err(err("solar terms are not lunar dates"));
+        }
+        let year = number_year(year)?;
+        let term = format!("{}{}", year, rest)
+            .parse::<Solarterm>()
+            .map_err(|e| match e {
+                IkigaiError::ParseError { .. } => err("unknown solar term"),
+                e => e,
+            })?;
+        return Ok(ParsedDate::Solar(term.time()?.date_naive()));
+    }
+
+    let (month, day) = rest.split_once('月').ok_or_else(|| err("missing month"))?;
+    let (month, is_leap) = match month.strip_prefix('闰') {
+        Some(month) => (month, true),
+        None => (month, false),
+    };
+    let has_suffix = day.ends_with(['日', '号']);
+    let day = day.strip_suffix(['日', '号']).unwrap_or(day);
+
+    let lunar_only =
+        is_leap || matches!(month, "正" | "冬" | "腊") || day.starts_with(['初', '廿', '卅']);
+    let calendar = match forced {
+        Some(Calendar::Solar) if lunar_only => {
+            return Err(err("lunar month or day names in a solar date"))
+        }
+        Some(calendar) => calendar,
+        None if !lunar_only && has_suffix => Calendar::Solar,
+        None => Calendar::Lunar,
+    };
+
+    let month = match month {
+        "正" => 1,
+        "冬" => 11,
+        "腊" => 12,
+        _ => parse_number(month)
+            .filter(|m| (1..=12).contains(m))
+            .ok_or_else(|| err("invalid month"))?,
+    };
+    let day = parse_number(day).ok_or_else(|| err("invalid day"))?;
+
+    match calendar {
+        Calendar::Lunar => {
+            let year = match year {
+                Some(Year::Ganzhi(pillar)) => {
+                    resolve_ganzhi_year(pillar, options).map_err(|reason| err(&reason))?
+                }
+                year => number_year(year)?,
+            };
+            Ok(ParsedDate::Lunar(LunarDate::new(
+                year, month, day, is_leap,
+            )?))
+        }
+        Calendar::Solar => {
+            let year = number_year(year)?;
+            let date = i32::try_from(year)
+                .ok()
+                .zip(u32::try_from(day).ok())
+                .and_then(|(year, day)| NaiveDate::from_ymd_opt(year, month as u32, day))
+                .ok_or(IkigaiError::InvalidDate)?;
+            Ok(ParsedDate::Solar(date))
+        }
+    }
+}
+
+// 年份: 阿拉伯数字、逐位的中文数字(二〇二三)或干支(甲辰)
+fn parse_year(s: &str) -> Option<Year> {
+    if let Ok(pillar) = s.parse::<Pillar>() {
+        return Some(Year::Ganzhi(pillar));
+    }
+    parse_year_number(s).map(Year::Number)
+}
+
+// 数字年份: 阿拉伯数字或逐位的中文数字(二〇二三、二零二三)
+pub(crate) fn parse_year_number(s: &str) -> Option<i64> {
+    if s.is_empty() {
+        return None;
+    }
+    s.chars().try_fold(0i64, |year, c| {
+        let digit = match c {
+            '零' => 0,
+            '0'..='9' => c.to_digit(10)? as i64,
+            _ => DIGITS.iter().position(|d| *d == c)? as i64,
+        };
+        year.checked_mul(10)?.checked_add(digit)
+    })
+}
+
+// 月、日中的数字(1..=99): 23、十五、二十三、廿三、卅、初八
+pub(crate) fn parse_number(s: &str) -> Option<i64> {
+    if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+        return s.parse().ok().filter(|n| *n > 0);
+    }
+    let digit = |c: char| {
+        DIGITS
+            .iter()
+            .position(|d| *d == c)
+            .map(|n| n as i64)
+            .filter(|n| *n > 0)
+    };
+    let chars: Vec<char> = s.chars().collect();
+    match chars.as_slice() {
+        ['初', '十'] => Some(10),
+        ['初', ones] => digit(*ones),
+        ['十'] => Some(10),
+        ['廿'] => Some(20),
+        ['卅'] => Some(30),
+        ['十', ones] => Some(10 + digit(*ones)?),
+        ['廿', ones] => Some(20 + digit(*ones)?),
+        ['卅', ones] => Some(30 + digit(*ones)?),
+        [tens, '十'] => Some(digit(*tens)? * 10),
+        [tens, '十', ones] => Some(digit(*tens)? * 10 + digit(*ones)?),
+        [ones] => digit(*ones),
+        _ => None,
+    }
+}
+
+fn resolve_ganzhi_year(pillar: Pillar, options: &ParseOptions) -> Result<i64, String> {
+    let years: Vec<i64> = options
+        .ganzhi_window
+        .clone()
+        .filter(|year| utils::order_mod(year - 3, 60) == pillar.order())
+        .collect();
+    match years.as_slice() {
+        [year] => Ok(*year),
+        [] => Err(format!(
+            "no {} year in {}..={}",
+            pillar,
+            options.ganzhi_window.start(),
+            options.ganzhi_window.end()
+        )),
+        _ => Err(format!(
+            "{} year is ambiguous: {}",
+            pillar,
+            years
+                .iter()
+                .map(|year| year.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lunar(year: i64, month: i64, day: i64, is_leap: bool) -> ParsedDate {
+        ParsedDate::Lunar(LunarDate {
+            year,
+            month,
+            day,
+            is_leap,
+        })
+    }
+
+    fn solar(y: i32, m: u32, d: u32) -> ParsedDate {
+        ParsedDate::Solar(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+    }
+
+    #[test]
+    fn test_parse() {
+        let options = ParseOptions {
+            default_year: Some(2024),
+            ..Default::default()
+        };
+        let tests = vec![
+            (
+                "chinese_digits",
+                "二〇二三年腊月廿三",
+                lunar(2023, 12, 23, false),
+            ),
+            ("ling", "二零二三年冬月初八", lunar(2023, 11, 8, false)),
+            ("default_year", "农历六月初八", lunar(2024, 6, 8, false)),
+            ("ganzhi", "甲辰年正月初一", lunar(2024, 1, 1, false)),
+            (
+                "ganzhi_wrapped",
+                "癸亥年腊月初一",
+                lunar(2043, 12, 1, false),
+            ),
+            ("arabic_lunar", "2023年闰2月15", lunar(2023, 2, 15, true)),
+            ("sa", "2023年腊月卅", lunar(2023, 12, 30, false)),
+            ("lunar_tens", "2024年八月十五", lunar(2024, 8, 15, false)),
+            (
+                "lunar_explicit",
+                "阴历2024年五月二十一",
+                lunar(2024, 5, 21, false),
+            ),
+            ("solarterm", "2024年清明", solar(2024, 4, 4)),
+            ("solarterm_default_year", "冬至", solar(2024, 12, 21)),
+            ("solar_arabic", "2024年3月5日", solar(2024, 3, 5)),
+            (
+                "solar_chinese",
+                "二〇二四年十二月二十五日",
+                solar(2024, 12, 25),
+            ),
+            ("solar_hao", "2024年10月1号", solar(2024, 10, 1)),
+            ("solar_explicit", "公历2024年2月29", solar(2024, 2, 29)),
+            ("trimmed", "  2024年3月5日 ", solar(2024, 3, 5)),
+        ];
+        for (name, text, want) in tests {
+            assert_eq!(parse(text, &options), Ok(want), "{} failed", name);
+        }
+
+        let options = ParseOptions {
+            default_year: Some(2025),
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("农历闰六月初八", &options),
+            Ok(lunar(2025, 6, 8, true))
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let tests = vec![
+            ("missing_year", "腊月廿三"),
+            ("missing_month", "2024年初一"),
+            ("bad_year", "二〇X三年腊月廿三"),
+            ("year_overflow", "99999999999999999999年正月初一"),
+            ("bad_month", "2024年十三月初一"),
+            ("bad_day", "2024年正月初零"),
+            ("unknown_term", "2024年春节"),
+            ("ganzhi_solar", "甲辰年3月5日"),
+            ("ganzhi_term", "甲辰年清明"),
+            ("lunar_term", "农历2024年清明"),
+            ("solar_lunar_names", "公历2024年正月初一"),
+            ("invalid_pillar", "甲丑年正月初一"),
+        ];
+        for (name, text) in tests {
+            let got = parse(text, &ParseOptions::default());
+            assert!(
+                matches!(got, Err(IkigaiError::InvalidDateText { .. })),
+                "{} failed: {:?}",
+                name,
+                got
+            );
+        }
+
+        let tests = vec![
+            (
+                "leap_month_not_in_year",
+                "2024年闰六月初八",
+                IkigaiError::LeapMonthNotInYear {
+                    year: 2024,
+                    month: 6,
+                },
+            ),
+            (
+                "day_out_of_month",
+                "2024年正月三十",
+                IkigaiError::InvalidLunarDay {
+                    year: 2024,
+                    month: 1,
+                    day: 30,
+                    max: 29,
+                },
+            ),
+            (
+                "solar_invalid_day",
+                "2023年2月29日",
+                IkigaiError::InvalidDate,
+            ),
+            (
+                "solar_huge_year",
+                "4294969320年3月5日",
+                IkigaiError::InvalidDate,
+            ),
+            (
+                "solar_huge_day",
+                "2024年3月4294967297日",
+                IkigaiError::InvalidDate,
+            ),
+        ];
+        for (name, text, want) in tests {
+            assert_eq!(
+                parse(text, &ParseOptions::default()),
+                Err(want),
+                "{} failed",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_ganzhi_window() {
+        let century = ParseOptions {
+            ganzhi_window: 1950..=2049,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("甲辰年正月初一", &century),
+            Err(IkigaiError::InvalidDateText {
+                input: "甲辰年正月初一".to_string(),
+                reason: "甲辰 year is ambiguous: 1964, 2024".to_string(),
+            })
+        );
+
+        let window = ParseOptions {
+            ganzhi_window: 1900..=1959,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("甲辰年正月初一", &window),
+            Ok(lunar(1904, 1, 1, false))
+        );
+
+        let narrow = ParseOptions {
+            ganzhi_window: 2000..=2010,
+            ..Default::default()
+        };
+        assert!(parse("甲辰年正月初一", &narrow).is_err());
+    }
+
+    #[test]
+    fn test_parsed_date_conversion() {
+        let date = lunar(2024, 1, 1, false);
+        assert_eq!(
+            date.to_naive_date(),
+            Ok(NaiveDate::from_ymd_opt(2024, 2, 10).unwrap())
+        );
+        let date = solar(2024, 2, 10);
+        assert_eq!(
+            date.to_lunar_date(),
+            Ok(LunarDate {
+                year: 2024,
+                month: 1,
+                day: 1,
+                is_leap: false
+            })
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use std::{fmt, str::FromStr};
+
+use super::{gan::Gan, locale::Locale, utils, zhi::Zhi};
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 干支纪年、纪月、纪日、纪时中的一柱(甲子、乙丑...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pillar {
+    gan: Gan,
+    zhi: Zhi,
+}
+
+impl Pillar {
+    // 天干与地支的阴阳须一致, 如甲子、乙丑, 不存在甲丑
+    pub fn new(gan: Gan, zhi: Zhi) -> IkigaiResult<Self> {
+        if (gan.order() - zhi.order()) % 2 != 0 {
+            return Err(IkigaiError::InvalidPillar(format!("{}{}", gan, zhi)));
+        }
+        Ok(Pillar { gan, zhi })
+    }
+
+    // 六十甲子序数(1..=60)
+    pub fn from_order(order: i64) -> IkigaiResult<Self> {
+        if !(1..=60).contains(&order) {
+            return Err(IkigaiError::OrderOutOfRange {
+                name: "Pillar",
+                order,
+                max: 60,
+            });
+        }
+        Ok(Pillar {
+            gan: Gan::new(utils::order_mod(order, 10))?,
+            zhi: Zhi::new(utils::order_mod(order, 12))?,
+        })
+    }
+
+    pub fn gan(&self) -> Gan {
+        self.gan
+    }
+
+    pub fn zhi(&self) -> Zhi {
+        self.zhi
+    }
+
+    // 六十甲子序数(1..=60)
+    pub fn order(&self) -> i64 {
+        utils::order_mod(
+            ((self.gan.order() + 10 - self.zhi.order()) % 10) / 2 * 12 + self.zhi.order(),
+            60,
+        )
+    }
+
+    pub fn alias(&self) -> String {
+        format!("{}{}", self.gan.alias(), self.zhi.alias())
+    }
+
+    // 指定语言的干支名称, 如 Jia Chen、갑진
+    pub fn name(&self, locale: Locale) -> String {
+        format!(
+            "{}{}{}",
+            self.gan.name(locale),
+            locale.separator(),
+            self.zhi.name(locale)
+        )
+    }
+}
+
+impl fmt::Display for Pillar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.gan, self.zhi)
+    }
+}
+
+impl FromStr for Pillar {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        let err = || IkigaiError::ParseError {
+            input: s.to_string(),
+            kind: "Pillar",
+        };
+        let mut chars = s.chars();
+        let (Some(gan), Some(zhi), None) = (chars.next(), chars.next(), chars.next()) else {
+            return Err(err());
+        };
+        let gan = gan.to_string().parse::<Gan>().map_err(|_| err())?;
+        let zhi = zhi.to_string().parse::<Zhi>().map_err(|_| err())?;
+        Pillar::new(gan, zhi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pillar_from_order() {
+        let tests = vec![
+            ("first", 1, "甲子"),
+            ("second", 2, "乙丑"),
+            ("eleventh", 11, "甲戌"),
+            ("jiachen", 41, "甲辰"),
+            ("last", 60, "癸亥"),
+        ];
+        for (name, order, want) in tests {
+            let pillar = Pillar::from_order(order).unwrap();
+            assert_eq!(pillar.to_string(), want, "{} failed", name);
+            assert_eq!(pillar.order(), order, "{} failed", name);
+        }
+        assert!(Pillar::from_order(0).is_err());
+        assert!(Pillar::from_order(61).is_err());
+    }
+
+    #[test]
+    fn test_pillar_from_str() {
+        for order in 1..=60 {
+            let pillar = Pillar::from_order(order).unwrap();
+            assert_eq!(pillar.to_string().parse::<Pillar>(), Ok(pillar));
+        }
+
+        let tests = vec![
+            ("mismatch", "甲丑"),
+            ("reversed", "子甲"),
+            ("too_short", "甲"),
+            ("too_long", "甲子年"),
+            ("empty", ""),
+        ];
+        for (name, s) in tests {
+            assert!(s.parse::<Pillar>().is_err(), "{} should fail", name);
+        }
+        assert_eq!(
+            "甲丑".parse::<Pillar>(),
+            Err(IkigaiError::InvalidPillar("甲丑".to_string()))
+        );
+    }
+}
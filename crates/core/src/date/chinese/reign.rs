@@ -0,0 +1,466 @@
+use std::{fmt, str::FromStr};
+
+use chrono::NaiveDate;
+
+use super::{lunar_date::LunarDate, lunisolar, parser, pillar::Pillar, utils};
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+const DIGITS: [&str; 10] = ["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+// 民国、主体纪年, 均以 1912 年为元年
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YearNumbering {
+    Minguo,
+    Juche,
+}
+
+const YEAR_NUMBERING_OFFSET: i64 = 1911;
+
+impl YearNumbering {
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            YearNumbering::Minguo => "民国",
+            YearNumbering::Juche => "主体",
+        }
+    }
+
+    // 公历年转纪年; 民国元年以前返回 0 或负数, 即民国前 (1 - 返回值) 年
+    pub fn year(&self, gregorian: i64) -> IkigaiResult<i64> {
+        let year = gregorian - YEAR_NUMBERING_OFFSET;
+        if *self == YearNumbering::Juche && year < 1 {
+            return Err(IkigaiError::YearOutOfRange {
+                year: gregorian,
+                min: YEAR_NUMBERING_OFFSET + 1,
+                max: i64::MAX,
+            });
+        }
+        Ok(year)
+    }
+
+    pub fn to_gregorian(&self, year: i64) -> i64 {
+        year + YEAR_NUMBERING_OFFSET
+    }
+
+    // 民国113年、民国元年、民国前1年、主体113年
+    pub fn label(&self, gregorian: i64) -> IkigaiResult<String> {
+        let year = self.year(gregorian)?;
+        Ok(match year {
+            1 => format!("{}元年", self.prefix()),
+            y if y < 1 => format!("{}前{}年", self.prefix(), 1 - y),
+            y => format!("{}{}年", self.prefix(), y),
+        })
+    }
+
+    // 解析纪年文字, 返回公历年份; 兼容繁体的民國
+    pub fn parse(&self, s: &str) -> IkigaiResult<i64> {
+        let err = || IkigaiError::ParseError {
+            input: s.to_string(),
+            kind: "YearNumbering",
+        };
+        let rest = s
+            .strip_prefix(self.prefix())
+            .or_else(|| match self {
+                YearNumbering::Minguo => s.strip_prefix("民國"),
+                YearNumbering::Juche => s.strip_prefix("主體"),
+            })
+            .and_then(|rest| rest.strip_suffix('年'))
+            .ok_or_else(err)?;
+        let (before, rest) = match rest.strip_prefix('前') {
+            Some(rest) if *self == YearNumbering::Minguo => (true, rest),
+            Some(_) => return Err(err()),
+            None => (false, rest),
+        };
+        let year = parse_year_number(rest).ok_or_else(err)?;
+        let year = if before { 1 - year } else { year };
+        let gregorian = self.to_gregorian(year);
+        self.year(gregorian)?;
+        Ok(gregorian)
+    }
+}
+
+// 年号, 元年为起始日所在的农历年
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reign {
+    name: &'static str,
+    dynasty: &'static str,
+    // 改元的农历日期(年, 月, 日)
+    start: (i64, i64, i64),
+}
+
+const fn reign(dynasty: &'static str, name: &'static str, start: (i64, i64, i64)) -> Reign {
+    Reign {
+        name,
+        dynasty,
+        start,
+    }
+}
+
+// 明清年号; 明代历法与今不同, 1645 年以前的日期换算可能有一日之差
+const REIGNS: [Reign; 27] = [
+    reign("明", "洪武", (1368, 1, 4)),
+    reign("明", "建文", (1399, 1, 1)),
+    reign("明", "永乐", (1403, 1, 1)),
+    reign("明", "洪熙", (1425, 1, 1)),
+    reign("明", "宣德", (1426, 1, 1)),
+    reign("明", "正统", (1436, 1, 1)),
+    reign("明", "景泰", (1450, 1, 1)),
+    reign("明", "天顺", (1457, 1, 21)),
+    reign("明", "成化", (1465, 1, 1)),
+    reign("明", "弘治", (1488, 1, 1)),
+    reign("明", "正德", (1506, 1, 1)),
+    reign("明", "嘉靖", (1522, 1, 1)),
+    reign("明", "隆庆", (1567, 1, 1)),
+    reign("明", "万历", (1573, 1, 1)),
+    reign("明", "泰昌", (1620, 8, 1)),
+    reign("明", "天启", (1621, 1, 1)),
+    reign("明", "崇祯", (1628, 1, 1)),
+    // 以清军入北京为界, 此前仍记作崇祯十七年
+    reign("清", "顺治", (1644, 5, 2)),
+    reign("清", "康熙", (1662, 1, 1)),
+    reign("清", "雍正", (1723, 1, 1)),
+    reign("清", "乾隆", (1736, 1, 1)),
+    reign("清", "嘉庆", (1796, 1, 1)),
+    reign("清", "道光", (1821, 1, 1)),
+    reign("清", "咸丰", (1851, 1, 1)),
+    reign("清", "同治", (1862, 1, 1)),
+    reign("清", "光绪", (1875, 1, 1)),
+    reign("清", "宣统", (1909, 1, 1)),
+];
+
+// 宣统三年十二月廿五(1912-02-12)清帝退位
+const REIGNS_END: (i64, i64, i64) = (1911, 12, 25);
+
+// 1929 年改用 UTC+8 以前的日期亦按 UTC+8 推算农历
+const CHINA_UTC_OFFSET: i64 = 8;
+
+impl Reign {
+    // 全部明清年号, 按时间先后排列
+    pub fn all() -> &'static [Reign] {
+        &REIGNS
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn dynasty(&self) -> &'static str {
+        self.dynasty
+    }
+
+    // 改元的农历日期
+    pub fn start(&self) -> LunarDate {
+        let (year, month, day) = self.start;
+        LunarDate {
+            year,
+            month,
+            day,
+            is_leap: false,
+        }
+    }
+
+    // 年号使用的年数
+    pub fn years(&self) -> i64 {
+        let i = REIGNS.iter().position(|r| r == self).unwrap();
+        let last = match REIGNS.get(i + 1) {
+            // 次一年号若从正月初一开始, 本年号止于上一年
+            Some(next) if next.start.1 == 1 && next.start.2 == 1 => next.start.0 - 1,
+            Some(next) => next.start.0,
+            None => REIGNS_END.0,
+        };
+        last - self.start.0 + 1
+    }
+
+    // 年号的第几年
+    pub fn year(&self, year: i64) -> IkigaiResult<ReignYear> {
+        if !(1..=self.years()).contains(&year) {
+            return Err(IkigaiError::YearOutOfRange {
+                year,
+                min: 1,
+                max: self.years(),
+            });
+        }
+        Ok(ReignYear { reign: *self, year })
+    }
+}
+
+impl fmt::Display for Reign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl FromStr for Reign {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        REIGNS
+            .into_iter()
+            .find(|r| r.name == s)
+            .ok_or_else(|| IkigaiError::ParseError {
+                input: s.to_string(),
+                kind: "Reign",
+            })
+    }
+}
+
+// 年号纪年, 如乾隆五十年
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReignYear {
+    reign: Reign,
+    year: i64,
+}
+
+impl ReignYear {
+    pub fn reign(&self) -> Reign {
+        self.reign
+    }
+
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    // 对应的农历年份(以公历纪年表示)
+    pub fn lunar_year(&self) -> i64 {
+        self.reign.start.0 + self.year - 1
+    }
+
+    pub fn ganzhi(&self) -> Pillar {
+        Pillar::from_order(utils::order_mod(self.lunar_year() - 3, 60)).unwrap()
+    }
+
+    // 带干支的纪年, 如乾隆五十年乙巳
+    pub fn label_with_ganzhi(&self) -> String {
+        format!("{}{}", self, self.ganzhi())
+    }
+
+    // 农历日期所在的年号纪年
+    pub fn from_lunar_date(date: &LunarDate) -> IkigaiResult<Self> {
+        let start = REIGNS[0].start();
+        let (year, month, day) = REIGNS_END;
+        let end = LunarDate {
+            year,
+            month,
+            day,
+            is_leap: false,
+        };
+        if *date < start || *date > end {
+            return Err(IkigaiError::YearOutOfRange {
+                year: date.year,
+                min: start.year,
+                max: end.year,
+            });
+        }
+        let reign = REIGNS
+            .into_iter()
+            .rev()
+            .find(|r| r.start() <= *date)
+            .unwrap();
+        reign.year(date.year - reign.start.0 + 1)
+    }
+
+    // 公历日期所在的年号纪年
+    pub fn from_naive_date(date: NaiveDate) -> IkigaiResult<Self> {
+        Self::from_lunar_date(&lunisolar::from_naive_date(date, CHINA_UTC_OFFSET)?)
+    }
+
+    // 涵盖某一农历年的全部年号纪年, 改元之年有两个
+    pub fn from_lunar_year(year: i64) -> Vec<Self> {
+        REIGNS
+            .iter()
+            .filter_map(|r| r.year(year - r.start.0 + 1).ok())
+            .collect()
+    }
+
+    // 该年号年正月初一(改元之年为改元当日)的公历日期
+    pub fn to_naive_date(&self) -> IkigaiResult<NaiveDate> {
+        let start = if self.year == 1 {
+            self.reign.start()
+        } else {
+            LunarDate {
+                year: self.lunar_year(),
+                month: 1,
+                day: 1,
+                is_leap: false,
+            }
+        };
+        lunisolar::to_naive_date(&start, CHINA_UTC_OFFSET)
+    }
+}
+
+// 形如 `乾隆五十年`, 首年写作 `乾隆元年`
+impl fmt::Display for ReignYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.year == 1 {
+            write!(f, "{}元年", self.reign)
+        } else {
+            write!(f, "{}{}年", self.reign, chinese_number(self.year))
+        }
+    }
+}
+
+// 接受 `乾隆五十年`、`乾隆50年`、`乾隆元年` 以及带干支的 `乾隆五十年乙巳`
+impl FromStr for ReignYear {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        let err = || IkigaiError::ParseError {
+            input: s.to_string(),
+            kind: "ReignYear",
+        };
+        let (name, rest) = s
+            .split_once('年')
+            .map_or((s, ""), |(head, rest)| (head, rest));
+        let reign = REIGNS
+            .into_iter()
+            .find(|r| name.starts_with(r.name))
+            .ok_or_else(err)?;
+        let year = parse_year_number(&name[reign.name.len()..]).ok_or_else(err)?;
+        let reign_year = reign.year(year)?;
+        if !rest.is_empty() {
+            let pillar = rest.parse::<Pillar>().map_err(|_| err())?;
+            if pillar != reign_year.ganzhi() {
+                return Err(IkigaiError::InvalidDateText {
+                    input: s.to_string(),
+                    reason: format!("{} is {}", reign_year, reign_year.ganzhi()),
+                });
+            }
+        }
+        Ok(reign_year)
+    }
+}
+
+// 纪年中的年数: 元、阿拉伯数字或中文数字
+fn parse_year_number(s: &str) -> Option<i64> {
+    if s == "元" {
+        return Some(1);
+    }
+    parser::parse_number(s)
+}
+
+// 1..=99 的中文数字(十一、五十、六十一)
+fn chinese_number(n: i64) -> String {
+    let (tens, ones) = ((n / 10) as usize, (n % 10) as usize);
+    let mut result = String::new();
+    if tens > 1 {
+        result += DIGITS[tens];
+    }
+    if tens > 0 {
+        result += "十";
+    }
+    if ones > 0 || tens == 0 {
+        result += DIGITS[ones];
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_year_numbering() {
+        let tests = vec![
+            ("minguo", YearNumbering::Minguo, 2024, "民国113年"),
+            ("minguo_first", YearNumbering::Minguo, 1912, "民国元年"),
+            ("minguo_before", YearNumbering::Minguo, 1911, "民国前1年"),
+            ("juche", YearNumbering::Juche, 2024, "主体113年"),
+            ("juche_first", YearNumbering::Juche, 1912, "主体元年"),
+        ];
+        for (name, numbering, year, want) in tests {
+            assert_eq!(numbering.label(year).unwrap(), want, "{} failed", name);
+            assert_eq!(numbering.parse(want), Ok(year), "{} failed", name);
+        }
+        assert_eq!(YearNumbering::Minguo.parse("民國113年"), Ok(2024));
+        assert!(YearNumbering::Juche.label(1911).is_err());
+        assert!(YearNumbering::Juche.parse("主体前1年").is_err());
+        assert!(YearNumbering::Minguo.parse("主体113年").is_err());
+    }
+
+    #[test]
+    fn test_reign_year_from_naive_date() {
+        let tests = vec![
+            ("qianlong", date(1785, 6, 1), "乾隆五十年乙巳"),
+            ("kangxi_last", date(1722, 6, 1), "康熙六十一年壬寅"),
+            ("yongzheng_first", date(1723, 2, 5), "雍正元年癸卯"),
+            ("xuantong", date(1911, 10, 10), "宣统三年辛亥"),
+            // 宣统三年腊月仍在 1912 年
+            ("xuantong_last_day", date(1912, 2, 12), "宣统三年辛亥"),
+            ("wanli", date(1620, 8, 1), "万历四十八年庚申"),
+            ("taichang", date(1620, 10, 1), "泰昌元年庚申"),
+            ("chongzhen", date(1644, 4, 1), "崇祯十七年甲申"),
+            ("shunzhi", date(1644, 7, 1), "顺治元年甲申"),
+            ("hongwu", date(1368, 3, 1), "洪武元年戊申"),
+        ];
+        for (name, solar, want) in tests {
+            let got = ReignYear::from_naive_date(solar).unwrap();
+            assert_eq!(got.label_with_ganzhi(), want, "{} failed", name);
+        }
+        assert!(ReignYear::from_naive_date(date(1912, 2, 13)).is_err());
+        assert!(ReignYear::from_naive_date(date(1300, 1, 1)).is_err());
+    }
+
+    #[test]
+    fn test_reign_year_from_str() {
+        let tests = vec![
+            ("chinese", "乾隆五十年", 1785),
+            ("digits", "乾隆50年", 1785),
+            ("first", "乾隆元年", 1736),
+            ("ganzhi", "乾隆五十年乙巳", 1785),
+            ("eleven", "嘉庆十一年", 1806),
+            ("guangxu", "光绪二十六年", 1900),
+        ];
+        for (name, s, want) in tests {
+            let got = s.parse::<ReignYear>().unwrap();
+            assert_eq!(got.lunar_year(), want, "{} failed", name);
+        }
+        for reign in Reign::all() {
+            for year in 1..=reign.years() {
+                let reign_year = reign.year(year).unwrap();
+                assert_eq!(reign_year.to_string().parse(), Ok(reign_year));
+            }
+        }
+
+        let errors = vec![
+            ("wrong_ganzhi", "乾隆五十年甲子"),
+            ("too_long", "乾隆六十一年"),
+            ("zero", "乾隆〇年"),
+            ("unknown", "开元元年"),
+            ("no_year", "乾隆"),
+        ];
+        for (name, s) in errors {
+            assert!(s.parse::<ReignYear>().is_err(), "{} should fail", name);
+        }
+    }
+
+    #[test]
+    fn test_reign_years() {
+        let tests = vec![
+            ("kangxi", "康熙", 61),
+            ("qianlong", "乾隆", 60),
+            ("taichang", "泰昌", 1),
+            ("wanli", "万历", 48),
+            ("chongzhen", "崇祯", 17),
+            ("xuantong", "宣统", 3),
+        ];
+        for (name, reign, want) in tests {
+            let reign: Reign = reign.parse().unwrap();
+            assert_eq!(reign.years(), want, "{} failed", name);
+        }
+        assert_eq!(
+            ReignYear::from_lunar_year(1620)
+                .iter()
+                .map(|y| y.to_string())
+                .collect::<Vec<_>>(),
+            vec!["万历四十八年", "泰昌元年"]
+        );
+        let qianlong: Reign = "乾隆".parse().unwrap();
+        assert_eq!(
+            qianlong.year(50).unwrap().to_naive_date(),
+            Ok(date(1785, 2, 9))
+        );
+    }
+}
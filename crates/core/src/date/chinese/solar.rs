@@ -1,6 +1,10 @@
 use chrono::{Datelike, Timelike};
 
 use super::{animal::Animal, constellation::Constellation, solarterm::Solarterm, utils::order_mod};
+use crate::ikigai_error::IkigaiResult;
+
+// 檀纪(단기)与公元纪年之差
+pub const DANGI_OFFSET: i64 = 2333;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Solar {
@@ -11,31 +15,24 @@ pub struct Solar {
 }
 
 impl Solar {
-    pub fn new(t: chrono::DateTime<chrono::Utc>) -> Option<Self> {
-        let (mut p, mut n) = Solarterm::calc_solarterm(&t);
+    pub fn new(t: chrono::DateTime<chrono::Utc>) -> IkigaiResult<Self> {
+        let (mut p, mut n) = Solarterm::calc_solarterm(&t)?;
         let mut c = None;
-        match (&mut p, &mut n) {
-            (Some(p), Some(n)) => {
-                if n.index() - p.index() == 1 {
-                    if p.is_in_day(&t) {
-                        c = Some(p.clone());
-                        *p = p.prev()?;
-                    }
-                    if n.is_in_day(&t) {
-                        c = Some(n.clone());
-                        *p = c.as_ref()?.prev()?;
-                        *n = c.as_ref()?.next()?;
-                    }
-                }
-                Some(Self {
-                    t,
-                    current_solarterm: c,
-                    prev_solarterm: p.clone(),
-                    next_solarterm: n.clone(),
-                })
-            }
-            _ => None,
+        if p.is_in_day(&t) {
+            let prev = p.prev()?;
+            c = Some(std::mem::replace(&mut p, prev));
+        }
+        if n.is_in_day(&t) {
+            p = n.prev()?;
+            let next = n.next()?;
+            c = Some(std::mem::replace(&mut n, next));
         }
+        Ok(Self {
+            t,
+            current_solarterm: c,
+            prev_solarterm: p,
+            next_solarterm: n,
+        })
     }
     pub fn is_leap(&self) -> bool {
         let year = self.t.year();
@@ -51,14 +48,32 @@ impl Solar {
         week_alias[self.week_number()]
     }
 
-    pub fn animal(&self) -> Option<Animal> {
-        Animal::new(order_mod((self.t.year() - 3) as i64, 12))
+    pub fn animal(&self) -> Animal {
+        Animal::new(order_mod((self.t.year() - 3) as i64, 12)).unwrap()
+    }
+
+    // 韩国檀纪年份(公元 2024 年为檀纪 4357 年)
+    pub fn dangi_year(&self) -> i64 {
+        self.t.year() as i64 + DANGI_OFFSET
     }
 
     pub fn constellation(&self) -> Constellation {
         Constellation::new(self.t)
     }
 
+    // 当天交节的节气
+    pub fn current_solarterm(&self) -> Option<&Solarterm> {
+        self.current_solarterm.as_ref()
+    }
+
+    pub fn prev_solarterm(&self) -> &Solarterm {
+        &self.prev_solarterm
+    }
+
+    pub fn next_solarterm(&self) -> &Solarterm {
+        &self.next_solarterm
+    }
+
     pub fn get_year(&self) -> i32 {
         self.t.year()
     }
@@ -91,6 +106,10 @@ impl Solar {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        date::chinese::solarterm::{SOLARTERM_FROM_YEAR, SOLARTERM_TO_YEAR},
+        ikigai_error::IkigaiError,
+    };
     use chrono::{TimeZone, Utc};
 
     #[test]
@@ -104,25 +123,25 @@ mod tests {
         let tests = vec![
             (
                 t1,
-                Solarterm::new(2741),
+                Solarterm::new(2741).ok(),
                 Solarterm::new(2740),
                 Solarterm::new(2742),
             ),
             (
                 t2,
-                Solarterm::new(2741),
+                Solarterm::new(2741).ok(),
                 Solarterm::new(2740),
                 Solarterm::new(2742),
             ),
             (
                 t3,
-                Solarterm::new(2741),
+                Solarterm::new(2741).ok(),
                 Solarterm::new(2740),
                 Solarterm::new(2742),
             ),
             (
                 t4,
-                Solarterm::new(2741),
+                Solarterm::new(2741).ok(),
                 Solarterm::new(2740),
                 Solarterm::new(2742),
             ),
@@ -137,6 +156,27 @@ mod tests {
             assert_eq!(solar.next_solarterm, ns.unwrap());
         }
     }
+    #[test]
+    fn test_new_solar_out_of_range() {
+        let t = Utc.with_ymd_and_hms(1800, 6, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            Solar::new(t),
+            Err(IkigaiError::YearOutOfRange {
+                year: 1800,
+                min: SOLARTERM_FROM_YEAR,
+                max: SOLARTERM_TO_YEAR
+            })
+        );
+        assert_eq!(
+            Solarterm::new(-1),
+            Err(IkigaiError::SolartermIndexOutOfRange {
+                index: -1,
+                min: 0,
+                max: 26327
+            })
+        );
+    }
+
     #[test]
     fn test_solar_is_leap() {
         let t1 = Utc.with_ymd_and_hms(2018, 3, 21,0, 0, 0).unwrap();
@@ -193,8 +233,8 @@ mod tests {
         let t2 = Utc.with_ymd_and_hms(2019, 3, 21,0, 0, 0).unwrap();
 
         let tests = vec![
-            ("test_1", Solar::new(t1).unwrap(), Animal::new(11)),
-            ("test_2", Solar::new(t2).unwrap(), Animal::new(12)),
+            ("test_1", Solar::new(t1).unwrap(), Animal::new(11).unwrap()),
+            ("test_2", Solar::new(t2).unwrap(), Animal::new(12).unwrap()),
         ];
 
         for (name, solar, want) in tests {
@@ -204,6 +244,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_solar_dangi_year() {
+        let tests = vec![("2024", 2024, 4357), ("1948", 1948, 4281)];
+        for (name, year, want) in tests {
+            let t = Utc.with_ymd_and_hms(year, 6, 1, 0, 0, 0).unwrap();
+            assert_eq!(Solar::new(t).unwrap().dangi_year(), want, "{} failed", name);
+        }
+    }
+
     #[test]
     fn test_solar_constellation() {
         let t1 = Utc.with_ymd_and_hms(2018, 3, 21,0, 0, 0).unwrap();
@@ -1,11 +1,29 @@
-use chrono::{DateTime, Datelike, NaiveDateTime, TimeZone, Utc};
+use std::{fmt, str::FromStr};
 
-use super::utils;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
 
+use super::{locale::Locale, utils};
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 节气表支持的公历年份
 pub const SOLARTERM_FROM_YEAR: i64 = 1904;
 pub const SOLARTERM_TO_YEAR: i64 = 3000;
 
-pub const SOLARTERM_J2000: [f64; 26328] = [
+// 校验年份是否在节气表范围内
+pub fn check_year(year: i64) -> IkigaiResult<()> {
+    if !(SOLARTERM_FROM_YEAR..=SOLARTERM_TO_YEAR).contains(&year) {
+        return Err(IkigaiError::YearOutOfRange {
+            year,
+            min: SOLARTERM_FROM_YEAR,
+            max: SOLARTERM_TO_YEAR,
+        });
+    }
+    Ok(())
+}
+
+// 各节气时刻的儒略日(北京时间), 每年 24 个, 自小寒起;
+// 2201 年大寒至 2202 年惊蛰(春分除外)原表缺失, 按太阳视黄经推算并以前后各年的残差插值补齐, 误差约数分钟
+pub static SOLARTERM_J2000: [f64; 26328] = [
     // 1904
     2416486.56738957,
     2416501.29017051,
@@ -7433,7 +7451,35 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2524948.93524078,
     // 2201
     2524963.66160025,
+    2524978.38356916,
+    2524993.13564650,
+    2525007.94840981,
+    2525022.84856274,
     2525037.86174030,
+    2525052.99327302,
+    2525068.25990878,
+    2525083.65341230,
+    2525099.16821813,
+    2525114.77723122,
+    2525130.46302179,
+    2525146.18177107,
+    2525161.91100865,
+    2525177.60075220,
+    2525193.23253008,
+    2525208.76336178,
+    2525224.18625967,
+    2525239.47491258,
+    2525254.63805989,
+    2525269.66907676,
+    2525284.59239216,
+    2525299.41822299,
+    2525314.18292762,
+    // 2202
+    2525328.90697239,
+    2525343.63229428,
+    2525358.38204308,
+    2525373.19702879,
+    2525388.09458973,
     2525403.10821857,
     2525418.23988023,
     2525433.50708204,
@@ -7453,10 +7499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2525649.83218420,
     2525664.66270814,
     2525679.42012800,
+    // 2203
     2525694.14918219,
     2525708.86817295,
     2525723.62399414,
-    // 2202
     2525738.43331571,
     2525753.33760007,
     2525768.34432937,
@@ -7478,10 +7524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2526015.08028460,
     2526029.90501222,
     2526044.66849566,
+    // 2204
     2526059.39171246,
     2526074.11647337,
     2526088.86641725,
-    // 2203
     2526103.68142979,
     2526118.57989323,
     2526133.59275137,
@@ -7503,10 +7549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2526380.31825660,
     2526395.14592734,
     2526409.90842619,
+    // 2205
     2526424.63469245,
     2526439.35864630,
     2526454.11105559,
-    // 2204
     2526468.92488847,
     2526483.82521731,
     2526498.83600237,
@@ -7528,10 +7574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2526745.56046122,
     2526760.39125352,
     2526775.15029498,
+    // 2206
     2526789.87939288,
     2526804.59933943,
     2526819.35439618,
-    // 2205
     2526834.16370372,
     2526849.06632910,
     2526864.07258530,
@@ -7553,10 +7599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2527110.80905003,
     2527125.63267252,
     2527140.39743446,
+    // 2207
     2527155.11954779,
     2527169.84552349,
     2527184.59389697,
-    // 2206
     2527199.40958568,
     2527214.30573849,
     2527229.31842527,
@@ -7578,10 +7624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2527476.04588566,
     2527490.87416408,
     2527505.63283169,
+    // 2208
     2527520.35997153,
     2527535.08054449,
     2527549.83448680,
-    // 2207
     2527564.64549563,
     2527579.54787201,
     2527594.55610092,
@@ -7603,10 +7649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2527841.29102600,
     2527856.11874574,
     2527870.87848322,
+    // 2209
     2527885.60482281,
     2527900.32596247,
     2527915.07894893,
-    // 2208
     2527929.89000640,
     2527944.79086000,
     2527959.79905124,
@@ -7628,10 +7674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2528206.53537292,
     2528221.36008619,
     2528236.12482234,
+    // 2210
     2528250.84705511,
     2528265.57226393,
     2528280.32003925,
-    // 2209
     2528295.13458279,
     2528310.02995410,
     2528325.04138502,
@@ -7653,10 +7699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2528571.77307514,
     2528586.60421275,
     2528601.36215871,
+    // 2211
     2528616.09066298,
     2528630.80889004,
     2528645.56283534,
-    // 2210
     2528660.37030563,
     2528675.27191635,
     2528690.27639309,
@@ -7678,10 +7724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2528937.01676723,
     2528951.84377157,
     2528966.60712039,
+    // 2212
     2528981.33182713,
     2528996.05539647,
     2529010.80560544,
-    // 2211
     2529025.61810034,
     2529040.51555630,
     2529055.52488310,
@@ -7703,10 +7749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2529302.25251800,
     2529317.08017157,
     2529331.84485506,
+    // 2213
     2529346.57070919,
     2529361.29623613,
     2529376.04746606,
-    // 2212
     2529390.86189445,
     2529405.75990511,
     2529420.77015189,
@@ -7728,10 +7774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2529667.49297793,
     2529682.32522173,
     2529697.08438327,
+    // 2214
     2529711.81443623,
     2529726.53411492,
     2529741.28966550,
-    // 2213
     2529756.09823464,
     2529771.00076144,
     2529786.00548934,
@@ -7753,10 +7799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2530032.74105826,
     2530047.56636768,
     2530062.33151660,
+    // 2215
     2530077.05443445,
     2530091.77996459,
     2530106.52859480,
-    // 2214
     2530121.34336589,
     2530136.23944008,
     2530151.25098344,
@@ -7778,10 +7824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2530397.97840745,
     2530412.80759914,
     2530427.56891070,
+    // 2216
     2530442.29586735,
     2530457.01791501,
     2530471.77053873,
-    // 2215
     2530486.58211391,
     2530501.48247887,
     2530516.49086519,
@@ -7803,10 +7849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2530763.22104648,
     2530778.05204077,
     2530792.81267110,
+    // 2217
     2530807.54164960,
     2530822.26271163,
     2530837.01713898,
-    // 2216
     2530851.82688823,
     2530866.72809002,
     2530881.73412573,
@@ -7828,10 +7874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2531128.46620872,
     2531143.29216629,
     2531158.05901155,
+    // 2218
     2531172.78263360,
     2531187.50965558,
     2531202.25825073,
-    // 2217
     2531217.07370555,
     2531231.96883057,
     2531246.98018187,
@@ -7853,10 +7899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2531493.70643224,
     2531508.53738024,
     2531523.29665011,
+    // 2219
     2531538.02550778,
     2531552.74546645,
     2531567.49989976,
-    // 2218
     2531582.30899286,
     2531597.21068423,
     2531612.21610109,
@@ -7878,10 +7924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2531858.95403393,
     2531873.78160297,
     2531888.54322102,
+    // 2220
     2531903.26881370,
     2531917.99113585,
     2531932.74278682,
-    // 2219
     2531947.55455825,
     2531962.45382565,
     2531977.46262803,
@@ -7903,10 +7949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2532224.19719581,
     2532239.02312337,
     2532253.78777806,
+    // 2221
     2532268.51143693,
     2532283.23674066,
     2532297.98595958,
-    // 2220
     2532312.80055066,
     2532327.69712042,
     2532342.70821392,
@@ -7928,10 +7974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2532589.44204797,
     2532604.27430140,
     2532619.03259375,
+    // 2222
     2532633.76153655,
     2532648.47943831,
     2532663.23321440,
-    // 2221
     2532678.03977699,
     2532692.94068142,
     2532707.94386196,
@@ -7953,10 +7999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2532954.69186308,
     2532969.51850007,
     2532984.28297155,
+    // 2223
     2532999.00617081,
     2533013.72981634,
     2533028.47763866,
-    // 2222
     2533043.28953563,
     2533058.18417456,
     2533073.19267739,
@@ -7978,10 +8024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2533319.93106342,
     2533334.76003615,
     2533349.52320978,
+    // 2224
     2533364.24921044,
     2533378.97214924,
     2533393.72268339,
-    // 2223
     2533408.53395538,
     2533423.43105234,
     2533438.43823225,
@@ -8003,10 +8049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2533685.17176967,
     2533700.00435049,
     2533714.76471072,
+    // 2225
     2533729.49483955,
     2533744.21511965,
     2533758.96998412,
-    // 2224
     2533773.77811371,
     2533788.67880649,
     2533803.68217871,
@@ -8028,10 +8074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2534050.41700814,
     2534065.24365472,
     2534080.01077698,
+    // 2226
     2534094.73451523,
     2534109.46128244,
     2534124.20962563,
-    // 2225
     2534139.02439263,
     2534153.91877447,
     2534168.92892851,
@@ -8053,10 +8099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2534415.65615172,
     2534430.48735310,
     2534445.24808240,
+    // 2227
     2534459.97602166,
     2534474.69646999,
     2534489.44920630,
-    // 2226
     2534504.25828041,
     2534519.15794585,
     2534534.16309421,
@@ -8078,10 +8124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2534780.89827451,
     2534795.72906474,
     2534810.49091316,
+    // 2228
     2534825.21846024,
     2534839.93964940,
     2534854.69195581,
-    // 2227
     2534869.50150014,
     2534884.40061252,
     2534899.40669911,
@@ -8103,10 +8149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2535146.13658315,
     2535160.96410273,
     2535175.73151728,
+    // 2229
     2535190.45603517,
     2535205.18293950,
     2535219.93168912,
-    // 2228
     2535234.74640642,
     2535249.64115970,
     2535264.65133946,
@@ -8128,10 +8174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2535511.37490019,
     2535526.20861003,
     2535540.96899559,
+    // 2230
     2535555.69944743,
     2535570.41911672,
     2535585.17366056,
-    // 2229
     2535599.98096874,
     2535614.88141817,
     2535629.88403744,
@@ -8153,10 +8199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2535876.62066859,
     2535891.44895304,
     2535906.21324188,
+    // 2231
     2535920.93856159,
     2535935.66229889,
     2535950.41231139,
-    // 2230
     2535965.22403231,
     2535980.12031290,
     2535995.12786499,
@@ -8178,10 +8224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2536241.85996651,
     2536256.68739489,
     2536271.45144684,
+    // 2232
     2536286.17615493,
     2536300.90031423,
     2536315.64999112,
-    // 2231
     2536330.46285237,
     2536345.35931861,
     2536360.36818326,
@@ -8203,10 +8249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2536607.10188398,
     2536621.93463379,
     2536636.69380399,
+    // 2233
     2536651.42354931,
     2536666.14239510,
     2536680.89682052,
-    // 2232
     2536695.70376215,
     2536710.60444153,
     2536725.60707807,
@@ -8228,10 +8274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2536972.34920753,
     2536987.17649337,
     2537001.94287696,
+    // 2234
     2537016.66621645,
     2537031.39120515,
     2537046.13843582,
-    // 2233
     2537060.95094689,
     2537075.84415416,
     2537090.85241731,
@@ -8253,10 +8299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2537337.59002825,
     2537352.42091655,
     2537367.18320089,
+    // 2235
     2537381.90993617,
     2537396.63089484,
     2537411.38128937,
-    // 2234
     2537426.18985266,
     2537441.08637740,
     2537456.09059193,
@@ -8278,10 +8324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2537702.83249457,
     2537717.66534492,
     2537732.42692480,
+    // 2236
     2537747.15605861,
     2537761.87628945,
     2537776.62925878,
-    // 2235
     2537791.43676468,
     2537806.33543459,
     2537821.33844779,
@@ -8303,10 +8349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2538068.07433333,
     2538082.90213418,
     2538097.67040155,
+    // 2237
     2538112.39496687,
     2538127.12237706,
     2538141.87078439,
-    // 2236
     2538156.68539249,
     2538171.57905043,
     2538186.58831997,
@@ -8328,10 +8374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2538433.31689098,
     2538448.15048479,
     2538462.91168102,
+    // 2238
     2538477.64143725,
     2538492.36154399,
     2538507.11513175,
-    // 2237
     2538521.92268966,
     2538536.82198624,
     2538551.82451921,
@@ -8353,10 +8399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2538798.56577205,
     2538813.39642486,
     2538828.16004378,
+    // 2239
     2538842.88652341,
     2538857.60856604,
     2538872.35899026,
-    // 2238
     2538887.16854384,
     2538902.06501126,
     2538917.07036646,
@@ -8378,10 +8424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2539163.80680764,
     2539178.63518033,
     2539193.40158422,
+    // 2240
     2539208.12604482,
     2539222.85122377,
     2539237.59944546,
-    // 2239
     2539252.41226358,
     2539267.30651794,
     2539282.31496587,
@@ -8403,10 +8449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2539529.04771098,
     2539543.88280416,
     2539558.64383170,
+    // 2241
     2539573.37525410,
     2539588.09499456,
     2539602.84980903,
-    // 2240
     2539617.65633693,
     2539632.55616385,
     2539647.55731534,
@@ -8428,10 +8474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2539894.29367660,
     2539909.12322993,
     2539923.89043523,
+    // 2242
     2539938.61607587,
     2539953.34156396,
     2539968.09053654,
-    // 2241
     2539982.90263075,
     2539997.79654011,
     2540012.80331737,
@@ -8453,10 +8499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2540259.53509911,
     2540274.36523358,
     2540289.12937682,
+    // 2243
     2540303.85574809,
     2540318.57883990,
     2540333.32896700,
-    // 2242
     2540348.13952367,
     2540363.03537218,
     2540378.04097587,
@@ -8478,10 +8524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2540624.77813154,
     2540639.61143083,
     2540654.37177877,
+    // 2244
     2540669.10137343,
     2540683.82059192,
     2540698.57429423,
-    // 2243
     2540713.38109791,
     2540728.28076691,
     2540743.28313406,
@@ -8503,10 +8549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2540990.02138346,
     2541004.84901121,
     2541019.61658531,
+    // 2245
     2541034.34026973,
     2541049.06644946,
     2541063.81382005,
-    // 2244
     2541078.62725152,
     2541093.52017706,
     2541108.52878500,
@@ -8528,10 +8574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2541355.26376873,
     2541370.09690508,
     2541384.85896551,
+    // 2246
     2541399.58713301,
     2541414.30701150,
     2541429.05804939,
-    // 2245
     2541443.86473148,
     2541458.76121337,
     2541473.76294751,
@@ -8553,10 +8599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2541720.50803562,
     2541735.34092945,
     2541750.10402303,
+    // 2247
     2541764.83191640,
     2541779.55237820,
     2541794.30302129,
-    // 2246
     2541809.10981990,
     2541824.00551522,
     2541839.00745640,
@@ -8578,10 +8624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2542085.74685216,
     2542100.57562252,
     2542115.34370165,
+    // 2248
     2542130.06823706,
     2542144.79447623,
     2542159.54193949,
-    // 2247
     2542174.35469789,
     2542189.24697645,
     2542204.25422095,
@@ -8603,10 +8649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2542450.98512760,
     2542465.82048545,
     2542480.58227727,
+    // 2249
     2542495.31382863,
     2542510.03416067,
     2542524.78872529,
-    // 2248
     2542539.59532617,
     2542554.49419708,
     2542569.49450384,
@@ -8628,10 +8674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2542816.22967915,
     2542831.06071576,
     2542845.82700234,
+    // 2250
     2542860.55360045,
     2542875.27781232,
     2542890.02755887,
-    // 2249
     2542904.83819238,
     2542919.73271754,
     2542934.73777427,
@@ -8653,10 +8699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2543181.47114062,
     2543196.30117559,
     2543211.06698617,
+    // 2251
     2543225.79220708,
     2543240.51600384,
     2543255.26428204,
-    // 2250
     2543270.07506544,
     2543284.96881959,
     2543299.97462658,
@@ -8678,10 +8724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2543546.71078684,
     2543561.54626946,
     2543576.30732781,
+    // 2252
     2543591.03804910,
     2543605.75686616,
     2543620.51052394,
-    // 2251
     2543635.31597653,
     2543650.21491611,
     2543665.21547493,
@@ -8703,10 +8749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2543911.95158174,
     2543926.78141326,
     2543941.55015123,
+    // 2253
     2543956.27557311,
     2543971.00206749,
     2543985.75018934,
-    // 2252
     2544000.56276318,
     2544015.45535239,
     2544030.46225267,
@@ -8728,10 +8774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2544277.19394528,
     2544292.02696124,
     2544306.79128312,
+    // 2254
     2544321.51941640,
     2544336.24142039,
     2544350.99198578,
-    // 2253
     2544365.80012353,
     2544380.69528593,
     2544395.69754457,
@@ -8753,10 +8799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2544642.43845629,
     2544657.27241121,
     2544672.03454586,
+    // 2255
     2544686.76380487,
     2544701.48363575,
     2544716.23584439,
-    // 2254
     2544731.04205619,
     2544745.93915791,
     2544760.94010278,
@@ -8778,10 +8824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2545007.68309520,
     2545022.51107477,
     2545037.27881090,
+    // 2256
     2545052.00231952,
     2545066.72820571,
     2545081.47487441,
-    // 2255
     2545096.28756804,
     2545111.17938613,
     2545126.18688051,
@@ -8803,10 +8849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2545372.92638923,
     2545387.76101816,
     2545402.52280478,
+    // 2257
     2545417.25253402,
     2545431.97206227,
     2545446.72431473,
-    // 2256
     2545461.53002708,
     2545476.42688119,
     2545491.42679665,
@@ -8828,10 +8874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2545738.17345830,
     2545753.00658863,
     2545767.77192028,
+    // 2258
     2545782.49925098,
     2545797.22112961,
     2545811.97039621,
-    // 2257
     2545826.77777689,
     2545841.67135615,
     2545856.67314985,
@@ -8853,10 +8899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2546103.41796787,
     2546118.24847472,
     2546133.01626596,
+    // 2259
     2546147.74099300,
     2546162.46550585,
     2546177.21193866,
-    // 2258
     2546192.02203237,
     2546206.91272936,
     2546221.91702668,
@@ -8878,10 +8924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2546468.65806819,
     2546483.49508300,
     2546498.25726811,
+    // 2260
     2546512.98906193,
     2546527.70837422,
     2546542.46212492,
-    // 2259
     2546557.26695934,
     2546572.16472432,
     2546587.16333538,
@@ -8903,10 +8949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2546833.90045863,
     2546848.73210073,
     2546863.50093911,
+    // 2261
     2546878.22787142,
     2546892.95408485,
     2546907.70328820,
-    // 2260
     2546922.51491454,
     2546937.40779505,
     2546952.41281552,
@@ -8928,10 +8974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2547199.14474794,
     2547213.97761525,
     2547228.74380739,
+    // 2262
     2547243.47112696,
     2547258.19447732,
     2547272.94380769,
-    // 2261
     2547287.75294318,
     2547302.64651142,
     2547317.64936976,
@@ -8953,10 +8999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2547564.38783911,
     2547579.22424702,
     2547593.98669223,
+    // 2263
     2547608.71735102,
     2547623.43652494,
     2547638.18923312,
-    // 2262
     2547652.99410599,
     2547667.89124392,
     2547682.89047287,
@@ -8978,10 +9024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2547929.62897989,
     2547944.45901549,
     2547959.22833116,
+    // 2264
     2547973.95311575,
     2547988.67952594,
     2548003.42638696,
-    // 2263
     2548018.23851674,
     2548033.12963847,
     2548048.13598046,
@@ -9003,10 +9049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2548294.86824506,
     2548309.70370324,
     2548324.46825021,
+    // 2265
     2548339.19862580,
     2548353.92037440,
     2548368.67238501,
-    // 2264
     2548383.47924973,
     2548398.37478201,
     2548413.37479859,
@@ -9028,10 +9074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2548660.11019225,
     2548674.94532970,
     2548689.71049608,
+    // 2266
     2548704.44022337,
     2548719.16207400,
     2548733.91354668,
-    // 2265
     2548748.72040653,
     2548763.61544060,
     2548778.61578846,
@@ -9053,10 +9099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2549025.35303233,
     2549040.18245862,
     2549054.95074331,
+    // 2267
     2549069.67493493,
     2549084.40048223,
     2549099.14688028,
-    // 2266
     2549113.95826657,
     2549128.84895367,
     2549143.85432976,
@@ -9078,10 +9124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2549390.59309143,
     2549405.42884810,
     2549420.19021916,
+    // 2268
     2549434.92065486,
     2549449.63939772,
     2549464.39216746,
-    // 2267
     2549479.19700885,
     2549494.09435848,
     2549509.09339132,
@@ -9103,10 +9149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2549755.83541113,
     2549770.66760565,
     2549785.43432732,
+    // 2269
     2549800.16081130,
     2549814.88418573,
     2549829.63260862,
-    // 2268
     2549844.44140580,
     2549859.33392295,
     2549874.33678162,
@@ -9128,10 +9174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2550121.07987213,
     2550135.91194978,
     2550150.67906468,
+    // 2270
     2550165.40438630,
     2550180.12740553,
     2550194.87365517,
-    // 2269
     2550209.68165650,
     2550224.57175432,
     2550239.57359347,
@@ -9153,10 +9199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2550486.32150214,
     2550501.15937723,
     2550515.92187529,
+    // 2271
     2550530.65300247,
     2550545.37110531,
     2550560.12294368,
-    // 2270
     2550574.92548723,
     2550589.82067917,
     2550604.81674950,
@@ -9178,10 +9224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2550851.56166810,
     2550866.39317522,
     2550881.16279887,
+    // 2272
     2550895.88851549,
     2550910.61447259,
     2550925.36140130,
-    // 2271
     2550940.17199526,
     2550955.06206378,
     2550970.06582579,
@@ -9203,10 +9249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2551216.80320532,
     2551231.63848818,
     2551246.40489387,
+    // 2273
     2551261.13455979,
     2551275.85765630,
     2551290.60836528,
-    // 2272
     2551305.41592894,
     2551320.30942735,
     2551335.30928957,
@@ -9228,10 +9274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2551582.04692718,
     2551596.88433087,
     2551611.64909246,
+    // 2274
     2551626.38011452,
     2551641.10077632,
     2551655.85289585,
-    // 2273
     2551670.65807765,
     2551685.55335359,
     2551700.55159923,
@@ -9253,10 +9299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2551947.29214795,
     2551962.12326572,
     2551976.89320360,
+    // 2275
     2551991.61786303,
     2552006.34383328,
     2552021.08963822,
-    // 2274
     2552035.90056927,
     2552050.79003900,
     2552065.79461621,
@@ -9278,10 +9324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2552312.53318215,
     2552327.37074485,
     2552342.13485986,
+    // 2276
     2552356.86614721,
     2552371.58644664,
     2552386.33869203,
-    // 2275
     2552401.14380975,
     2552416.03954132,
     2552431.03798744,
@@ -9303,10 +9349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2552677.77423554,
     2552692.60990316,
     2552707.37768816,
+    // 2277
     2552722.10740450,
     2552736.83121357,
     2552751.58187280,
-    // 2276
     2552766.38987516,
     2552781.28334530,
     2552796.28419236,
@@ -9328,10 +9374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2553043.02075311,
     2553057.85305527,
     2553072.62259109,
+    // 2278
     2553087.34862065,
     2553102.07421143,
     2553116.82103471,
-    // 2277
     2553131.63105973,
     2553146.52089717,
     2553161.52374375,
@@ -9353,10 +9399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2553408.26408010,
     2553423.10189745,
     2553437.86438983,
+    // 2279
     2553452.59602890,
     2553467.31480778,
     2553482.06765199,
-    // 2278
     2553496.87126528,
     2553511.76755769,
     2553526.76440961,
@@ -9378,10 +9424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2553773.50851329,
     2553788.34014105,
     2553803.10816867,
+    // 2280
     2553817.83392353,
     2553832.55845889,
     2553847.30589641,
-    // 2279
     2553862.11561502,
     2553877.00681363,
     2553892.01024286,
@@ -9403,10 +9449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2554138.75399673,
     2554153.58778399,
     2554168.35459942,
+    // 2281
     2554183.08185612,
     2554197.80466490,
     2554212.55257001,
-    // 2280
     2554227.35984954,
     2554242.25089399,
     2554257.25112499,
@@ -9428,10 +9474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2554503.99677849,
     2554518.83534341,
     2554533.59917252,
+    // 2282
     2554548.33034464,
     2554563.04904364,
     2554577.80029120,
-    // 2281
     2554592.60275290,
     2554607.49674891,
     2554622.49221187,
@@ -9453,10 +9499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2554869.24043988,
     2554884.07234544,
     2554898.84257172,
+    // 2283
     2554913.56738383,
     2554928.29276595,
     2554943.03772080,
-    // 2282
     2554957.84697414,
     2554972.73458346,
     2554987.73676870,
@@ -9478,10 +9524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2555234.47921244,
     2555249.31630405,
     2555264.08173450,
+    // 2284
     2555278.81220150,
     2555293.53338802,
     2555308.28417701,
-    // 2283
     2555323.08943193,
     2555337.98285270,
     2555352.98048343,
@@ -9503,10 +9549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2555599.71700908,
     2555614.55420581,
     2555629.32086113,
+    // 2285
     2555644.05164734,
     2555658.77397165,
     2555673.52534731,
-    // 2284
     2555688.33149490,
     2555703.22523675,
     2555718.22365286,
@@ -9528,10 +9574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2555964.96266948,
     2555979.79497783,
     2555994.56524540,
+    // 2286
     2556009.29026421,
     2556024.01574554,
     2556038.76101187,
-    // 2285
     2556053.57053171,
     2556068.45858793,
     2556083.46080862,
@@ -9553,10 +9599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2556330.20177424,
     2556345.04076078,
     2556359.80435832,
+    // 2287
     2556374.53583944,
     2556389.25447548,
     2556404.00599038,
-    // 2286
     2556418.80864459,
     2556433.70305879,
     2556448.69865810,
@@ -9578,10 +9624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2556695.44097464,
     2556710.27587476,
     2556725.04460775,
+    // 2288
     2556739.77258792,
     2556754.49649908,
     2556769.24471970,
-    // 2287
     2556784.05238692,
     2556798.94318474,
     2556813.94369723,
@@ -9603,10 +9649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2557060.68277095,
     2557075.51739940,
     2557090.28726949,
+    // 2289
     2557105.01502530,
     2557119.74010565,
     2557134.48741838,
-    // 2288
     2557149.29565863,
     2557164.18473762,
     2557179.18471417,
@@ -9628,10 +9674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2557425.92236141,
     2557440.76250692,
     2557455.52711780,
+    // 2290
     2557470.26009642,
     2557484.97963695,
     2557499.73234786,
-    // 2289
     2557514.53507780,
     2557529.42967577,
     2557544.42428973,
@@ -9653,10 +9699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2557791.16758863,
     2557806.00007098,
     2557820.76995237,
+    // 2291
     2557835.49555682,
     2557850.22082472,
     2557864.96682152,
-    // 2290
     2557879.77605842,
     2557894.66463538,
     2557909.66656568,
@@ -9678,10 +9724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2558156.41107619,
     2558171.24691432,
     2558186.01312113,
+    // 2292
     2558200.74185013,
     2558215.46361677,
     2558230.21266659,
-    // 2291
     2558245.01872269,
     2558259.91072546,
     2558274.90944167,
@@ -9703,10 +9749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2558521.65231509,
     2558536.49112468,
     2558551.25672328,
+    // 2293
     2558565.98803644,
     2558580.70833680,
     2558595.45948200,
-    // 2292
     2558610.26318572,
     2558625.15661734,
     2558640.15276914,
@@ -9728,10 +9774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2558886.90132671,
     2558901.73454714,
     2558916.50582755,
+    // 2294
     2558931.23081702,
     2558945.95616790,
     2558960.70027684,
-    // 2293
     2558975.50862108,
     2558990.39474334,
     2559005.39542932,
@@ -9753,10 +9799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2559252.14377800,
     2559266.98347347,
     2559281.74892365,
+    // 2295
     2559296.48059937,
     2559311.20034160,
     2559325.95100479,
-    // 2294
     2559340.75364366,
     2559355.64604080,
     2559370.64058280,
@@ -9778,10 +9824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2559617.38475638,
     2559632.22184132,
     2559646.99031609,
+    // 2296
     2559661.72032871,
     2559676.44369827,
     2559691.19344463,
-    // 2295
     2559705.99986364,
     2559720.89130617,
     2559735.88957203,
@@ -9803,10 +9849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2559982.63158034,
     2559997.46587908,
     2560012.23713590,
+    // 2297
     2560026.96433365,
     2560041.69059624,
     2560056.43721545,
-    // 2296
     2560071.24640820,
     2560086.13445442,
     2560101.13492804,
@@ -9828,10 +9874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2560347.87419066,
     2560362.71520411,
     2560377.48003117,
+    // 2298
     2560392.21303917,
     2560406.93223853,
     2560421.68453657,
-    // 2297
     2560436.48677799,
     2560451.38089449,
     2560466.37491856,
@@ -9853,10 +9899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2560713.11912089,
     2560727.95381987,
     2560742.72376209,
+    // 2299
     2560757.45051540,
     2560772.17476373,
     2560786.92103236,
-    // 2298
     2560801.72857614,
     2560816.61706429,
     2560831.61722781,
@@ -9878,10 +9924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2561078.36100536,
     2561093.19741523,
     2561107.96633458,
+    // 2300
     2561122.69492437,
     2561137.41841733,
     2561152.16619332,
-    // 2299
     2561166.97287572,
     2561181.86264329,
     2561196.86130299,
@@ -9903,10 +9949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2561443.59748434,
     2561458.43840546,
     2561473.20457863,
+    // 2301
     2561487.93792455,
     2561502.65840738,
     2561517.41083331,
-    // 2300
     2561532.21377562,
     2561547.10743410,
     2561562.10175789,
@@ -9928,10 +9974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2561808.84300172,
     2561823.67658793,
     2561838.44818279,
+    // 2302
     2561853.17397787,
     2561867.89988604,
     2561882.64486127,
-    // 2301
     2561897.45353284,
     2561912.34001064,
     2561927.34038286,
@@ -9953,10 +9999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2562174.08492788,
     2562188.92303301,
     2562203.68885052,
+    // 2303
     2562218.41904812,
     2562233.13944270,
     2562247.88891658,
-    // 2302
     2562262.69252520,
     2562277.58392957,
     2562292.57944580,
@@ -9978,10 +10024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2562539.32353057,
     2562554.16120883,
     2562568.92746900,
+    // 2304
     2562583.65738850,
     2562598.37816155,
     2562613.12770537,
-    // 2303
     2562627.93172793,
     2562642.82336275,
     2562657.81972258,
@@ -10003,10 +10049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2562904.56928130,
     2562919.40324911,
     2562934.17482082,
+    // 2305
     2562948.90045312,
     2562963.62582591,
     2562978.36998335,
-    // 2304
     2562993.17769388,
     2563008.06311237,
     2563023.06236484,
@@ -10028,10 +10074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2563269.80913756,
     2563284.65081979,
     2563299.41642054,
+    // 2306
     2563314.14896317,
     2563328.86766786,
     2563343.61809809,
-    // 2305
     2563358.41866253,
     2563373.30995811,
     2563388.30173634,
@@ -10053,10 +10099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2563635.05032190,
     2563649.88740677,
     2563664.65732782,
+    // 2307
     2563679.38581882,
     2563694.10912414,
     2563708.85599610,
-    // 2306
     2563723.66128012,
     2563738.54905989,
     2563753.54579580,
@@ -10078,10 +10124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2564000.29226656,
     2564015.12863512,
     2564029.89968189,
+    // 2308
     2564044.62795240,
     2564059.35308930,
     2564074.09988930,
-    // 2307
     2564088.90733061,
     2564103.79502048,
     2564118.79327506,
@@ -10103,10 +10149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2564365.52870477,
     2564380.37105294,
     2564395.13746893,
+    // 2309
     2564409.87175492,
     2564424.59212084,
     2564439.34507057,
-    // 2308
     2564454.14749994,
     2564469.04116053,
     2564484.03424928,
@@ -10128,10 +10174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2564730.77632661,
     2564745.61182688,
     2564760.38376600,
+    // 2310
     2564775.11051686,
     2564789.83589974,
     2564804.58120896,
-    // 2309
     2564819.38889471,
     2564834.27536910,
     2564849.27456670,
@@ -10153,10 +10199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2565096.01951459,
     2565110.85846426,
     2565125.62696379,
+    // 2311
     2565140.35694485,
     2565155.07890090,
     2565169.82706299,
-    // 2310
     2565184.63137196,
     2565199.52084062,
     2565214.51669566,
@@ -10178,10 +10224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2565461.25768300,
     2565476.09862192,
     2565490.86589316,
+    // 2312
     2565505.59854718,
     2565520.31948275,
     2565535.07065741,
-    // 2311
     2565549.87370199,
     2565564.76588766,
     2565579.76035651,
@@ -10203,10 +10249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2565826.50533797,
     2565841.34053419,
     2565856.11398760,
+    // 2313
     2565870.84099785,
     2565885.56802068,
     2565900.31306967,
-    // 2312
     2565915.12162740,
     2565930.00698033,
     2565945.00612225,
@@ -10228,10 +10274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2566191.74828653,
     2566206.58950169,
     2566221.35627223,
+    // 2314
     2566236.08887776,
     2566250.80921399,
     2566265.55994006,
-    // 2313
     2566280.36219121,
     2566295.25353247,
     2566310.24649017,
@@ -10253,10 +10299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2566556.99358392,
     2566571.83120298,
     2566586.59928466,
+    // 2315
     2566601.32848489,
     2566616.05033098,
     2566630.79839275,
-    // 2314
     2566645.60272525,
     2566660.49212492,
     2566675.48818719,
@@ -10278,10 +10324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2566922.24181009,
     2566937.07655704,
     2566951.84744922,
+    // 2316
     2566966.57347689,
     2566981.29807968,
     2566996.04266696,
-    // 2315
     2567010.84987333,
     2567025.73588175,
     2567040.73463027,
@@ -10303,10 +10349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2567287.48064364,
     2567302.32314312,
     2567317.08896186,
+    // 2317
     2567331.82220634,
     2567346.54093298,
     2567361.29195725,
-    // 2316
     2567376.09232451,
     2567390.98400716,
     2567405.97534736,
@@ -10328,10 +10374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2567652.72853136,
     2567667.56561801,
     2567682.33695262,
+    // 2318
     2567697.06418904,
     2567711.78767123,
     2567726.53220525,
-    // 2317
     2567741.33683261,
     2567756.22167832,
     2567771.21744079,
@@ -10353,10 +10399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2568017.97030422,
     2568032.80902631,
     2568047.57942951,
+    // 2319
     2568062.30850583,
     2568077.03146791,
     2568091.77761917,
-    // 2318
     2568106.58174000,
     2568121.46797614,
     2568136.46261241,
@@ -10378,10 +10424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2568383.20517148,
     2568398.04780401,
     2568412.81508287,
+    // 2320
     2568427.54904929,
     2568442.26949790,
     2568457.02128454,
-    // 2319
     2568471.82298031,
     2568486.71474951,
     2568501.70668610,
@@ -10403,10 +10449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2568748.45104550,
     2568763.28721288,
     2568778.06097077,
+    // 2321
     2568792.78830799,
     2568807.51503654,
     2568822.25995299,
-    // 2320
     2568837.06772651,
     2568851.95240564,
     2568866.95019945,
@@ -10428,10 +10474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2569113.69148258,
     2569128.53311755,
     2569143.30168789,
+    // 2322
     2569158.03358344,
     2569172.75470505,
     2569187.50385863,
-    // 2321
     2569202.30627769,
     2569217.19558609,
     2569232.18837230,
@@ -10453,10 +10499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2569478.93088605,
     2569493.77184079,
     2569508.54036689,
+    // 2323
     2569523.27175596,
     2569537.99274039,
     2569552.74164625,
-    // 2322
     2569567.54395373,
     2569582.43326095,
     2569597.42666515,
@@ -10478,10 +10524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2569844.17386851,
     2569859.01028682,
     2569873.78395520,
+    // 2324
     2569888.51115708,
     2569903.23756851,
     2569917.98215936,
-    // 2323
     2569932.78979356,
     2569947.67445700,
     2569962.67250552,
@@ -10503,10 +10549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2570209.40817949,
     2570224.25195628,
     2570239.01987652,
+    // 2325
     2570253.75458007,
     2570268.47525654,
     2570283.22712949,
-    // 2324
     2570298.02858723,
     2570312.91994899,
     2570327.91108839,
@@ -10528,10 +10574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2570574.65297114,
     2570589.49154940,
     2570604.26247916,
+    // 2326
     2570618.99176721,
     2570633.71528593,
     2570648.46213907,
-    // 2325
     2570663.26676771,
     2570678.15359105,
     2570693.14873343,
@@ -10553,10 +10599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2570939.89816002,
     2570954.73531674,
     2570969.50647771,
+    // 2327
     2570984.23418624,
     2570998.95825016,
     2571013.70351231,
-    // 2326
     2571028.50920301,
     2571043.39489279,
     2571058.39130916,
@@ -10578,10 +10624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2571305.13589903,
     2571319.97859042,
     2571334.74470071,
+    // 2328
     2571349.47806744,
     2571364.19699510,
     2571378.94809843,
-    // 2327
     2571393.74853037,
     2571408.64010632,
     2571423.63133471,
@@ -10603,10 +10649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2571670.38507342,
     2571685.22216182,
     2571699.99522645,
+    // 2329
     2571714.72241415,
     2571729.44735010,
     2571744.19131569,
-    // 2328
     2571758.99678637,
     2571773.88040806,
     2571788.87627643,
@@ -10628,10 +10674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2572035.62933584,
     2572050.47072772,
     2572065.24099572,
+    // 2330
     2572079.97174292,
     2572094.69347526,
     2572109.44029794,
-    // 2329
     2572124.24225690,
     2572139.12837861,
     2572154.12024651,
@@ -10653,10 +10699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2572400.86998301,
     2572415.71291624,
     2572430.48119209,
+    // 2331
     2572445.21411722,
     2572459.93421033,
     2572474.68378431,
-    // 2330
     2572489.48423200,
     2572504.37316704,
     2572519.36374601,
@@ -10678,10 +10724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2572766.11715239,
     2572780.95395607,
     2572795.72837575,
+    // 2332
     2572810.45574520,
     2572825.18260291,
     2572839.92703546,
-    // 2331
     2572854.73458202,
     2572869.61840700,
     2572884.61559887,
@@ -10703,10 +10749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2573131.35652664,
     2573146.20019572,
     2573160.96898615,
+    // 2333
     2573175.70287300,
     2573190.42392066,
     2573205.17460000,
-    // 2332
     2573219.97621252,
     2573234.86615122,
     2573249.85718029,
@@ -10728,10 +10774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2573496.60262149,
     2573511.44364329,
     2573526.21400160,
+    // 2334
     2573540.94453146,
     2573555.66642241,
     2573570.41368489,
-    // 2333
     2573585.21612526,
     2573600.10306141,
     2573615.09593214,
@@ -10753,10 +10799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2573861.84905247,
     2573876.68731456,
     2573891.46094155,
+    // 2335
     2573906.18865803,
     2573920.91382190,
     2573935.65778862,
-    // 2334
     2573950.46335501,
     2573965.34679876,
     2573980.34254851,
@@ -10778,10 +10824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2574227.08350343,
     2574241.92826499,
     2574256.69622746,
+    // 2336
     2574271.43116157,
     2574286.15109168,
     2574300.90256151,
-    // 2335
     2574315.70270973,
     2574330.59335791,
     2574345.58313483,
@@ -10803,10 +10849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2574592.32908498,
     2574607.16838407,
     2574621.94209768,
+    // 2337
     2574636.67167395,
     2574651.39706020,
     2574666.14296352,
-    // 2336
     2574680.94807719,
     2574695.83260423,
     2574710.82703336,
@@ -10828,10 +10874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2574957.57285766,
     2574972.41321586,
     2574987.18505611,
+    // 2338
     2575001.91515769,
     2575016.63879526,
     2575031.38515408,
-    // 2337
     2575046.18900249,
     2575061.07435634,
     2575076.06759666,
@@ -10853,10 +10899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2575322.81201948,
     2575337.65520043,
     2575352.42216281,
+    // 2339
     2575367.15530872,
     2575381.87428786,
     2575396.62438893,
-    // 2338
     2575411.42410709,
     2575426.31389237,
     2575441.30387979,
@@ -10878,10 +10924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2575688.05938825,
     2575702.89612590,
     2575717.66970604,
+    // 2340
     2575732.39622659,
     2575747.12164335,
     2575761.86499413,
-    // 2339
     2575776.67109628,
     2575791.55408260,
     2575806.55028435,
@@ -10903,10 +10949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2576053.29704363,
     2576068.14028591,
     2576082.91010870,
+    // 2341
     2576097.64245482,
     2576112.36339673,
     2576127.11150993,
-    // 2340
     2576141.91228532,
     2576156.79927048,
     2576171.78946888,
@@ -10928,10 +10974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2576418.53915048,
     2576433.38244193,
     2576448.15235398,
+    // 2342
     2576462.88432829,
     2576477.60472155,
     2576492.35220712,
-    // 2341
     2576507.15194394,
     2576522.03803810,
     2576537.02744464,
@@ -10953,10 +10999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2576783.78303398,
     2576798.62161707,
     2576813.39669635,
+    // 2343
     2576828.12441855,
     2576842.85042020,
     2576857.59365719,
-    // 2342
     2576872.39905068,
     2576887.28068218,
     2576902.27524870,
@@ -10978,10 +11024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2577149.01643562,
     2577163.86147610,
     2577178.63031477,
+    // 2344
     2577193.36531248,
     2577208.08590172,
     2577222.83707593,
-    // 2343
     2577237.63745276,
     2577252.52716835,
     2577267.51636354,
@@ -11003,10 +11049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2577514.26212939,
     2577529.10322979,
     2577543.87611364,
+    // 2345
     2577558.60673070,
     2577573.33069489,
     2577588.07728394,
-    // 2344
     2577602.88071093,
     2577617.76573014,
     2577632.75827867,
@@ -11028,10 +11074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2577879.50713068,
     2577894.34770030,
     2577909.12140756,
+    // 2346
     2577923.85056065,
     2577938.57497282,
     2577953.31953038,
-    // 2345
     2577968.12356156,
     2577983.00676609,
     2577998.00013475,
@@ -11053,10 +11099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2578244.74471874,
     2578259.59028136,
     2578274.35841189,
+    // 2347
     2578289.09293074,
     2578303.81190560,
     2578318.56210754,
-    // 2346
     2578333.36069676,
     2578348.24970527,
     2578363.23793594,
@@ -11078,10 +11124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2578609.99129894,
     2578624.83046291,
     2578639.60527349,
+    // 2348
     2578654.33384537,
     2578669.05970005,
     2578683.80411710,
-    // 2347
     2578698.60945127,
     2578713.49231287,
     2578728.48677905,
@@ -11103,10 +11149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2578975.23025825,
     2578990.07342672,
     2579004.84569636,
+    // 2349
     2579019.57817052,
     2579034.30148925,
     2579049.04935987,
-    // 2348
     2579063.85185709,
     2579078.73773123,
     2579093.72868672,
@@ -11128,10 +11174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2579340.47491188,
     2579355.31926508,
     2579370.08833500,
+    // 2350
     2579384.82166594,
     2579399.54151512,
     2579414.29053705,
-    // 2349
     2579429.08978318,
     2579443.97723780,
     2579458.96575874,
@@ -11153,10 +11199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2579705.72418243,
     2579720.56199702,
     2579735.33658512,
+    // 2351
     2579750.06330694,
     2579764.78879091,
     2579779.53130653,
-    // 2350
     2579794.33651064,
     2579809.21782488,
     2579824.21258822,
@@ -11178,10 +11224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2580070.96264212,
     2580085.80707573,
     2580100.57618291,
+    // 2352
     2580115.30955037,
     2580130.02961055,
     2580144.77863491,
-    // 2351
     2580159.57835139,
     2580174.46607110,
     2580189.45500736,
@@ -11203,10 +11249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2580436.21002517,
     2580451.05327816,
     2580465.82529833,
+    // 2353
     2580480.55678904,
     2580495.27856107,
     2580510.02480516,
-    // 2352
     2580524.82507117,
     2580539.70916662,
     2580554.69840984,
@@ -11228,10 +11274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2580801.45653443,
     2580816.29755628,
     2580831.07320840,
+    // 2354
     2580845.80203957,
     2580860.52729979,
     2580875.27031268,
-    // 2353
     2580890.07384440,
     2580904.95425448,
     2580919.94620681,
@@ -11253,10 +11299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2581166.69345747,
     2581181.54023643,
     2581196.30947513,
+    // 2355
     2581211.04489069,
     2581225.76447309,
     2581240.51475005,
-    // 2354
     2581255.31293586,
     2581270.20083792,
     2581285.18735923,
@@ -11278,10 +11324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2581531.93917000,
     2581546.78005564,
     2581561.55474029,
+    // 2356
     2581576.28481013,
     2581591.01025357,
     2581605.75589429,
-    // 2355
     2581620.56031903,
     2581635.44374992,
     2581650.43649881,
@@ -11303,10 +11349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2581897.17905730,
     2581912.02203849,
     2581926.79609972,
+    // 2357
     2581941.52762474,
     2581956.25213528,
     2581970.99862181,
-    // 2356
     2581985.80198855,
     2582000.68618622,
     2582015.67771717,
@@ -11328,10 +11374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2582262.41947860,
     2582277.26612010,
     2582292.03551568,
+    // 2358
     2582306.77010388,
     2582321.48929150,
     2582336.23869369,
-    // 2357
     2582351.03664587,
     2582365.92406781,
     2582380.91103470,
@@ -11353,10 +11399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2582627.66474530,
     2582642.50469948,
     2582657.28079116,
+    // 2359
     2582672.00893083,
     2582686.73489523,
     2582701.47776761,
-    // 2358
     2582716.28235754,
     2582731.16312147,
     2582746.15665256,
@@ -11378,10 +11424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2582992.89863841,
     2583007.74368072,
     2583022.51544690,
+    // 2360
     2583037.24929584,
     2583051.97151718,
     2583066.72020741,
-    // 2359
     2583081.52107261,
     2583096.40737561,
     2583111.39645124,
@@ -11403,10 +11449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2583358.14067646,
     2583372.98580093,
     2583387.75754709,
+    // 2361
     2583402.49128776,
     2583417.21296991,
     2583431.96133657,
-    // 2360
     2583446.76112496,
     2583461.64669375,
     2583476.63463661,
@@ -11428,10 +11474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2583723.38704285,
     2583738.22699724,
     2583753.00298375,
+    // 2362
     2583767.73117634,
     2583782.45718456,
     2583797.20001291,
-    // 2361
     2583812.00452223,
     2583826.88483192,
     2583841.87766343,
@@ -11453,10 +11499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2584088.62504840,
     2584103.47055318,
     2584118.23908390,
+    // 2363
     2584132.97294384,
     2584147.69186450,
     2584162.44082992,
-    // 2362
     2584177.23883912,
     2584192.12599936,
     2584207.11285794,
@@ -11478,10 +11524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2584453.87155222,
     2584468.71331860,
     2584483.48611961,
+    // 2364
     2584498.21603352,
     2584512.93873359,
     2584527.68374262,
-    // 2363
     2584542.48524514,
     2584557.36827463,
     2584572.35864061,
@@ -11503,10 +11549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2584819.11393370,
     2584833.95643566,
     2584848.73165450,
+    // 2365
     2584863.46147020,
     2584878.18580634,
     2584892.92935797,
-    // 2364
     2584907.73155727,
     2584922.61219012,
     2584937.60246016,
@@ -11528,10 +11574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2585184.35360698,
     2585199.20165831,
     2585213.97136244,
+    // 2366
     2585228.70654209,
     2585243.42504438,
     2585258.17381763,
-    // 2365
     2585272.96984554,
     2585287.85549330,
     2585302.83958386,
@@ -11553,10 +11599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2585549.60042846,
     2585564.44204606,
     2585579.21849852,
+    // 2367
     2585593.94788208,
     2585608.67357233,
     2585623.41692301,
-    // 2366
     2585638.22021790,
     2585653.10033071,
     2585668.09145640,
@@ -11578,10 +11624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2585914.83806953,
     2585929.68284836,
     2585944.45643905,
+    // 2368
     2585959.18952023,
     2585973.91311913,
     2585988.66057723,
-    // 2367
     2586003.46227497,
     2586018.34670528,
     2586033.33584663,
@@ -11603,10 +11649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2586280.08238464,
     2586294.92981812,
     2586309.70131012,
+    // 2369
     2586324.43635676,
     2586339.15699122,
     2586353.90602538,
-    // 2368
     2586368.70432515,
     2586383.59020523,
     2586398.57630468,
@@ -11628,10 +11674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2586645.33178046,
     2586660.17346283,
     2586674.95102653,
+    // 2370
     2586689.67972627,
     2586704.40604427,
     2586719.14833128,
-    // 2369
     2586733.95223836,
     2586748.83142098,
     2586763.82332785,
@@ -11653,10 +11699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2587010.56989785,
     2587025.41704849,
     2587040.18836899,
+    // 2371
     2587054.92302809,
     2587069.64362285,
     2587084.39210971,
-    // 2370
     2587099.19056968,
     2587114.07618261,
     2587129.06265517,
@@ -11678,10 +11724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2587375.81471236,
     2587390.65975673,
     2587405.43343911,
+    // 2372
     2587420.16647609,
     2587434.88953497,
     2587449.63680241,
-    // 2371
     2587464.43751902,
     2587479.32156538,
     2587494.30992737,
@@ -11703,10 +11749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2587741.05728652,
     2587755.89988772,
     2587770.67725062,
+    // 2373
     2587785.40765174,
     2587800.13433679,
     2587814.87842899,
-    // 2372
     2587829.68255282,
     2587844.56299634,
     2587859.55430055,
@@ -11728,10 +11774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2588106.29818205,
     2588121.14598766,
     2588135.91566549,
+    // 2374
     2588150.65103763,
     2588165.37008818,
     2588180.11950182,
-    // 2373
     2588194.91644569,
     2588209.80287254,
     2588224.78766771,
@@ -11753,10 +11799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2588471.54648899,
     2588486.38824807,
     2588501.16287776,
+    // 2375
     2588515.89213165,
     2588530.61598260,
     2588545.35953329,
-    // 2374
     2588560.16145725,
     2588575.04239569,
     2588590.03279730,
@@ -11778,10 +11824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2588836.78577708,
     2588851.62926538,
     2588866.40344856,
+    // 2376
     2588881.13424131,
     2588895.85760438,
     2588910.60222983,
-    // 2375
     2588925.40350439,
     2588940.28529947,
     2588955.27455467,
@@ -11803,10 +11849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2589202.02756574,
     2589216.87629551,
     2589231.64715704,
+    // 2377
     2589246.38243487,
     2589261.10127903,
     2589275.84936900,
-    // 2376
     2589290.64492395,
     2589305.52923822,
     2589320.51235322,
@@ -11828,10 +11874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2589567.27391667,
     2589582.11653291,
     2589596.89440664,
+    // 2378
     2589611.62343292,
     2589626.34919558,
     2589641.09082432,
-    // 2377
     2589655.89302410,
     2589670.77048524,
     2589685.75984021,
@@ -11853,10 +11899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2589932.50953096,
     2589947.35627014,
     2589962.12911995,
+    // 2379
     2589976.86308992,
     2589991.58479726,
     2590006.33199093,
-    // 2378
     2590021.13077791,
     2590036.01416901,
     2590050.99994761,
@@ -11878,10 +11924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2590297.75169149,
     2590312.59838875,
     2590327.37105566,
+    // 2380
     2590342.10523485,
     2590356.82690457,
     2590371.57492617,
-    // 2379
     2590386.37384535,
     2590401.25814659,
     2590416.24408710,
@@ -11903,10 +11949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2590662.99355092,
     2590677.83651239,
     2590692.61481515,
+    // 2381
     2590707.34454122,
     2590722.07126831,
     2590736.81406295,
-    // 2380
     2590751.61773169,
     2590766.49656114,
     2590781.48722593,
@@ -11928,10 +11974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2591028.23237208,
     2591043.08160185,
     2591057.85288860,
+    // 2382
     2591072.58836846,
     2591087.30772520,
     2591102.05595613,
-    // 2381
     2591116.85220999,
     2591131.73673371,
     2591146.72036404,
@@ -11953,10 +11999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2591393.47614578,
     2591408.32124761,
     2591423.09665684,
+    // 2383
     2591437.82837059,
     2591452.55179437,
     2591467.29647331,
-    // 2382
     2591482.09657028,
     2591496.97744898,
     2591511.96509723,
@@ -11978,10 +12024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2591758.71372419,
     2591773.55812309,
     2591788.33544415,
+    // 2384
     2591803.06701757,
     2591817.79298389,
     2591832.53751983,
-    // 2383
     2591847.34022400,
     2591862.22060837,
     2591877.21001873,
@@ -12003,10 +12049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2592123.95496781,
     2592138.80500145,
     2592153.57662720,
+    // 2385
     2592168.31352225,
     2592183.03332607,
     2592197.78291916,
-    // 2384
     2592212.57906849,
     2592227.46422603,
     2592242.44696447,
@@ -12028,10 +12074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2592489.20468872,
     2592504.04781767,
     2592518.82510318,
+    // 2386
     2592533.55485787,
     2592548.28034751,
     2592563.02310873,
-    // 2385
     2592577.82531476,
     2592592.70405589,
     2592607.69336907,
@@ -12053,10 +12099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2592854.44692497,
     2592869.29233956,
     2592884.06590159,
+    // 2387
     2592898.79799089,
     2592913.52013924,
     2592928.26545309,
-    // 2386
     2592943.06491318,
     2592957.94683478,
     2592972.93373352,
@@ -12078,10 +12124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2593219.69240567,
     2593234.54071634,
     2593249.31241891,
+    // 2388
     2593264.04712263,
     2593278.76686576,
     2593293.51460760,
-    // 2387
     2593308.31115716,
     2593323.19507133,
     2593338.17884716,
@@ -12103,10 +12149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2593584.93857435,
     2593599.78254386,
     2593614.56180477,
+    // 2389
     2593629.29157191,
     2593644.01808652,
     2593658.75975556,
-    // 2388
     2593673.56208334,
     2593688.43896914,
     2593703.42786722,
@@ -12128,10 +12174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2593950.17920056,
     2593965.02887696,
     2593979.80194452,
+    // 2390
     2593994.53739521,
     2594009.25785128,
     2594024.00510059,
-    // 2389
     2594038.80142065,
     2594053.68390097,
     2594068.66662532,
@@ -12153,10 +12199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2594315.42415514,
     2594330.27135628,
     2594345.04641836,
+    // 2391
     2594359.78008512,
     2594374.50290657,
     2594389.24911228,
-    // 2390
     2594404.04795909,
     2594418.92958643,
     2594433.91504273,
@@ -12178,10 +12224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2594680.66512708,
     2594695.50923406,
     2594710.28776530,
+    // 2392
     2594725.01871261,
     2594739.74561402,
     2594754.48933777,
-    // 2391
     2594769.29271758,
     2594784.17194190,
     2594799.16161242,
@@ -12203,10 +12249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2595045.90658126,
     2595060.75743493,
     2595075.52944631,
+    // 2393
     2595090.26626139,
     2595104.98580368,
     2595119.73479739,
-    // 2392
     2595134.53043550,
     2595149.41487712,
     2595164.39698550,
@@ -12228,10 +12274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2595411.15393059,
     2595425.99941040,
     2595440.77679239,
+    // 2394
     2595455.50788221,
     2595470.23235467,
     2595484.97546282,
-    // 2393
     2595499.77581171,
     2595514.65436510,
     2595529.64159517,
@@ -12253,10 +12299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2595776.39256763,
     2595791.23845670,
     2595806.01467886,
+    // 2395
     2595820.74663536,
     2595835.47053256,
     2595850.21460482,
-    // 2394
     2595865.01465908,
     2595879.89431883,
     2595894.88104380,
@@ -12278,10 +12324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2596141.63158065,
     2596156.48177239,
     2596171.25403164,
+    // 2396
     2596185.99069959,
     2596200.71078838,
     2596215.45986650,
-    // 2395
     2596230.25589658,
     2596245.14008372,
     2596260.12221380,
@@ -12303,10 +12349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2596506.87481069,
     2596521.71909306,
     2596536.49842102,
+    // 2397
     2596551.22885793,
     2596565.95568906,
     2596580.69812535,
-    // 2396
     2596595.50056861,
     2596610.37779624,
     2596625.36616932,
@@ -12328,10 +12374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2596872.11407008,
     2596886.96192635,
     2596901.73522530,
+    // 2398
     2596916.46890220,
     2596931.18985627,
     2596945.93569093,
-    // 2397
     2596960.73290354,
     2596975.61428751,
     2596990.59801987,
@@ -12353,10 +12399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2597237.35767939,
     2597252.20564574,
     2597266.97863613,
+    // 2399
     2597281.71226183,
     2597296.43253610,
     2597311.17850895,
-    // 2398
     2597325.97492923,
     2597340.85668182,
     2597355.84015430,
@@ -12378,10 +12424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2597602.59857385,
     2597617.44261542,
     2597632.22151347,
+    // 2400
     2597646.95110383,
     2597661.67710025,
     2597676.41848192,
-    // 2399
     2597691.22025029,
     2597706.09684717,
     2597721.08507872,
@@ -12403,10 +12449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2597967.83834647,
     2597982.69001545,
     2597997.46315203,
+    // 2401
     2598012.19963869,
     2598026.91904934,
     2598041.66629155,
-    // 2400
     2598056.46056176,
     2598071.34223119,
     2598086.32229744,
@@ -12428,10 +12474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2598333.08427016,
     2598347.93228269,
     2598362.70969831,
+    // 2402
     2598377.44266183,
     2598392.16624422,
     2598406.91010913,
-    // 2401
     2598421.70826672,
     2598436.58629807,
     2598451.57017179,
@@ -12453,10 +12499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2598698.32448665,
     2598713.17051558,
     2598727.94890383,
+    // 2403
     2598742.68067015,
     2598757.40627631,
     2598772.14954733,
-    // 2402
     2598786.95050909,
     2598801.82839675,
     2598816.81496949,
@@ -12478,10 +12524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2599063.56605556,
     2599078.41771928,
     2599093.19041251,
+    // 2404
     2599107.92795940,
     2599122.64805436,
     2599137.39756247,
-    // 2403
     2599152.19319434,
     2599167.07732849,
     2599182.05840288,
@@ -12503,10 +12549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2599428.81201418,
     2599443.65849287,
     2599458.43826615,
+    // 2405
     2599473.16984115,
     2599487.89617366,
     2599502.63903572,
-    // 2404
     2599517.44044807,
     2599532.31777479,
     2599547.30493194,
@@ -12528,10 +12574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2599794.05441842,
     2599808.90332045,
     2599823.67960780,
+    // 2406
     2599838.41330512,
     2599853.13606639,
     2599867.88074289,
-    // 2405
     2599882.67872708,
     2599897.55818385,
     2599912.54218240,
@@ -12553,10 +12599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2600159.29760566,
     2600174.14875583,
     2600188.92280118,
+    // 2407
     2600203.65917016,
     2600218.37972207,
     2600233.12732524,
-    // 2406
     2600247.92284903,
     2600262.80507425,
     2600277.78665949,
@@ -12578,10 +12624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2600524.53988434,
     2600539.38518063,
     2600554.16586613,
+    // 2408
     2600568.89701715,
     2600583.62473561,
     2600598.36730294,
-    // 2407
     2600613.17003616,
     2600628.04685540,
     2600643.03502514,
@@ -12603,10 +12649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2600889.78246861,
     2600904.63352641,
     2600919.40783516,
+    // 2409
     2600934.14423215,
     2600948.86531415,
     2600963.61272091,
-    // 2408
     2600978.40876122,
     2600993.29037953,
     2601008.27171900,
@@ -12628,10 +12674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2601255.03101433,
     2601269.87945368,
     2601284.65488440,
+    // 2410
     2601299.38838308,
     2601314.11033250,
     2601328.85522414,
-    // 2409
     2601343.65228117,
     2601358.53185837,
     2601373.51496611,
@@ -12653,10 +12699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2601620.27525341,
     2601635.11984336,
     2601649.89808074,
+    // 2411
     2601664.62775829,
     2601679.35280502,
     2601694.09393824,
-    // 2410
     2601708.89456097,
     2601723.77076940,
     2601738.75765847,
@@ -12678,10 +12724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2601985.51743318,
     2602000.36918069,
     2602015.14147034,
+    // 2412
     2602029.87795575,
     2602044.59661797,
     2602059.34412707,
-    // 2411
     2602074.13776365,
     2602089.01969540,
     2602103.99890402,
@@ -12703,10 +12749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2602350.76080686,
     2602365.60888379,
     2602380.38801716,
+    // 2413
     2602395.12024442,
     2602409.84479254,
     2602424.58712613,
-    // 2412
     2602439.38560223,
     2602454.26148765,
     2602469.24517531,
@@ -12728,10 +12774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2602716.00121127,
     2602730.84989179,
     2602745.62813160,
+    // 2414
     2602760.36102770,
     2602775.08493777,
     2602789.82779538,
-    // 2413
     2602804.62575020,
     2602819.50216638,
     2602834.48500656,
@@ -12753,10 +12799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2603081.23982956,
     2603096.09242943,
     2603110.86631263,
+    // 2415
     2603125.60375273,
     2603140.32373030,
     2603155.07180537,
-    // 2414
     2603169.86606979,
     2603184.74788885,
     2603199.72719098,
@@ -12778,10 +12824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2603446.47960512,
     2603461.32605621,
     2603476.10698174,
+    // 2416
     2603490.83855619,
     2603505.56592188,
     2603520.30845774,
-    // 2415
     2603535.11039876,
     2603549.98673577,
     2603564.97361502,
@@ -12803,10 +12849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2603811.71995600,
     2603826.57152276,
     2603841.34778392,
+    // 2417
     2603856.08343178,
     2603870.80533966,
     2603885.55104195,
-    // 2416
     2603900.34725151,
     2603915.22671328,
     2603930.20792862,
@@ -12828,10 +12874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2604176.96274751,
     2604191.81463518,
     2604206.59067931,
+    // 2418
     2604221.32648719,
     2604236.04775968,
     2604250.79360111,
-    // 2417
     2604265.58878541,
     2604280.46837083,
     2604295.44891483,
@@ -12853,10 +12899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2604542.20337483,
     2604557.04975687,
     2604571.83072691,
+    // 2419
     2604586.56177344,
     2604601.28865754,
     2604616.03003026,
-    // 2418
     2604630.83112738,
     2604645.70623004,
     2604660.69245632,
@@ -12878,10 +12924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2604907.44221891,
     2604922.29510056,
     2604937.06959710,
+    // 2420
     2604951.80749294,
     2604966.52834278,
     2604981.27675874,
-    // 2419
     2604996.07181357,
     2605010.95357511,
     2605025.93296820,
@@ -12903,10 +12949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2605272.68594854,
     2605287.53554025,
     2605302.31418860,
+    // 2421
     2605317.04843434,
     2605331.77289475,
     2605346.51749616,
-    // 2420
     2605361.31586928,
     2605376.19375247,
     2605391.17680795,
@@ -12928,10 +12974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2605637.93106070,
     2605652.77816835,
     2605667.55698283,
+    // 2422
     2605682.28839010,
     2605697.01314853,
     2605711.75494389,
-    // 2421
     2605726.55423017,
     2605741.43002410,
     2605756.41454508,
@@ -12953,10 +12999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2606003.17448750,
     2606018.02719022,
     2606032.80008638,
+    // 2423
     2606047.53693644,
     2606062.25551612,
     2606077.00282069,
-    // 2422
     2606091.79588652,
     2606106.67736437,
     2606121.65602549,
@@ -12978,10 +13024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2606368.41889335,
     2606383.26647260,
     2606398.04674317,
+    // 2424
     2606412.77837746,
     2606427.50400570,
     2606442.24568712,
-    // 2423
     2606457.04524643,
     2606471.92045833,
     2606486.90513823,
@@ -13003,10 +13049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2606733.66323712,
     2606748.51451120,
     2606763.29254152,
+    // 2425
     2606778.02710423,
     2606792.74980247,
     2606807.49332294,
-    // 2424
     2606822.28925471,
     2606837.16563181,
     2606852.14598005,
@@ -13028,10 +13074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2607098.90770676,
     2607113.76144474,
     2607128.53726075,
+    // 2426
     2607143.27459520,
     2607157.99514120,
     2607172.74173595,
-    // 2425
     2607187.53528706,
     2607202.41458455,
     2607217.39243413,
@@ -13053,10 +13099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2607464.15218005,
     2607478.99889166,
     2607493.78033982,
+    // 2427
     2607508.51151093,
     2607523.23862679,
     2607537.97988751,
-    // 2426
     2607552.78078188,
     2607567.65520633,
     2607582.64055242,
@@ -13078,10 +13124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2607829.39427147,
     2607844.24694185,
     2607859.02234228,
+    // 2428
     2607873.75931488,
     2607888.48060229,
     2607903.22773041,
-    // 2427
     2607918.02305676,
     2607932.90334471,
     2607947.88279913,
@@ -13103,10 +13149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2608194.63853879,
     2608209.49067371,
     2608224.26881087,
+    // 2429
     2608239.00429192,
     2608253.72705282,
     2608268.47192760,
-    // 2428
     2608283.26793299,
     2608298.14573599,
     2608313.12629216,
@@ -13128,10 +13174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2608559.88164398,
     2608574.72983169,
     2608589.51090795,
+    // 2430
     2608604.24232724,
     2608618.96803504,
     2608633.70854864,
-    // 2429
     2608648.50759617,
     2608663.38116465,
     2608678.36489467,
@@ -13153,10 +13199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2608925.12010859,
     2608939.97470676,
     2608954.74949213,
+    // 2431
     2608969.48781965,
     2608984.20755319,
     2608998.95521583,
-    // 2430
     2609013.74822027,
     2609028.62877983,
     2609043.60618156,
@@ -13178,10 +13224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2609290.35962267,
     2609305.20930041,
     2609319.98994503,
+    // 2432
     2609334.72385105,
     2609349.44973378,
     2609364.19328183,
-    // 2431
     2609378.99237620,
     2609393.86848203,
     2609408.85163397,
@@ -13203,10 +13249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2609655.60198259,
     2609670.45216321,
     2609685.23166931,
+    // 2433
     2609699.96543333,
     2609714.68989069,
     2609729.43280626,
-    // 2432
     2609744.23050510,
     2609759.10606605,
     2609774.08773474,
@@ -13228,10 +13274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2610020.84474388,
     2610035.69874959,
     2610050.47329017,
+    // 2434
     2610065.21067697,
     2610079.92994927,
     2610094.67672412,
-    // 2433
     2610109.46929432,
     2610124.34905440,
     2610139.32612156,
@@ -13253,10 +13299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2610386.08810219,
     2610400.93517806,
     2610415.71594766,
+    // 2435
     2610430.44655332,
     2610445.17220103,
     2610459.91244108,
-    // 2434
     2610474.71167924,
     2610489.58520907,
     2610504.56930414,
@@ -13278,10 +13324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2610751.32919388,
     2610766.18171511,
     2610780.95854334,
+    // 2436
     2610795.69424583,
     2610810.41572852,
     2610825.16036367,
-    // 2435
     2610839.95493532,
     2610854.83207494,
     2610869.81057301,
@@ -13303,10 +13349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2611116.56952963,
     2611131.42409016,
     2611146.20203721,
+    // 2437
     2611160.93919425,
     2611175.66077258,
     2611190.40609683,
-    // 2436
     2611205.19966855,
     2611220.07676522,
     2611235.05395829,
@@ -13328,10 +13374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2611481.81239462,
     2611496.66120470,
     2611511.44386365,
+    // 2438
     2611526.17569660,
     2611540.90247562,
     2611555.64271690,
-    // 2437
     2611570.44185494,
     2611585.31401392,
     2611600.29670797,
@@ -13353,10 +13399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2611847.05192843,
     2611861.90677106,
     2611876.68256186,
+    // 2439
     2611891.42091194,
     2611906.14148342,
     2611920.88883894,
-    // 2438
     2611935.68230664,
     2611950.56196753,
     2611965.53904985,
@@ -13378,10 +13424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2612212.29302029,
     2612227.14470251,
     2612241.92468004,
+    // 2440
     2612256.65987819,
     2612271.38453423,
     2612286.12901007,
-    // 2439
     2612300.92660710,
     2612315.80342471,
     2612330.78486458,
@@ -13403,10 +13449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2612577.53854844,
     2612592.38919868,
     2612607.17079178,
+    // 2441
     2612621.90392022,
     2612636.62936884,
     2612651.37074202,
-    // 2440
     2612666.16874304,
     2612681.04232372,
     2612696.02412571,
@@ -13428,10 +13474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2612942.78130642,
     2612957.63758169,
     2612972.41340804,
+    // 2442
     2612987.15221922,
     2613001.87170777,
     2613016.61871011,
-    // 2441
     2613031.41042736,
     2613046.28951778,
     2613061.26509323,
@@ -13453,10 +13499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2613308.02468819,
     2613322.87420930,
     2613337.65610271,
+    // 2443
     2613352.38896069,
     2613367.11516704,
     2613381.85683647,
-    // 2442
     2613396.65559381,
     2613411.52943945,
     2613426.51208759,
@@ -13478,10 +13524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2613673.26859717,
     2613688.12082716,
     2613702.89995768,
+    // 2444
     2613717.63565919,
     2613732.35949887,
     2613747.10390787,
-    // 2443
     2613761.90033612,
     2613776.77648964,
     2613791.75596420,
@@ -13503,10 +13549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2614038.51093109,
     2614053.36624194,
     2614068.14313589,
+    // 2445
     2614082.88135007,
     2614097.60227803,
     2614112.34897448,
-    // 2444
     2614127.14215038,
     2614142.02064405,
     2614156.99720659,
@@ -13528,10 +13574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2614403.75920647,
     2614418.60738112,
     2614433.38942413,
+    // 2446
     2614448.12031374,
     2614462.84638689,
     2614477.58593152,
-    // 2445
     2614492.38469773,
     2614507.25660179,
     2614522.23937176,
@@ -13553,10 +13599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2614769.00232637,
     2614783.85644592,
     2614798.63248577,
+    // 2447
     2614813.36900987,
     2614828.08896286,
     2614842.83388077,
-    // 2446
     2614857.62657358,
     2614872.50394785,
     2614887.48071680,
@@ -13578,10 +13624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2615134.24285828,
     2615149.09672197,
     2615163.87593492,
+    // 2448
     2615178.61210595,
     2615193.33466868,
     2615208.07885257,
-    // 2447
     2615222.87330133,
     2615237.74915234,
     2615252.72720460,
@@ -13603,10 +13649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2615499.48812709,
     2615514.33925533,
     2615529.12266439,
+    // 2449
     2615543.85551392,
     2615558.58168776,
     2615573.32155202,
-    // 2448
     2615588.11893390,
     2615602.98973212,
     2615617.96996419,
@@ -13628,10 +13674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2615864.72803761,
     2615879.58534908,
     2615894.36226988,
+    // 2450
     2615909.10182601,
     2615923.82192871,
     2615938.56890794,
-    // 2449
     2615953.36031121,
     2615968.23824978,
     2615983.21227756,
@@ -13653,10 +13699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2616229.96932865,
     2616244.82063006,
     2616259.60222821,
+    // 2451
     2616274.33656390,
     2616289.06215621,
     2616303.80496755,
-    // 2450
     2616318.60264507,
     2616333.47699027,
     2616348.45781545,
@@ -13678,10 +13724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2616595.21180847,
     2616610.06393404,
     2616624.84495966,
+    // 2452
     2616639.57978036,
     2616654.30485482,
     2616669.04786807,
-    // 2451
     2616683.84511831,
     2616698.71952689,
     2616713.69951262,
@@ -13703,10 +13749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2616960.45020784,
     2616975.30792539,
     2616990.08542790,
+    // 2453
     2617004.82502286,
     2617019.54548669,
     2617034.29252470,
-    // 2452
     2617049.08442919,
     2617063.96265129,
     2617078.93752471,
@@ -13728,10 +13774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2617325.69350007,
     2617340.54387978,
     2617355.32734873,
+    // 2454
     2617370.05981657,
     2617384.78633393,
     2617399.52638519,
-    // 2453
     2617414.32443285,
     2617429.19585089,
     2617444.17718341,
@@ -13753,10 +13799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2617690.93264497,
     2617705.78723121,
     2617720.56597668,
+    // 2455
     2617735.30303080,
     2617750.02536232,
     2617764.77012307,
-    // 2454
     2617779.56428568,
     2617794.44041898,
     2617809.41762296,
@@ -13778,10 +13824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2618056.16972103,
     2618071.02527823,
     2618085.80415804,
+    // 2456
     2618100.54249080,
     2618115.26500605,
     2618130.01133393,
-    // 2455
     2618144.80544188,
     2618159.68284832,
     2618174.65973624,
@@ -13803,10 +13849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2618421.41642694,
     2618436.26642561,
     2618451.04984987,
+    // 2457
     2618465.78196522,
     2618480.50860143,
     2618495.24829528,
-    // 2456
     2618510.04654854,
     2618524.91741993,
     2618539.89859951,
@@ -13828,10 +13874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2618786.65969642,
     2618801.51570300,
     2618816.29196370,
+    // 2458
     2618831.02981174,
     2618845.74921911,
     2618860.49469239,
-    // 2457
     2618875.28591112,
     2618890.16294354,
     2618905.13738577,
@@ -13853,10 +13899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2619151.90379361,
     2619166.75616195,
     2619181.53590377,
+    // 2459
     2619196.27014122,
     2619210.99288628,
     2619225.73497567,
-    // 2458
     2619240.52955659,
     2619255.40334033,
     2619270.38162774,
@@ -13878,10 +13924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2619517.14943258,
     2619532.00125799,
     2619546.78359609,
+    // 2460
     2619561.51693736,
     2619576.24200533,
     2619590.98227773,
-    // 2459
     2619605.77843955,
     2619620.64938637,
     2619635.62807733,
@@ -13903,10 +13949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2619882.38974092,
     2619897.24897370,
     2619912.02707452,
+    // 2461
     2619926.76734828,
     2619941.48733896,
     2619956.23378116,
-    // 2460
     2619971.02390790,
     2619985.90029541,
     2620000.87235373,
@@ -13928,10 +13974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2620247.63467460,
     2620262.48680839,
     2620277.27050482,
+    // 2462
     2620292.00425234,
     2620306.73035345,
     2620321.47094885,
-    // 2461
     2620336.26763940,
     2620351.13856762,
     2620366.11751836,
@@ -13953,10 +13999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2620612.87784039,
     2620627.73228526,
     2620642.51297872,
+    // 2463
     2620657.24933082,
     2620671.97309679,
     2620686.71668070,
-    // 2462
     2620701.51176878,
     2620716.38612084,
     2620731.36358178,
@@ -13978,10 +14024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2620978.11697633,
     2620992.97496146,
     2621007.75381181,
+    // 2464
     2621022.49348659,
     2621037.21505700,
     2621051.96194491,
-    // 2463
     2621066.75456198,
     2621081.63208629,
     2621096.60709568,
@@ -14003,10 +14049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2621343.36477699,
     2621358.21679327,
     2621373.00191187,
+    // 2465
     2621387.73488151,
     2621402.46191066,
     2621417.20133613,
-    // 2464
     2621431.99895770,
     2621446.86882718,
     2621461.84894420,
@@ -14028,10 +14074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2621708.60706608,
     2621723.46466198,
     2621738.24383890,
+    // 2466
     2621752.98255399,
     2621767.70383076,
     2621782.44886451,
-    // 2465
     2621797.24066648,
     2621812.11604105,
     2621827.09010411,
@@ -14053,10 +14099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2622073.84693028,
     2622088.70246972,
     2622103.48309693,
+    // 2467
     2622118.22053462,
     2622132.94378063,
     2622147.68830571,
-    // 2466
     2622162.48230520,
     2622177.35728536,
     2622192.33374660,
@@ -14078,10 +14124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2622439.09183941,
     2622453.94366738,
     2622468.72798369,
+    // 2468
     2622483.46190270,
     2622498.18922278,
     2622512.93008299,
-    // 2467
     2622527.72810491,
     2622542.59893375,
     2622557.57858980,
@@ -14103,10 +14149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2622804.33127822,
     2622819.18993645,
     2622833.96788343,
+    // 2469
     2622848.70801811,
     2622863.42839574,
     2622878.17520641,
-    // 2468
     2622892.96615336,
     2622907.84314538,
     2622922.81594419,
@@ -14128,10 +14174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2623169.57655280,
     2623184.42898932,
     2623199.21083195,
+    // 2470
     2623213.94456881,
     2623228.66874232,
     2623243.40958400,
-    // 2469
     2623258.20477470,
     2623273.07650201,
     2623288.05456441,
@@ -14153,10 +14199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2623534.82030154,
     2623549.67351408,
     2623564.45487695,
+    // 2471
     2623579.18901134,
     2623593.91256373,
     2623608.65331514,
-    // 2470
     2623623.44788959,
     2623638.31946813,
     2623653.29684236,
@@ -14178,10 +14224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2623900.05587225,
     2623914.91511545,
     2623929.69353688,
+    // 2472
     2623944.43356581,
     2623959.15366270,
     2623973.89976745,
-    // 2471
     2623988.68994303,
     2624003.56594098,
     2624018.53812241,
@@ -14203,10 +14249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2624265.30093568,
     2624280.15402861,
     2624294.93945411,
+    // 2473
     2624309.67300432,
     2624324.39957807,
     2624339.13869738,
-    // 2472
     2624353.93474998,
     2624368.80323519,
     2624383.78085929,
@@ -14228,10 +14274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2624630.54195816,
     2624645.39901296,
     2624660.17965785,
+    // 2474
     2624674.91756748,
     2624689.63995698,
     2624704.38368203,
-    // 2473
     2624719.17596661,
     2624734.04922478,
     2624749.02290081,
@@ -14253,10 +14299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2624995.78019280,
     2625010.63738384,
     2625025.41707001,
+    // 2475
     2625040.15561098,
     2625054.87753781,
     2625069.62284886,
-    // 2474
     2625084.41523596,
     2625099.29066620,
     2625114.26504973,
@@ -14278,10 +14324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2625361.02672039,
     2625375.87918261,
     2625390.66449943,
+    // 2476
     2625405.39789950,
     2625420.12516099,
     2625434.86477356,
-    // 2475
     2625449.66217468,
     2625464.53144086,
     2625479.51036846,
@@ -14303,10 +14349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2625726.26504635,
     2625741.12510429,
     2625755.90482750,
+    // 2477
     2625770.64504120,
     2625785.36583495,
     2625800.11142755,
-    // 2476
     2625814.90177495,
     2625829.77683964,
     2625844.74862228,
@@ -14328,10 +14374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2626091.50789398,
     2626106.36388713,
     2626121.14666628,
+    // 2478
     2626135.88317032,
     2626150.60711808,
     2626165.34939620,
-    // 2477
     2626180.14295059,
     2626195.01483519,
     2626209.99028529,
@@ -14353,10 +14399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2626456.75191963,
     2626471.60598708,
     2626486.39039736,
+    // 2479
     2626501.12533523,
     2626515.85149199,
     2626530.59227040,
-    // 2478
     2626545.38835039,
     2626560.25869600,
     2626575.23634158,
@@ -14378,10 +14424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2626821.98829850,
     2626836.84868405,
     2626851.62796108,
+    // 2480
     2626866.36953879,
     2626881.09074284,
     2626895.83835160,
-    // 2479
     2626910.62933789,
     2626925.50623131,
     2626940.47832943,
@@ -14403,10 +14449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2627187.23792965,
     2627202.09159220,
     2627216.87627860,
+    // 2481
     2627231.61053516,
     2627246.33662193,
     2627261.07681638,
-    // 2480
     2627275.87265502,
     2627290.74242084,
     2627305.71987438,
@@ -14428,10 +14474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2627552.48510324,
     2627567.34088680,
     2627582.12230975,
+    // 2482
     2627596.85834906,
     2627611.58114803,
     2627626.32293414,
-    // 2481
     2627641.11580558,
     2627655.98749690,
     2627670.96225990,
@@ -14453,10 +14499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2627917.72638082,
     2627932.58514013,
     2627947.36402296,
+    // 2483
     2627962.10308982,
     2627976.82323071,
     2627991.56820110,
-    // 2482
     2628006.35825754,
     2628021.23313029,
     2628036.20527921,
@@ -14478,10 +14524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2628282.97478651,
     2628297.82820757,
     2628312.61438267,
+    // 2484
     2628327.34800080,
     2628342.07507980,
     2628356.81385216,
-    // 2483
     2628371.60993806,
     2628386.47748132,
     2628401.45457205,
@@ -14503,10 +14549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2628648.21469202,
     2628663.07498398,
     2628677.85646506,
+    // 2485
     2628692.59657635,
     2628707.31857156,
     2628722.06322618,
-    // 2484
     2628736.85379891,
     2628751.72683351,
     2628766.69779101,
@@ -14528,10 +14574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2629013.45673026,
     2629028.31451956,
     2629043.09663136,
+    // 2486
     2629057.83481381,
     2629072.55790205,
     2629087.30153704,
-    // 2485
     2629102.09370601,
     2629116.96625734,
     2629131.93948503,
@@ -14553,10 +14599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2629378.70190816,
     2629393.55559384,
     2629408.34096777,
+    // 2487
     2629423.07513729,
     2629437.80200044,
     2629452.54187857,
-    // 2486
     2629467.33850155,
     2629482.20772223,
     2629497.18560946,
@@ -14578,10 +14624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2629743.93699907,
     2629758.79815559,
     2629773.57797481,
+    // 2488
     2629788.31933417,
     2629803.04027298,
     2629817.78703015,
-    // 2487
     2629832.57737785,
     2629847.45324391,
     2629862.42457152,
@@ -14603,10 +14649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2630109.18214243,
     2630124.03826897,
     2630138.82298839,
+    // 2489
     2630153.55863462,
     2630168.28358137,
     2630183.02411584,
-    // 2488
     2630197.81791957,
     2630212.68744984,
     2630227.66261487,
@@ -14628,10 +14674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2630474.42469137,
     2630489.28097135,
     2630504.06509814,
+    // 2490
     2630518.80107160,
     2630533.52567766,
     2630548.26633272,
-    // 2489
     2630563.05983895,
     2630577.92933789,
     2630592.90391522,
@@ -14653,10 +14699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2630839.65995164,
     2630854.52047010,
     2630869.29993881,
+    // 2491
     2630884.04080845,
     2630898.76130995,
     2630913.50748213,
-    // 2490
     2630928.29708969,
     2630943.17214619,
     2630958.14276764,
@@ -14678,10 +14724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2631204.90498126,
     2631219.75878772,
     2631234.54492071,
+    // 2492
     2631249.27926112,
     2631264.00651364,
     2631278.74614168,
-    // 2491
     2631293.54222606,
     2631308.41028707,
     2631323.38684400,
@@ -14703,10 +14749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2631570.14597380,
     2631585.00470291,
     2631599.78664783,
+    // 2493
     2631614.52504911,
     2631629.24756210,
     2631643.99067999,
-    // 2492
     2631658.78203320,
     2631673.65375357,
     2631688.62562939,
@@ -14728,10 +14774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2631935.38765857,
     2631950.24645242,
     2631965.02673583,
+    // 2494
     2631979.76493788,
     2631994.48551146,
     2632009.22877112,
-    // 2493
     2632024.01836864,
     2632038.89079294,
     2632053.86186393,
@@ -14753,10 +14799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2632300.63400426,
     2632315.48807191,
     2632330.27412888,
+    // 2495
     2632345.00738640,
     2632359.73358748,
     2632374.47143570,
-    // 2494
     2632389.26651238,
     2632404.13324188,
     2632419.10958603,
@@ -14778,10 +14824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2632665.86968590,
     2632680.73182511,
     2632695.51322545,
+    // 2496
     2632710.25449279,
     2632724.97572791,
     2632739.72092305,
-    // 2495
     2632754.51016569,
     2632769.38328459,
     2632784.35261749,
@@ -14803,10 +14849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2633031.11455514,
     2633045.97345891,
     2633060.75842944,
+    // 2497
     2633075.49631710,
     2633090.22069093,
     2633104.96242880,
-    // 2496
     2633119.75437940,
     2633134.62377604,
     2633149.59581390,
@@ -14828,10 +14874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2633396.36085068,
     2633411.21737610,
     2633426.00372879,
+    // 2498
     2633440.73969656,
     2633455.46615832,
     2633470.20629348,
-    // 2497
     2633485.00089207,
     2633499.86892030,
     2633514.84350937,
@@ -14853,10 +14899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2633761.59895460,
     2633776.46093522,
     2633791.24108443,
+    // 2499
     2633805.98291557,
     2633820.70373310,
     2633835.45050004,
-    // 2498
     2633850.24010132,
     2633865.11532874,
     2633880.08535850,
@@ -14878,10 +14924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2634126.84772878,
     2634141.70395545,
     2634156.49066308,
+    // 2500
     2634171.22643965,
     2634185.95333155,
     2634200.69365283,
-    // 2499
     2634215.48876579,
     2634230.35714726,
     2634245.33249324,
@@ -14903,10 +14949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2634492.09106106,
     2634506.95069018,
     2634521.73555857,
+    // 2501
     2634536.47398248,
     2634551.19833965,
     2634565.94038731,
-    // 2500
     2634580.73259657,
     2634595.60248910,
     2634610.57475206,
@@ -14928,10 +14974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2634857.33176246,
     2634872.19346136,
     2634886.97477488,
+    // 2502
     2634901.71564796,
     2634916.43670870,
     2634931.18172554,
-    // 2501
     2634945.97077607,
     2634960.84388407,
     2634975.81340542,
@@ -14953,10 +14999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2635222.57875193,
     2635237.43376640,
     2635252.22120570,
+    // 2503
     2635266.95583871,
     2635281.68351991,
     2635296.42259306,
-    // 2502
     2635311.21857760,
     2635326.08574748,
     2635341.06206126,
@@ -14978,10 +15024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2635587.81563932,
     2635602.67671598,
     2635617.45900750,
+    // 2504
     2635632.19975656,
     2635646.92246349,
     2635661.66756585,
-    // 2503
     2635676.45854589,
     2635691.33157222,
     2635706.30231197,
@@ -15003,10 +15049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2635953.06143190,
     2635967.92063129,
     2635982.70340397,
+    // 2505
     2635997.44158753,
     2636012.16403224,
     2636026.90655131,
-    // 2504
     2636041.69713160,
     2636056.56789887,
     2636071.53902744,
@@ -15028,10 +15074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2636318.30984549,
     2636333.16499965,
     2636347.95102303,
+    // 2506
     2636362.68476288,
     2636377.41031395,
     2636392.14801839,
-    // 2505
     2636406.94183087,
     2636421.80787170,
     2636436.78239563,
@@ -15053,10 +15099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2636683.54586030,
     2636698.40793200,
     2636713.18791710,
+    // 2507
     2636727.92867097,
     2636742.64819806,
     2636757.39285230,
-    // 2506
     2636772.18048133,
     2636787.05330260,
     2636802.02144045,
@@ -15078,10 +15124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2637048.79124501,
     2637063.64937087,
     2637078.43558664,
+    // 2508
     2637093.17225935,
     2637107.89742226,
     2637122.63731041,
-    // 2507
     2637137.42937385,
     2637152.29625815,
     2637167.26786044,
@@ -15103,10 +15149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2637414.03122512,
     2637428.89063280,
     2637443.67742217,
+    // 2509
     2637458.41503435,
     2637473.14050455,
     2637487.88076208,
-    // 2508
     2637502.67292070,
     2637517.53983290,
     2637532.51093155,
@@ -15128,10 +15174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2637779.26767463,
     2637794.13087014,
     2637808.91223316,
+    // 2510
     2637823.65419693,
     2637838.37490342,
     2637853.12041629,
-    // 2509
     2637867.90843981,
     2637882.78118578,
     2637897.74866167,
@@ -15153,10 +15199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2638144.51313030,
     2638159.36947718,
     2638174.15717403,
+    // 2511
     2638188.89233424,
     2638203.61958644,
     2638218.35869245,
-    // 2510
     2638233.15373578,
     2638248.02050698,
     2638262.99539138,
@@ -15178,10 +15224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2638509.74857416,
     2638524.61036464,
     2638539.39495367,
+    // 2512
     2638554.13520150,
     2638568.85902765,
     2638583.60257291,
-    // 2511
     2638598.39385809,
     2638613.26472934,
     2638628.23536464,
@@ -15203,10 +15249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2638874.99052099,
     2638889.85325352,
     2638904.63685763,
+    // 2513
     2638919.37755806,
     2638934.09959369,
     2638948.84324892,
-    // 2512
     2638963.63211681,
     2638978.50293957,
     2638993.47152270,
@@ -15228,10 +15274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2639240.23669675,
     2639255.09371749,
     2639269.88243352,
+    // 2514
     2639284.61777979,
     2639299.34531828,
     2639314.08362305,
-    // 2513
     2639328.87814658,
     2639343.74345711,
     2639358.71751277,
@@ -15253,10 +15299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2639605.47241951,
     2639620.33538059,
     2639635.11759087,
+    // 2515
     2639649.85960180,
     2639664.58147209,
     2639679.32706428,
-    // 2514
     2639694.11641802,
     2639708.98925219,
     2639723.95792994,
@@ -15278,10 +15324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2639970.71916937,
     2639985.57863935,
     2640000.36411777,
+    // 2516
     2640015.10266499,
     2640029.82759121,
     2640044.56975420,
-    // 2515
     2640059.36176286,
     2640074.23087559,
     2640089.20208639,
@@ -15303,10 +15349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2640335.96652813,
     2640350.82440420,
     2640365.61165086,
+    // 2517
     2640380.34770959,
     2640395.07387404,
     2640409.81303616,
-    // 2516
     2640424.60640888,
     2640439.47277017,
     2640454.44551624,
@@ -15328,10 +15374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2640701.20865020,
     2640716.07183344,
     2640730.85224715,
+    // 2518
     2640745.59338992,
     2640760.31262827,
     2640775.05705578,
-    // 2517
     2640789.84375587,
     2640804.71583376,
     2640819.68258490,
@@ -15353,10 +15399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2641066.45821836,
     2641081.31573296,
     2641096.10271632,
+    // 2519
     2641110.83799875,
     2641125.56347131,
     2641140.30180062,
-    // 2518
     2641155.09437679,
     2641169.96010741,
     2641184.93265395,
@@ -15378,10 +15424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2641431.69754246,
     2641446.55908088,
     2641461.34549704,
+    // 2520
     2641476.08471912,
     2641490.80936511,
     2641505.55075115,
-    // 2519
     2641520.34171713,
     2641535.20944214,
     2641550.17908772,
@@ -15403,10 +15449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2641796.94002206,
     2641811.80447839,
     2641826.58779549,
+    // 2521
     2641841.32982090,
     2641856.05106431,
     2641870.79527635,
-    // 2520
     2641885.58249015,
     2641900.45289853,
     2641915.41882243,
@@ -15428,10 +15474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2642162.18816500,
     2642177.04562988,
     2642191.83468646,
+    // 2522
     2642206.57015800,
     2642221.29773738,
     2642236.03589738,
-    // 2521
     2642250.83004215,
     2642265.69469108,
     2642280.66774804,
@@ -15453,10 +15499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2642527.42568974,
     2642542.28854663,
     2642557.07184484,
+    // 2523
     2642571.81281295,
     2642586.53510053,
     2642601.27911401,
-    // 2522
     2642616.06858074,
     2642630.93961118,
     2642645.90814928,
@@ -15478,10 +15524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2642892.66915407,
     2642907.53136797,
     2642922.31654003,
+    // 2524
     2642937.05650138,
     2642951.77982685,
     2642966.52229711,
-    // 2523
     2642981.31186224,
     2642996.18079025,
     2643011.14931369,
@@ -15503,10 +15549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2643257.91304597,
     2643272.77237863,
     2643287.56215548,
+    // 2525
     2643302.29864909,
     2643317.02602047,
     2643331.76420457,
-    // 2524
     2643346.55738704,
     2643361.42159642,
     2643376.39333508,
@@ -15528,10 +15574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2643623.14861757,
     2643638.01359896,
     2643652.79610108,
+    // 2526
     2643667.53875409,
     2643682.25941571,
     2643697.00426460,
-    // 2525
     2643711.79112364,
     2643726.66225438,
     2643741.62784405,
@@ -15553,10 +15599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2643988.39212978,
     2644003.25177614,
     2644018.03911978,
+    // 2527
     2644032.77684965,
     2644047.50271825,
     2644062.24320261,
-    // 2526
     2644077.03552396,
     2644091.90247934,
     2644106.87364062,
@@ -15578,10 +15624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2644353.63010956,
     2644368.49055100,
     2644383.27840301,
+    // 2528
     2644398.01684144,
     2644412.74321324,
     2644427.48403621,
-    // 2527
     2644442.27675435,
     2644457.14374233,
     2644472.11471933,
@@ -15603,10 +15649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2644718.87103012,
     2644733.73579320,
     2644748.51796994,
+    // 2529
     2644763.26001945,
     2644777.98012651,
     2644792.72446283,
-    // 2528
     2644807.51085815,
     2644822.38169817,
     2644837.34702058,
@@ -15628,10 +15674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2645084.11955002,
     2645098.97759481,
     2645113.76602100,
+    // 2530
     2645128.50110810,
     2645143.22723763,
     2645157.96449870,
-    // 2529
     2645172.75691578,
     2645187.62073296,
     2645202.59237643,
@@ -15653,10 +15699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2645449.35658028,
     2645464.21933659,
     2645479.00429284,
+    // 2531
     2645493.74416398,
     2645508.46699676,
     2645523.20875992,
-    // 2530
     2645537.99782901,
     2645552.86592968,
     2645567.83375442,
@@ -15678,10 +15724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2645814.59884340,
     2645829.46355732,
     2645844.24875190,
+    // 2532
     2645858.99059463,
     2645873.71305396,
     2645888.45626244,
-    // 2531
     2645903.24372129,
     2645918.11221369,
     2645933.07759565,
@@ -15703,10 +15749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2646179.84431537,
     2646194.70425091,
     2646209.49536604,
+    // 2533
     2646224.23227463,
     2646238.96058553,
     2646253.69862277,
-    // 2532
     2646268.49193075,
     2646283.35501548,
     2646298.32588051,
@@ -15728,10 +15774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2646545.08296986,
     2646559.94806781,
     2646574.73163542,
+    // 2534
     2646589.47416711,
     2646604.19577369,
     2646618.94029159,
-    // 2533
     2646633.72787439,
     2646648.59828664,
     2646663.56400228,
@@ -15753,10 +15799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2646910.32991537,
     2646925.19168049,
     2646939.97833982,
+    // 2535
     2646954.71736430,
     2646969.44192062,
     2646984.18328900,
-    // 2534
     2646998.97406586,
     2647013.84178123,
     2647028.81128771,
@@ -15778,10 +15824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2647275.57174289,
     2647290.43279195,
     2647305.22273430,
+    // 2536
     2647319.96063206,
     2647334.68795222,
     2647349.42727985,
-    // 2535
     2647364.22021799,
     2647379.08529632,
     2647394.05635072,
@@ -15803,10 +15849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2647640.81315441,
     2647655.68012003,
     2647670.46372237,
+    // 2537
     2647685.20720097,
     2647699.92774145,
     2647714.67230843,
-    // 2536
     2647729.45806468,
     2647744.32818821,
     2647759.29217802,
@@ -15828,10 +15874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2648006.06210226,
     2648020.92247945,
     2648035.71181046,
+    // 2538
     2648050.44905994,
     2648065.17560265,
     2648079.91425967,
-    // 2537
     2648094.70608090,
     2648109.57024899,
     2648124.54032702,
@@ -15853,10 +15899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2648371.30059920,
     2648386.16283899,
     2648400.94992835,
+    // 2539
     2648415.68964788,
     2648430.41475630,
     2648445.15625586,
-    // 2538
     2648459.94721538,
     2648474.81441588,
     2648489.78333390,
@@ -15878,10 +15924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2648736.54460179,
     2648751.41002697,
     2648766.19420246,
+    // 2540
     2648780.93696162,
     2648795.65869150,
     2648810.40302038,
-    // 2539
     2648825.18992723,
     2648840.05955651,
     2648855.02416927,
@@ -15903,10 +15949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2649101.79311889,
     2649116.65248908,
     2649131.44275643,
+    // 2541
     2649146.17865001,
     2649160.90589628,
     2649175.64302738,
-    // 2540
     2649190.43553430,
     2649205.29811120,
     2649220.26865415,
@@ -15928,10 +15974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2649467.03287236,
     2649481.89730226,
     2649496.68135166,
+    // 2542
     2649511.42210860,
     2649526.14328592,
     2649540.88529814,
-    // 2541
     2649555.67214438,
     2649570.54004935,
     2649585.50532182,
@@ -15953,10 +15999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2649832.27725106,
     2649847.14117105,
     2649861.92709130,
+    // 2543
     2649876.66717110,
     2649891.38972798,
     2649906.13087830,
-    // 2542
     2649920.91853540,
     2649935.78522028,
     2649950.75116391,
@@ -15978,10 +16024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2650197.51657143,
     2650212.37816059,
     2650227.16986887,
+    // 2544
     2650241.90767185,
     2650256.63582213,
     2650271.37390864,
-    // 2543
     2650286.16635523,
     2650301.02887614,
     2650315.99831808,
@@ -16003,10 +16049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2650562.75451950,
     2650577.62227297,
     2650592.40693953,
+    // 2545
     2650607.15098699,
     2650621.87222117,
     2650636.61668965,
-    // 2544
     2650651.40230487,
     2650666.27125146,
     2650681.23387209,
@@ -16028,10 +16074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2650928.00014735,
     2650942.86227724,
     2650957.65115301,
+    // 2546
     2650972.38981243,
     2650987.11563101,
     2651001.85542756,
-    // 2545
     2651016.64616421,
     2651031.51098174,
     2651046.47932362,
@@ -16053,10 +16099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2651293.23798804,
     2651308.10021972,
     2651322.88921540,
+    // 2547
     2651337.62804509,
     2651352.35428594,
     2651367.09433490,
-    // 2546
     2651381.88600331,
     2651396.75144824,
     2651411.72081482,
@@ -16078,10 +16124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2651658.47749797,
     2651673.34532491,
     2651688.13019090,
+    // 2548
     2651702.87425945,
     2651717.59555004,
     2651732.34006870,
-    // 2547
     2651747.12572598,
     2651761.99495256,
     2651776.95796058,
@@ -16103,10 +16149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2652023.72274912,
     2652038.58454672,
     2652053.37624675,
+    // 2549
     2652068.11393842,
     2652082.84165200,
     2652097.57949184,
-    // 2548
     2652112.37129667,
     2652127.23355276,
     2652142.20263181,
@@ -16128,10 +16174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2652388.96052130,
     2652403.82538775,
     2652418.61218171,
+    // 2550
     2652433.35339815,
     2652448.07700924,
     2652462.81878681,
-    // 2549
     2652477.60718086,
     2652492.47377569,
     2652507.43954114,
@@ -16153,10 +16199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2652754.20307275,
     2652769.06860296,
     2652783.85417415,
+    // 2551
     2652798.59633041,
     2652813.31886061,
     2652828.06214432,
-    // 2550
     2652842.84957490,
     2652857.71791521,
     2652872.68287167,
@@ -16178,10 +16224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2653119.44622850,
     2653134.30698682,
     2653149.09871227,
+    // 2552
     2653163.83593700,
     2653178.56444459,
     2653193.30239043,
-    // 2551
     2653208.09554208,
     2653222.95814657,
     2653237.92837790,
@@ -16203,10 +16249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2653484.68938704,
     2653499.55597512,
     2653514.34023456,
+    // 2553
     2653529.08252874,
     2653543.80311807,
     2653558.54585362,
-    // 2552
     2653573.33119624,
     2653588.19895374,
     2653603.16191688,
@@ -16228,10 +16274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2653849.93839055,
     2653864.80191921,
     2653879.58922542,
+    // 2554
     2653894.32809193,
     2653909.05126616,
     2653923.79047864,
-    // 2553
     2653938.57816245,
     2653953.44240123,
     2653968.40811041,
@@ -16253,10 +16299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2654215.18085162,
     2654230.04312208,
     2654244.83368193,
+    // 2555
     2654259.57140316,
     2654274.29784875,
     2654289.03544279,
-    // 2554
     2654303.82608027,
     2654318.68816132,
     2654333.65604911,
@@ -16278,10 +16324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2654580.42140666,
     2654595.29072331,
     2654610.07635443,
+    // 2556
     2654624.82120955,
     2654639.54232215,
     2654654.28640576,
-    // 2555
     2654669.07064599,
     2654683.93814620,
     2654698.89861223,
@@ -16303,10 +16349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2654945.66863202,
     2654960.53233921,
     2654975.32430533,
+    // 2557
     2654990.06352807,
     2655004.79099933,
     2655019.52971002,
-    // 2556
     2655034.32038833,
     2655049.18247784,
     2655064.14932155,
@@ -16328,10 +16374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2655310.90982262,
     2655325.77450973,
     2655340.56323920,
+    // 2558
     2655355.30373596,
     2655370.02881090,
     2655384.76942225,
-    // 2557
     2655399.55882307,
     2655414.42367981,
     2655429.38980297,
@@ -16353,10 +16399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2655676.15242542,
     2655691.02037132,
     2655705.80609897,
+    // 2559
     2655720.54967437,
     2655735.27149505,
     2655750.01543567,
-    // 2558
     2655764.80159917,
     2655779.67014340,
     2655794.63337888,
@@ -16378,10 +16424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2656041.39504703,
     2656056.25796147,
     2656071.05136056,
+    // 2560
     2656085.78969712,
     2656100.51862660,
     2656115.25652890,
-    // 2559
     2656130.04902476,
     2656144.91074915,
     2656159.87979164,
@@ -16403,10 +16449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2656406.63475098,
     2656421.50272906,
     2656436.28996363,
+    // 2561
     2656451.03312112,
     2656465.75582521,
     2656480.49825911,
-    // 2560
     2656495.28452211,
     2656510.15078922,
     2656525.11365909,
@@ -16428,10 +16474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2656771.87861031,
     2656786.74499516,
     2656801.53293492,
+    // 2562
     2656816.27484276,
     2656830.99845268,
     2656845.74013245,
-    // 2561
     2656860.52736913,
     2656875.39295922,
     2656890.35703241,
@@ -16453,10 +16499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2657137.11822656,
     2657151.98022400,
     2657166.77231997,
+    // 2563
     2657181.51041357,
     2657196.23884591,
     2657210.97705484,
-    // 2562
     2657225.76956954,
     2657240.63182745,
     2657255.60088785,
@@ -16478,10 +16524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2657502.35819602,
     2657517.22676424,
     2657532.01214912,
+    // 2564
     2657546.75659104,
     2657561.47794648,
     2657576.22211280,
-    // 2563
     2657591.00712955,
     2657605.87504886,
     2657620.83638487,
@@ -16503,10 +16549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2657867.60531156,
     2657882.46935213,
     2657897.25925050,
+    // 2565
     2657911.99825792,
     2657926.72342213,
     2657941.46200931,
-    // 2564
     2657956.25075233,
     2657971.11329449,
     2657986.07890480,
@@ -16528,10 +16574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2658232.84664807,
     2658247.71022555,
     2658262.49971709,
+    // 2566
     2658277.23806431,
     2658291.96289904,
     2658306.70062379,
-    // 2565
     2658321.48936631,
     2658336.35131731,
     2658351.31718316,
@@ -16553,10 +16599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2658598.08665853,
     2658612.95594582,
     2658627.74148754,
+    // 2567
     2658642.48548965,
     2658657.20602316,
     2658671.94915393,
-    // 2566
     2658686.73295182,
     2658701.59981307,
     2658716.56015376,
@@ -16578,10 +16624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2658963.32760011,
     2658978.19182707,
     2658992.98553700,
+    // 2568
     2659007.72466274,
     2659022.45309370,
     2659037.19086046,
-    // 2567
     2659051.98172646,
     2659066.84219650,
     2659081.80865839,
@@ -16603,10 +16649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2659328.56761417,
     2659343.43516814,
     2659358.22406395,
+    // 2569
     2659372.96651978,
     2659387.69053397,
     2659402.43174608,
-    // 2568
     2659417.21870382,
     2659432.08292490,
     2659447.04560306,
@@ -16628,10 +16674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2659693.81229001,
     2659708.68043706,
     2659723.46758990,
+    // 2570
     2659738.21062591,
     2659752.93296673,
     2659767.67537415,
-    // 2569
     2659782.46100131,
     2659797.32694974,
     2659812.28895565,
@@ -16653,10 +16699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2660059.05394564,
     2660073.91686788,
     2660088.71004707,
+    // 2571
     2660103.44795117,
     2660118.17643504,
     2660132.91369091,
-    // 2570
     2660147.70567724,
     2660162.56660867,
     2660177.53497252,
@@ -16678,10 +16724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2660424.29501100,
     2660439.16517568,
     2660453.95276532,
+    // 2572
     2660468.69758541,
     2660483.41979048,
     2660498.16295916,
-    // 2571
     2660512.94768981,
     2660527.81372517,
     2660542.77420167,
@@ -16703,10 +16749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2660789.54084743,
     2660804.40837578,
     2660819.19915892,
+    // 2573
     2660833.94100283,
     2660848.66603945,
     2660863.40620212,
-    // 2572
     2660878.19346897,
     2660893.05629098,
     2660908.01938839,
@@ -16728,10 +16774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2661154.78318082,
     2661169.64747111,
     2661184.43987906,
+    // 2574
     2661199.17908370,
     2661213.90657599,
     2661228.64459372,
-    // 2573
     2661243.43504323,
     2661258.29613543,
     2661273.26248683,
@@ -16753,10 +16799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2661520.02505046,
     2661534.89508407,
     2661549.68125773,
+    // 2575
     2661564.42648604,
     2661579.14801684,
     2661593.89249266,
-    // 2574
     2661608.67720391,
     2661623.54495746,
     2661638.50545042,
@@ -16778,10 +16824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2661885.26997287,
     2661900.13472464,
     2661914.92741715,
+    // 2576
     2661929.66712020,
     2661944.39475970,
     2661959.13351140,
-    // 2575
     2661973.92396167,
     2661988.78573277,
     2662003.75195030,
@@ -16803,10 +16849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2662250.51547114,
     2662265.38171886,
     2662280.17129406,
+    // 2577
     2662294.91171934,
     2662309.63593110,
     2662324.37493680,
-    // 2576
     2662339.16223684,
     2662354.02458493,
     2662368.98814280,
@@ -16828,10 +16874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2662615.76052769,
     2662630.63016354,
     2662645.41659666,
+    // 2578
     2662660.16007793,
     2662674.88067329,
     2662689.62259755,
-    // 2577
     2662704.40586857,
     2662719.27106216,
     2662734.23076797,
@@ -16853,10 +16899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2662981.00272006,
     2662995.86676815,
     2663010.66073829,
+    // 2579
     2663025.39903529,
     2663040.12726405,
     2663054.86381012,
-    // 2578
     2663069.65436611,
     2663084.51359309,
     2663099.47988337,
@@ -16878,10 +16924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2663346.24254439,
     2663361.11278172,
     2663375.90208924,
+    // 2580
     2663390.64660213,
     2663405.36994288,
     2663420.11197294,
-    // 2579
     2663434.89690482,
     2663449.76076054,
     2663464.72043969,
@@ -16903,10 +16949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2663711.48612270,
     2663726.35542978,
     2663741.14551048,
+    // 2581
     2663755.88895833,
     2663770.61305779,
     2663785.35449162,
-    // 2580
     2663800.14034390,
     2663815.00379013,
     2663829.96470690,
@@ -16928,10 +16974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2664076.72842607,
     2664091.59252140,
     2664106.38586982,
+    // 2582
     2664121.12432247,
     2664135.85230859,
     2664150.58930929,
-    // 2581
     2664165.37998455,
     2664180.23980323,
     2664195.20608820,
@@ -16953,10 +16999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2664441.96746141,
     2664456.83836005,
     2664471.62530783,
+    // 2583
     2664486.37036457,
     2664501.09172671,
     2664515.83526201,
-    // 2582
     2664530.61931820,
     2664545.48580968,
     2664560.44547042,
@@ -16978,10 +17024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2664807.20745253,
     2664822.07505532,
     2664836.86805181,
+    // 2584
     2664851.60954683,
     2664866.33625408,
     2664881.07557127,
-    // 2583
     2664895.86398359,
     2664910.72547358,
     2664925.68915928,
@@ -17003,10 +17049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2665172.44884441,
     2665187.31573021,
     2665202.10817606,
+    // 2585
     2665216.84876741,
     2665231.57497167,
     2665246.31303974,
-    // 2584
     2665261.10107248,
     2665275.96125785,
     2665290.92457264,
@@ -17028,10 +17074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2665537.68838830,
     2665552.55972069,
     2665567.34693916,
+    // 2586
     2665582.09231175,
     2665596.81360096,
     2665611.55691593,
-    // 2585
     2665626.34013549,
     2665641.20571065,
     2665656.16422523,
@@ -17053,10 +17099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2665902.92976816,
     2665917.79444247,
     2665932.58840142,
+    // 2587
     2665947.32767026,
     2665962.05610492,
     2665976.79378809,
-    // 2586
     2665991.58440855,
     2666006.44443206,
     2666021.41023716,
@@ -17078,10 +17124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2666268.17173049,
     2666283.04044887,
     2666297.83031291,
+    // 2588
     2666312.57309480,
     2666327.29701138,
     2666342.03748550,
-    // 2587
     2666356.82330950,
     2666371.68589004,
     2666386.64667844,
@@ -17103,10 +17149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2666633.41707979,
     2666648.28755622,
     2666663.07611493,
+    // 2589
     2666677.81978936,
     2666692.54165268,
     2666707.28289574,
-    // 2588
     2666722.06641146,
     2666736.92984303,
     2666751.88878737,
@@ -17128,10 +17174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2666998.66253676,
     2667013.52717870,
     2667028.32123907,
+    // 2590
     2667043.05905945,
     2667057.78648163,
     2667072.52176154,
-    // 2589
     2667087.31097553,
     2667102.16854792,
     2667117.13329670,
@@ -17153,10 +17199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2667363.90347773,
     2667378.77524707,
     2667393.56391622,
+    // 2591
     2667408.30899548,
     2667423.03097952,
     2667437.77325074,
-    // 2590
     2667452.55664592,
     2667467.42068449,
     2667482.37876101,
@@ -17178,10 +17224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2667729.14508663,
     2667744.01540418,
     2667758.80854756,
+    // 2592
     2667773.55230436,
     2667788.27846531,
     2667803.01911238,
-    // 2591
     2667817.80584580,
     2667832.66740412,
     2667847.62820028,
@@ -17203,10 +17249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2668094.39044575,
     2668109.25748852,
     2668124.05200144,
+    // 2593
     2668138.79253006,
     2668153.52049609,
     2668168.25813373,
-    // 2592
     2668183.04733280,
     2668197.90633397,
     2668212.86991627,
@@ -17228,10 +17274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2668459.63370302,
     2668474.50632504,
     2668489.29420104,
+    // 2594
     2668504.04027620,
     2668518.76179192,
     2668533.50544798,
-    // 2593
     2668548.28864959,
     2668563.15426411,
     2668578.11233728,
@@ -17253,10 +17299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2668824.87672103,
     2668839.74381959,
     2668854.53819663,
+    // 2595
     2668869.27888798,
     2668884.00677271,
     2668898.74516228,
-    // 2594
     2668913.53466078,
     2668928.39509261,
     2668943.35964110,
@@ -17278,10 +17324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2669190.12001546,
     2669204.98967651,
     2669219.78254095,
+    // 2596
     2669234.52543117,
     2669249.25126169,
     2669263.99071876,
-    // 2595
     2669278.77743550,
     2669293.63816391,
     2669308.59934457,
@@ -17303,10 +17349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2669555.36283126,
     2669570.23592460,
     2669585.02543736,
+    // 2597
     2669599.77156031,
     2669614.49385241,
     2669629.23665770,
-    // 2596
     2669644.01955767,
     2669658.88345455,
     2669673.84077281,
@@ -17328,10 +17374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2669920.60646325,
     2669935.47189252,
     2669950.26699891,
+    // 2598
     2669965.00613801,
     2669979.73479609,
     2669994.47134031,
-    // 2597
     2670009.26132166,
     2670024.11943737,
     2670039.08410413,
@@ -17353,10 +17399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2670285.84728209,
     2670300.71774341,
     2670315.50718367,
+    // 2599
     2670330.25149831,
     2670344.97481748,
     2670359.71675773,
-    // 2598
     2670374.50176599,
     2670389.36548314,
     2670404.32489561,
@@ -17378,10 +17424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2670651.08833239,
     2670665.95889857,
     2670680.74972236,
+    // 2600
     2670695.49357332,
     2670710.21753247,
     2670724.95862161,
-    // 2599
     2670739.74365063,
     2670754.60619567,
     2670769.56580430,
@@ -17403,10 +17449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2671016.33396351,
     2671031.19980585,
     2671045.99405234,
+    // 2601
     2671060.73246341,
     2671075.45946219,
     2671090.19465898,
-    // 2600
     2671104.98284809,
     2671119.83972897,
     2671134.80289707,
@@ -17428,10 +17474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2671381.57506304,
     2671396.44778626,
     2671411.23572310,
+    // 2602
     2671425.98073594,
     2671440.70109192,
     2671455.44254593,
-    // 2601
     2671470.22373984,
     2671485.08665431,
     2671500.04262551,
@@ -17453,10 +17499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2671746.81328228,
     2671761.68234803,
     2671776.47623141,
+    // 2603
     2671791.21801035,
     2671805.94429079,
     2671820.68254881,
-    // 2602
     2671835.46914517,
     2671850.32831078,
     2671865.28910937,
@@ -17478,10 +17524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2672112.05439019,
     2672126.92400578,
     2672141.71895637,
+    // 2604
     2672156.46129076,
     2672171.18846163,
     2672185.92645928,
-    // 2603
     2672200.71339630,
     2672215.57143011,
     2672230.53165186,
@@ -17503,10 +17549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2672477.29465174,
     2672492.16912998,
     2672506.95874591,
+    // 2605
     2672521.70580515,
     2672536.42780187,
     2672551.17101310,
-    // 2604
     2672565.95303960,
     2672580.81659290,
     2672595.77212468,
@@ -17528,10 +17574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2672842.53792756,
     2672857.40496039,
     2672872.20035887,
+    // 2606
     2672886.94023798,
     2672901.66843738,
     2672916.40522380,
-    // 2605
     2672931.19427110,
     2672946.05226036,
     2672961.01562631,
@@ -17553,10 +17599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2673207.77946420,
     2673222.65078173,
     2673237.44271643,
+    // 2607
     2673252.18662561,
     2673266.91119744,
     2673281.65161336,
-    // 2606
     2673296.43701247,
     2673311.29857379,
     2673326.25798098,
@@ -17578,10 +17624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2673573.01826068,
     2673587.89245511,
     2673602.68434613,
+    // 2608
     2673617.43085052,
     2673632.15463598,
     2673646.89700316,
-    // 2607
     2673661.68053768,
     2673676.54323950,
     2673691.50049890,
@@ -17603,10 +17649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2673938.26418706,
     2673953.13193335,
     2673967.92878861,
+    // 2609
     2673982.66889892,
     2673997.39786790,
     2674012.13384401,
-    // 2608
     2674026.92276736,
     2674041.77913698,
     2674056.74184023,
@@ -17628,10 +17674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2674303.50607112,
     2674318.37942082,
     2674333.16953800,
+    // 2610
     2674347.91562408,
     2674362.63833597,
     2674377.38070262,
-    // 2609
     2674392.16376851,
     2674407.02681836,
     2674421.98363725,
@@ -17653,10 +17699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2674668.74777656,
     2674683.61824518,
     2674698.41133178,
+    // 2611
     2674713.15496376,
     2674727.88090615,
     2674742.62140061,
-    // 2610
     2674757.40786179,
     2674772.26923846,
     2674787.22963162,
@@ -17678,10 +17724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2675033.99593445,
     2675048.86413965,
     2675063.65947932,
+    // 2612
     2675078.40019356,
     2675093.12778612,
     2675107.86448130,
-    // 2611
     2675122.65226316,
     2675137.50951444,
     2675152.47109696,
@@ -17703,10 +17749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2675399.24107850,
     2675414.11562958,
     2675428.90458226,
+    // 2613
     2675443.65085439,
     2675458.37155426,
     2675473.11356137,
-    // 2612
     2675487.89428557,
     2675502.75691590,
     2675517.71166452,
@@ -17728,10 +17774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2675764.48724440,
     2675779.35568257,
     2675794.15050323,
+    // 2614
     2675808.89078703,
     2675823.61729142,
     2675838.35348807,
-    // 2613
     2675853.13996873,
     2675867.99690748,
     2675882.95764000,
@@ -17753,10 +17799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2676129.73056129,
     2676144.60161953,
     2676159.39543154,
+    // 2615
     2676174.13843559,
     2676188.86399429,
     2676203.60244436,
-    // 2614
     2676218.38769574,
     2676233.24621018,
     2676248.20465604,
@@ -17778,10 +17824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2676494.96821047,
     2676509.84402342,
     2676524.63577613,
+    // 2616
     2676539.38361745,
     2676554.10684495,
     2676568.84987409,
-    // 2615
     2676583.63199505,
     2676598.49435389,
     2676613.44909540,
@@ -17803,10 +17849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2676860.21457854,
     2676875.08277931,
     2676889.87980890,
+    // 2617
     2676904.62005498,
     2676919.34890192,
     2676934.08478145,
-    // 2616
     2676948.87318070,
     2676963.72896102,
     2676978.69053089,
@@ -17828,10 +17874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2677225.45553286,
     2677240.32867799,
     2677255.11996729,
+    // 2618
     2677269.86496550,
     2677284.58817846,
     2677299.32896699,
-    // 2617
     2677314.11222642,
     2677328.97349490,
     2677343.93028809,
@@ -17853,10 +17899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2677590.69300949,
     2677605.56641956,
     2677620.35940989,
+    // 2619
     2677635.10470209,
     2677649.82925126,
     2677664.57022898,
-    // 2618
     2677679.35435854,
     2677694.21556141,
     2677709.17324682,
@@ -17878,10 +17924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2677955.93588239,
     2677970.80557978,
     2677985.60340269,
+    // 2620
     2678000.34465436,
     2678015.07348282,
     2678029.80937201,
-    // 2619
     2678044.59701076,
     2678059.45228643,
     2678074.41285852,
@@ -17903,10 +17949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2678321.17434380,
     2678336.05051786,
     2678350.84165818,
+    // 2621
     2678365.58940323,
     2678380.31177103,
     2678395.05432165,
-    // 2620
     2678409.83557093,
     2678424.69744349,
     2678439.65140788,
@@ -17928,10 +17974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2678686.41535026,
     2678701.28581273,
     2678716.08077934,
+    // 2622
     2678730.82342604,
     2678745.55019967,
     2678760.28861910,
-    // 2621
     2678775.07486072,
     2678789.93326769,
     2678804.89275206,
@@ -17953,10 +17999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2679051.65815474,
     2679066.52806447,
     2679081.32317843,
+    // 2623
     2679096.06532938,
     2679110.79251712,
     2679125.53044729,
-    // 2622
     2679140.31748520,
     2679155.17544199,
     2679170.13541333,
@@ -17978,10 +18024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2679416.89656209,
     2679431.77265132,
     2679446.56337739,
+    // 2624
     2679461.31105191,
     2679476.03311386,
     2679490.77599815,
-    // 2623
     2679505.55721935,
     2679520.41978334,
     2679535.37393949,
@@ -18003,10 +18049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2679782.14412563,
     2679797.01316175,
     2679811.80963150,
+    // 2625
     2679826.54969555,
     2679841.27704363,
     2679856.01213579,
-    // 2624
     2679870.79866524,
     2679885.65369780,
     2679900.61375748,
@@ -18028,10 +18074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2680147.38736462,
     2680162.26052444,
     2680177.05366432,
+    // 2626
     2680191.79767145,
     2680206.52156556,
     2680221.26013805,
-    // 2625
     2680236.04302531,
     2680250.90131053,
     2680265.85735563,
@@ -18053,10 +18099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2680512.62461860,
     2680527.50046192,
     2680542.29351265,
+    // 2627
     2680557.04068485,
     2680571.76448051,
     2680586.50626528,
-    // 2626
     2680601.28845566,
     2680616.14929591,
     2680631.10401855,
@@ -18078,10 +18124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2680877.87106195,
     2680892.74151350,
     2680907.54066527,
+    // 2628
     2680922.28248480,
     2680937.01230930,
     2680951.74827229,
-    // 2627
     2680966.53614985,
     2680981.39058945,
     2680996.35039943,
@@ -18103,10 +18149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2681243.11444401,
     2681257.99063270,
     2681272.78290852,
+    // 2629
     2681287.53036129,
     2681302.25366729,
     2681316.99570810,
-    // 2628
     2681331.77764990,
     2681346.63865909,
     2681361.59277119,
@@ -18128,10 +18174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2681608.35865320,
     2681623.23132388,
     2681638.02564381,
+    // 2630
     2681652.76964922,
     2681667.49512119,
     2681682.23444974,
-    // 2629
     2681697.01910715,
     2681711.87829181,
     2681726.83609334,
@@ -18153,10 +18199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2681973.60499221,
     2681988.47564766,
     2682003.27295439,
+    // 2631
     2682018.01482472,
     2682032.74309306,
     2682047.47976434,
-    // 2630
     2682062.26702310,
     2682077.12314450,
     2682092.08304345,
@@ -18178,10 +18224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2682338.84340247,
     2682353.72148993,
     2682368.51371636,
+    // 2632
     2682383.26272975,
     2682397.98538949,
     2682412.72845426,
-    // 2631
     2682427.50917956,
     2682442.37091463,
     2682457.32379066,
@@ -18203,10 +18249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2682704.08959347,
     2682718.96078594,
     2682733.75794171,
+    // 2633
     2682748.50018049,
     2682763.22790541,
     2682777.96460542,
-    // 2632
     2682792.75062278,
     2682807.60629990,
     2682822.56486662,
@@ -18228,10 +18274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2683069.33386435,
     2683084.20625642,
     2683099.00123311,
+    // 2634
     2683113.74482991,
     2683128.47077525,
     2683143.20893518,
-    // 2633
     2683157.99362433,
     2683172.85098604,
     2683187.80812773,
@@ -18253,10 +18299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2683434.57026076,
     2683449.44643191,
     2683464.23817953,
+    // 2635
     2683478.98581599,
     2683493.70863575,
     2683508.45120519,
-    // 2634
     2683523.23273848,
     2683538.09454988,
     2683553.04855442,
@@ -18278,10 +18324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2683799.81794033,
     2683814.68799488,
     2683829.48620306,
+    // 2636
     2683844.22699035,
     2683858.95553430,
     2683873.69048116,
-    // 2635
     2683888.47728579,
     2683903.33111355,
     2683918.29033542,
@@ -18303,10 +18349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2684165.06093220,
     2684179.93641499,
     2684194.72926704,
+    // 2637
     2684209.47477891,
     2684224.19757530,
     2684238.93681277,
-    // 2636
     2684253.71776530,
     2684268.57589645,
     2684283.52925935,
@@ -18328,10 +18374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2684530.30049690,
     2684545.17547447,
     2684559.96919688,
+    // 2638
     2684574.71440519,
     2684589.43798735,
     2684604.17716799,
-    // 2637
     2684618.95871057,
     2684633.81681475,
     2684648.77095609,
@@ -18353,10 +18399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2684895.54377113,
     2684910.41515449,
     2684925.21426517,
+    // 2639
     2684939.95621421,
     2684954.68533662,
     2684969.42091931,
-    // 2638
     2684984.20763985,
     2684999.06129041,
     2685014.01943788,
@@ -18378,10 +18424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2685260.77840779,
     2685275.65736894,
     2685290.45090767,
+    // 2640
     2685305.20050230,
     2685319.92404804,
     2685334.66699151,
-    // 2639
     2685349.44776571,
     2685364.30831132,
     2685379.26003795,
@@ -18403,10 +18449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2685626.02248753,
     2685640.89571567,
     2685655.69253051,
+    // 2641
     2685670.43631636,
     2685685.16330800,
     2685699.90119408,
-    // 2640
     2685714.68603536,
     2685729.54240184,
     2685744.49912611,
@@ -18428,10 +18474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2685991.26616281,
     2686006.13880847,
     2686020.93585364,
+    // 2642
     2686035.67877441,
     2686050.40595128,
     2686065.14279781,
-    // 2641
     2686079.92823109,
     2686094.78393660,
     2686109.74156948,
@@ -18453,10 +18499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2686356.50038956,
     2686371.37918739,
     2686386.17210151,
+    // 2643
     2686400.92132953,
     2686415.64420528,
     2686430.38717497,
-    // 2642
     2686445.16779117,
     2686460.02923610,
     2686474.98176028,
@@ -18478,10 +18524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2686721.74630400,
     2686736.61898264,
     2686751.41870722,
+    // 2644
     2686766.16155671,
     2686780.89069298,
     2686795.62660840,
-    // 2643
     2686810.41276037,
     2686825.26643972,
     2686840.22411963,
@@ -18503,10 +18549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2687086.98918427,
     2687101.86503476,
     2687116.66074224,
+    // 2645
     2687131.40679908,
     2687146.13228571,
     2687160.87151416,
-    // 2644
     2687175.65432424,
     2687190.51142703,
     2687205.46555353,
@@ -18528,10 +18574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2687452.22937161,
     2687467.10588522,
     2687481.89928443,
+    // 2646
     2687496.64658234,
     2687511.37025128,
     2687526.11166591,
-    // 2645
     2687540.89317892,
     2687555.75309617,
     2687570.70650326,
@@ -18553,10 +18599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2687817.47802147,
     2687832.34861678,
     2687847.14755965,
+    // 2647
     2687861.88894124,
     2687876.61829773,
     2687891.35381374,
-    // 2646
     2687906.14120149,
     2687920.99511047,
     2687935.95408272,
@@ -18578,10 +18624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2688182.71899254,
     2688197.59681909,
     2688212.39015019,
+    // 2648
     2688227.13798434,
     2688241.86104209,
     2688256.60221386,
-    // 2647
     2688271.38283768,
     2688286.24213256,
     2688301.19430711,
@@ -18603,10 +18649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2688547.96714025,
     2688562.84188309,
     2688577.63720585,
+    // 2649
     2688592.38129857,
     2688607.10571491,
     2688621.84305332,
-    // 2648
     2688636.62481894,
     2688651.48056438,
     2688666.43451012,
@@ -18628,10 +18674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2688913.21397594,
     2688928.08661859,
     2688942.88513247,
+    // 2650
     2688957.62707718,
     2688972.35454078,
     2688987.08921446,
-    // 2649
     2689001.87376885,
     2689016.72641508,
     2689031.68267092,
@@ -18653,10 +18699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2689278.44920992,
     2689293.32937500,
     2689308.12319905,
+    // 2651
     2689322.87321710,
     2689337.59623384,
     2689352.33889934,
-    // 2650
     2689367.11849190,
     2689381.97836541,
     2689396.92866537,
@@ -18678,10 +18724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2689643.69551625,
     2689658.56995236,
     2689673.36965995,
+    // 2652
     2689688.11387505,
     2689702.84253823,
     2689717.57929534,
-    // 2651
     2689732.36423621,
     2689747.21792156,
     2689762.17342733,
@@ -18703,10 +18749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2690008.93999232,
     2690023.81559292,
     2690038.61310553,
+    // 2653
     2690053.35829030,
     2690068.08508674,
     2690082.82302818,
-    // 2652
     2690097.60677880,
     2690112.46217641,
     2690127.41674683,
@@ -18728,10 +18774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2690374.17845927,
     2690389.05732838,
     2690403.85089031,
+    // 2654
     2690418.59943737,
     2690433.32228604,
     2690448.06408988,
-    // 2653
     2690462.84418249,
     2690477.70405961,
     2690492.65569707,
@@ -18753,10 +18799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2690739.42373508,
     2690754.29665233,
     2690769.09722287,
+    // 2655
     2690783.83987900,
     2690798.56962978,
     2690813.30518832,
-    // 2654
     2690828.09182310,
     2690842.94485168,
     2690857.90246343,
@@ -18778,10 +18824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2691104.66033386,
     2691119.53943509,
     2691134.33582648,
+    // 2656
     2691149.08444582,
     2691163.80971018,
     2691178.55047442,
-    // 2655
     2691193.33201649,
     2691208.18963162,
     2691223.14159513,
@@ -18803,10 +18849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2691469.90140179,
     2691484.77887650,
     2691499.57475277,
+    // 2657
     2691514.32185799,
     2691529.04674263,
     2691543.78658600,
-    // 2656
     2691558.56799142,
     2691573.42516098,
     2691588.37754551,
@@ -18828,10 +18874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2691835.14608875,
     2691850.01844548,
     2691864.81831614,
+    // 2658
     2691879.56060733,
     2691894.28989106,
     2691909.02518168,
-    // 2657
     2691923.81146123,
     2691938.66435057,
     2691953.62166510,
@@ -18853,10 +18899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2692200.38046173,
     2692215.25969584,
     2692230.05323642,
+    // 2659
     2692244.80256947,
     2692259.52574285,
     2692274.26817398,
-    // 2658
     2692289.04845477,
     2692303.90846500,
     2692318.85963095,
@@ -18878,10 +18924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2692565.62715100,
     2692580.50213963,
     2692595.29988873,
+    // 2660
     2692610.04402624,
     2692624.77040061,
     2692639.50708215,
-    // 2659
     2692654.28999835,
     2692669.14413424,
     2692684.09824520,
@@ -18903,10 +18949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2692930.87335037,
     2692945.74802233,
     2692960.54639014,
+    // 2661
     2692975.28960786,
     2692990.01621734,
     2693004.75135175,
-    // 2660
     2693019.53437037,
     2693034.38678507,
     2693049.34087158,
@@ -18928,10 +18974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2693296.11050592,
     2693310.99064521,
     2693325.78414108,
+    // 2662
     2693340.53313015,
     2693355.25493976,
     2693369.99601903,
-    // 2661
     2693384.77402881,
     2693399.63228573,
     2693414.58123103,
@@ -18953,10 +18999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2693661.35602893,
     2693676.23037737,
     2693691.03121013,
+    // 2663
     2693705.77486292,
     2693720.50422226,
     2693735.23994348,
-    // 2662
     2693750.02513004,
     2693764.87719726,
     2693779.83233346,
@@ -18978,10 +19024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2694026.59564750,
     2694041.47426238,
     2694056.27230015,
+    // 2664
     2694071.02006593,
     2694085.74661836,
     2694100.48604853,
-    // 2663
     2694115.26831788,
     2694130.12389458,
     2694145.07576139,
@@ -19003,10 +19049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2694391.83852586,
     2694406.71779737,
     2694421.51295887,
+    // 2665
     2694436.26116408,
     2694450.98481737,
     2694465.72536738,
-    // 2664
     2694480.50526033,
     2694495.36286025,
     2694510.31337707,
@@ -19028,10 +19074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2694757.08718178,
     2694771.96082038,
     2694786.76186414,
+    // 2666
     2694801.50418561,
     2694816.23348980,
     2694830.96791849,
-    // 2665
     2694845.75354500,
     2694860.60517184,
     2694875.56164555,
@@ -19053,10 +19099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2695122.32242166,
     2695137.20322185,
     2695151.99912146,
+    // 2667
     2695166.74882948,
     2695181.47301479,
     2695196.21443270,
-    // 2666
     2695210.99456811,
     2695225.85264099,
     2695240.80306946,
@@ -19078,10 +19124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2695487.56777358,
     2695502.44635293,
     2695517.24509209,
+    // 2668
     2695531.99222946,
     2695546.71867011,
     2695561.45713500,
-    // 2667
     2695576.23878946,
     2695591.09340735,
     2695606.04513553,
@@ -19103,10 +19149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2695852.81511664,
     2695867.69043034,
     2695882.49148806,
+    // 2669
     2695897.23552467,
     2695911.96462280,
     2695926.70007674,
-    // 2668
     2695941.48471447,
     2695956.33636582,
     2695971.29090275,
@@ -19128,10 +19174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2696218.05294204,
     2696232.93363637,
     2696247.72771602,
+    // 2670
     2696262.47771558,
     2696277.20052690,
     2696291.94275791,
-    // 2669
     2696306.72177081,
     2696321.58082497,
     2696336.53011875,
@@ -19153,10 +19199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2696583.30128766,
     2696598.17620813,
     2696612.97589175,
+    // 2671
     2696627.72006971,
     2696642.44841684,
     2696657.18496036,
-    // 2670
     2696671.96942657,
     2696686.82259863,
     2696701.77719500,
@@ -19178,10 +19224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2696948.54388100,
     2696963.42134955,
     2696978.22020706,
+    // 2672
     2696992.96599543,
     2697007.69274414,
     2697022.42984248,
-    // 2671
     2697037.21228600,
     2697052.06576122,
     2697067.01825079,
@@ -19203,10 +19249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2697313.78565747,
     2697328.66650050,
     2697343.46118267,
+    // 2673
     2697358.20993589,
     2697372.93200018,
     2697387.67203123,
-    // 2672
     2697402.44953264,
     2697417.30613266,
     2697432.25411562,
@@ -19228,10 +19274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2697679.03179119,
     2697693.90665121,
     2697708.70839865,
+    // 2674
     2697723.45131634,
     2697738.18039675,
     2697752.91434856,
-    // 2673
     2697767.69858232,
     2697782.54866128,
     2697797.50296956,
@@ -19253,10 +19299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2698044.26503455,
     2698059.14589214,
     2698073.94372640,
+    // 2675
     2698088.69327696,
     2698103.41895516,
     2698118.15947289,
-    // 2674
     2698132.94017593,
     2698147.79622901,
     2698162.74606689,
@@ -19278,10 +19324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2698409.50733899,
     2698424.38769242,
     2698439.18571740,
+    // 2676
     2698453.93445653,
     2698468.66000397,
     2698483.39976214,
-    // 2675
     2698498.18009621,
     2698513.03537140,
     2698527.98497972,
@@ -19303,10 +19349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2698774.75391350,
     2698789.62916064,
     2698804.43110288,
+    // 2677
     2698819.17451579,
     2698833.90407780,
     2698848.63867609,
-    // 2676
     2698863.42355642,
     2698878.27416254,
     2698893.22869338,
@@ -19328,10 +19374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2699139.98936213,
     2699154.87100794,
     2699169.66613039,
+    // 2678
     2699184.41606565,
     2699199.13898495,
     2699213.88030917,
-    // 2677
     2699228.65890622,
     2699243.51667751,
     2699258.46535819,
@@ -19353,10 +19399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2699505.23350997,
     2699520.11135417,
     2699534.91139671,
+    // 2679
     2699549.65753705,
     2699564.38509228,
     2699579.12240468,
-    // 2678
     2699593.90496038,
     2699608.75803741,
     2699623.71016441,
@@ -19378,10 +19424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2699870.47353887,
     2699885.35164670,
     2699900.15337012,
+    // 2680
     2699914.89946919,
     2699929.62835484,
     2699944.36479275,
-    // 2679
     2699959.14822287,
     2699973.99986462,
     2699988.95234217,
@@ -19403,10 +19449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2700235.71289378,
     2700250.59524688,
     2700265.39067861,
+    // 2681
     2700280.14115380,
     2700294.86394018,
     2700309.60530578,
-    // 2680
     2700324.38292119,
     2700339.23997067,
     2700354.18701624,
@@ -19428,10 +19474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2700600.95944510,
     2700615.83485938,
     2700630.63624507,
+    // 2682
     2700645.38003202,
     2700660.10904720,
     2700674.84414711,
-    // 2681
     2700689.62839971,
     2700704.47949487,
     2700719.43351156,
@@ -19453,10 +19499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2700966.19743462,
     2700981.07690742,
     2700995.87546176,
+    // 2683
     2701010.62330980,
     2701025.34965103,
     2701040.08857076,
-    // 2682
     2701054.87021998,
     2701069.72497119,
     2701084.67601842,
@@ -19478,10 +19524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2701331.44290903,
     2701346.32455898,
     2701361.12121959,
+    // 2684
     2701375.87018851,
     2701390.59351127,
     2701405.33292483,
-    // 2683
     2701420.11084434,
     2701434.96589613,
     2701449.91347287,
@@ -19503,10 +19549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2701696.69312779,
     2701711.56920132,
     2701726.37195031,
+    // 2685
     2701741.11503398,
     2701755.84412121,
     2701770.57722947,
-    // 2684
     2701785.36064903,
     2701800.20916342,
     2701815.16206595,
@@ -19528,10 +19574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2702061.93134749,
     2702076.81382632,
     2702091.61082919,
+    // 2686
     2702106.36083028,
     2702121.08457528,
     2702135.82467716,
-    // 2685
     2702150.60281579,
     2702165.45813122,
     2702180.40540085,
@@ -19553,10 +19599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2702427.17643692,
     2702442.05696729,
     2702456.85718137,
+    // 2687
     2702471.60566411,
     2702486.33281781,
     2702501.07162192,
-    // 2686
     2702515.85270444,
     2702530.70605042,
     2702545.65546797,
@@ -19578,10 +19624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2702792.42074430,
     2702807.29897344,
     2702822.10258796,
+    // 2688
     2702836.84861034,
     2702851.57908776,
     2702866.31511030,
-    // 2687
     2702881.09957468,
     2702895.95007174,
     2702910.90271847,
@@ -19603,10 +19649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2703157.66169045,
     2703172.54515673,
     2703187.34123261,
+    // 2689
     2703202.09226365,
     2703216.81529230,
     2703231.55685382,
-    // 2688
     2703246.33450482,
     2703261.19146563,
     2703276.13817612,
@@ -19628,10 +19674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2703522.90883290,
     2703537.78673942,
     2703552.58847792,
+    // 2690
     2703567.33382340,
     2703582.06224296,
     2703596.79812384,
-    // 2689
     2703611.58114809,
     2703626.43260739,
     2703641.38518172,
@@ -19653,10 +19699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2703888.14565013,
     2703903.02587724,
     2703917.82731797,
+    // 2691
     2703932.57519759,
     2703947.30343519,
     2703962.04126599,
-    // 2690
     2703976.82375431,
     2703991.67650005,
     2704006.62777221,
@@ -19678,10 +19724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2704253.38631620,
     2704268.27029687,
     2704283.06784631,
+    // 2692
     2704297.81913351,
     2704312.54296450,
     2704327.28395676,
-    // 2691
     2704342.06144313,
     2704356.91701475,
     2704371.86311694,
@@ -19703,10 +19749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2704618.63354794,
     2704633.51029259,
     2704648.31373388,
+    // 2693
     2704663.05802920,
     2704677.78807913,
     2704692.52243272,
-    // 2692
     2704707.30647240,
     2704722.15560883,
     2704737.10832336,
@@ -19728,10 +19774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2704983.87044953,
     2704998.75132905,
     2705013.54889718,
+    // 2694
     2705028.29776806,
     2705043.02262565,
     2705057.76210584,
-    // 2693
     2705072.54177511,
     2705087.39664732,
     2705102.34536580,
@@ -19753,10 +19799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2705349.11421147,
     2705363.99501903,
     2705378.79290002,
+    // 2695
     2705393.54137928,
     2705408.26622081,
     2705423.00527780,
-    // 2694
     2705437.78451897,
     2705452.63865155,
     2705467.58677590,
@@ -19778,10 +19824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2705714.35926454,
     2705729.23654799,
     2705744.03988401,
+    // 2696
     2705758.78384739,
     2705773.51310728,
     2705788.24654315,
-    // 2695
     2705803.02958237,
     2705817.87770662,
     2705832.82949820,
@@ -19803,10 +19849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2706079.59759698,
     2706094.48127552,
     2706109.27764750,
+    // 2697
     2706124.02770273,
     2706138.74975354,
     2706153.48907780,
-    // 2696
     2706168.26481885,
     2706183.11890546,
     2706198.06354429,
@@ -19828,10 +19874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2706444.84181929,
     2706459.72205263,
     2706474.52354178,
+    // 2698
     2706489.27029405,
     2706503.99729263,
     2706518.73325602,
-    // 2697
     2706533.51345617,
     2706548.36372461,
     2706563.31248773,
@@ -19853,10 +19899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2706810.07762203,
     2706824.95798219,
     2706839.76161380,
+    // 2699
     2706854.50908691,
     2706869.23874715,
     2706883.97523398,
-    // 2698
     2706898.75801542,
     2706913.60816144,
     2706928.55851835,
@@ -19878,10 +19924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2707175.31748537,
     2707190.20304185,
     2707205.00105532,
+    // 2700
     2707219.75340801,
     2707234.47722591,
     2707249.21872729,
-    // 2699
     2707263.99559538,
     2707278.85092681,
     2707293.79549298,
@@ -19903,10 +19949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2707540.56645472,
     2707555.44521821,
     2707570.24909656,
+    // 2701
     2707584.99443801,
     2707599.72403823,
     2707614.45879571,
-    // 2700
     2707629.24182344,
     2707644.09091121,
     2707659.04223554,
@@ -19928,10 +19974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2707905.80498272,
     2707920.68700822,
     2707935.48748900,
+    // 2702
     2707950.23630172,
     2707964.96279952,
     2707979.70102302,
-    // 2701
     2707994.48135210,
     2708009.33420852,
     2708024.28309416,
@@ -19953,10 +19999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2708271.04803476,
     2708285.93255916,
     2708300.73181813,
+    // 2703
     2708315.48325190,
     2708330.20833135,
     2708344.94895018,
-    // 2702
     2708359.72702667,
     2708374.58141248,
     2708389.52734734,
@@ -19978,10 +20024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2708636.29378230,
     2708651.17302890,
     2708665.97894219,
+    // 2704
     2708680.72489299,
     2708695.45628819,
     2708710.19093514,
-    // 2703
     2708724.97495919,
     2708739.82301757,
     2708754.77453746,
@@ -20003,10 +20049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2709001.53458654,
     2709016.41864868,
     2709031.21714231,
+    // 2705
     2709045.96818289,
     2709060.69268825,
     2709075.43297931,
-    // 2704
     2709090.21085406,
     2709105.06519925,
     2709120.01101801,
@@ -20028,10 +20074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2709366.78157722,
     2709381.66287594,
     2709396.46322997,
+    // 2706
     2709411.21154019,
     2709425.93798323,
     2709440.67604083,
-    // 2705
     2709455.45609288,
     2709470.30864137,
     2709485.25717475,
@@ -20053,10 +20099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2709732.02398730,
     2709746.90290128,
     2709761.70679315,
+    // 2707
     2709776.45264788,
     2709791.18261174,
     2709805.91783463,
-    // 2706
     2709820.70137465,
     2709835.55080341,
     2709850.50246680,
@@ -20078,10 +20124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2710097.26745302,
     2710112.15293700,
     2710126.95034060,
+    // 2708
     2710141.70174847,
     2710156.42426476,
     2710171.16435266,
-    // 2707
     2710185.93983757,
     2710200.79396201,
     2710215.73760124,
@@ -20103,10 +20149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2710462.51679955,
     2710477.39691217,
     2710492.20002749,
+    // 2709
     2710506.94582300,
     2710521.67361740,
     2710536.40789272,
-    // 2708
     2710551.18827782,
     2710566.03637716,
     2710580.98496053,
@@ -20128,10 +20174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2710827.75512405,
     2710842.63679855,
     2710857.43910860,
+    // 2710
     2710872.18701110,
     2710886.91454647,
     2710901.65087665,
-    // 2709
     2710916.43117691,
     2710931.28107311,
     2710946.22909487,
@@ -20153,10 +20199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2711192.99525088,
     2711207.88115892,
     2711222.68020379,
+    // 2711
     2711237.43275818,
     2711252.15721742,
     2711266.89834055,
-    // 2710
     2711281.67503291,
     2711296.52900335,
     2711311.47256804,
@@ -20178,10 +20224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2711558.23949251,
     2711573.11928982,
     2711587.92519348,
+    // 2712
     2711602.67127937,
     2711617.40232688,
     2711632.13687338,
-    // 2711
     2711646.92022435,
     2711661.76779670,
     2711676.71816007,
@@ -20203,10 +20249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2711923.47910900,
     2711938.36308558,
     2711953.16291246,
+    // 2713
     2711967.91281554,
     2711982.63777827,
     2711997.37628301,
-    // 2712
     2712012.15420864,
     2712027.00650697,
     2712041.95220517,
@@ -20228,10 +20274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2712288.72015700,
     2712303.60441702,
     2712318.40473592,
+    // 2714
     2712333.15470281,
     2712347.87977309,
     2712362.61824160,
-    // 2713
     2712377.39594082,
     2712392.24820685,
     2712407.19401385,
@@ -20253,10 +20299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2712653.95761085,
     2712668.83823330,
     2712683.64478079,
+    // 2715
     2712698.39152145,
     2712713.12290837,
     2712727.85766253,
-    // 2714
     2712742.64113379,
     2712757.48879068,
     2712772.43937698,
@@ -20278,10 +20324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2713019.19571195,
     2713034.08270770,
     2713048.88239309,
+    // 2716
     2713063.63523588,
     2713078.35954741,
     2713093.10016911,
-    // 2715
     2713107.87631619,
     2713122.72963762,
     2713137.67262016,
@@ -20303,10 +20349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2713384.44115959,
     2713399.32331502,
     2713414.12651013,
+    // 2717
     2713428.87475180,
     2713443.60279681,
     2713458.33944299,
-    // 2716
     2713473.11963685,
     2713487.96939021,
     2713502.91684125,
@@ -20328,10 +20374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2713749.68135142,
     2713764.56192429,
     2713779.36548989,
+    // 2718
     2713794.11254739,
     2713808.84163535,
     2713823.57742272,
-    // 2717
     2713838.35945876,
     2713853.20880626,
     2713868.15839242,
@@ -20353,10 +20399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2714114.92354201,
     2714129.80977596,
     2714144.60806968,
+    // 2719
     2714159.36049578,
     2714174.08399441,
     2714188.82495653,
-    // 2718
     2714203.60088646,
     2714218.45500940,
     2714233.39812711,
@@ -20378,10 +20424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2714480.17153008,
     2714495.05242554,
     2714509.85782923,
+    // 2720
     2714524.60399465,
     2714539.33356350,
     2714554.06750057,
-    // 2719
     2714568.84891294,
     2714583.69578918,
     2714598.64446677,
@@ -20403,10 +20449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2714845.41413770,
     2714860.29805136,
     2714875.09980193,
+    // 2721
     2714889.84879270,
     2714904.57463964,
     2714919.31112724,
-    // 2720
     2714934.08892355,
     2714948.93841954,
     2714963.88355759,
@@ -20428,10 +20474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2715210.65720363,
     2715225.54375196,
     2715240.34418897,
+    // 2722
     2715255.09606493,
     2715269.82061166,
     2715284.56007979,
-    // 2721
     2715299.33619694,
     2715314.18831813,
     2715329.13149123,
@@ -20453,10 +20499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2715575.89910407,
     2715590.78034120,
     2715605.58793435,
+    // 2723
     2715620.33516005,
     2715635.06732429,
     2715649.80219959,
-    // 2722
     2715664.58579378,
     2715679.43278530,
     2715694.38258077,
@@ -20478,10 +20524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2715941.14196578,
     2715956.02877933,
     2715970.82948937,
+    // 2724
     2715985.58180426,
     2716000.30692996,
     2716015.04688275,
-    // 2723
     2716029.82373845,
     2716044.67617596,
     2716059.61953083,
@@ -20503,10 +20549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2716306.39017727,
     2716321.27459287,
     2716336.07709833,
+    // 2725
     2716350.82660812,
     2716365.55317607,
     2716380.29054054,
-    // 2724
     2716395.06893889,
     2716409.91929061,
     2716424.86492967,
@@ -20528,10 +20574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2716671.63213452,
     2716686.51353031,
     2716701.31922037,
+    // 2726
     2716716.06600601,
     2716730.79601247,
     2716745.53046853,
-    // 2725
     2716760.31251882,
     2716775.15993913,
     2716790.10928734,
@@ -20553,10 +20599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2717036.87214799,
     2717051.76029737,
     2717066.56028821,
+    // 2727
     2717081.31403434,
     2717096.03833440,
     2717110.77944226,
-    // 2726
     2717125.55499685,
     2717140.40817398,
     2717155.35002448,
@@ -20578,10 +20624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2717402.11760253,
     2717417.00068770,
     2717431.80663257,
+    // 2728
     2717446.55497380,
     2717461.28472265,
     2717476.02024070,
-    // 2727
     2717490.80090753,
     2717505.64834498,
     2717520.59525650,
@@ -20603,10 +20649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2717767.35836422,
     2717782.24138386,
     2717797.04484503,
+    // 2729
     2717811.79329306,
     2717826.52108085,
     2717841.25706828,
-    // 2728
     2717856.03659862,
     2717870.88513851,
     2717885.83136658,
@@ -20628,10 +20674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2718132.59963200,
     2718147.48654821,
     2718162.28587267,
+    // 2730
     2718177.03818626,
     2718191.76181955,
     2718206.50195173,
-    // 2729
     2718221.27738577,
     2718236.13028669,
     2718251.07271994,
@@ -20653,10 +20699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2718497.84100765,
     2718512.72213462,
     2718527.52884303,
+    // 2731
     2718542.27520933,
     2718557.00596984,
     2718571.73980993,
-    // 2730
     2718586.52201652,
     2718601.36824103,
     2718616.31705479,
@@ -20678,10 +20724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2718863.08206910,
     2718877.96842087,
     2718892.77009616,
+    // 2732
     2718907.52075104,
     2718922.24566785,
     2718936.98297330,
-    // 2731
     2718951.75895557,
     2718966.60844679,
     2718981.55100613,
@@ -20703,10 +20749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2719228.32576635,
     2719243.21264716,
     2719258.01478553,
+    // 2733
     2719272.76572784,
     2719287.49067987,
     2719302.22808207,
-    // 2732
     2719317.00354788,
     2719331.85283388,
     2719346.79480029,
@@ -20728,10 +20774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2719593.56463488,
     2719608.44693942,
     2719623.25456780,
+    // 2734
     2719638.00175301,
     2719652.73286325,
     2719667.46667366,
-    // 2733
     2719682.24847746,
     2719697.09386736,
     2719712.04166158,
@@ -20753,10 +20799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2719958.80269745,
     2719973.69167694,
     2719988.49320081,
+    // 2735
     2720003.24757924,
     2720017.97306428,
     2720032.71418101,
-    // 2734
     2720047.49011017,
     2720062.34224261,
     2720077.28324126,
@@ -20778,10 +20824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2720324.04705284,
     2720338.93231167,
     2720353.73797783,
+    // 2736
     2720368.48804285,
     2720383.21714288,
     2720397.95409987,
-    // 2735
     2720412.73372973,
     2720427.58217781,
     2720442.52746126,
@@ -20803,10 +20849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2720689.28989439,
     2720704.17340114,
     2720718.97910250,
+    // 2737
     2720733.72712922,
     2720748.45631562,
     2720763.19122010,
-    // 2736
     2720777.97163407,
     2720792.81871176,
     2720807.76556390,
@@ -20828,10 +20874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2721054.53027305,
     2721069.41963959,
     2721084.22025074,
+    // 2738
     2721098.97410757,
     2721113.69799579,
     2721128.43858149,
-    // 2737
     2721143.21335516,
     2721158.06599833,
     2721173.00726734,
@@ -20853,10 +20899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2721419.77170216,
     2721434.65549244,
     2721449.46365398,
+    // 2739
     2721464.21231587,
     2721478.94379594,
     2721493.67900162,
-    // 2738
     2721508.46081270,
     2721523.30735244,
     2721538.25489608,
@@ -20878,10 +20924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2721785.01494806,
     2721799.90146455,
     2721814.70586834,
+    // 2740
     2721829.45693989,
     2721844.18453272,
     2721858.92187760,
-    // 2739
     2721873.69985588,
     2721888.54850980,
     2721903.49208149,
@@ -20903,10 +20949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2722150.26052549,
     2722165.14859554,
     2722179.95014686,
+    // 2741
     2722194.70281810,
     2722209.42768118,
     2722224.16711030,
-    // 2740
     2722238.94259429,
     2722253.79371655,
     2722268.73524315,
@@ -20928,10 +20974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2722515.50545648,
     2722530.38693501,
     2722545.19417316,
+    // 2742
     2722559.94065050,
     2722574.67170788,
     2722589.40534333,
-    // 2741
     2722604.18757262,
     2722619.03327171,
     2722633.98180326,
@@ -20953,10 +20999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2722880.74979706,
     2722895.63758667,
     2722910.43894978,
+    // 2743
     2722925.19135517,
     2722939.91618493,
     2722954.65515537,
-    // 2742
     2722969.43063685,
     2722984.28111573,
     2722999.22234890,
@@ -20978,10 +21024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2723245.99636348,
     2723260.88322664,
     2723275.68747309,
+    // 2744
     2723290.43789978,
     2723305.16439466,
     2723319.90074357,
-    // 2743
     2723334.67712195,
     2723349.52478619,
     2723364.46705916,
@@ -21003,10 +21049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2723611.24143436,
     2723626.12516688,
     2723640.93243205,
+    // 2745
     2723655.67970884,
     2723670.40921606,
     2723685.14205911,
-    // 2744
     2723699.92147813,
     2723714.76541507,
     2723729.71061273,
@@ -21028,10 +21074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2723976.48011377,
     2723991.37061843,
     2724006.17217924,
+    // 2746
     2724020.92666248,
     2724035.65079376,
     2724050.39098083,
-    // 2745
     2724065.16485814,
     2724080.01584955,
     2724094.95498166,
@@ -21053,10 +21099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2724341.71981688,
     2724356.60545728,
     2724371.41358112,
+    // 2747
     2724386.16378436,
     2724400.89482530,
     2724415.63108380,
-    // 2746
     2724430.41171897,
     2724445.25844142,
     2724460.20379002,
@@ -21078,10 +21124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2724706.96304394,
     2724721.84930939,
     2724736.65548681,
+    // 2748
     2724751.40563521,
     2724766.13447518,
     2724780.87045870,
-    // 2747
     2724795.64927173,
     2724810.49613605,
     2724825.44003483,
@@ -21103,10 +21149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2725072.20468176,
     2725087.09505263,
     2725101.89691647,
+    // 2749
     2725116.65082520,
     2725131.37501326,
     2725146.11479713,
-    // 2748
     2725160.88894668,
     2725175.73992973,
     2725190.67973764,
@@ -21128,10 +21174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2725437.44518007,
     2725452.32902402,
     2725467.13785127,
+    // 2750
     2725481.88574080,
     2725496.61720625,
     2725511.35103971,
-    // 2749
     2725526.13246306,
     2725540.97739439,
     2725555.92440372,
@@ -21153,10 +21199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2725802.68393074,
     2725817.57283696,
     2725832.37733257,
+    // 2751
     2725847.13054513,
     2725861.85776139,
     2725876.59646561,
-    // 2750
     2725891.37307896,
     2725906.22202853,
     2725921.16324280,
@@ -21178,10 +21224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2726167.92489891,
     2726182.81433229,
     2726197.61898194,
+    // 2752
     2726212.37228384,
     2726227.09918871,
     2726241.83801578,
-    // 2751
     2726256.61409428,
     2726271.46324421,
     2726286.40405422,
@@ -21203,10 +21249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2726533.16809822,
     2726548.05138916,
     2726562.85964746,
+    // 2753
     2726577.60706836,
     2726592.33808246,
     2726607.07147738,
-    // 2752
     2726621.85249340,
     2726636.69682049,
     2726651.64315919,
@@ -21228,10 +21274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2726898.40818782,
     2726913.29770290,
     2726928.09905254,
+    // 2754
     2726942.85264614,
     2726957.57694089,
     2726972.31668052,
-    // 2753
     2726987.09129528,
     2727001.94231377,
     2727016.88246497,
@@ -21253,10 +21299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2727263.65001998,
     2727278.53647333,
     2727293.34272821,
+    // 2755
     2727308.09292065,
     2727322.82153461,
     2727337.55759030,
-    // 2754
     2727352.33588254,
     2727367.18284055,
     2727382.12645056,
@@ -21278,10 +21324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2727628.89604708,
     2727643.78166716,
     2727658.58886462,
+    // 2756
     2727673.33725954,
     2727688.06600221,
     2727702.79927979,
-    // 2755
     2727717.57731988,
     2727732.42120269,
     2727747.36449861,
@@ -21303,10 +21349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2727994.13847253,
     2728009.03020491,
     2728023.83233828,
+    // 2757
     2728038.58678493,
     2728053.31022948,
     2728068.04934795,
-    // 2756
     2728082.82160813,
     2728097.67093961,
     2728112.60814945,
@@ -21328,10 +21374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2728359.38076343,
     2728374.26623317,
     2728389.07533596,
+    // 2758
     2728403.82437139,
     2728418.55544046,
     2728433.28959888,
-    // 2757
     2728448.06959587,
     2728462.91379335,
     2728477.85837071,
@@ -21353,10 +21399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2728724.62346012,
     2728739.51202988,
     2728754.31837414,
+    // 2759
     2728769.07086448,
     2728783.79951954,
     2728798.53701892,
-    // 2758
     2728813.31448322,
     2728828.16155290,
     2728843.10282642,
@@ -21378,10 +21424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2729089.86802306,
     2729104.75948439,
     2729119.56364729,
+    // 2760
     2729134.31811284,
     2729149.04386218,
     2729163.78328808,
-    // 2759
     2729178.55781526,
     2729193.40718723,
     2729208.34605557,
@@ -21403,10 +21449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2729455.11391265,
     2729469.99872019,
     2729484.80823791,
+    // 2761
     2729499.55592604,
     2729514.28702827,
     2729529.01973262,
-    // 2760
     2729543.80005321,
     2729558.64320747,
     2729573.58856876,
@@ -21428,10 +21474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2729820.35459674,
     2729835.24578309,
     2729850.04987183,
+    // 2762
     2729864.80410886,
     2729879.52988292,
     2729894.26886364,
-    // 2761
     2729909.04368915,
     2729923.89286116,
     2729938.83241460,
@@ -21453,10 +21499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2730185.59405637,
     2730200.48406808,
     2730215.29138015,
+    // 2763
     2730230.04471588,
     2730244.77358380,
     2730259.51163252,
-    // 2762
     2730274.28880981,
     2730289.13639785,
     2730304.07761853,
@@ -21478,10 +21524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2730550.83933161,
     2730565.72554601,
     2730580.53531217,
+    // 2764
     2730595.28465856,
     2730610.01596385,
     2730624.74985713,
-    // 2763
     2730639.52970779,
     2730654.37318017,
     2730669.31710425,
@@ -21503,10 +21549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2730916.08142667,
     2730930.97335933,
     2730945.77598661,
+    // 2765
     2730960.53115412,
     2730975.25560883,
     2730989.99572601,
-    // 2764
     2731004.76910994,
     2731019.61922502,
     2731034.55699186,
@@ -21528,10 +21574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2731281.32413822,
     2731296.21014547,
     2731311.01791784,
+    // 2766
     2731325.76744537,
     2731340.49735364,
     2731355.23237979,
-    // 2765
     2731370.01161288,
     2731384.85708234,
     2731399.80116628,
@@ -21553,10 +21599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2731646.56851576,
     2731661.45594725,
     2731676.26309797,
+    // 2767
     2731691.01359612,
     2731705.74239881,
     2731720.47750542,
-    // 2766
     2731735.25502863,
     2731750.09991226,
     2731765.04165583,
@@ -21578,10 +21624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2732011.80989171,
     2732026.70277230,
     2732041.50646329,
+    // 2768
     2732056.26140003,
     2732070.98565258,
     2732085.72451767,
-    // 2767
     2732100.49677056,
     2732115.34507923,
     2732130.28151605,
@@ -21603,10 +21649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2732377.05328434,
     2732391.93937925,
     2732406.74951889,
+    // 2769
     2732421.49790548,
     2732436.22880152,
     2732450.96118892,
-    // 2768
     2732465.74014915,
     2732480.58192889,
     2732495.52506928,
@@ -21628,10 +21674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2732742.29158972,
     2732757.18258437,
     2732771.98849488,
+    // 2770
     2732786.74232093,
     2732801.46950442,
     2732816.20742453,
-    // 2769
     2732830.98283123,
     2732845.82996063,
     2732860.76903645,
@@ -21653,10 +21699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2733107.52823495,
     2733122.42002294,
     2733137.22661234,
+    // 2771
     2733151.98147856,
     2733166.70942546,
     2733181.44871833,
-    // 2770
     2733196.22460968,
     2733211.07293486,
     2733226.01217304,
@@ -21678,10 +21724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2733472.77391248,
     2733487.66034677,
     2733502.47100829,
+    // 2772
     2733517.21984159,
     2733531.95146106,
     2733546.68449471,
-    // 2771
     2733561.46437890,
     2733576.30681307,
     2733591.25059312,
@@ -21703,10 +21749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2733838.01465835,
     2733852.90762708,
     2733867.71153734,
+    // 2773
     2733882.46660801,
     2733897.19130215,
     2733911.93036417,
-    // 2772
     2733926.70338022,
     2733941.55204611,
     2733956.48927303,
@@ -21728,10 +21774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2734203.25441714,
     2734218.14359753,
     2734232.95193948,
+    // 2774
     2734247.70374049,
     2734262.43306992,
     2734277.16913813,
-    // 2773
     2734291.94658890,
     2734306.79214452,
     2734321.73377242,
@@ -21753,10 +21799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2734568.49845617,
     2734583.38667347,
     2734598.19675018,
+    // 2775
     2734612.94774723,
     2734627.67878700,
     2734642.41338756,
-    // 2774
     2734657.19194137,
     2734672.03508738,
     2734686.97677560,
@@ -21778,10 +21824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2734933.73889259,
     2734948.63307278,
     2734963.43754176,
+    // 2776
     2734978.19409251,
     2734992.91922255,
     2735007.65942837,
-    // 2775
     2735022.43204619,
     2735037.28089347,
     2735052.21669724,
@@ -21803,10 +21849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2735298.98538748,
     2735313.87215834,
     2735328.68188144,
+    // 2777
     2735343.43121887,
     2735358.16201324,
     2735372.89561792,
-    // 2776
     2735387.67456654,
     2735402.51747526,
     2735417.46029586,
@@ -21828,10 +21874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2735664.22936209,
     2735679.11887625,
     2735693.92545934,
+    // 2778
     2735708.67742737,
     2735723.40516536,
     2735738.14133195,
-    // 2777
     2735752.91756438,
     2735767.76330117,
     2735782.70351181,
@@ -21853,10 +21899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2736029.46942377,
     2736044.36261203,
     2736059.16804365,
+    // 2779
     2736073.92326595,
     2736088.64917802,
     2736103.38816922,
-    // 2778
     2736118.16174450,
     2736133.00977316,
     2736147.94695658,
@@ -21878,10 +21924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2736394.71881750,
     2736409.60617491,
     2736424.41757401,
+    // 2780
     2736439.16624864,
     2736453.89739042,
     2736468.62911290,
-    // 2779
     2736483.40748132,
     2736498.24790848,
     2736513.18986216,
@@ -21903,10 +21949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2736759.96121917,
     2736774.85483731,
     2736789.66064146,
+    // 2781
     2736804.41571308,
     2736819.14141555,
     2736833.87931807,
-    // 2780
     2736848.65216198,
     2736863.49845731,
     2736878.43450377,
@@ -21928,10 +21974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2737125.20106263,
     2737140.09274213,
     2737154.90108310,
+    // 2782
     2737169.65509444,
     2737184.38395962,
     2737199.12152472,
-    // 2781
     2737213.89756804,
     2737228.74352705,
     2737243.68253689,
@@ -21953,10 +21999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2737490.44678207,
     2737505.33510266,
     2737520.14684140,
+    // 2783
     2737534.89770479,
     2737549.63013779,
     2737564.36431075,
-    // 2782
     2737579.14381852,
     2737593.98596043,
     2737608.92788362,
@@ -21978,10 +22024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2737855.68800132,
     2737870.58319477,
     2737885.38834281,
+    // 2784
     2737900.14518544,
     2737914.87047108,
     2737929.61052403,
-    // 2783
     2737944.38305687,
     2737959.23152144,
     2737974.16694145,
@@ -22003,10 +22049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2738220.93197742,
     2738235.82123443,
     2738250.63113896,
+    // 2785
     2738265.38194155,
     2738280.11189237,
     2738294.84610133,
-    // 2784
     2738309.62353645,
     2738324.46665227,
     2738339.40778858,
@@ -22028,10 +22074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2738586.17314079,
     2738601.06340782,
     2738615.87291045,
+    // 2786
     2738630.62495418,
     2738645.35464263,
     2738660.08977328,
-    // 2785
     2738674.86685189,
     2738689.71060705,
     2738704.65095359,
@@ -22053,10 +22099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2738951.40764924,
     2738966.30311039,
     2738981.10946844,
+    // 2787
     2738995.86694111,
     2739010.59336834,
     2739025.33380106,
-    // 2786
     2739040.10689758,
     2739054.95520885,
     2739069.89079521,
@@ -22078,10 +22124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2739316.65302245,
     2739331.54112442,
     2739346.35303341,
+    // 2788
     2739361.10292469,
     2739375.83485561,
     2739390.56781400,
-    // 2787
     2739405.34665211,
     2739420.18772722,
     2739435.12935874,
@@ -22103,10 +22149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2739681.89444459,
     2739696.78651689,
     2739711.59300743,
+    // 2789
     2739726.34689184,
     2739741.07372367,
     2739755.81083521,
-    // 2788
     2739770.58515319,
     2739785.43079016,
     2739800.36822787,
@@ -22128,10 +22174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2740047.13346030,
     2740062.02566015,
     2740076.83187260,
+    // 2790
     2740091.58593450,
     2740106.31251136,
     2740121.05019603,
-    // 2789
     2740135.82427789,
     2740150.67081907,
     2740165.60834266,
@@ -22153,10 +22199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2740412.37982298,
     2740427.26769889,
     2740442.07946959,
+    // 2791
     2740456.82875354,
     2740471.56017576,
     2740486.29206852,
-    // 2790
     2740501.07017241,
     2740515.91007126,
     2740530.85099674,
@@ -22178,10 +22224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2740777.62093023,
     2740792.51662195,
     2740807.32256960,
+    // 2792
     2740822.07872557,
     2740836.80350440,
     2740851.54148242,
-    // 2791
     2740866.31239357,
     2740881.15793095,
     2740896.09130834,
@@ -22203,10 +22249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2741142.86275307,
     2741157.75445883,
     2741172.56424387,
+    // 2793
     2741187.31672586,
     2741202.04552049,
     2741216.78018527,
-    // 2792
     2741231.55510199,
     2741246.39737086,
     2741261.33488177,
@@ -22228,10 +22274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2741508.10514272,
     2741522.99569296,
     2741537.80751447,
+    // 2794
     2741552.55948704,
     2741567.29093010,
     2741582.02520881,
-    // 2793
     2741596.80302330,
     2741611.64471235,
     2741626.58450504,
@@ -22253,10 +22299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2741873.34149026,
     2741888.23846641,
     2741903.04539505,
+    // 2795
     2741917.80392635,
     2741932.53051140,
     2741947.27150926,
-    // 2794
     2741962.04425195,
     2741976.89243641,
     2741991.82681601,
@@ -22278,10 +22324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2742238.58961608,
     2742253.47993486,
     2742268.29228063,
+    // 2796
     2742283.04342673,
     2742297.77498505,
     2742312.50857109,
-    // 2795
     2742327.28655005,
     2742342.12786248,
     2742357.06830109,
@@ -22303,10 +22349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2742603.83405807,
     2742618.72723236,
     2742633.53670996,
+    // 2797
     2742648.29057549,
     2742663.01917375,
     2742677.75510071,
-    // 2796
     2742692.53021534,
     2742707.37393597,
     2742722.31168858,
@@ -22328,10 +22374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2742969.07207659,
     2742983.96785749,
     2742998.77549373,
+    // 2798
     2743013.53253695,
     2743028.25957946,
     2743042.99900249,
-    // 2797
     2743057.77229175,
     2743072.61935421,
     2743087.55505867,
@@ -22353,10 +22399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2743334.31961149,
     2743349.20935604,
     2743364.02344539,
+    // 2799
     2743378.77473111,
     2743393.50816776,
     2743408.24144405,
-    // 2798
     2743423.02056485,
     2743437.86070406,
     2743452.80144834,
@@ -22378,10 +22424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2743699.56208683,
     2743714.45770796,
     2743729.26546816,
+    // 2800
     2743744.02220187,
     2743758.74927069,
     2743773.48799088,
-    // 2799
     2743788.26112387,
     2743803.10693952,
     2743818.04181391,
@@ -22403,10 +22449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2744064.80633506,
     2744079.69890482,
     2744094.50732834,
+    // 2801
     2744109.26111386,
     2744123.98911135,
     2744138.72565692,
-    // 2800
     2744153.50027138,
     2744168.34474750,
     2744183.28202192,
@@ -22428,10 +22474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2744430.05341143,
     2744444.94247847,
     2744459.75425255,
+    // 2802
     2744474.50441190,
     2744489.23571325,
     2744503.96840053,
-    // 2801
     2744518.74649418,
     2744533.58715209,
     2744548.52786138,
@@ -22453,10 +22499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2744795.29081197,
     2744810.18766418,
     2744824.99408386,
+    // 2803
     2744839.75154998,
     2744854.47681465,
     2744869.21611007,
-    // 2802
     2744883.98731528,
     2744898.83389474,
     2744913.76715527,
@@ -22478,10 +22524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2745160.53732449,
     2745175.42914381,
     2745190.24072231,
+    // 2804
     2745204.99245803,
     2745219.72216843,
     2745234.45525807,
-    // 2803
     2745249.23041757,
     2745264.07054495,
     2745279.00789657,
@@ -22503,10 +22549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2745525.77978037,
     2745540.67254089,
     2745555.48381507,
+    // 2805
     2745570.23671510,
     2745584.96632252,
     2745599.70030316,
-    // 2804
     2745614.47532670,
     2745629.31601215,
     2745644.25275043,
@@ -22528,10 +22574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2745891.01345579,
     2745905.91057298,
     2745920.71809765,
+    // 2806
     2745935.47626795,
     2745950.20283550,
     2745964.94280965,
-    // 2805
     2745979.71490283,
     2745994.56157648,
     2746009.49503747,
@@ -22553,10 +22599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2746256.25938164,
     2746271.14995097,
     2746285.96403051,
+    // 2807
     2746300.71560257,
     2746315.44857727,
     2746330.18178998,
-    // 2806
     2746344.96003925,
     2746359.79966957,
     2746374.73900646,
@@ -22578,10 +22624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2746621.49979360,
     2746636.39539444,
     2746651.20466301,
+    // 2808
     2746665.96032320,
     2746680.68798817,
     2746695.42491713,
-    // 2807
     2746710.19819025,
     2746725.04187356,
     2746739.97668946,
@@ -22603,10 +22649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2746986.73919127,
     2747001.63494617,
     2747016.44368106,
+    // 2809
     2747031.19944321,
     2747045.92644493,
     2747060.66365762,
-    // 2808
     2747075.43618341,
     2747090.28047774,
     2747105.21509916,
@@ -22628,10 +22674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2747351.98246814,
     2747366.87331127,
     2747381.68765692,
+    // 2810
     2747396.43891732,
     2747411.17169438,
     2747425.90421966,
-    // 2809
     2747440.68237471,
     2747455.52155681,
     2747470.46125843,
@@ -22653,10 +22699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2747717.21641478,
     2747732.11466925,
     2747746.92346942,
+    // 2811
     2747761.68236219,
     2747776.40967704,
     2747791.14963055,
-    // 2810
     2747805.92190032,
     2747820.76788579,
     2747835.70090713,
@@ -22678,10 +22724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2748082.46181886,
     2748097.35558613,
     2748112.16705345,
+    // 2812
     2748126.92113212,
     2748141.65096123,
     2748156.38641811,
-    // 2811
     2748171.16142009,
     2748186.00334978,
     2748200.93973715,
@@ -22703,10 +22749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2748447.70808236,
     2748462.59972658,
     2748477.41210468,
+    // 2813
     2748492.16404826,
     2748506.89503566,
     2748521.62841295,
-    // 2812
     2748536.40510905,
     2748551.24535454,
     2748566.18374433,
@@ -22728,10 +22774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2748812.94624722,
     2748827.84368636,
     2748842.65055856,
+    // 2814
     2748857.40847134,
     2748872.13402844,
     2748886.87363269,
-    // 2813
     2748901.64488839,
     2748916.49149897,
     2748931.42449837,
@@ -22753,10 +22799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2749178.19591557,
     2749193.08788530,
     2749207.90152863,
+    // 2815
     2749222.65340859,
     2749237.38487488,
     2749252.11756211,
-    // 2814
     2749266.89375957,
     2749281.73270233,
     2749296.67022503,
@@ -22778,10 +22824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2749543.44107822,
     2749558.33677252,
     2749573.14816979,
+    // 2816
     2749587.90306239,
     2749602.63174774,
     2749617.36670476,
-    // 2815
     2749632.13987088,
     2749646.98065449,
     2749661.91482841,
@@ -22803,10 +22849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2749908.68184889,
     2749923.57981352,
     2749938.38870387,
+    // 2817
     2749953.14629436,
     2749967.87281703,
     2749982.61093096,
-    // 2816
     2749997.38193874,
     2750012.22598604,
     2750027.15802500,
@@ -22828,10 +22874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2750273.92873702,
     2750288.82030400,
     2750303.63560842,
+    // 2818
     2750318.38751102,
     2750333.12102783,
     2750347.85389341,
-    // 2817
     2750362.63220311,
     2750377.47098269,
     2750392.40993129,
@@ -22853,10 +22899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2750639.16682926,
     2750654.06496482,
     2750668.87493370,
+    // 2819
     2750683.63325888,
     2750698.36143362,
     2750713.10055665,
-    // 2818
     2750727.87351553,
     2750742.71837986,
     2750757.65171975,
@@ -22878,10 +22924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2751004.41272507,
     2751019.30882534,
     2751034.11970333,
+    // 2820
     2751048.87510031,
     2751063.60347397,
     2751078.33960925,
-    // 2819
     2751093.11274093,
     2751107.95514504,
     2751122.88958527,
@@ -22903,10 +22949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2751369.65851099,
     2751384.55112078,
     2751399.36569524,
+    // 2821
     2751414.11762881,
     2751428.84963048,
     2751443.58187778,
-    // 2820
     2751458.35856663,
     2751473.19691648,
     2751488.13487363,
@@ -22928,10 +22974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2751734.89276077,
     2751749.79196881,
     2751764.60064546,
+    // 2822
     2751779.35987250,
     2751794.08639881,
     2751808.82617796,
-    // 2821
     2751823.59719493,
     2751838.44277453,
     2751853.37451099,
@@ -22953,10 +22999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2752100.13708059,
     2752115.03123340,
     2752129.84529786,
+    // 2823
     2752144.59950168,
     2752159.33118136,
     2752174.06568680,
-    // 2822
     2752188.84128421,
     2752203.68102195,
     2752218.61689470,
@@ -22978,10 +23024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2752465.38037234,
     2752480.27513443,
     2752495.08822161,
+    // 2824
     2752509.84255138,
     2752524.57318692,
     2752539.30766742,
-    // 2823
     2752554.08261703,
     2752568.92249236,
     2752583.85779400,
@@ -23003,10 +23049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2752830.61804249,
     2752845.51618709,
     2752860.32389675,
+    // 2825
     2752875.08169970,
     2752889.80723709,
     2752904.54582694,
-    // 2824
     2752919.31617756,
     2752934.16090070,
     2752949.09232614,
@@ -23028,10 +23074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2753195.86389979,
     2753210.75559889,
     2753225.57005749,
+    // 2826
     2753240.32133553,
     2753255.05347360,
     2753269.78553098,
-    // 2825
     2753284.56246138,
     2753299.40067950,
     2753314.33860266,
@@ -23053,10 +23099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2753561.10113124,
     2753575.99889200,
     2753590.81003022,
+    // 2827
     2753605.56680030,
     2753620.29493260,
     2753635.03143911,
-    // 2826
     2753649.80361590,
     2753664.64543974,
     2753679.57799949,
@@ -23078,10 +23124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2753926.34313467,
     2753941.24171667,
     2753956.05238877,
+    // 2828
     2753970.80935011,
     2753985.53636753,
     2754000.27275501,
-    // 2827
     2754015.04325169,
     2754029.88479195,
     2754044.81579194,
@@ -23103,10 +23149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2754291.58798726,
     2754306.48152153,
     2754321.29788606,
+    // 2829
     2754336.05031741,
     2754350.78334858,
     2754365.51515908,
-    // 2828
     2754380.29165540,
     2754395.12827528,
     2754410.06482374,
@@ -23128,10 +23174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2754656.82177663,
     2754671.72171532,
     2754686.53196704,
+    // 2830
     2754701.29172233,
     2754716.01951856,
     2754730.75931611,
-    // 2829
     2754745.53097031,
     2754760.37568817,
     2754775.30702465,
@@ -23153,10 +23199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2755022.06770818,
     2755036.96423018,
     2755051.77802712,
+    // 2831
     2755066.53399615,
     2755081.26490409,
     2755096.00080395,
-    // 2830
     2755110.77527886,
     2755125.61603569,
     2755140.55034512,
@@ -23178,10 +23224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2755387.31408238,
     2755402.20942164,
     2755417.02473941,
+    // 2832
     2755431.77864955,
     2755446.51058880,
     2755461.24391878,
-    // 2831
     2755476.01963127,
     2755490.85801814,
     2755505.79391002,
@@ -23203,10 +23249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2755752.55274766,
     2755767.45335540,
     2755782.26263484,
+    // 2833
     2755797.02209280,
     2755811.74816261,
     2755826.48729438,
-    // 2832
     2755841.25718994,
     2755856.10164448,
     2755871.03204732,
@@ -23228,10 +23274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2756117.79969645,
     2756132.69410765,
     2756147.50993734,
+    // 2834
     2756162.26365861,
     2756176.99650371,
     2756191.73009290,
-    // 2833
     2756206.50659031,
     2756221.34521399,
     2756236.28172824,
@@ -23253,10 +23299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2756483.03940499,
     2756497.93716329,
     2756512.75096514,
+    // 2835
     2756527.50810796,
     2756542.23893344,
     2756556.97552952,
-    // 2834
     2756571.74978604,
     2756586.59083953,
     2756601.52453251,
@@ -23278,10 +23324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2756848.28356232,
     2756863.18309972,
     2756877.99312153,
+    // 2836
     2756892.75163558,
     2756907.47854951,
     2756922.21683281,
-    // 2835
     2756936.98739167,
     2756951.83067184,
     2756966.76131746,
@@ -23303,10 +23349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2757213.53379748,
     2757228.42640756,
     2757243.24205310,
+    // 2837
     2757257.99363647,
     2757272.72623014,
     2757287.45773457,
-    // 2836
     2757302.23431218,
     2757317.07117198,
     2757332.00822699,
@@ -23328,10 +23374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2757578.77189238,
     2757593.67055993,
     2757608.48067831,
+    // 2838
     2757623.23837856,
     2757637.96554074,
     2757652.70308644,
-    // 2837
     2757667.47431095,
     2757682.31720596,
     2757697.24875539,
@@ -23353,10 +23399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2757944.01866175,
     2757958.91683914,
     2757973.72927274,
+    // 2839
     2757988.48563294,
     2758003.21392343,
     2758017.94915321,
-    // 2838
     2758032.72025233,
     2758047.56000644,
     2758062.49103652,
@@ -23378,10 +23424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2758309.26430586,
     2758324.15974898,
     2758338.97642291,
+    // 2840
     2758353.72953773,
     2758368.46169116,
     2758383.19300446,
-    // 2839
     2758397.96766448,
     2758412.80295076,
     2758427.73706278,
@@ -23403,10 +23449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2758674.49978763,
     2758689.40137109,
     2758704.21170863,
+    // 2841
     2758718.97172239,
     2758733.69819200,
     2758748.43695811,
-    // 2840
     2758763.20615119,
     2758778.04899984,
     2758792.97743154,
@@ -23428,10 +23474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2759039.74349967,
     2759054.63973915,
     2759069.45530307,
+    // 2842
     2759084.21049592,
     2759098.94266376,
     2759113.67726849,
-    // 2841
     2759128.45243692,
     2759143.29127061,
     2759158.22552059,
@@ -23453,10 +23499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2759404.98175169,
     2759419.87939886,
     2759434.69505297,
+    // 2843
     2759449.45128220,
     2759464.18329583,
     2759478.91847437,
-    // 2842
     2759493.69347704,
     2759508.53270138,
     2759523.46670758,
@@ -23478,10 +23524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2759770.22155837,
     2759785.12349794,
     2759799.93405807,
+    // 2844
     2759814.69381038,
     2759829.42012273,
     2759844.15861310,
-    // 2843
     2759858.92781641,
     2759873.77072816,
     2759888.69964241,
@@ -23503,10 +23549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2760135.46661969,
     2760150.36186608,
     2760165.17919397,
+    // 2845
     2760179.93247077,
     2760194.66552665,
     2760209.39747075,
-    // 2844
     2760224.17323079,
     2760239.00950710,
     2760253.94498212,
@@ -23528,10 +23574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2760500.70059612,
     2760515.60043951,
     2760530.41385275,
+    // 2846
     2760545.17247316,
     2760559.90222148,
     2760574.63962885,
-    // 2845
     2760589.41217926,
     2760604.25357791,
     2760619.18522216,
@@ -23553,10 +23599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2760865.94234719,
     2760880.84305867,
     2760895.65597616,
+    // 2847
     2760910.41521980,
     2760925.14405280,
     2760939.88184573,
-    // 2846
     2760954.65284351,
     2760969.49422927,
     2760984.42403476,
@@ -23578,10 +23624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2761231.18932362,
     2761246.08447351,
     2761260.90212119,
+    // 2848
     2761275.65549602,
     2761290.38908522,
     2761305.12105515,
-    // 2847
     2761319.89723081,
     2761334.73302820,
     2761349.66824599,
@@ -23603,10 +23649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2761596.42810237,
     2761611.32869119,
     2761626.13896108,
+    // 2849
     2761640.89794624,
     2761655.62454529,
     2761670.36262062,
-    // 2848
     2761685.13241690,
     2761699.97503512,
     2761714.90441785,
@@ -23628,10 +23674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2761961.67508945,
     2761976.57244555,
     2761991.38635281,
+    // 2850
     2762006.14183388,
     2762020.87170126,
     2762035.60629496,
-    // 2849
     2762050.37913956,
     2762065.21825526,
     2762080.15071403,
@@ -23653,10 +23699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2762326.91807552,
     2762341.81541996,
     2762356.63239498,
+    // 2851
     2762371.38724821,
     2762386.11942825,
     2762400.85216919,
-    // 2850
     2762415.62648319,
     2762430.46277481,
     2762445.39603826,
@@ -23678,10 +23724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2762692.15977293,
     2762707.06303562,
     2762721.87411488,
+    // 2852
     2762736.63451737,
     2762751.36043829,
     2762766.09846393,
-    // 2851
     2762780.86615237,
     2762795.70753958,
     2762810.63411996,
@@ -23703,10 +23749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2763057.40729146,
     2763072.30423697,
     2763087.12182141,
+    // 2853
     2763101.87648415,
     2763116.60930936,
     2763131.34198333,
-    // 2852
     2763146.11659143,
     2763160.95258497,
     2763175.88585068,
@@ -23728,10 +23774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2763422.64615282,
     2763437.54558933,
     2763452.36081748,
+    // 2854
     2763467.11869596,
     2763481.84992011,
     2763496.58622290,
-    // 2853
     2763511.35976076,
     2763526.19943672,
     2763541.13133340,
@@ -23753,10 +23799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2763787.88997555,
     2763802.79259729,
     2763817.60508906,
+    // 2855
     2763832.36540657,
     2763847.09319084,
     2763861.83157131,
-    // 2854
     2763876.60121549,
     2763891.44291766,
     2763906.37107577,
@@ -23778,10 +23824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2764153.13931590,
     2764168.03611020,
     2764182.85503679,
+    // 2856
     2764197.60887224,
     2764212.34254772,
     2764227.07402475,
-    // 2855
     2764241.84945347,
     2764256.68425030,
     2764271.61842500,
@@ -23803,10 +23849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2764518.37675641,
     2764533.27874988,
     2764548.09168680,
+    // 2857
     2764562.85115624,
     2764577.57924401,
     2764592.31649892,
-    // 2856
     2764607.08657981,
     2764621.92733686,
     2764636.85624368,
@@ -23828,10 +23874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2764883.61926673,
     2764898.51997319,
     2764913.33478668,
+    // 2858
     2764928.09336901,
     2764942.82350807,
     2764957.56024131,
-    // 2857
     2764972.33214012,
     2764987.17209962,
     2765002.10237179,
@@ -23853,10 +23899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2765248.86042443,
     2765263.75819364,
     2765278.57740899,
+    // 2859
     2765293.33302131,
     2765308.06749225,
     2765322.80073980,
-    // 2858
     2765337.57671344,
     2765352.41260393,
     2765367.34645050,
@@ -23878,10 +23924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2765614.09990596,
     2765629.00308045,
     2765643.81456952,
+    // 2860
     2765658.57534562,
     2765673.30215337,
     2765688.04088962,
-    // 2859
     2765702.80964972,
     2765717.65166378,
     2765732.57890345,
@@ -23903,10 +23949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2765979.34680341,
     2765994.24432598,
     2766009.06039315,
+    // 2861
     2766023.81543112,
     2766038.54676779,
     2766053.28008076,
-    // 2860
     2766068.05353814,
     2766082.89058427,
     2766097.82304896,
@@ -23928,10 +23974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2766344.58526915,
     2766359.48369916,
     2766374.29984826,
+    // 2862
     2766389.05580858,
     2766403.78717531,
     2766418.52105880,
-    // 2861
     2766433.29451456,
     2766448.13189127,
     2766463.06409190,
@@ -23953,10 +23999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2766709.82610149,
     2766724.73025296,
     2766739.54257324,
+    // 2863
     2766754.30344584,
     2766769.02993585,
     2766783.76770778,
-    // 2862
     2766798.53512237,
     2766813.37554473,
     2766828.30118825,
@@ -23978,10 +24024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2767075.07247696,
     2767089.97059942,
     2767104.79005814,
+    // 2864
     2767119.54467285,
     2767134.27805390,
     2767149.00933010,
-    // 2863
     2767163.78327863,
     2767178.61680696,
     2767193.54860534,
@@ -24003,10 +24049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2767440.30869554,
     2767455.21048792,
     2767470.02535807,
+    // 2865
     2767484.78450059,
     2767499.51418598,
     2767514.25054316,
-    // 2864
     2767529.02144264,
     2767543.86031693,
     2767558.78898366,
@@ -24028,10 +24074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2767805.55029237,
     2767820.45277855,
     2767835.26692982,
+    // 2866
     2767850.02703584,
     2767864.75632664,
     2767879.49431707,
-    // 2865
     2767894.26499833,
     2767909.10568435,
     2767924.03401374,
@@ -24053,10 +24099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2768170.79336577,
     2768185.69155207,
     2768200.51166114,
+    // 2867
     2768215.26689098,
     2768230.00163346,
     2768244.73411114,
-    // 2866
     2768259.51004652,
     2768274.34497432,
     2768289.27861398,
@@ -24078,10 +24124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2768536.03342312,
     2768550.93767821,
     2768565.75076760,
+    // 2868
     2768580.51152764,
     2768595.23886793,
     2768609.97659992,
-    // 2867
     2768624.74516979,
     2768639.58570691,
     2768654.51248214,
@@ -24103,10 +24149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2768901.27924644,
     2768916.17997799,
     2768930.99660308,
+    // 2869
     2768945.75402088,
     2768960.48481280,
     2768975.21931209,
-    // 2868
     2768989.99102469,
     2769004.82827821,
     2769019.75829772,
@@ -24128,10 +24174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2769266.51833935,
     2769281.41752531,
     2769296.23654188,
+    // 2870
     2769310.99310865,
     2769325.72683451,
     2769340.46051724,
-    // 2869
     2769355.23525096,
     2769370.07127716,
     2769385.00365810,
@@ -24153,10 +24199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2769631.76054284,
     2769646.66585277,
     2769661.47900538,
+    // 2871
     2769676.24133154,
     2769690.96876607,
     2769705.70781255,
-    // 2870
     2769720.47577034,
     2769735.31676178,
     2769750.24203607,
@@ -24178,10 +24224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2769997.01016879,
     2770011.90883610,
     2770026.72753158,
+    // 2872
     2770041.48286969,
     2770056.21580003,
     2770070.94816203,
-    // 2871
     2770085.72188835,
     2770100.55662717,
     2770115.48809278,
@@ -24203,10 +24249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2770362.25222052,
     2770377.15260394,
     2770391.96816289,
+    // 2873
     2770406.72541027,
     2770421.45556109,
     2770436.19007688,
-    // 2872
     2770450.96169187,
     2770465.79910762,
     2770480.72890414,
@@ -24228,10 +24274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2770727.49669633,
     2770742.40059424,
     2770757.21369639,
+    // 2874
     2770771.97406306,
     2770786.70131943,
     2770801.43876944,
-    // 2873
     2770816.20699846,
     2770831.04705212,
     2770845.97313984,
@@ -24253,10 +24299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2771092.74175875,
     2771107.64106643,
     2771122.46196461,
+    // 2875
     2771137.21721927,
     2771151.95147682,
     2771166.68274075,
-    // 2874
     2771181.45700774,
     2771196.28990309,
     2771211.22144089,
@@ -24278,10 +24324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2771457.98214883,
     2771472.88698646,
     2771487.70209684,
+    // 2876
     2771502.46279570,
     2771517.19125026,
     2771531.92773769,
-    // 2875
     2771546.69615601,
     2771561.53417806,
     2771576.45968583,
@@ -24303,10 +24349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2771823.22543217,
     2771838.12866243,
     2771852.94529090,
+    // 2877
     2771867.70494500,
     2771882.43528205,
     2771897.17134762,
-    // 2876
     2771911.94168622,
     2771926.77944189,
     2771941.70689695,
@@ -24328,10 +24374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2772188.46489906,
     2772203.36436932,
     2772218.18502763,
+    // 2878
     2772232.94156049,
     2772247.67662557,
     2772262.40994028,
-    // 2877
     2772277.18553241,
     2772292.02056852,
     2772306.95303700,
@@ -24353,10 +24399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2772553.70495575,
     2772568.61129645,
     2772583.42536642,
+    // 2879
     2772598.18795958,
     2772612.91570275,
     2772627.65451434,
-    // 2878
     2772642.42246296,
     2772657.26294068,
     2772672.18789262,
@@ -24378,10 +24424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2772918.95120460,
     2772933.85263842,
     2772948.67170702,
+    // 2880
     2772963.42885823,
     2772978.16114539,
     2772992.89437222,
-    // 2879
     2773007.66663841,
     2773022.50167677,
     2773037.43132461,
@@ -24403,10 +24449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2773284.18956170,
     2773299.09073780,
     2773313.90930070,
+    // 2881
     2773328.66678583,
     2773343.39903412,
     2773358.13268580,
-    // 2880
     2773372.90520196,
     2773387.74069474,
     2773402.67055043,
@@ -24428,10 +24474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2773649.42799462,
     2773664.33399101,
     2773679.14810100,
+    // 2882
     2773693.91072610,
     2773708.63879214,
     2773723.37784474,
-    // 2881
     2773738.14598775,
     2773752.98650790,
     2773767.91133765,
@@ -24453,10 +24499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2774014.67038933,
     2774029.57045317,
     2774044.39175778,
+    // 2883
     2774059.14828575,
     2774073.88330101,
     2774088.61596748,
-    // 2882
     2774103.39072917,
     2774118.22453322,
     2774133.15578247,
@@ -24478,10 +24524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2774379.91135888,
     2774394.81451081,
     2774409.63023566,
+    // 2884
     2774424.38955220,
     2774439.11906302,
     2774453.85463662,
-    // 2883
     2774468.62454831,
     2774483.46192747,
     2774498.38897188,
@@ -24503,10 +24549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2774745.15526841,
     2774760.05906382,
     2774774.87364037,
+    // 2885
     2774789.63334518,
     2774804.36144508,
     2774819.09764758,
-    // 2884
     2774833.86609262,
     2774848.70449447,
     2774863.63057452,
@@ -24528,10 +24574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2775110.39776591,
     2775125.29709959,
     2775140.11785344,
+    // 2886
     2775154.87304744,
     2775169.60712518,
     2775184.33831302,
-    // 2885
     2775199.11243860,
     2775213.94527476,
     2775228.87659354,
@@ -24553,10 +24599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2775475.63849288,
     2775490.54531265,
     2775505.36040676,
+    // 2887
     2775520.12233872,
     2775534.84986440,
     2775549.58669528,
-    // 2886
     2775564.35332446,
     2775579.19098410,
     2775594.11415862,
@@ -24578,10 +24624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2775840.88503416,
     2775855.78910616,
     2775870.60819043,
+    // 2888
     2775885.36726778,
     2775900.09860712,
     2775914.83258921,
-    // 2887
     2775929.60258840,
     2775944.43709869,
     2775959.36334633,
@@ -24603,10 +24649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2776206.12617696,
     2776221.02765443,
     2776235.84842916,
+    // 2889
     2776250.60583555,
     2776265.33977292,
     2776280.07265203,
-    // 2888
     2776294.84593710,
     2776309.67961476,
     2776324.60909104,
@@ -24628,10 +24674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2776571.36725196,
     2776586.27469419,
     2776601.08949565,
+    // 2890
     2776615.85308899,
     2776630.58145360,
     2776645.32103649,
-    // 2889
     2776660.08905214,
     2776674.92952190,
     2776689.85349861,
@@ -24653,10 +24699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2776936.61329040,
     2776951.51571754,
     2776966.33744441,
+    // 2891
     2776981.09525973,
     2776995.82978024,
     2777010.56302418,
-    // 2890
     2777025.33672824,
     2777040.17075623,
     2777055.10064927,
@@ -24678,10 +24724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2777301.85638185,
     2777316.76067248,
     2777331.57947356,
+    // 2892
     2777346.33886059,
     2777361.07021318,
     2777375.80466302,
-    // 2891
     2777390.57537751,
     2777405.41084704,
     2777420.33818590,
@@ -24703,10 +24749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2777667.09896328,
     2777682.00616335,
     2777696.82215400,
+    // 2893
     2777711.58477376,
     2777726.31342772,
     2777741.05132130,
-    // 2892
     2777755.81903401,
     2777770.65784415,
     2777785.58207969,
@@ -24728,10 +24774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2778032.34035063,
     2778047.24142312,
     2778062.06423622,
+    // 2894
     2778076.82147110,
     2778091.55749851,
     2778106.29023655,
-    // 2893
     2778121.06539676,
     2778135.89865354,
     2778150.82978426,
@@ -24753,10 +24799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2778397.58179857,
     2778412.48829225,
     2778427.30512259,
+    // 2895
     2778442.06739120,
     2778456.79719691,
     2778471.53458713,
-    // 2894
     2778486.30346981,
     2778501.14128472,
     2778516.06598374,
@@ -24778,10 +24824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2778762.82853410,
     2778777.73318960,
     2778792.55058448,
+    // 2896
     2778807.31061215,
     2778822.04078560,
     2778836.77630464,
-    // 2895
     2778851.54567147,
     2778866.38214174,
     2778881.30792978,
@@ -24803,10 +24849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2779128.07196214,
     2779142.97200901,
     2779157.79258720,
+    // 2897
     2779172.54815947,
     2779187.28177891,
     2779202.01300590,
-    // 2896
     2779216.78636529,
     2779231.61896688,
     2779246.54915684,
@@ -24828,10 +24874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2779493.31253815,
     2779508.21977760,
     2779523.03423052,
+    // 2898
     2779537.79669031,
     2779552.52385862,
     2779567.26160464,
-    // 2897
     2779582.02807486,
     2779596.86665858,
     2779611.78935774,
@@ -24853,10 +24899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2779858.55553327,
     2779873.45949227,
     2779888.28036612,
+    // 2899
     2779903.03881546,
     2779917.77145360,
     2779932.50425986,
-    // 2898
     2779947.27508530,
     2779962.10794115,
     2779977.03456461,
@@ -24878,10 +24924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2780223.79617334,
     2780238.70004474,
     2780253.52061455,
+    // 2900
     2780268.27908536,
     2780283.01148530,
     2780297.74411972,
-    // 2899
     2780312.51481446,
     2780327.34738907,
     2780342.27372967,
@@ -24903,10 +24949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2780589.03522976,
     2780603.94365654,
     2780618.75947021,
+    // 2901
     2780633.52299402,
     2780648.25109946,
     2780662.98931709,
-    // 2900
     2780677.75584409,
     2780692.59413024,
     2780707.51623748,
@@ -24928,10 +24974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2780954.27493916,
     2780969.17704362,
     2780983.99979492,
+    // 2902
     2780998.75738470,
     2781013.49284372,
     2781028.22555944,
-    // 2901
     2781042.99976001,
     2781057.83262777,
     2781072.76234218,
@@ -24953,10 +24999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2781319.51621115,
     2781334.42306581,
     2781349.24179808,
+    // 2903
     2781364.00314960,
     2781378.73372750,
     2781393.46926450,
-    // 2902
     2781408.23829163,
     2781423.07381907,
     2781437.99839934,
@@ -24978,10 +25024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2781684.75897669,
     2781699.66699779,
     2781714.48493729,
+    // 2904
     2781729.24705725,
     2781743.97636602,
     2781758.71256603,
-    // 2903
     2781773.47985524,
     2781788.31609147,
     2781803.23917066,
@@ -25003,10 +25049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2782050.00007078,
     2782064.90225663,
     2782079.72558320,
+    // 2905
     2782094.48268633,
     2782109.21802490,
     2782123.94951003,
-    // 2904
     2782138.72313722,
     2782153.55460469,
     2782168.48389038,
@@ -25028,10 +25074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2782415.23966174,
     2782430.14827452,
     2782444.96532894,
+    // 2906
     2782459.72925827,
     2782474.45876461,
     2782489.19726110,
-    // 2905
     2782503.96507601,
     2782518.80314117,
     2782533.72582769,
@@ -25053,10 +25099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2782780.48328743,
     2782795.38930435,
     2782810.21010556,
+    // 2907
     2782824.97101833,
     2782839.70384823,
     2782854.43915591,
-    // 2906
     2782869.20993754,
     2782884.04476832,
     2782898.97057086,
@@ -25078,10 +25124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2783145.72974227,
     2783160.63262148,
     2783175.45426686,
+    // 2908
     2783190.21185023,
     2783204.94555916,
     2783219.67760873,
-    // 2907
     2783234.44986161,
     2783249.28202627,
     2783264.20992025,
@@ -25103,10 +25149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2783510.97340727,
     2783525.88218590,
     2783540.69753485,
+    // 2909
     2783555.46082387,
     2783570.18809476,
     2783584.92589289,
-    // 2908
     2783599.69173386,
     2783614.52987063,
     2783629.45167490,
@@ -25128,10 +25174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2783876.21759961,
     2783891.12130145,
     2783905.94371803,
+    // 2910
     2783920.70186431,
     2783935.43596156,
     2783950.16834438,
-    // 2909
     2783964.94052094,
     2783979.77276361,
     2783994.70049115,
@@ -25153,10 +25199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2784241.46211619,
     2784256.36896226,
     2784271.18981354,
+    // 2911
     2784285.95042579,
     2784300.68214243,
     2784315.41583754,
-    // 2910
     2784330.18491576,
     2784345.01766117,
     2784359.94166866,
@@ -25178,10 +25224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2784606.70573287,
     2784621.61579820,
     2784636.43392982,
+    // 2912
     2784651.19788361,
     2784665.92693175,
     2784680.66419220,
-    // 2911
     2784695.43027686,
     2784710.26646334,
     2784725.18719768,
@@ -25203,10 +25249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2784971.94905219,
     2784986.85191299,
     2785001.67592981,
+    // 2913
     2785016.43368593,
     2785031.16959313,
     2785045.90151107,
-    // 2912
     2785060.67522094,
     2785075.50643973,
     2785090.43495533,
@@ -25228,10 +25274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2785337.19037252,
     2785352.09867016,
     2785366.91685663,
+    // 2914
     2785381.68004145,
     2785396.41044469,
     2785411.14800038,
-    // 2913
     2785425.91662017,
     2785440.75354164,
     2785455.67672991,
@@ -25253,10 +25299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2785702.43263072,
     2785717.34100477,
     2785732.16123972,
+    // 2915
     2785746.92348923,
     2785761.65485022,
     2785776.39079685,
-    // 2914
     2785791.15965893,
     2785805.99489979,
     2785820.91869577,
@@ -25278,10 +25324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2786067.67656931,
     2786082.58043121,
     2786097.40410984,
+    // 2916
     2786112.16170438,
     2786126.89630489,
     2786141.62724060,
-    // 2915
     2786156.39937418,
     2786171.22968229,
     2786186.15702242,
@@ -25303,10 +25349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2786432.91452189,
     2786447.82481772,
     2786462.64195283,
+    // 2917
     2786477.40641956,
     2786492.13478379,
     2786506.87274620,
-    // 2916
     2786521.63859715,
     2786536.47576500,
     2786551.39658881,
@@ -25328,10 +25374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2786798.15349723,
     2786813.05935317,
     2786827.88202662,
+    // 2918
     2786842.64244722,
     2786857.37665882,
     2786872.11085253,
-    // 2917
     2786886.88238880,
     2786901.71547332,
     2786916.64149346,
@@ -25353,10 +25399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2787163.39521779,
     2787178.30097113,
     2787193.12328494,
+    // 2919
     2787207.88311439,
     2787222.61654409,
     2787237.34962194,
-    // 2918
     2787252.12035467,
     2787266.95221208,
     2787281.87737400,
@@ -25378,10 +25424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2787528.63682810,
     2787543.54713236,
     2787558.36419512,
+    // 2920
     2787573.12828973,
     2787587.85635483,
     2787602.59391201,
-    // 2919
     2787617.35931183,
     2787632.19600079,
     2787647.11616325,
@@ -25403,10 +25449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2787893.88005379,
     2787908.78337082,
     2787923.60657518,
+    // 2921
     2787938.36386774,
     2787953.09823710,
     2787967.82929568,
-    // 2920
     2787982.60132053,
     2787997.43184378,
     2788012.35905481,
@@ -25428,10 +25474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2788259.12122842,
     2788274.02940202,
     2788288.84912599,
+    // 2922
     2788303.61102306,
     2788318.34171706,
     2788333.07677395,
-    // 2921
     2788347.84478141,
     2788362.67859348,
     2788377.60101781,
@@ -25453,10 +25499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2788624.36188039,
     2788639.27287537,
     2788654.09316992,
+    // 2923
     2788668.85718810,
     2788683.58746561,
     2788698.32382063,
-    // 2922
     2788713.09022172,
     2788727.92462674,
     2788742.84498665,
@@ -25478,10 +25524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2788989.60620173,
     2789004.51118967,
     2789019.33667500,
+    // 2924
     2789034.09507727,
     2789048.83086856,
     2789063.56176073,
-    // 2923
     2789078.33394517,
     2789093.16293331,
     2789108.08904920,
@@ -25503,10 +25549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2789354.84662145,
     2789369.75751366,
     2789384.57630293,
+    // 2925
     2789399.34122742,
     2789414.07104112,
     2789428.80906010,
-    // 2924
     2789443.57582338,
     2789458.41224394,
     2789473.33297528,
@@ -25528,10 +25574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2789720.08799677,
     2789734.99621458,
     2789749.81858491,
+    // 2926
     2789764.58082774,
     2789779.31432855,
     2789794.05003744,
-    // 2925
     2789808.82059563,
     2789823.65487923,
     2789838.57952689,
@@ -25553,10 +25599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2790085.33468911,
     2790100.24115228,
     2790115.06575611,
+    // 2927
     2790129.82535450,
     2790144.56016177,
     2790159.29227854,
-    // 2926
     2790174.06380719,
     2790188.89433404,
     2790203.82006650,
@@ -25578,10 +25624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2790450.57781667,
     2790465.49034279,
     2790480.30890215,
+    // 2928
     2790495.07449400,
     2790509.80308397,
     2790524.54104237,
-    // 2927
     2790539.30602691,
     2790554.14228685,
     2790569.06148545,
@@ -25603,10 +25649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2790815.82154678,
     2790830.72744752,
     2790845.55175855,
+    // 2929
     2790860.31147390,
     2790875.04645641,
     2790889.77916808,
-    // 2928
     2790904.55082646,
     2790919.38199265,
     2790934.30792547,
@@ -25628,10 +25674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2791181.06490597,
     2791195.97285150,
     2791210.79503497,
+    // 2930
     2791225.55711723,
     2791240.29039086,
     2791255.02544163,
-    // 2929
     2791269.79553839,
     2791284.62856834,
     2791299.55219461,
@@ -25653,10 +25699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2791546.30672788,
     2791561.21838113,
     2791576.03779989,
+    // 2931
     2791590.80295552,
     2791605.53286431,
     2791620.27073917,
-    // 2930
     2791635.03703986,
     2791649.87297259,
     2791664.79295670,
@@ -25678,10 +25724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2791911.55442465,
     2791926.45858598,
     2791941.28313291,
+    // 2932
     2791956.04064232,
     2791970.77566760,
     2791985.50612627,
-    // 2931
     2792000.27805311,
     2792015.10716695,
     2792030.03351298,
@@ -25703,10 +25749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2792276.79755419,
     2792291.70720819,
     2792306.52596584,
+    // 2933
     2792321.28867658,
     2792336.01775282,
     2792350.75314998,
-    // 2932
     2792365.51921020,
     2792380.35332960,
     2792395.27395955,
@@ -25728,10 +25774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2792642.03642373,
     2792656.94639803,
     2792671.76754482,
+    // 2934
     2792686.53030971,
     2792701.26127798,
     2792715.99632381,
-    // 2933
     2792730.76344441,
     2792745.59655374,
     2792760.51772309,
@@ -25753,10 +25799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2793007.28104407,
     2793022.18797343,
     2793037.01412624,
+    // 2935
     2793051.77327813,
     2793066.50844015,
     2793081.23877602,
-    // 2934
     2793096.00924790,
     2793110.83673260,
     2793125.76047500,
@@ -25778,10 +25824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2793372.51961185,
     2793387.43308762,
     2793402.25281385,
+    // 2936
     2793417.01892211,
     2793431.74798647,
     2793446.48549995,
-    // 2935
     2793461.24986190,
     2793476.08438850,
     2793491.00168912,
@@ -25803,10 +25849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2793737.75903380,
     2793752.66703107,
     2793767.49118145,
+    // 2937
     2793782.25259903,
     2793796.98701487,
     2793811.72092690,
-    // 2936
     2793826.49137204,
     2793841.32291660,
     2793856.24667572,
@@ -25828,10 +25874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2794103.00118391,
     2794117.90926793,
     2794132.73353772,
+    // 2938
     2794147.49492489,
     2794162.22953002,
     2794176.96327214,
-    // 2937
     2794191.73413096,
     2794206.56532172,
     2794221.48918737,
@@ -25853,10 +25899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2794468.23887984,
     2794483.15315435,
     2794497.97353123,
+    // 2939
     2794512.74018268,
     2794527.46987465,
     2794542.20811558,
-    // 2938
     2794556.97331643,
     2794571.80889192,
     2794586.72724706,
@@ -25878,10 +25924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2794833.48285605,
     2794848.38992490,
     2794863.21629888,
+    // 2940
     2794877.97592526,
     2794892.71162086,
     2794907.44293220,
-    // 2939
     2794922.21416640,
     2794937.04295649,
     2794951.96768621,
@@ -25903,10 +25949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2795198.72282268,
     2795213.63360617,
     2795228.45581161,
+    // 2941
     2795243.21962133,
     2795257.95164814,
     2795272.68722988,
-    // 2940
     2795287.45509044,
     2795302.28805281,
     2795317.20922061,
@@ -25928,10 +25974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2795563.95982008,
     2795578.87221864,
     2795593.69392475,
+    // 2942
     2795608.45965289,
     2795623.19140198,
     2795637.92924761,
-    // 2941
     2795652.69662803,
     2795667.53166021,
     2795682.45196627,
@@ -25953,10 +25999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2795929.20732692,
     2795944.11386068,
     2795958.94063542,
+    // 2943
     2795973.69996863,
     2795988.43633208,
     2796003.16739718,
-    // 2942
     2796017.93937985,
     2796032.76765570,
     2796047.69269564,
@@ -25978,10 +26024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2796294.45156704,
     2796309.36389734,
     2796324.18353765,
+    // 2944
     2796338.94845311,
     2796353.67765796,
     2796368.41440113,
-    // 2943
     2796383.17951881,
     2796398.01386959,
     2796412.93243778,
@@ -26003,10 +26049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2796659.69592493,
     2796674.60510906,
     2796689.42758197,
+    // 2945
     2796704.18930019,
     2796718.92135641,
     2796733.65519630,
-    // 2944
     2796748.42325580,
     2796763.25501194,
     2796778.17695562,
@@ -26028,10 +26074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2797024.94316709,
     2797039.85093788,
     2797054.67648972,
+    // 2946
     2797069.43657429,
     2797084.17135131,
     2797098.90277027,
-    // 2945
     2797113.67292471,
     2797128.50128571,
     2797143.42436639,
@@ -26053,10 +26099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2797390.18347366,
     2797405.09893426,
     2797419.91990025,
+    // 2947
     2797434.68713892,
     2797449.41655121,
     2797464.15430897,
-    // 2946
     2797478.91814699,
     2797493.75216783,
     2797508.66832766,
@@ -26078,10 +26124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2797755.42972757,
     2797770.33845467,
     2797785.16484215,
+    // 2948
     2797799.92577557,
     2797814.66098564,
     2797829.39298665,
-    // 2947
     2797844.16289857,
     2797858.99146717,
     2797873.91393537,
@@ -26103,10 +26149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2798120.67292413,
     2798135.58320469,
     2798150.40710179,
+    // 2949
     2798165.17003506,
     2798179.90340805,
     2798194.63778203,
-    // 2948
     2798209.40661622,
     2798224.23786388,
     2798239.15943974,
@@ -26128,10 +26174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2798485.91111855,
     2798500.82550887,
     2798515.64698911,
+    // 2950
     2798530.41376065,
     2798545.14449986,
     2798559.88274653,
-    // 2949
     2798574.64868248,
     2798589.48375879,
     2798604.40229035,
@@ -26153,10 +26199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2798851.15767275,
     2798866.06598877,
     2798880.89393955,
+    // 2951
     2798895.65385698,
     2798910.39014772,
     2798925.12074826,
-    // 2950
     2798939.89175433,
     2798954.71896478,
     2798969.64270217,
@@ -26178,10 +26224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2799216.39849150,
     2799231.31218368,
     2799246.13466317,
+    // 2952
     2799260.90015232,
     2799275.63108907,
     2799290.36709312,
-    // 2951
     2799305.13266769,
     2799319.96510704,
     2799334.88323507,
@@ -26203,10 +26249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2799581.63677413,
     2799596.54913895,
     2799611.37244280,
+    // 2953
     2799626.13723591,
     2799640.86958259,
     2799655.60558159,
-    // 2952
     2799670.37273894,
     2799685.20531709,
     2799700.12508092,
@@ -26228,10 +26274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2799946.88032478,
     2799961.78839856,
     2799976.61601803,
+    // 2954
     2799991.37687698,
     2800006.11388528,
     2800020.84593486,
-    // 2953
     2800035.61774171,
     2800050.44587354,
     2800065.36953309,
@@ -26253,10 +26299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2800312.11854475,
     2800327.03365132,
     2800341.85492835,
+    // 2955
     2800356.62227249,
     2800371.35242803,
     2800386.09064194,
-    // 2954
     2800400.85544538,
     2800415.68986566,
     2800430.60667123,
@@ -26278,10 +26324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2800677.36313845,
     2800692.27252972,
     2800707.09729586,
+    // 2956
     2800721.85861705,
     2800736.59218117,
     2800751.32478826,
-    // 2955
     2800766.09339977,
     2800780.92299165,
     2800795.84454955,
@@ -26303,10 +26349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2801042.60687422,
     2801057.51623703,
     2801072.34110506,
+    // 2957
     2801087.10218811,
     2801101.83569448,
     2801116.56765490,
-    // 2956
     2801131.33633453,
     2801146.16518980,
     2801161.08693426,
@@ -26328,10 +26374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2801407.84204373,
     2801422.75787048,
     2801437.57931913,
+    // 2958
     2801452.34664577,
     2801467.07631311,
     2801481.81399299,
-    // 2957
     2801496.57792279,
     2801511.41170833,
     2801526.32785846,
@@ -26353,10 +26399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2801773.08759048,
     2801787.99743531,
     2801802.82593110,
+    // 2959
     2801817.58689477,
     2801832.32297453,
     2801847.05372496,
-    // 2958
     2801861.82338644,
     2801876.64966747,
     2801891.57111515,
@@ -26378,10 +26424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2802138.32934111,
     2802153.24277893,
     2802168.06717642,
+    // 2960
     2802182.83222383,
     2802197.56473265,
     2802212.29974847,
-    // 2959
     2802227.06618683,
     2802241.89672641,
     2802256.81475236,
@@ -26403,10 +26449,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2802503.56822769,
     2802518.48235335,
     2802533.30505407,
+    // 2961
     2802548.07128337,
     2802562.80277761,
     2802577.53997823,
-    // 2960
     2802592.30600313,
     2802607.13939207,
     2802622.05748917,
@@ -26428,10 +26474,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2802868.81553549,
     2802883.72433966,
     2802898.55293413,
+    // 2962
     2802913.31361409,
     2802928.05082646,
     2802942.78218359,
-    // 2961
     2802957.55379938,
     2802972.38102266,
     2802987.30436945,
@@ -26453,10 +26499,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2803234.05606018,
     2803248.97233320,
     2803263.79542641,
+    // 2963
     2803278.56275656,
     2803293.29349502,
     2803308.03059279,
-    // 2962
     2803322.79515615,
     2803337.62791933,
     2803352.54424280,
@@ -26478,10 +26524,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2803599.30018905,
     2803614.21297703,
     2803629.03843653,
+    // 2964
     2803643.80237595,
     2803658.53555802,
     2803673.26954689,
-    // 2963
     2803688.03653955,
     2803702.86641946,
     2803717.78557293,
@@ -26503,10 +26549,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2803964.54597378,
     2803979.45617770,
     2803994.28393999,
+    // 2965
     2804009.04574907,
     2804023.78164923,
     2804038.51353402,
-    // 2964
     2804053.28348302,
     2804068.11104920,
     2804083.03288407,
@@ -26528,10 +26574,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2804329.78178323,
     2804344.69857382,
     2804359.52091705,
+    // 2966
     2804374.28960053,
     2804389.02034865,
     2804403.75926940,
-    // 2965
     2804418.52392226,
     2804433.35825700,
     2804448.27422276,
@@ -26553,10 +26599,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2804695.03045666,
     2804709.94104809,
     2804724.76882850,
+    // 2967
     2804739.53070591,
     2804754.26633763,
     2804768.99833521,
-    // 2966
     2804783.76771970,
     2804798.59533480,
     2804813.51636739,
@@ -26578,10 +26624,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2805060.27685166,
     2805075.18894720,
     2805090.01407290,
+    // 2968
     2805104.77721783,
     2805119.51012041,
     2805134.24317881,
-    // 2967
     2805149.01018258,
     2805163.83907349,
     2805178.75808484,
@@ -26603,10 +26649,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2805425.51616569,
     2805440.43174945,
     2805455.25370098,
+    // 2969
     2805470.02032657,
     2805484.75006908,
     2805499.48682538,
-    // 2968
     2805514.25058199,
     2805529.08332776,
     2805543.99925974,
@@ -26628,10 +26674,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2805790.76350886,
     2805805.67354735,
     2805820.50293718,
+    // 2970
     2805835.26393885,
     2805850.00075910,
     2805864.73121573,
-    // 2969
     2805879.50119201,
     2805894.32654636,
     2805909.24766102,
@@ -26653,10 +26699,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2806156.00227885,
     2806170.91899232,
     2806185.74417630,
+    // 2971
     2806200.51147536,
     2806215.24354738,
     2806229.97957939,
-    // 2970
     2806244.74428778,
     2806259.57470873,
     2806274.48999248,
@@ -26678,10 +26724,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2806521.24293254,
     2806536.15804828,
     2806550.98337858,
+    // 2972
     2806565.74949962,
     2806580.48223453,
     2806595.21786328,
-    // 2971
     2806609.98362189,
     2806624.81412016,
     2806639.73087912,
@@ -26703,10 +26749,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2806886.48713926,
     2806901.39751756,
     2806916.22672178,
+    // 2973
     2806930.98842851,
     2806945.72556145,
     2806960.45715195,
-    // 2972
     2806975.22798594,
     2806990.05480636,
     2807004.97688184,
@@ -26728,10 +26774,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2807251.72109192,
     2807266.63888490,
     2807281.46234579,
+    // 2974
     2807296.23126919,
     2807310.96245409,
     2807325.70107500,
-    // 2973
     2807340.46579640,
     2807355.29953090,
     2807370.21527265,
@@ -26753,10 +26799,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2807616.96548879,
     2807631.87890326,
     2807646.70697995,
+    // 2975
     2807661.47070026,
     2807676.20557225,
     2807690.93843929,
-    // 2974
     2807705.70621737,
     2807720.53414272,
     2807735.45323957,
@@ -26778,10 +26824,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2807982.20849347,
     2807997.12136084,
     2808011.94947143,
+    // 2976
     2808026.71292999,
     2808041.44799119,
     2808056.18036727,
-    // 2975
     2808070.94841613,
     2808085.77560228,
     2808100.69486332,
@@ -26803,10 +26849,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2808347.44338745,
     2808362.36091213,
     2808377.18393199,
+    // 2977
     2808391.95269337,
     2808406.68336175,
     2808421.42169518,
-    // 2976
     2808436.18558487,
     2808451.01886935,
     2808465.93383228,
@@ -26828,10 +26874,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2808712.68946545,
     2808727.60026023,
     2808742.42983980,
+    // 2978
     2808757.19210240,
     2808771.92946390,
     2808786.66138521,
-    // 2977
     2808801.43174584,
     2808816.25820793,
     2808831.17910920,
@@ -26853,10 +26899,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2809077.93121458,
     2809092.84627651,
     2809107.67210706,
+    // 2979
     2809122.43793279,
     2809137.17099829,
     2809151.90596826,
-    // 2978
     2809166.67209903,
     2809181.50176990,
     2809196.41863153,
@@ -26878,10 +26924,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2809443.17482838,
     2809458.09061420,
     2809472.91403323,
+    // 2980
     2809487.68014303,
     2809502.41057374,
     2809517.14610022,
-    // 2979
     2809531.90975985,
     2809546.74059306,
     2809561.65581441,
@@ -26903,10 +26949,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2809808.42269976,
     2809823.33315234,
     2809838.16253908,
+    // 2981
     2809852.92312742,
     2809867.65931923,
     2809882.38892676,
-    // 2980
     2809897.15822446,
     2809911.98291781,
     2809926.90370661,
@@ -26928,10 +26974,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2810173.65960116,
     2810188.57801664,
     2810203.40291961,
+    // 2982
     2810218.17141123,
     2810232.90274243,
     2810247.63954504,
-    // 2981
     2810262.40312263,
     2810277.23400356,
     2810292.14793921,
@@ -26953,10 +26999,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2810538.90452636,
     2810553.82060676,
     2810568.64868795,
+    // 2983
     2810583.41443097,
     2810598.14842029,
     2810612.88219968,
-    // 2982
     2810627.64781115,
     2810642.47534193,
     2810657.39108407,
@@ -26978,10 +27024,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2810904.15062048,
     2810919.06381530,
     2810933.89410960,
+    // 2984
     2810948.65762659,
     2810963.39446310,
     2810978.12633758,
-    // 2983
     2810992.89532040,
     2811007.72099740,
     2811022.64004488,
@@ -27003,10 +27049,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2811269.38834010,
     2811284.30714989,
     2811299.13091680,
+    // 2985
     2811313.90050395,
     2811328.63155932,
     2811343.37032848,
-    // 2984
     2811358.13424328,
     2811372.96743637,
     2811387.88175356,
@@ -27028,10 +27074,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2811634.63628741,
     2811649.54953050,
     2811664.37951568,
+    // 2986
     2811679.14321844,
     2811693.88008645,
     2811708.61275297,
-    // 2985
     2811723.38202829,
     2811738.20887792,
     2811753.12839549,
@@ -27053,10 +27099,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2811999.87929844,
     2812014.79540316,
     2812029.62424062,
+    // 2987
     2812044.39011211,
     2812059.12499934,
     2812073.85882917,
-    // 2986
     2812088.62572478,
     2812103.45342202,
     2812118.37050859,
@@ -27078,10 +27124,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2812365.11908860,
     2812380.03795748,
     2812394.86272795,
+    // 2988
     2812409.63157006,
     2812424.36262654,
     2812439.09981399,
-    // 2987
     2812453.86290645,
     2812468.69420052,
     2812483.60777044,
@@ -27103,10 +27149,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2812730.36491555,
     2812745.27689052,
     2812760.10796872,
+    // 2989
     2812774.87043395,
     2812789.60830367,
     2812804.33944872,
-    // 2988
     2812819.10964997,
     2812833.93489250,
     2812848.85543843,
@@ -27128,10 +27174,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2813095.60030800,
     2813110.51797276,
     2813125.34432582,
+    // 2990
     2813140.11269534,
     2813154.84601504,
     2813169.58303276,
-    // 2989
     2813184.34868408,
     2813199.17956297,
     2813214.09501915,
@@ -27153,10 +27199,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2813460.84430680,
     2813475.76093194,
     2813490.58717570,
+    // 2991
     2813505.35368528,
     2813520.08626285,
     2813534.82135082,
-    // 2990
     2813549.58610814,
     2813564.41541272,
     2813579.33058853,
@@ -27178,10 +27224,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2813826.09170768,
     2813841.00367971,
     2813855.83373157,
+    // 2992
     2813870.59536519,
     2813885.33159640,
     2813900.06155151,
-    // 2991
     2813914.83013494,
     2813929.65437606,
     2813944.57364635,
@@ -27203,10 +27249,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2814191.32752934,
     2814206.24626922,
     2814221.07000216,
+    // 2993
     2814235.83848756,
     2814250.56849477,
     2814265.30527751,
-    // 2992
     2814280.06761562,
     2814294.89862998,
     2814309.81156324,
@@ -27228,10 +27274,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2814556.57210395,
     2814571.48727451,
     2814586.31670792,
+    // 2994
     2814601.08142474,
     2814615.81662488,
     2814630.54908064,
-    // 2993
     2814645.31546564,
     2814660.14116666,
     2814675.05718211,
@@ -27253,10 +27299,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2814921.81361356,
     2814936.72954673,
     2814951.56032334,
+    // 2995
     2814966.32548001,
     2814981.06151893,
     2814995.79367629,
-    // 2994
     2815010.56057743,
     2815025.38543812,
     2815040.30147277,
@@ -27278,10 +27324,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2815287.05070390,
     2815301.97089109,
     2815316.79580561,
+    // 2996
     2815331.56565270,
     2815346.29650036,
     2815361.03414537,
-    // 2995
     2815375.79638906,
     2815390.62730458,
     2815405.53904656,
@@ -27303,10 +27349,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2815652.29729307,
     2815667.21068606,
     2815682.04189915,
+    // 2997
     2815696.80503300,
     2815711.54242120,
     2815726.27378574,
-    // 2996
     2815741.04301610,
     2815755.86804932,
     2815770.78710938,
@@ -27328,10 +27374,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2816017.53335821,
     2816032.45158914,
     2816047.28016331,
+    // 2998
     2816062.04785743,
     2816076.78223932,
     2816091.51758446,
-    // 2997
     2816106.28355142,
     2816121.11222592,
     2816136.02763907,
@@ -27353,10 +27399,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2816382.77537148,
     2816397.69559870,
     2816412.52280041,
+    // 2999
     2816427.29182139,
     2816442.02404297,
     2816456.76023208,
-    // 2998
     2816471.52333426,
     2816486.35265639,
     2816501.26537626,
@@ -27378,10 +27424,10 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2816748.02255481,
     2816762.93674998,
     2816777.76953538,
+    // 3000
     2816792.53293317,
     2816807.27104834,
     2816822.00159690,
-    // 2999
     2816836.77065667,
     2816851.59409900,
     2816866.51259272,
@@ -27403,41 +27449,18 @@ pub const SOLARTERM_J2000: [f64; 26328] = [
     2817113.25863581,
     2817128.17854684,
     2817143.00498383,
-    2817157.77492697,
-    2817172.50756889,
-    2817187.24532249,
-    // 3000
-    2817202.00947572,
-    2817216.84034796,
-    2817231.75379556,
-    2817246.78121645,
-    2817261.92577468,
-    2817277.20496340,
-    2817292.60377997,
-    2817308.12389296,
-    2817323.73126803,
-    2817339.41341765,
-    2817355.12352689,
-    2817370.84062887,
-    2817386.51695933,
-    2817402.13135426,
-    2817417.64772867,
-    2817433.05271530,
-    2817448.32987147,
-    2817463.47925331,
-    2817478.50525092,
-    2817493.42234443,
-    2817508.25155166,
-    2817523.01866912,
-    2817537.75402778,
-    2817552.48905733,
+];
+
+const SOLARTERM_ALIAS: [&str; 24] = [
+    "小寒", "大寒", "立春", "雨水", "惊蛰", "春分", "清明", "谷雨", "立夏", "小满", "芒种", "夏至",
+    "小暑", "大暑", "立秋", "处暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至",
 ];
 
 macro_rules! unwrap_or_return_false {
     ($expr:expr) => {
         match $expr {
-            Some(value) => value,
-            None => return false,
+            Ok(value) => value,
+            Err(_) => return false,
         }
     };
 }
@@ -27449,42 +27472,45 @@ pub struct Solarterm {
 
 impl Solarterm {
     // 创建节气对象
-    pub fn new(index: i64) -> Option<Self> {
+    pub fn new(index: i64) -> IkigaiResult<Self> {
         if !Self::is_supported(index) {
-            return None;
+            return Err(IkigaiError::SolartermIndexOutOfRange {
+                index,
+                min: 0,
+                max: Self::len_j2000() - 1,
+            });
         }
         //Some(Self {index: index % 24 })
-        Some(Self { index })
+        Ok(Self { index })
     }
 
-    // 计算节气区间
-    pub fn calc_solarterm(t: &DateTime<Utc>) -> (Option<Solarterm>, Option<Solarterm>) {
+    // 计算节气区间: 返回相邻的两个节气, 前者时刻不晚于 t, 后者晚于 t
+    pub fn calc_solarterm(t: &DateTime<Utc>) -> IkigaiResult<(Solarterm, Solarterm)> {
         let mut prev = 0;
         let mut next = Self::len_j2000() - 1;
         let ts = t.timestamp();
+        if ts < Self::get_timestamp(prev)? || ts >= Self::get_timestamp(next)? {
+            return Err(IkigaiError::YearOutOfRange {
+                year: t.year() as i64,
+                min: SOLARTERM_FROM_YEAR,
+                max: SOLARTERM_TO_YEAR,
+            });
+        }
         while next - prev > 1 {
             let mid = prev + ((next - prev + 1) / 2);
-            if Self::get_timestamp(prev) <= Some(ts) && Some(ts) < Self::get_timestamp(mid) {
+            if Self::get_timestamp(prev)? <= ts && ts < Self::get_timestamp(mid)? {
                 next = mid;
             } else {
                 prev = mid;
             }
         }
-        if Some(ts) == Self::get_timestamp(prev) && prev > 0 {
-            prev -= 1;
-        }
-        let p = Solarterm::new(prev);
-        let n = Solarterm::new(next);
-        (p, n)
+        Ok((Solarterm::new(prev)?, Solarterm::new(next)?))
     }
 
     // 获取指定年份立春的时间
-    pub fn spring_timestamp(year: i64) -> Option<i64> {
-        if !(SOLARTERM_FROM_YEAR..=SOLARTERM_TO_YEAR).contains(&year) {
-            None
-        } else {
-            Self::get_timestamp(24 * (year - SOLARTERM_FROM_YEAR) + 2)
-        }
+    pub fn spring_timestamp(year: i64) -> IkigaiResult<i64> {
+        check_year(year)?;
+        Self::get_timestamp(24 * (year - SOLARTERM_FROM_YEAR) + 2)
     }
 
     // 返回两个对象是否相同
@@ -27494,12 +27520,17 @@ impl Solarterm {
 
     // 返回节气名称(立春雨水...)
     pub fn alias(&self) -> &str {
-        let solarterm_alias = [
-            "小寒", "大寒", "立春", "雨水", "惊蛰", "春分", "清明", "谷雨", "立夏", "小满", "芒种",
-            "夏至", "小暑", "大暑", "立秋", "处暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪",
-            "大雪", "冬至",
-        ];
-        solarterm_alias[(self.index % 24) as usize]
+        SOLARTERM_ALIAS[(self.index % 24) as usize]
+    }
+
+    // 返回指定语言的节气名称
+    pub fn name(&self, locale: Locale) -> &'static str {
+        locale.solarterm(self.index)
+    }
+
+    // 节气所在的公历年份(小寒为一年中的第一个节气)
+    pub fn year(&self) -> i64 {
+        SOLARTERM_FROM_YEAR + self.index / 24
     }
 
     // 返回节气在索引表的索引
@@ -27513,23 +27544,22 @@ impl Solarterm {
     }
 
     // 返回当前节气的时间戳
-    pub fn timestamp(&self) -> Option<i64> {
+    pub fn timestamp(&self) -> IkigaiResult<i64> {
         Self::get_timestamp(self.index)
     }
 
     // 根据节气时间戳获取DateTime对象
-    pub fn time(&self) -> Option<DateTime<Utc>> {
-        let datetime = NaiveDateTime::from_timestamp_opt(self.timestamp()?, 0)?;
-        Some(DateTime::<Utc>::from_naive_utc_and_offset(datetime, Utc))
+    pub fn time(&self) -> IkigaiResult<DateTime<Utc>> {
+        DateTime::<Utc>::from_timestamp(self.timestamp()?, 0).ok_or(IkigaiError::InvalidDate)
     }
 
     // 上一个节气
-    pub fn prev(&self) -> Option<Solarterm> {
+    pub fn prev(&self) -> IkigaiResult<Solarterm> {
         Solarterm::new(self.index - 1)
     }
 
     // 下一个节气
-    pub fn next(&self) -> Option<Solarterm> {
+    pub fn next(&self) -> IkigaiResult<Solarterm> {
         Solarterm::new(self.index + 1)
     }
 
@@ -27541,7 +27571,7 @@ impl Solarterm {
 
     pub fn is_in_day(&self, t: &DateTime<Utc>) -> bool {
         let s = unwrap_or_return_false!(self.time());
-        let t1 =unwrap_or_return_false!(Utc.with_ymd_and_hms(s.year(), s.month(), s.day(),0, 0, 0).latest());
+        let t1 =unwrap_or_return_false!(Utc.with_ymd_and_hms(s.year(), s.month(), s.day(),0, 0, 0).latest().ok_or(IkigaiError::InvalidDate));
 
         let t2 = t1 + chrono::Duration::days(1);
 
@@ -27549,12 +27579,12 @@ impl Solarterm {
     }
 
     // 根据索引获取时间戳
-    fn get_timestamp(index: i64) -> Option<i64> {
+    fn get_timestamp(index: i64) -> IkigaiResult<i64> {
         let jd = SOLARTERM_J2000[index as usize];
         let (y, m, d, h, i, s) = utils::dd(jd);
         let date = Utc
-            .with_ymd_and_hms(y, m as u32, d as u32,h as u32, i as u32, s as u32).latest()?;
-        Some(date.timestamp())
+            .with_ymd_and_hms(y, m as u32, d as u32,h as u32, i as u32, s as u32).latest().ok_or(IkigaiError::InvalidDate)?;
+        Ok(date.timestamp())
     }
 
     // 返回j2000数组的长度
@@ -27567,3 +27597,137 @@ impl Solarterm {
         0 <= index && index < Self::len_j2000()
     }
 }
+
+// 形如 `2024年立春`, 可由 FromStr 解析回来
+impl fmt::Display for Solarterm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}年{}", self.year(), self.alias())
+    }
+}
+
+impl FromStr for Solarterm {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        let err = || IkigaiError::ParseError {
+            input: s.to_string(),
+            kind: "Solarterm",
+        };
+        let (k, year) = SOLARTERM_ALIAS
+            .iter()
+            .enumerate()
+            .find_map(|(k, alias)| Some((k as i64, s.strip_suffix(alias)?)))
+            .ok_or_else(err)?;
+        let year = year.strip_suffix('年').unwrap_or(year);
+        let year: i64 = year.parse().map_err(|_| err())?;
+        check_year(year)?;
+        Solarterm::new(24 * (year - SOLARTERM_FROM_YEAR) + k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solarterm_display() {
+        let tests = vec![
+            ("first", 0, "1904年小寒"),
+            ("lichun_2024", 24 * 120 + 2, "2024年立春"),
+            ("dongzhi_2024", 24 * 120 + 23, "2024年冬至"),
+        ];
+        for (name, index, want) in tests {
+            let term = Solarterm::new(index).unwrap();
+            assert_eq!(term.to_string(), want, "{} failed", name);
+            assert_eq!(want.parse::<Solarterm>(), Ok(term), "{} failed", name);
+        }
+        assert_eq!(
+            "2024立春".parse::<Solarterm>().unwrap().index(),
+            24 * 120 + 2
+        );
+    }
+
+    #[test]
+    fn test_solarterm_from_str_error() {
+        let tests = vec![
+            ("no_year", "立春"),
+            ("no_term", "2024年"),
+            ("unknown_term", "2024年春节"),
+            ("bad_year", "二零二四年立春"),
+        ];
+        for (name, s) in tests {
+            assert!(s.parse::<Solarterm>().is_err(), "{} should fail", name);
+        }
+        assert!(matches!(
+            "1800年立春".parse::<Solarterm>(),
+            Err(IkigaiError::YearOutOfRange { year: 1800, .. })
+        ));
+    }
+
+    #[test]
+    fn test_calc_solarterm() {
+        let lichun = "2024年立春".parse::<Solarterm>().unwrap();
+        let t = lichun.time().unwrap();
+        // 恰在交节时刻, 区间以该节气开始
+        let tests = vec![
+            (
+                "before",
+                t - chrono::Duration::seconds(1),
+                lichun.index() - 1,
+            ),
+            ("exact", t, lichun.index()),
+            ("after", t + chrono::Duration::seconds(1), lichun.index()),
+        ];
+        for (name, t, want) in tests {
+            let (prev, next) = Solarterm::calc_solarterm(&t).unwrap();
+            assert_eq!(prev.index(), want, "{} failed", name);
+            assert_eq!(next.index(), want + 1, "{} failed", name);
+        }
+    }
+
+    #[test]
+    fn test_solarterm_range() {
+        // 2200 年之后的节气须与前后衔接, 间隔约 15 天
+        let mut term = "2200年冬至".parse::<Solarterm>().unwrap();
+        for _ in 0..48 {
+            let next = term.next().unwrap();
+            let days = (next.timestamp().unwrap() - term.timestamp().unwrap()) as f64 / 86400.0;
+            assert!((14.5..16.0).contains(&days), "{} failed", next);
+            term = next;
+        }
+        assert_eq!(term.time().unwrap().format("%Y-%m").to_string(), "2202-12");
+        let lichun = "2201年立春".parse::<Solarterm>().unwrap();
+        assert_eq!(
+            lichun.time().unwrap().format("%Y-%m-%d").to_string(),
+            "2201-02-04"
+        );
+
+        // 3000 年冬至为表中最后一项
+        let dongzhi = "3000年冬至".parse::<Solarterm>().unwrap();
+        assert_eq!(
+            dongzhi.time().unwrap().format("%Y-%m").to_string(),
+            "3000-12"
+        );
+        assert!(dongzhi.next().is_err());
+
+        let tests = vec![
+            (1903, false),
+            (1904, true),
+            (2201, true),
+            (3000, true),
+            (3001, false),
+        ];
+        for (year, ok) in tests {
+            assert_eq!(check_year(year).is_ok(), ok, "{} failed", year);
+        }
+        assert_eq!(
+            "3001年立春".parse::<Solarterm>(),
+            Err(IkigaiError::YearOutOfRange {
+                year: 3001,
+                min: SOLARTERM_FROM_YEAR,
+                max: SOLARTERM_TO_YEAR
+            })
+        );
+        assert!(Solarterm::spring_timestamp(3001).is_err());
+    }
+}
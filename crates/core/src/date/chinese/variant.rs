@@ -0,0 +1,24 @@
+// 农历的地区变体: 规则相同, 按各自的标准时区划分朔日与中气
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CalendarVariant {
+    // 中国农历, UTC+8
+    #[default]
+    Chinese,
+    // 越南阴历(âm lịch), UTC+7, 生肖以猫代兔
+    Vietnamese,
+    // 韩国阴历(음력), UTC+9
+    Korean,
+    // 日本旧历(旧暦), UTC+9, 用于推算六曜
+    Japanese,
+}
+
+impl CalendarVariant {
+    // 划分日期所用的时区, 单位为小时
+    pub fn utc_offset(&self) -> i64 {
+        match self {
+            CalendarVariant::Chinese => 8,
+            CalendarVariant::Vietnamese => 7,
+            CalendarVariant::Korean | CalendarVariant::Japanese => 9,
+        }
+    }
+}
@@ -1,4 +1,9 @@
-#[derive(Debug, PartialEq)]
+use std::{fmt, str::FromStr};
+
+use super::locale::Locale;
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Zhi {
     order: i64,
 }
@@ -9,11 +14,15 @@ const ZHI_ALIAS: [&str; 12] = [
 
 impl Zhi {
     // Creates a `Zhi` object
-    pub fn new(order: i64) -> Option<Zhi> {
+    pub fn new(order: i64) -> IkigaiResult<Zhi> {
         if Zhi::is_supported(order) {
-            Some(Zhi { order })
+            Ok(Zhi { order })
         } else {
-            None
+            Err(IkigaiError::OrderOutOfRange {
+                name: "Zhi",
+                order,
+                max: 12,
+            })
         }
     }
 
@@ -22,6 +31,11 @@ impl Zhi {
         ZHI_ALIAS[(self.order - 1) as usize % 12]
     }
 
+    // Returns the name of the 'Zhi' in the given locale
+    pub fn name(&self, locale: Locale) -> &'static str {
+        locale.zhi(self.order)
+    }
+
     // Returns the order of the 'Zhi' (1234...)
     pub fn order(&self) -> i64 {
         self.order
@@ -31,6 +45,27 @@ impl Zhi {
         (1..=12).contains(&order)
     }
 }
+impl fmt::Display for Zhi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.alias())
+    }
+}
+
+impl FromStr for Zhi {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        let order = ZHI_ALIAS
+            .iter()
+            .position(|alias| *alias == s)
+            .ok_or_else(|| IkigaiError::ParseError {
+                input: s.to_string(),
+                kind: "Zhi",
+            })?;
+        Zhi::new(order as i64 + 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,14 +78,30 @@ mod tests {
         12
     }
 
+    fn out_of_range(order: i64) -> IkigaiError {
+        IkigaiError::OrderOutOfRange {
+            name: "Zhi",
+            order,
+            max: max_order(),
+        }
+    }
+
     #[test]
     fn test_new_zhi() {
         let tests = vec![
-            ("nil_min", min_order() - 1, None),
-            ("nil_max", max_order() + 1, None),
-            ("test_min", min_order(), Some(Zhi { order: min_order() })),
-            ("test_max", max_order(), Some(Zhi { order: max_order() })),
-            ("test", 8, Some(Zhi { order: 8 })),
+            (
+                "nil_min",
+                min_order() - 1,
+                Err(out_of_range(min_order() - 1)),
+            ),
+            (
+                "nil_max",
+                max_order() + 1,
+                Err(out_of_range(max_order() + 1)),
+            ),
+            ("test_min", min_order(), Ok(Zhi { order: min_order() })),
+            ("test_max", max_order(), Ok(Zhi { order: max_order() })),
+            ("test", 8, Ok(Zhi { order: 8 })),
         ];
 
         for (name, order, want) in tests {
@@ -78,6 +129,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_zhi_from_str() {
+        let tests = vec![
+            ("test_min", "子", Some(1)),
+            ("test_max", "亥", Some(12)),
+            ("test", "未", Some(8)),
+            ("gan", "甲", None),
+            ("empty", "", None),
+        ];
+
+        for (name, s, want) in tests {
+            let got = s.parse::<Zhi>().ok().map(|zhi| zhi.order());
+            assert_eq!(got, want, "{} failed", name);
+        }
+    }
+
     #[test]
     fn test_zhi_order() {
         let tests = vec![
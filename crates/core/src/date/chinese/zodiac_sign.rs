@@ -0,0 +1,486 @@
+use std::{fmt, str::FromStr};
+
+use super::locale::Locale;
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 西洋十二星座, 自白羊起每 30° 黄经一宫
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZodiacSign {
+    Aries,
+    Taurus,
+    Gemini,
+    Cancer,
+    Leo,
+    Virgo,
+    Libra,
+    Scorpio,
+    Sagittarius,
+    Capricorn,
+    Aquarius,
+    Pisces,
+}
+
+// 四象(元素)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Element {
+    Fire,
+    Earth,
+    Air,
+    Water,
+}
+
+// 三态(本位、固定、变动)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modality {
+    Cardinal,
+    Fixed,
+    Mutable,
+}
+
+// 守护星
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Planet {
+    Sun,
+    Moon,
+    Mercury,
+    Venus,
+    Mars,
+    Jupiter,
+    Saturn,
+    Uranus,
+    Neptune,
+    Pluto,
+}
+
+// 两个星座之间的相位, 由相隔的宫数决定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aspect {
+    Conjunction,
+    SemiSextile,
+    Sextile,
+    Square,
+    Trine,
+    Quincunx,
+    Opposition,
+}
+
+impl ZodiacSign {
+    pub const ALL: [ZodiacSign; 12] = [
+        ZodiacSign::Aries,
+        ZodiacSign::Taurus,
+        ZodiacSign::Gemini,
+        ZodiacSign::Cancer,
+        ZodiacSign::Leo,
+        ZodiacSign::Virgo,
+        ZodiacSign::Libra,
+        ZodiacSign::Scorpio,
+        ZodiacSign::Sagittarius,
+        ZodiacSign::Capricorn,
+        ZodiacSign::Aquarius,
+        ZodiacSign::Pisces,
+    ];
+
+    // 序数从 1(白羊)到 12(双鱼), 超出范围时循环取值
+    pub fn from_order(order: i64) -> ZodiacSign {
+        Self::ALL[(order - 1).rem_euclid(12) as usize]
+    }
+
+    // 由太阳黄经(度)求星座
+    pub fn from_longitude(longitude: f64) -> ZodiacSign {
+        Self::from_order((longitude.rem_euclid(360.0) / 30.0) as i64 + 1)
+    }
+
+    pub fn order(&self) -> i64 {
+        *self as i64 + 1
+    }
+
+    // 星座起点的黄经
+    pub fn start_longitude(&self) -> f64 {
+        (self.order() - 1) as f64 * 30.0
+    }
+
+    pub fn alias(&self) -> &'static str {
+        self.name(Locale::ZhHans)
+    }
+
+    // 指定语言的星座名称
+    pub fn name(&self, locale: Locale) -> &'static str {
+        locale.constellation(self.order())
+    }
+
+    pub fn english(&self) -> &'static str {
+        self.name(Locale::En)
+    }
+
+    // 拉丁学名(Scorpius、Capricornus 与英文写法不同)
+    pub fn latin(&self) -> &'static str {
+        match self {
+            ZodiacSign::Scorpio => "Scorpius",
+            ZodiacSign::Capricorn => "Capricornus",
+            _ => self.english(),
+        }
+    }
+
+    // 星座符号 ♈ ~ ♓
+    pub fn symbol(&self) -> char {
+        char::from_u32(0x2648 + self.order() as u32 - 1).unwrap()
+    }
+
+    // 火、土、风、水依次轮转
+    pub fn element(&self) -> Element {
+        [Element::Fire, Element::Earth, Element::Air, Element::Water]
+            [(self.order() as usize - 1) % 4]
+    }
+
+    // 本位、固定、变动依次轮转
+    pub fn modality(&self) -> Modality {
+        [Modality::Cardinal, Modality::Fixed, Modality::Mutable][(self.order() as usize - 1) % 3]
+    }
+
+    // 现代守护星, 天蝎、水瓶、双鱼分别为冥王星、天王星、海王星
+    pub fn ruling_planet(&self) -> Planet {
+        match self {
+            ZodiacSign::Scorpio => Planet::Pluto,
+            ZodiacSign::Aquarius => Planet::Uranus,
+            ZodiacSign::Pisces => Planet::Neptune,
+            _ => self.traditional_ruler(),
+        }
+    }
+
+    // 古典守护星, 只用肉眼可见的七曜
+    pub fn traditional_ruler(&self) -> Planet {
+        match self {
+            ZodiacSign::Aries | ZodiacSign::Scorpio => Planet::Mars,
+            ZodiacSign::Taurus | ZodiacSign::Libra => Planet::Venus,
+            ZodiacSign::Gemini | ZodiacSign::Virgo => Planet::Mercury,
+            ZodiacSign::Cancer => Planet::Moon,
+            ZodiacSign::Leo => Planet::Sun,
+            ZodiacSign::Sagittarius | ZodiacSign::Pisces => Planet::Jupiter,
+            ZodiacSign::Capricorn | ZodiacSign::Aquarius => Planet::Saturn,
+        }
+    }
+
+    // 对宫星座
+    pub fn opposite(&self) -> ZodiacSign {
+        Self::from_order(self.order() + 6)
+    }
+
+    pub fn aspect(&self, other: &ZodiacSign) -> Aspect {
+        let distance = (self.order() - other.order()).rem_euclid(12);
+        match distance.min(12 - distance) {
+            0 => Aspect::Conjunction,
+            1 => Aspect::SemiSextile,
+            2 => Aspect::Sextile,
+            3 => Aspect::Square,
+            4 => Aspect::Trine,
+            5 => Aspect::Quincunx,
+            _ => Aspect::Opposition,
+        }
+    }
+
+    // 同元素或火风、土水相配视为相合
+    pub fn is_compatible(&self, other: &ZodiacSign) -> bool {
+        self.element() == other.element() || self.element().complement() == other.element()
+    }
+}
+
+impl Element {
+    // 互补元素: 火与风、土与水
+    pub fn complement(&self) -> Element {
+        match self {
+            Element::Fire => Element::Air,
+            Element::Air => Element::Fire,
+            Element::Earth => Element::Water,
+            Element::Water => Element::Earth,
+        }
+    }
+
+    pub fn alias(&self) -> &'static str {
+        match self {
+            Element::Fire => "火",
+            Element::Earth => "土",
+            Element::Air => "风",
+            Element::Water => "水",
+        }
+    }
+}
+
+impl Modality {
+    pub fn alias(&self) -> &'static str {
+        match self {
+            Modality::Cardinal => "本位",
+            Modality::Fixed => "固定",
+            Modality::Mutable => "变动",
+        }
+    }
+}
+
+impl Planet {
+    pub fn alias(&self) -> &'static str {
+        match self {
+            Planet::Sun => "太阳",
+            Planet::Moon => "月亮",
+            Planet::Mercury => "水星",
+            Planet::Venus => "金星",
+            Planet::Mars => "火星",
+            Planet::Jupiter => "木星",
+            Planet::Saturn => "土星",
+            Planet::Uranus => "天王星",
+            Planet::Neptune => "海王星",
+            Planet::Pluto => "冥王星",
+        }
+    }
+}
+
+impl Aspect {
+    // 相位角度
+    pub fn degrees(&self) -> i64 {
+        match self {
+            Aspect::Conjunction => 0,
+            Aspect::SemiSextile => 30,
+            Aspect::Sextile => 60,
+            Aspect::Square => 90,
+            Aspect::Trine => 120,
+            Aspect::Quincunx => 150,
+            Aspect::Opposition => 180,
+        }
+    }
+}
+
+impl fmt::Display for ZodiacSign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.english())
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Modality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Planet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+// 接受英文名、拉丁名、任一语言的名称或符号, 英文不区分大小写
+impl FromStr for ZodiacSign {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        ZodiacSign::ALL
+            .into_iter()
+            .find(|sign| {
+                sign.english().eq_ignore_ascii_case(s)
+                    || sign.latin().eq_ignore_ascii_case(s)
+                    || s.chars().eq(std::iter::once(sign.symbol()))
+                    || Locale::ALL.iter().any(|&locale| sign.name(locale) == s)
+            })
+            .ok_or_else(|| IkigaiError::ParseError {
+                input: s.to_string(),
+                kind: "ZodiacSign",
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zodiac_sign_attributes() {
+        let tests = vec![
+            (
+                ZodiacSign::Aries,
+                "Aries",
+                '♈',
+                Element::Fire,
+                Modality::Cardinal,
+                Planet::Mars,
+            ),
+            (
+                ZodiacSign::Taurus,
+                "Taurus",
+                '♉',
+                Element::Earth,
+                Modality::Fixed,
+                Planet::Venus,
+            ),
+            (
+                ZodiacSign::Gemini,
+                "Gemini",
+                '♊',
+                Element::Air,
+                Modality::Mutable,
+                Planet::Mercury,
+            ),
+            (
+                ZodiacSign::Cancer,
+                "Cancer",
+                '♋',
+                Element::Water,
+                Modality::Cardinal,
+                Planet::Moon,
+            ),
+            (
+                ZodiacSign::Leo,
+                "Leo",
+                '♌',
+                Element::Fire,
+                Modality::Fixed,
+                Planet::Sun,
+            ),
+            (
+                ZodiacSign::Scorpio,
+                "Scorpius",
+                '♏',
+                Element::Water,
+                Modality::Fixed,
+                Planet::Pluto,
+            ),
+            (
+                ZodiacSign::Capricorn,
+                "Capricornus",
+                '♑',
+                Element::Earth,
+                Modality::Cardinal,
+                Planet::Saturn,
+            ),
+            (
+                ZodiacSign::Aquarius,
+                "Aquarius",
+                '♒',
+                Element::Air,
+                Modality::Fixed,
+                Planet::Uranus,
+            ),
+            (
+                ZodiacSign::Pisces,
+                "Pisces",
+                '♓',
+                Element::Water,
+                Modality::Mutable,
+                Planet::Neptune,
+            ),
+        ];
+        for (sign, latin, symbol, element, modality, planet) in tests {
+            assert_eq!(sign.latin(), latin, "{} latin", sign);
+            assert_eq!(sign.symbol(), symbol, "{} symbol", sign);
+            assert_eq!(sign.element(), element, "{} element", sign);
+            assert_eq!(sign.modality(), modality, "{} modality", sign);
+            assert_eq!(sign.ruling_planet(), planet, "{} ruling planet", sign);
+        }
+        assert_eq!(ZodiacSign::Scorpio.english(), "Scorpio");
+        assert_eq!(ZodiacSign::Scorpio.traditional_ruler(), Planet::Mars);
+        assert_eq!(ZodiacSign::Pisces.alias(), "双鱼");
+    }
+
+    #[test]
+    fn test_zodiac_sign_order() {
+        for (i, sign) in ZodiacSign::ALL.iter().enumerate() {
+            assert_eq!(sign.order(), i as i64 + 1);
+            assert_eq!(ZodiacSign::from_order(sign.order()), *sign);
+            assert_eq!(ZodiacSign::from_longitude(sign.start_longitude()), *sign);
+        }
+        assert_eq!(ZodiacSign::from_order(13), ZodiacSign::Aries);
+        assert_eq!(ZodiacSign::from_order(0), ZodiacSign::Pisces);
+        assert_eq!(ZodiacSign::from_longitude(-1.0), ZodiacSign::Pisces);
+        assert_eq!(ZodiacSign::from_longitude(359.9), ZodiacSign::Pisces);
+    }
+
+    #[test]
+    fn test_zodiac_sign_compatibility() {
+        let tests = vec![
+            (
+                "same_element",
+                ZodiacSign::Aries,
+                ZodiacSign::Leo,
+                true,
+                Aspect::Trine,
+            ),
+            (
+                "fire_air",
+                ZodiacSign::Aries,
+                ZodiacSign::Gemini,
+                true,
+                Aspect::Sextile,
+            ),
+            (
+                "earth_water",
+                ZodiacSign::Taurus,
+                ZodiacSign::Pisces,
+                true,
+                Aspect::Sextile,
+            ),
+            (
+                "opposite",
+                ZodiacSign::Aries,
+                ZodiacSign::Libra,
+                true,
+                Aspect::Opposition,
+            ),
+            (
+                "fire_water",
+                ZodiacSign::Aries,
+                ZodiacSign::Cancer,
+                false,
+                Aspect::Square,
+            ),
+            (
+                "neighbour",
+                ZodiacSign::Pisces,
+                ZodiacSign::Aries,
+                false,
+                Aspect::SemiSextile,
+            ),
+            (
+                "quincunx",
+                ZodiacSign::Virgo,
+                ZodiacSign::Aries,
+                false,
+                Aspect::Quincunx,
+            ),
+            (
+                "self",
+                ZodiacSign::Virgo,
+                ZodiacSign::Virgo,
+                true,
+                Aspect::Conjunction,
+            ),
+        ];
+        for (name, a, b, compatible, aspect) in tests {
+            assert_eq!(a.is_compatible(&b), compatible, "{} failed", name);
+            assert_eq!(b.is_compatible(&a), compatible, "{} failed", name);
+            assert_eq!(a.aspect(&b), aspect, "{} failed", name);
+            assert_eq!(b.aspect(&a), aspect, "{} failed", name);
+        }
+        assert_eq!(ZodiacSign::Cancer.opposite(), ZodiacSign::Capricorn);
+        assert_eq!(Aspect::Trine.degrees(), 120);
+    }
+
+    #[test]
+    fn test_zodiac_sign_from_str() {
+        let tests = vec![
+            ("english", "Scorpio", ZodiacSign::Scorpio),
+            ("latin", "scorpius", ZodiacSign::Scorpio),
+            ("symbol", "♑", ZodiacSign::Capricorn),
+            ("zh_hans", "双鱼", ZodiacSign::Pisces),
+            ("zh_hant", "雙魚", ZodiacSign::Pisces),
+        ];
+        for (name, s, want) in tests {
+            assert_eq!(s.parse::<ZodiacSign>(), Ok(want), "{} failed", name);
+        }
+        for sign in ZodiacSign::ALL {
+            assert_eq!(sign.to_string().parse::<ZodiacSign>(), Ok(sign));
+        }
+        assert!("Ophiuchus".parse::<ZodiacSign>().is_err());
+    }
+}
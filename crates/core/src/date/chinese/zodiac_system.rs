@@ -0,0 +1,164 @@
+use std::{fmt, str::FromStr};
+
+use super::zodiac_sign::ZodiacSign;
+use crate::{
+    date::astro::julian_century,
+    ikigai_error::{IkigaiError, IkigaiResult},
+};
+
+// 星座的划分方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ZodiacSystem {
+    // 回归黄道: 以春分点为白羊 0°, 与十二中气一致
+    #[default]
+    Tropical,
+    // 恒星黄道: 回归黄经减去 Lahiri 岁差(ayanamsa)
+    Sidereal,
+    // 天文十三星座: 按 IAU 星座边界在黄道上的交点划分, 含蛇夫座
+    Astronomical,
+}
+
+// 蛇夫座在十三星座中的序数, 其余星座沿用十二星座的序数
+pub const OPHIUCHUS_ORDER: i64 = 13;
+
+// Lahiri 岁差在 J2000.0 的取值(度)
+const LAHIRI_AYANAMSA_J2000: f64 = 23.857092;
+
+// IAU 星座边界与黄道交点的 J2000 黄经(度)及进入的星座序数, 按黄经升序
+const ASTRONOMICAL_BOUNDARIES: [(f64, i64); 13] = [
+    (29.09, 1),
+    (53.47, 2),
+    (90.43, 3),
+    (118.26, 4),
+    (138.18, 5),
+    (174.15, 6),
+    (218.02, 7),
+    (241.13, 8),
+    (247.70, OPHIUCHUS_ORDER),
+    (266.31, 9),
+    (299.73, 10),
+    (327.72, 11),
+    (351.57, 12),
+];
+
+// 自 J2000.0 起黄经方向的累计岁差(度), t 为儒略世纪数
+fn precession(jd: f64) -> f64 {
+    let t = julian_century(jd);
+    1.396971 * t + 0.0003086 * t * t
+}
+
+impl ZodiacSystem {
+    pub const ALL: [ZodiacSystem; 3] = [
+        ZodiacSystem::Tropical,
+        ZodiacSystem::Sidereal,
+        ZodiacSystem::Astronomical,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ZodiacSystem::Tropical => "tropical",
+            ZodiacSystem::Sidereal => "sidereal",
+            ZodiacSystem::Astronomical => "astronomical",
+        }
+    }
+
+    // 儒略日 jd 的 Lahiri 岁差(度)
+    pub fn lahiri_ayanamsa(jd: f64) -> f64 {
+        LAHIRI_AYANAMSA_J2000 + precession(jd)
+    }
+
+    // 由儒略日 jd 时太阳的回归黄经(度)求星座序数, 十三星座中蛇夫为 13
+    pub fn order_by_longitude(&self, longitude: f64, jd: f64) -> i64 {
+        match self {
+            ZodiacSystem::Tropical => ZodiacSign::from_longitude(longitude).order(),
+            ZodiacSystem::Sidereal => {
+                ZodiacSystem::Tropical.order_by_longitude(longitude - Self::lahiri_ayanamsa(jd), jd)
+            }
+            ZodiacSystem::Astronomical => {
+                // 星座边界固定在天球上, 须先把当日黄经换算到 J2000 历元
+                let longitude = (longitude - precession(jd)).rem_euclid(360.0);
+                ASTRONOMICAL_BOUNDARIES
+                    .iter()
+                    .rev()
+                    .find(|(start, _)| *start <= longitude)
+                    .map_or(12, |&(_, order)| order)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ZodiacSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ZodiacSystem {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        ZodiacSystem::ALL
+            .into_iter()
+            .find(|system| system.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| IkigaiError::ParseError {
+                input: s.to_string(),
+                kind: "ZodiacSystem",
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::astro::J2000_JD;
+
+    #[test]
+    fn test_lahiri_ayanamsa() {
+        // 1956-03-21 印度历法改革委员会采用的取值约为 23°15'
+        let got = ZodiacSystem::lahiri_ayanamsa(2435553.5);
+        assert!((got - 23.25).abs() < 0.01, "got {}", got);
+        let got = ZodiacSystem::lahiri_ayanamsa(J2000_JD);
+        assert!((got - LAHIRI_AYANAMSA_J2000).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_order_by_longitude() {
+        let tests = vec![
+            ("tropical_aries", ZodiacSystem::Tropical, 0.0, 1),
+            ("tropical_pisces", ZodiacSystem::Tropical, 359.9, 12),
+            ("sidereal_pisces", ZodiacSystem::Sidereal, 20.0, 12),
+            ("sidereal_aries", ZodiacSystem::Sidereal, 24.0, 1),
+            ("astronomical_pisces", ZodiacSystem::Astronomical, 10.0, 12),
+            ("astronomical_aries", ZodiacSystem::Astronomical, 30.0, 1),
+            ("scorpius", ZodiacSystem::Astronomical, 242.0, 8),
+            (
+                "ophiuchus",
+                ZodiacSystem::Astronomical,
+                250.0,
+                OPHIUCHUS_ORDER,
+            ),
+            ("sagittarius", ZodiacSystem::Astronomical, 270.0, 9),
+            ("pisces_wrap", ZodiacSystem::Astronomical, 355.0, 12),
+        ];
+        for (name, system, longitude, want) in tests {
+            assert_eq!(
+                system.order_by_longitude(longitude, J2000_JD),
+                want,
+                "{} failed",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_zodiac_system_from_str() {
+        for system in ZodiacSystem::ALL {
+            assert_eq!(system.to_string().parse::<ZodiacSystem>(), Ok(system));
+        }
+        assert_eq!(
+            "Sidereal".parse::<ZodiacSystem>(),
+            Ok(ZodiacSystem::Sidereal)
+        );
+        assert!("vedic".parse::<ZodiacSystem>().is_err());
+    }
+}
@@ -0,0 +1,297 @@
+use std::fmt;
+
+use chrono::{Duration, NaiveDate};
+
+use crate::{
+    date::astro::from_jdn,
+    ikigai_error::{IkigaiError, IkigaiResult},
+};
+
+// 格里历 1582 年 10 月启用, 西方教会的复活节自 1583 年起按格里历推算
+const GREGORIAN_FROM_YEAR: i64 = 1583;
+// 尼西亚公会议(325 年)确定复活节的推算原则
+const JULIAN_FROM_YEAR: i64 = 326;
+const COMPUTUS_TO_YEAR: i64 = 9999;
+
+// 推算复活节所依据的教会传统
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Church {
+    // 天主教与新教, 按格里历推算
+    #[default]
+    Western,
+    // 东正教, 按儒略历推算后换算为格里历
+    Orthodox,
+}
+
+// 以复活节为基准的移动节日
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feast {
+    // 东正教大斋期的首日
+    CleanMonday,
+    // 西方教会大斋期的首日
+    AshWednesday,
+    PalmSunday,
+    MaundyThursday,
+    GoodFriday,
+    HolySaturday,
+    Easter,
+    EasterMonday,
+    Ascension,
+    Pentecost,
+    WhitMonday,
+    TrinitySunday,
+    CorpusChristi,
+}
+
+impl Feast {
+    pub const ALL: [Feast; 13] = [
+        Feast::CleanMonday,
+        Feast::AshWednesday,
+        Feast::PalmSunday,
+        Feast::MaundyThursday,
+        Feast::GoodFriday,
+        Feast::HolySaturday,
+        Feast::Easter,
+        Feast::EasterMonday,
+        Feast::Ascension,
+        Feast::Pentecost,
+        Feast::WhitMonday,
+        Feast::TrinitySunday,
+        Feast::CorpusChristi,
+    ];
+
+    // 距复活节的天数
+    pub fn offset(&self) -> i64 {
+        match self {
+            Feast::CleanMonday => -48,
+            Feast::AshWednesday => -46,
+            Feast::PalmSunday => -7,
+            Feast::MaundyThursday => -3,
+            Feast::GoodFriday => -2,
+            Feast::HolySaturday => -1,
+            Feast::Easter => 0,
+            Feast::EasterMonday => 1,
+            Feast::Ascension => 39,
+            Feast::Pentecost => 49,
+            Feast::WhitMonday => 50,
+            Feast::TrinitySunday => 56,
+            Feast::CorpusChristi => 60,
+        }
+    }
+
+    pub fn alias(&self) -> &'static str {
+        match self {
+            Feast::CleanMonday => "洁净星期一",
+            Feast::AshWednesday => "圣灰星期三",
+            Feast::PalmSunday => "棕枝主日",
+            Feast::MaundyThursday => "濯足节",
+            Feast::GoodFriday => "耶稣受难日",
+            Feast::HolySaturday => "圣周六",
+            Feast::Easter => "复活节",
+            Feast::EasterMonday => "复活节星期一",
+            Feast::Ascension => "耶稣升天节",
+            Feast::Pentecost => "圣灵降临节",
+            Feast::WhitMonday => "圣灵降临节星期一",
+            Feast::TrinitySunday => "圣三主日",
+            Feast::CorpusChristi => "基督圣体节",
+        }
+    }
+
+    pub fn english(&self) -> &'static str {
+        match self {
+            Feast::CleanMonday => "Clean Monday",
+            Feast::AshWednesday => "Ash Wednesday",
+            Feast::PalmSunday => "Palm Sunday",
+            Feast::MaundyThursday => "Maundy Thursday",
+            Feast::GoodFriday => "Good Friday",
+            Feast::HolySaturday => "Holy Saturday",
+            Feast::Easter => "Easter Sunday",
+            Feast::EasterMonday => "Easter Monday",
+            Feast::Ascension => "Ascension Day",
+            Feast::Pentecost => "Pentecost",
+            Feast::WhitMonday => "Whit Monday",
+            Feast::TrinitySunday => "Trinity Sunday",
+            Feast::CorpusChristi => "Corpus Christi",
+        }
+    }
+
+    // 某年该节日的格里历日期
+    pub fn date(&self, year: i64, church: Church) -> IkigaiResult<NaiveDate> {
+        Ok(easter(year, church)? + Duration::days(self.offset()))
+    }
+}
+
+impl fmt::Display for Feast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.english())
+    }
+}
+
+// 某年复活节主日的格里历日期
+pub fn easter(year: i64, church: Church) -> IkigaiResult<NaiveDate> {
+    match church {
+        Church::Western => gregorian_easter(year),
+        Church::Orthodox => {
+            let (month, day) = julian_easter(year)?;
+            julian_to_gregorian(year, month, day)
+        }
+    }
+}
+
+// 某年全部移动节日, 按日期排列
+pub fn feasts(year: i64, church: Church) -> IkigaiResult<Vec<(Feast, NaiveDate)>> {
+    Feast::ALL
+        .into_iter()
+        .map(|feast| Ok((feast, feast.date(year, church)?)))
+        .collect()
+}
+
+fn check_year(year: i64, min: i64) -> IkigaiResult<()> {
+    if !(min..=COMPUTUS_TO_YEAR).contains(&year) {
+        return Err(IkigaiError::YearOutOfRange {
+            year,
+            min,
+            max: COMPUTUS_TO_YEAR,
+        });
+    }
+    Ok(())
+}
+
+// 格里历复活节, 采用 Meeus/Jones/Butcher 算法
+fn gregorian_easter(year: i64) -> IkigaiResult<NaiveDate> {
+    check_year(year, GREGORIAN_FROM_YEAR)?;
+    let a = year % 19;
+    let (b, c) = (year / 100, year % 100);
+    let (d, e) = (b / 4, b % 4);
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    // h 为春分后满月距 3 月 21 日的天数(默冬周期中的闰余)
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32).ok_or(IkigaiError::InvalidDate)
+}
+
+// 儒略历复活节的(月, 日), 采用 Meeus 的儒略历算法
+fn julian_easter(year: i64) -> IkigaiResult<(u32, u32)> {
+    check_year(year, JULIAN_FROM_YEAR)?;
+    let (a, b, c) = (year % 4, year % 7, year % 19);
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    Ok((((d + e + 114) / 31) as u32, ((d + e + 114) % 31 + 1) as u32))
+}
+
+// 儒略历日期换算为格里历, 经由儒略日数
+pub fn julian_to_gregorian(year: i64, month: u32, day: u32) -> IkigaiResult<NaiveDate> {
+    let is_leap = year % 4 == 0;
+    let max_day = match month {
+        2 if is_leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return Err(IkigaiError::InvalidDate),
+    };
+    if day == 0 || day > max_day {
+        return Err(IkigaiError::InvalidDate);
+    }
+    let a = (14 - month as i64) / 12;
+    let y = year + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+    let jdn = day as i64 + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
+    from_jdn(jdn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_western_easter() {
+        let tests = vec![
+            ("first_gregorian", date(1583, 4, 10)),
+            ("earliest", date(1818, 3, 22)),
+            ("y1900", date(1900, 4, 15)),
+            ("latest", date(1943, 4, 25)),
+            ("y1961", date(1961, 4, 2)),
+            ("y2000", date(2000, 4, 23)),
+            ("y2024", date(2024, 3, 31)),
+            ("y2025", date(2025, 4, 20)),
+            ("y2038", date(2038, 4, 25)),
+            ("y2100", date(2100, 3, 28)),
+            ("earliest_again", date(2285, 3, 22)),
+        ];
+        for (name, want) in tests {
+            let year = chrono::Datelike::year(&want) as i64;
+            assert_eq!(easter(year, Church::Western), Ok(want), "{} failed", name);
+        }
+        assert!(easter(1582, Church::Western).is_err());
+        assert!(easter(10000, Church::Western).is_err());
+    }
+
+    #[test]
+    fn test_orthodox_easter() {
+        let tests = vec![
+            ("y1900", date(1900, 4, 22)),
+            ("y2000", date(2000, 4, 30)),
+            ("same_2017", date(2017, 4, 16)),
+            ("y2023", date(2023, 4, 16)),
+            ("y2024", date(2024, 5, 5)),
+            ("same_2025", date(2025, 4, 20)),
+            ("y2026", date(2026, 4, 12)),
+        ];
+        for (name, want) in tests {
+            let year = chrono::Datelike::year(&want) as i64;
+            assert_eq!(easter(year, Church::Orthodox), Ok(want), "{} failed", name);
+        }
+        // 两者都是星期日, 东正教复活节不早于西方教会
+        for year in 1583..=2500 {
+            let western = easter(year, Church::Western).unwrap();
+            let orthodox = easter(year, Church::Orthodox).unwrap();
+            assert_eq!(chrono::Datelike::weekday(&western), chrono::Weekday::Sun);
+            assert_eq!(chrono::Datelike::weekday(&orthodox), chrono::Weekday::Sun);
+            assert!(orthodox >= western, "{} failed", year);
+        }
+    }
+
+    #[test]
+    fn test_feasts() {
+        let tests = vec![
+            (Feast::AshWednesday, Church::Western, date(2024, 2, 14)),
+            (Feast::GoodFriday, Church::Western, date(2024, 3, 29)),
+            (Feast::Ascension, Church::Western, date(2024, 5, 9)),
+            (Feast::Pentecost, Church::Western, date(2024, 5, 19)),
+            (Feast::CorpusChristi, Church::Western, date(2024, 5, 30)),
+            (Feast::CleanMonday, Church::Orthodox, date(2024, 3, 18)),
+            (Feast::Pentecost, Church::Orthodox, date(2024, 6, 23)),
+        ];
+        for (feast, church, want) in tests {
+            assert_eq!(feast.date(2024, church), Ok(want), "{} failed", feast);
+        }
+        let all = feasts(2024, Church::Western).unwrap();
+        assert_eq!(all.len(), Feast::ALL.len());
+        assert!(all.windows(2).all(|w| w[0].1 < w[1].1));
+        assert_eq!(Feast::Easter.alias(), "复活节");
+    }
+
+    #[test]
+    fn test_julian_to_gregorian() {
+        let tests = vec![
+            ("gregorian_reform", (1582, 10, 5), date(1582, 10, 15)),
+            ("y1700_leap_day", (1700, 2, 29), date(1700, 3, 11)),
+            ("twentieth_century", (1917, 10, 25), date(1917, 11, 7)),
+            ("y2100_shift", (2100, 3, 1), date(2100, 3, 15)),
+        ];
+        for (name, (y, m, d), want) in tests {
+            assert_eq!(julian_to_gregorian(y, m, d), Ok(want), "{} failed", name);
+        }
+        assert!(julian_to_gregorian(2023, 2, 29).is_err());
+        assert!(julian_to_gregorian(2023, 13, 1).is_err());
+    }
+}
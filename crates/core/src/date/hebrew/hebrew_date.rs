@@ -0,0 +1,553 @@
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 创世纪元 1 年提斯利月初一(儒略历公元前 3761-10-07), 以 num_days_from_ce 计
+const HEBREW_EPOCH: i64 = -1373427;
+// 一日分为 24 小时, 每小时 1080 分(chalakim)
+const PARTS_PER_HOUR: i64 = 1080;
+const PARTS_PER_DAY: i64 = 24 * PARTS_PER_HOUR;
+// 朔望月长 29 日 12 小时 793 分
+const PARTS_PER_MONTH: i64 = 29 * PARTS_PER_DAY + 12 * PARTS_PER_HOUR + 793;
+// 纪元前夜的合朔(BaHaRaD)为星期日 23:11:20, 早于午夜 876 分
+const MOLAD_EPOCH_PARTS: i64 = -876;
+const HEBREW_FROM_YEAR: i64 = 1;
+const HEBREW_TO_YEAR: i64 = 9999;
+
+// 希伯来历月份; 平年有亚达月, 闰年改为亚达一月与亚达二月
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HebrewMonth {
+    Tishrei,
+    Cheshvan,
+    Kislev,
+    Tevet,
+    Shevat,
+    Adar,
+    AdarI,
+    AdarII,
+    Nisan,
+    Iyar,
+    Sivan,
+    Tammuz,
+    Av,
+    Elul,
+}
+
+impl HebrewMonth {
+    // 以提斯利月为岁首的平年各月
+    const COMMON_YEAR: [HebrewMonth; 12] = [
+        HebrewMonth::Tishrei,
+        HebrewMonth::Cheshvan,
+        HebrewMonth::Kislev,
+        HebrewMonth::Tevet,
+        HebrewMonth::Shevat,
+        HebrewMonth::Adar,
+        HebrewMonth::Nisan,
+        HebrewMonth::Iyar,
+        HebrewMonth::Sivan,
+        HebrewMonth::Tammuz,
+        HebrewMonth::Av,
+        HebrewMonth::Elul,
+    ];
+
+    const LEAP_YEAR: [HebrewMonth; 13] = [
+        HebrewMonth::Tishrei,
+        HebrewMonth::Cheshvan,
+        HebrewMonth::Kislev,
+        HebrewMonth::Tevet,
+        HebrewMonth::Shevat,
+        HebrewMonth::AdarI,
+        HebrewMonth::AdarII,
+        HebrewMonth::Nisan,
+        HebrewMonth::Iyar,
+        HebrewMonth::Sivan,
+        HebrewMonth::Tammuz,
+        HebrewMonth::Av,
+        HebrewMonth::Elul,
+    ];
+
+    // 某年依次出现的月份
+    pub fn months_in_year(year: i64) -> &'static [HebrewMonth] {
+        if is_leap_year(year) {
+            &Self::LEAP_YEAR
+        } else {
+            &Self::COMMON_YEAR
+        }
+    }
+
+    pub fn english(&self) -> &'static str {
+        match self {
+            HebrewMonth::Tishrei => "Tishrei",
+            HebrewMonth::Cheshvan => "Cheshvan",
+            HebrewMonth::Kislev => "Kislev",
+            HebrewMonth::Tevet => "Tevet",
+            HebrewMonth::Shevat => "Shevat",
+            HebrewMonth::Adar => "Adar",
+            HebrewMonth::AdarI => "Adar I",
+            HebrewMonth::AdarII => "Adar II",
+            HebrewMonth::Nisan => "Nisan",
+            HebrewMonth::Iyar => "Iyar",
+            HebrewMonth::Sivan => "Sivan",
+            HebrewMonth::Tammuz => "Tammuz",
+            HebrewMonth::Av => "Av",
+            HebrewMonth::Elul => "Elul",
+        }
+    }
+
+    pub fn hebrew(&self) -> &'static str {
+        match self {
+            HebrewMonth::Tishrei => "תשרי",
+            HebrewMonth::Cheshvan => "חשוון",
+            HebrewMonth::Kislev => "כסלו",
+            HebrewMonth::Tevet => "טבת",
+            HebrewMonth::Shevat => "שבט",
+            HebrewMonth::Adar => "אדר",
+            HebrewMonth::AdarI => "אדר א׳",
+            HebrewMonth::AdarII => "אדר ב׳",
+            HebrewMonth::Nisan => "ניסן",
+            HebrewMonth::Iyar => "אייר",
+            HebrewMonth::Sivan => "סיוון",
+            HebrewMonth::Tammuz => "תמוז",
+            HebrewMonth::Av => "אב",
+            HebrewMonth::Elul => "אלול",
+        }
+    }
+
+    pub fn alias(&self) -> &'static str {
+        match self {
+            HebrewMonth::Tishrei => "提斯利月",
+            HebrewMonth::Cheshvan => "玛西班月",
+            HebrewMonth::Kislev => "基斯流月",
+            HebrewMonth::Tevet => "提别月",
+            HebrewMonth::Shevat => "细罢特月",
+            HebrewMonth::Adar => "亚达月",
+            HebrewMonth::AdarI => "亚达一月",
+            HebrewMonth::AdarII => "亚达二月",
+            HebrewMonth::Nisan => "尼散月",
+            HebrewMonth::Iyar => "以珥月",
+            HebrewMonth::Sivan => "西弯月",
+            HebrewMonth::Tammuz => "搭模斯月",
+            HebrewMonth::Av => "埃波月",
+            HebrewMonth::Elul => "以禄月",
+        }
+    }
+
+    // 以尼散月为 1 的传统月序, 亚达一月与亚达月同为 12, 亚达二月为 13
+    pub fn number(&self) -> i64 {
+        match self {
+            HebrewMonth::Nisan => 1,
+            HebrewMonth::Iyar => 2,
+            HebrewMonth::Sivan => 3,
+            HebrewMonth::Tammuz => 4,
+            HebrewMonth::Av => 5,
+            HebrewMonth::Elul => 6,
+            HebrewMonth::Tishrei => 7,
+            HebrewMonth::Cheshvan => 8,
+            HebrewMonth::Kislev => 9,
+            HebrewMonth::Tevet => 10,
+            HebrewMonth::Shevat => 11,
+            HebrewMonth::Adar | HebrewMonth::AdarI => 12,
+            HebrewMonth::AdarII => 13,
+        }
+    }
+
+    // 在某年中的序号(提斯利月为 0); 亚达月与亚达一月的月序相同, 该年没有此月时按月名报错
+    fn position(&self, year: i64) -> IkigaiResult<usize> {
+        Self::months_in_year(year)
+            .iter()
+            .position(|m| m == self)
+            .ok_or(IkigaiError::MonthNotInYear {
+                year,
+                month: self.english(),
+            })
+    }
+}
+
+impl fmt::Display for HebrewMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.english())
+    }
+}
+
+// 默冬周期 19 年中第 3、6、8、11、14、17、19 年为闰年
+pub fn is_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+// 纪元至某年岁首前经过的朔望月数
+fn months_elapsed(year: i64) -> i64 {
+    (235 * year - 234).div_euclid(19)
+}
+
+// 纪元至某年提斯利月合朔所在日的天数, 已含午后合朔(molad zaken)
+// 与岁首不逢星期日、三、五(lo ADU rosh)两条推迟规则
+fn elapsed_days(year: i64) -> i64 {
+    let months = months_elapsed(year);
+    let parts = 12084 + 13753 * months;
+    let days = 29 * months + parts.div_euclid(25920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+// 另两条推迟规则: 避免平年长 356 天(GaTaRaD)与闰年后一年长 382 天(BeTUTaKPaT)
+fn year_length_correction(year: i64) -> i64 {
+    let (ny0, ny1, ny2) = (
+        elapsed_days(year - 1),
+        elapsed_days(year),
+        elapsed_days(year + 1),
+    );
+    if ny2 - ny1 == 356 {
+        2
+    } else if ny1 - ny0 == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+// 岁首(Rosh Hashanah), 以 num_days_from_ce 计
+fn new_year(year: i64) -> i64 {
+    HEBREW_EPOCH + elapsed_days(year) + year_length_correction(year)
+}
+
+fn check_year(year: i64) -> IkigaiResult<()> {
+    if !(HEBREW_FROM_YEAR..=HEBREW_TO_YEAR).contains(&year) {
+        return Err(IkigaiError::YearOutOfRange {
+            year,
+            min: HEBREW_FROM_YEAR,
+            max: HEBREW_TO_YEAR,
+        });
+    }
+    Ok(())
+}
+
+// 一年的天数: 平年 353、354、355 天, 闰年 383、384、385 天
+pub fn days_in_year(year: i64) -> IkigaiResult<i64> {
+    check_year(year)?;
+    Ok(new_year(year + 1) - new_year(year))
+}
+
+pub fn days_in_month(year: i64, month: HebrewMonth) -> IkigaiResult<u32> {
+    let len = days_in_year(year)?;
+    month.position(year)?;
+    Ok(match month {
+        // 全年 355 或 385 天时玛西班月为大月, 353 或 383 天时基斯流月为小月
+        HebrewMonth::Cheshvan if len % 10 == 5 => 30,
+        HebrewMonth::Kislev if len % 10 == 3 => 29,
+        HebrewMonth::Tishrei
+        | HebrewMonth::Kislev
+        | HebrewMonth::Shevat
+        | HebrewMonth::AdarI
+        | HebrewMonth::Nisan
+        | HebrewMonth::Sivan
+        | HebrewMonth::Av => 30,
+        _ => 29,
+    })
+}
+
+// 平均合朔时刻(molad), 采用耶路撒冷地方平时
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Molad {
+    // 自 num_days_from_ce 的零日零时起的分数
+    parts: i64,
+}
+
+impl Molad {
+    // 某年某月的合朔
+    pub fn new(year: i64, month: HebrewMonth) -> IkigaiResult<Self> {
+        check_year(year)?;
+        let position = month.position(year)? as i64;
+        let months = months_elapsed(year) + position;
+        Ok(Molad {
+            parts: HEBREW_EPOCH * PARTS_PER_DAY + MOLAD_EPOCH_PARTS + months * PARTS_PER_MONTH,
+        })
+    }
+
+    // 精确到分钟的时刻, 余下不足一分钟的部分见 chalakim
+    pub fn time(&self) -> NaiveDateTime {
+        let (days, parts) = (
+            self.parts.div_euclid(PARTS_PER_DAY),
+            self.parts.rem_euclid(PARTS_PER_DAY),
+        );
+        let (hour, minute) = (parts / PARTS_PER_HOUR, parts % PARTS_PER_HOUR / 18);
+        let date = NaiveDate::from_num_days_from_ce_opt(days as i32).unwrap();
+        date.and_time(NaiveTime::from_hms_opt(hour as u32, minute as u32, 0).unwrap())
+    }
+
+    // 不足一分钟的分数, 每分钟 18 分
+    pub fn chalakim(&self) -> u32 {
+        (self.parts.rem_euclid(PARTS_PER_DAY) % 18) as u32
+    }
+}
+
+// 希伯来历日期, 不含时刻; 一日实际始于前一日日落
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HebrewDate {
+    year: i64,
+    month: HebrewMonth,
+    day: u32,
+}
+
+impl HebrewDate {
+    // 创建并校验日期, 月份须出现在该年(平年无亚达一、二月, 闰年无亚达月)
+    pub fn new(year: i64, month: HebrewMonth, day: u32) -> IkigaiResult<Self> {
+        let max = days_in_month(year, month)?;
+        if !(1..=max).contains(&day) {
+            return Err(IkigaiError::InvalidLunarDay {
+                year,
+                month: month.number(),
+                day: day as i64,
+                max: max as i64,
+            });
+        }
+        Ok(HebrewDate { year, month, day })
+    }
+
+    pub fn from_naive_date(date: NaiveDate) -> IkigaiResult<Self> {
+        let fixed = date.num_days_from_ce() as i64;
+        // 平均年长 35975351/98496 日, 估算后逐年校正
+        let mut year = (fixed - HEBREW_EPOCH) * 98496 / 35975351;
+        while new_year(year + 1) <= fixed {
+            year += 1;
+        }
+        check_year(year)?;
+        let mut day = fixed - new_year(year);
+        for &month in HebrewMonth::months_in_year(year) {
+            let len = days_in_month(year, month)? as i64;
+            if day < len {
+                return Ok(HebrewDate {
+                    year,
+                    month,
+                    day: day as u32 + 1,
+                });
+            }
+            day -= len;
+        }
+        Err(IkigaiError::InvalidDate)
+    }
+
+    pub fn to_naive_date(&self) -> IkigaiResult<NaiveDate> {
+        let mut fixed = new_year(self.year) + self.day as i64 - 1;
+        for &month in HebrewMonth::months_in_year(self.year) {
+            if month == self.month {
+                break;
+            }
+            fixed += days_in_month(self.year, month)? as i64;
+        }
+        i32::try_from(fixed)
+            .ok()
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .ok_or(IkigaiError::InvalidDate)
+    }
+
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    pub fn month(&self) -> HebrewMonth {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+}
+
+// 形如 `1 Tishrei 5785`
+impl fmt::Display for HebrewDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.day, self.month, self.year)
+    }
+}
+
+impl TryFrom<NaiveDate> for HebrewDate {
+    type Error = IkigaiError;
+
+    fn try_from(date: NaiveDate) -> IkigaiResult<Self> {
+        HebrewDate::from_naive_date(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_new_year() {
+        let tests = vec![
+            (5700, date(1939, 9, 14), 385),
+            (5760, date(1999, 9, 11), 385),
+            (5783, date(2022, 9, 26), 355),
+            (5784, date(2023, 9, 16), 383),
+            (5785, date(2024, 10, 3), 355),
+            (5786, date(2025, 9, 23), 354),
+        ];
+        for (year, want, len) in tests {
+            let rosh = HebrewDate::new(year, HebrewMonth::Tishrei, 1).unwrap();
+            assert_eq!(rosh.to_naive_date(), Ok(want), "{} failed", year);
+            assert_eq!(days_in_year(year), Ok(len), "{} failed", year);
+        }
+        // 推迟规则保证岁首不逢星期日、三、五, 年长只有六种
+        for year in 5000..6000 {
+            let rosh = HebrewDate::new(year, HebrewMonth::Tishrei, 1)
+                .unwrap()
+                .to_naive_date()
+                .unwrap();
+            assert!(![0, 3, 5].contains(&rosh.weekday().num_days_from_sunday()));
+            let len = days_in_year(year).unwrap();
+            let lens: &[i64] = if is_leap_year(year) {
+                &[383, 384, 385]
+            } else {
+                &[353, 354, 355]
+            };
+            assert!(lens.contains(&len), "{} has {} days", year, len);
+        }
+    }
+
+    #[test]
+    fn test_leap_year() {
+        let leaps: Vec<i64> = (5782..=5800).filter(|&y| is_leap_year(y)).collect();
+        assert_eq!(leaps, vec![5782, 5784, 5787, 5790, 5793, 5795, 5798]);
+        assert_eq!(HebrewMonth::months_in_year(5784).len(), 13);
+        assert_eq!(HebrewMonth::months_in_year(5785).len(), 12);
+        assert_eq!(
+            HebrewDate::new(5784, HebrewMonth::Adar, 1),
+            Err(IkigaiError::MonthNotInYear {
+                year: 5784,
+                month: "Adar"
+            })
+        );
+        assert_eq!(
+            HebrewDate::new(5785, HebrewMonth::AdarII, 1),
+            Err(IkigaiError::MonthNotInYear {
+                year: 5785,
+                month: "Adar II"
+            })
+        );
+        assert_eq!(
+            HebrewDate::new(5784, HebrewMonth::AdarII, 14)
+                .unwrap()
+                .to_naive_date(),
+            Ok(date(2024, 3, 24))
+        );
+    }
+
+    #[test]
+    fn test_conversion() {
+        let tests = vec![
+            (
+                "hanukkah_5785",
+                (5785, HebrewMonth::Kislev, 25),
+                date(2024, 12, 26),
+            ),
+            (
+                "pesach_5784",
+                (5784, HebrewMonth::Nisan, 15),
+                date(2024, 4, 23),
+            ),
+            (
+                "shavuot_5784",
+                (5784, HebrewMonth::Sivan, 6),
+                date(2024, 6, 12),
+            ),
+            (
+                "adar_i_5784",
+                (5784, HebrewMonth::AdarI, 30),
+                date(2024, 3, 10),
+            ),
+            (
+                "elul_end_5784",
+                (5784, HebrewMonth::Elul, 29),
+                date(2024, 10, 2),
+            ),
+            (
+                "long_cheshvan",
+                (5785, HebrewMonth::Cheshvan, 30),
+                date(2024, 12, 1),
+            ),
+        ];
+        for (name, (y, m, d), want) in tests {
+            let hebrew = HebrewDate::new(y, m, d).unwrap();
+            assert_eq!(hebrew.to_naive_date(), Ok(want), "{} failed", name);
+            assert_eq!(
+                HebrewDate::from_naive_date(want),
+                Ok(hebrew),
+                "{} failed",
+                name
+            );
+        }
+
+        // 逐日往返换算
+        let mut day = date(1900, 1, 1);
+        let mut prev = HebrewDate::from_naive_date(day).unwrap();
+        while day < date(2100, 1, 1) {
+            day = day.succ_opt().unwrap();
+            let hebrew = HebrewDate::from_naive_date(day).unwrap();
+            assert_ne!(hebrew, prev);
+            assert_eq!(hebrew.to_naive_date(), Ok(day));
+            prev = hebrew;
+        }
+        assert!(HebrewDate::new(5786, HebrewMonth::Kislev, 30).is_ok());
+        assert_eq!(
+            HebrewDate::new(5786, HebrewMonth::Cheshvan, 30),
+            Err(IkigaiError::InvalidLunarDay {
+                year: 5786,
+                month: 8,
+                day: 30,
+                max: 29
+            })
+        );
+        assert!(HebrewDate::new(0, HebrewMonth::Tishrei, 1).is_err());
+        assert_eq!(
+            HebrewDate::new(5785, HebrewMonth::Tishrei, 1)
+                .unwrap()
+                .to_string(),
+            "1 Tishrei 5785"
+        );
+    }
+
+    #[test]
+    fn test_molad() {
+        // BaHaRaD: 纪元前夜星期日 23:11 又 6 分
+        let molad = Molad::new(1, HebrewMonth::Tishrei).unwrap();
+        assert_eq!(
+            molad.time().time(),
+            NaiveTime::from_hms_opt(23, 11, 0).unwrap()
+        );
+        assert_eq!(molad.chalakim(), 6);
+
+        let tests = vec![
+            ((5785, HebrewMonth::Tishrei), "2024-10-03 03:21", 13),
+            ((5785, HebrewMonth::Cheshvan), "2024-11-01 16:05", 14),
+        ];
+        for ((year, month), want, chalakim) in tests {
+            let molad = Molad::new(year, month).unwrap();
+            assert_eq!(molad.time().format("%Y-%m-%d %H:%M").to_string(), want);
+            assert_eq!(molad.chalakim(), chalakim);
+        }
+        // 相邻两次合朔相隔 29 日 12 小时 44 分 1 分
+        let next = Molad::new(5785, HebrewMonth::Cheshvan).unwrap();
+        let prev = Molad::new(5785, HebrewMonth::Tishrei).unwrap();
+        assert_eq!(next.parts - prev.parts, PARTS_PER_MONTH);
+        assert_eq!(
+            Molad::new(5785, HebrewMonth::AdarI),
+            Err(IkigaiError::MonthNotInYear {
+                year: 5785,
+                month: "Adar I"
+            })
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use super::hebrew_date::{is_leap_year, HebrewDate, HebrewMonth};
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 公历年与岁首在其秋季的希伯来历年之差
+const GREGORIAN_TO_HEBREW_YEAR: i64 = 3761;
+
+// 犹太教主要节日; 节日于前一日日落开始, 日期取首个白天所在的公历日
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JewishHoliday {
+    RoshHashanah,
+    YomKippur,
+    Sukkot,
+    Hanukkah,
+    Purim,
+    Pesach,
+    Shavuot,
+}
+
+impl JewishHoliday {
+    pub const ALL: [JewishHoliday; 7] = [
+        JewishHoliday::RoshHashanah,
+        JewishHoliday::YomKippur,
+        JewishHoliday::Sukkot,
+        JewishHoliday::Hanukkah,
+        JewishHoliday::Purim,
+        JewishHoliday::Pesach,
+        JewishHoliday::Shavuot,
+    ];
+
+    pub fn alias(&self) -> &'static str {
+        match self {
+            JewishHoliday::RoshHashanah => "犹太新年",
+            JewishHoliday::YomKippur => "赎罪日",
+            JewishHoliday::Sukkot => "住棚节",
+            JewishHoliday::Hanukkah => "光明节",
+            JewishHoliday::Purim => "普珥节",
+            JewishHoliday::Pesach => "逾越节",
+            JewishHoliday::Shavuot => "七七节",
+        }
+    }
+
+    pub fn english(&self) -> &'static str {
+        match self {
+            JewishHoliday::RoshHashanah => "Rosh Hashanah",
+            JewishHoliday::YomKippur => "Yom Kippur",
+            JewishHoliday::Sukkot => "Sukkot",
+            JewishHoliday::Hanukkah => "Hanukkah",
+            JewishHoliday::Purim => "Purim",
+            JewishHoliday::Pesach => "Pesach",
+            JewishHoliday::Shavuot => "Shavuot",
+        }
+    }
+
+    // 以色列的节期天数, 以色列以外的逾越节与七七节各多一天
+    pub fn days(&self) -> u32 {
+        match self {
+            JewishHoliday::RoshHashanah => 2,
+            JewishHoliday::YomKippur | JewishHoliday::Purim | JewishHoliday::Shavuot => 1,
+            JewishHoliday::Sukkot | JewishHoliday::Pesach => 7,
+            JewishHoliday::Hanukkah => 8,
+        }
+    }
+
+    // 某希伯来历年中的首日
+    pub fn hebrew_date(&self, year: i64) -> IkigaiResult<HebrewDate> {
+        let (month, day) = match self {
+            JewishHoliday::RoshHashanah => (HebrewMonth::Tishrei, 1),
+            JewishHoliday::YomKippur => (HebrewMonth::Tishrei, 10),
+            JewishHoliday::Sukkot => (HebrewMonth::Tishrei, 15),
+            JewishHoliday::Hanukkah => (HebrewMonth::Kislev, 25),
+            // 闰年的普珥节在亚达二月
+            JewishHoliday::Purim if is_leap_year(year) => (HebrewMonth::AdarII, 14),
+            JewishHoliday::Purim => (HebrewMonth::Adar, 14),
+            JewishHoliday::Pesach => (HebrewMonth::Nisan, 15),
+            JewishHoliday::Shavuot => (HebrewMonth::Sivan, 6),
+        };
+        HebrewDate::new(year, month, day)
+    }
+
+    // 某公历年中的首日, 每个节日每年恰好出现一次
+    pub fn date(&self, year: i32) -> IkigaiResult<NaiveDate> {
+        let hebrew_year = year as i64 + GREGORIAN_TO_HEBREW_YEAR;
+        for y in [hebrew_year - 1, hebrew_year] {
+            let date = self.hebrew_date(y)?.to_naive_date()?;
+            if date.year() == year {
+                return Ok(date);
+            }
+        }
+        Err(IkigaiError::HolidayNotInYear {
+            name: self.english(),
+            year: year as i64,
+        })
+    }
+}
+
+impl fmt::Display for JewishHoliday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.english())
+    }
+}
+
+// 某公历年的全部节日, 按日期排列
+pub fn holidays(year: i32) -> IkigaiResult<Vec<(JewishHoliday, NaiveDate)>> {
+    let mut holidays = JewishHoliday::ALL
+        .into_iter()
+        .map(|holiday| Ok((holiday, holiday.date(year)?)))
+        .collect::<IkigaiResult<Vec<_>>>()?;
+    holidays.sort_by_key(|&(_, date)| date);
+    Ok(holidays)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_holiday_date() {
+        let tests = vec![
+            (JewishHoliday::RoshHashanah, date(2024, 10, 3)),
+            (JewishHoliday::YomKippur, date(2024, 10, 12)),
+            (JewishHoliday::Sukkot, date(2024, 10, 17)),
+            (JewishHoliday::Hanukkah, date(2024, 12, 26)),
+            (JewishHoliday::Purim, date(2024, 3, 24)),
+            (JewishHoliday::Pesach, date(2024, 4, 23)),
+            (JewishHoliday::Shavuot, date(2024, 6, 12)),
+            (JewishHoliday::RoshHashanah, date(2025, 9, 23)),
+            (JewishHoliday::YomKippur, date(2025, 10, 2)),
+            (JewishHoliday::Hanukkah, date(2025, 12, 15)),
+            (JewishHoliday::Purim, date(2025, 3, 14)),
+            (JewishHoliday::Pesach, date(2025, 4, 13)),
+            (JewishHoliday::Shavuot, date(2025, 6, 2)),
+            (JewishHoliday::Pesach, date(2000, 4, 20)),
+            (JewishHoliday::RoshHashanah, date(1948, 10, 4)),
+        ];
+        for (holiday, want) in tests {
+            assert_eq!(
+                holiday.date(want.year()),
+                Ok(want),
+                "{} {} failed",
+                holiday,
+                want.year()
+            );
+        }
+    }
+
+    #[test]
+    fn test_holidays() {
+        let got: Vec<_> = holidays(2024)
+            .unwrap()
+            .into_iter()
+            .map(|(holiday, _)| holiday)
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                JewishHoliday::Purim,
+                JewishHoliday::Pesach,
+                JewishHoliday::Shavuot,
+                JewishHoliday::RoshHashanah,
+                JewishHoliday::YomKippur,
+                JewishHoliday::Sukkot,
+                JewishHoliday::Hanukkah,
+            ]
+        );
+        // 逾越节总在尼散月 15 日, 且不逢星期一、三、五
+        for year in 1900..2100 {
+            let weekday = JewishHoliday::Pesach.date(year).unwrap().weekday();
+            assert!(
+                ![1, 3, 5].contains(&weekday.num_days_from_sunday()),
+                "{}",
+                year
+            );
+        }
+        assert_eq!(JewishHoliday::Hanukkah.days(), 8);
+        assert_eq!(JewishHoliday::YomKippur.alias(), "赎罪日");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod hebrew_date;
+pub mod holiday;
@@ -0,0 +1,509 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use super::umm_al_qura;
+use crate::{
+    date::astro::{from_jdn, jdn},
+    ikigai_error::{IkigaiError, IkigaiResult},
+};
+
+// 回历元年穆哈兰姆月初一(儒略历 622-07-16)的儒略日数, 即民用历元
+const HIJRI_EPOCH_JDN: i64 = 1948440;
+// 表格历以 30 年为一周期, 其中 11 个闰年
+const CYCLE_YEARS: i64 = 30;
+const CYCLE_DAYS: i64 = 30 * 354 + 11;
+const TABULAR_FROM_YEAR: i64 = 1;
+const TABULAR_TO_YEAR: i64 = 9999;
+
+// 表格历的置闰方案: 30 年周期中哪 11 年在第 12 月末加一天
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeapPattern {
+    // 第 16 年为闰年, 最常用的方案(al-Khwarizmi 所载, 亦即 Kuwaiti 算法)
+    #[default]
+    Standard,
+    // 第 15 年为闰年, 见于 Kūshyār ibn Labbān
+    Kushyar,
+    // 法蒂玛王朝与 Bohra 派使用
+    Fatimid,
+    // Habash al-Hasib 与 al-Biruni 所载
+    HabashAlHasib,
+}
+
+impl LeapPattern {
+    // 30 年周期内的闰年序号
+    fn leap_years(&self) -> [i64; 11] {
+        match self {
+            LeapPattern::Standard => [2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29],
+            LeapPattern::Kushyar => [2, 5, 7, 10, 13, 15, 18, 21, 24, 26, 29],
+            LeapPattern::Fatimid => [2, 5, 8, 10, 13, 16, 19, 21, 24, 27, 29],
+            LeapPattern::HabashAlHasib => [2, 5, 8, 11, 13, 16, 19, 21, 24, 27, 30],
+        }
+    }
+
+    pub fn is_leap_year(&self, year: i64) -> bool {
+        self.leap_years()
+            .contains(&((year - 1).rem_euclid(CYCLE_YEARS) + 1))
+    }
+
+    // 某年穆哈兰姆月初一的儒略日数
+    fn year_start(&self, year: i64) -> i64 {
+        let (cycles, rest) = (
+            (year - 1).div_euclid(CYCLE_YEARS),
+            (year - 1).rem_euclid(CYCLE_YEARS),
+        );
+        let leaps = self.leap_years().iter().filter(|&&y| y <= rest).count() as i64;
+        HIJRI_EPOCH_JDN + cycles * CYCLE_DAYS + rest * 354 + leaps
+    }
+}
+
+// 回历的推算方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HijriCalendar {
+    // 按固定规则置闰的表格历, 适用于任意年份
+    Tabular(LeapPattern),
+    // 沙特官方历, 仅覆盖内置数据表的年份
+    UmmAlQura,
+}
+
+impl Default for HijriCalendar {
+    fn default() -> Self {
+        HijriCalendar::Tabular(LeapPattern::default())
+    }
+}
+
+impl HijriCalendar {
+    // 支持的年份范围
+    pub fn year_range(&self) -> (i64, i64) {
+        match self {
+            HijriCalendar::Tabular(_) => (TABULAR_FROM_YEAR, TABULAR_TO_YEAR),
+            HijriCalendar::UmmAlQura => (umm_al_qura::FROM_YEAR, umm_al_qura::TO_YEAR),
+        }
+    }
+
+    fn check_year(&self, year: i64) -> IkigaiResult<()> {
+        let (min, max) = self.year_range();
+        if !(min..=max).contains(&year) {
+            return Err(IkigaiError::YearOutOfRange { year, min, max });
+        }
+        Ok(())
+    }
+
+    pub fn days_in_month(&self, year: i64, month: u32) -> IkigaiResult<u32> {
+        self.check_year(year)?;
+        if !(1..=12).contains(&month) {
+            return Err(IkigaiError::InvalidLunarMonth {
+                year,
+                month: month as i64,
+            });
+        }
+        Ok(match self {
+            // 单月 30 天, 双月 29 天, 闰年第 12 月为 30 天
+            HijriCalendar::Tabular(pattern) => {
+                if month % 2 == 1 || (month == 12 && pattern.is_leap_year(year)) {
+                    30
+                } else {
+                    29
+                }
+            }
+            HijriCalendar::UmmAlQura => umm_al_qura::days_in_month(year, month),
+        })
+    }
+
+    // 平年 354 天, 闰年 355 天
+    pub fn days_in_year(&self, year: i64) -> IkigaiResult<u32> {
+        self.check_year(year)?;
+        Ok((self.year_start(year + 1) - self.year_start(year)) as u32)
+    }
+
+    fn year_start(&self, year: i64) -> i64 {
+        match self {
+            HijriCalendar::Tabular(pattern) => pattern.year_start(year),
+            HijriCalendar::UmmAlQura => umm_al_qura::year_start(year),
+        }
+    }
+
+    // 儒略日数所在的回历年, 先按表格历估算再逐年校正
+    fn year_of(&self, jdn: i64) -> IkigaiResult<i64> {
+        let pattern = match self {
+            HijriCalendar::Tabular(pattern) => *pattern,
+            HijriCalendar::UmmAlQura => LeapPattern::default(),
+        };
+        let mut year = (jdn - HIJRI_EPOCH_JDN).div_euclid(CYCLE_DAYS) * CYCLE_YEARS + 1;
+        while pattern.year_start(year + 1) <= jdn {
+            year += 1;
+        }
+        let (min, max) = self.year_range();
+        if jdn < self.year_start(min) || jdn >= self.year_start(max + 1) {
+            return Err(IkigaiError::YearOutOfRange { year, min, max });
+        }
+        // 官方历与表格历的年界可能相差一两天
+        year = year.clamp(min, max);
+        while self.year_start(year) > jdn {
+            year -= 1;
+        }
+        while self.year_start(year + 1) <= jdn {
+            year += 1;
+        }
+        Ok(year)
+    }
+}
+
+// 回历月份
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HijriMonth {
+    Muharram = 1,
+    Safar,
+    RabiAlAwwal,
+    RabiAlThani,
+    JumadaAlUla,
+    JumadaAlAkhirah,
+    Rajab,
+    Shaban,
+    Ramadan,
+    Shawwal,
+    DhuAlQadah,
+    DhuAlHijjah,
+}
+
+impl HijriMonth {
+    pub const ALL: [HijriMonth; 12] = [
+        HijriMonth::Muharram,
+        HijriMonth::Safar,
+        HijriMonth::RabiAlAwwal,
+        HijriMonth::RabiAlThani,
+        HijriMonth::JumadaAlUla,
+        HijriMonth::JumadaAlAkhirah,
+        HijriMonth::Rajab,
+        HijriMonth::Shaban,
+        HijriMonth::Ramadan,
+        HijriMonth::Shawwal,
+        HijriMonth::DhuAlQadah,
+        HijriMonth::DhuAlHijjah,
+    ];
+
+    pub fn from_number(month: u32) -> Option<HijriMonth> {
+        Self::ALL.get((month as usize).checked_sub(1)?).copied()
+    }
+
+    pub fn number(&self) -> u32 {
+        *self as u32
+    }
+
+    pub fn english(&self) -> &'static str {
+        match self {
+            HijriMonth::Muharram => "Muharram",
+            HijriMonth::Safar => "Safar",
+            HijriMonth::RabiAlAwwal => "Rabi' al-Awwal",
+            HijriMonth::RabiAlThani => "Rabi' al-Thani",
+            HijriMonth::JumadaAlUla => "Jumada al-Ula",
+            HijriMonth::JumadaAlAkhirah => "Jumada al-Akhirah",
+            HijriMonth::Rajab => "Rajab",
+            HijriMonth::Shaban => "Sha'ban",
+            HijriMonth::Ramadan => "Ramadan",
+            HijriMonth::Shawwal => "Shawwal",
+            HijriMonth::DhuAlQadah => "Dhu al-Qa'dah",
+            HijriMonth::DhuAlHijjah => "Dhu al-Hijjah",
+        }
+    }
+
+    pub fn arabic(&self) -> &'static str {
+        match self {
+            HijriMonth::Muharram => "محرم",
+            HijriMonth::Safar => "صفر",
+            HijriMonth::RabiAlAwwal => "ربيع الأول",
+            HijriMonth::RabiAlThani => "ربيع الآخر",
+            HijriMonth::JumadaAlUla => "جمادى الأولى",
+            HijriMonth::JumadaAlAkhirah => "جمادى الآخرة",
+            HijriMonth::Rajab => "رجب",
+            HijriMonth::Shaban => "شعبان",
+            HijriMonth::Ramadan => "رمضان",
+            HijriMonth::Shawwal => "شوال",
+            HijriMonth::DhuAlQadah => "ذو القعدة",
+            HijriMonth::DhuAlHijjah => "ذو الحجة",
+        }
+    }
+
+    pub fn alias(&self) -> &'static str {
+        match self {
+            HijriMonth::Muharram => "穆哈兰姆月",
+            HijriMonth::Safar => "色法尔月",
+            HijriMonth::RabiAlAwwal => "赖比尔·敖外鲁月",
+            HijriMonth::RabiAlThani => "赖比尔·阿色尼月",
+            HijriMonth::JumadaAlUla => "主马达·敖外鲁月",
+            HijriMonth::JumadaAlAkhirah => "主马达·阿色尼月",
+            HijriMonth::Rajab => "赖哲卜月",
+            HijriMonth::Shaban => "舍尔邦月",
+            HijriMonth::Ramadan => "赖买丹月",
+            HijriMonth::Shawwal => "闪瓦鲁月",
+            HijriMonth::DhuAlQadah => "都尔喀尔德月",
+            HijriMonth::DhuAlHijjah => "都尔黑哲月",
+        }
+    }
+}
+
+impl fmt::Display for HijriMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.english())
+    }
+}
+
+// 回历日期, 不含时刻; 与农历相同, 日期本身不记录推算方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HijriDate {
+    year: i64,
+    month: u32,
+    day: u32,
+}
+
+impl HijriDate {
+    // 按默认的表格历创建并校验回历日期
+    pub fn new(year: i64, month: u32, day: u32) -> IkigaiResult<Self> {
+        Self::new_in(year, month, day, HijriCalendar::default())
+    }
+
+    pub fn new_in(year: i64, month: u32, day: u32, calendar: HijriCalendar) -> IkigaiResult<Self> {
+        let max = calendar.days_in_month(year, month)?;
+        if !(1..=max).contains(&day) {
+            return Err(IkigaiError::InvalidLunarDay {
+                year,
+                month: month as i64,
+                day: day as i64,
+                max: max as i64,
+            });
+        }
+        Ok(HijriDate { year, month, day })
+    }
+
+    pub fn from_naive_date(date: NaiveDate) -> IkigaiResult<Self> {
+        Self::from_naive_date_in(date, HijriCalendar::default())
+    }
+
+    pub fn from_naive_date_in(date: NaiveDate, calendar: HijriCalendar) -> IkigaiResult<Self> {
+        let day_number = jdn(date);
+        let year = calendar.year_of(day_number)?;
+        let mut day = day_number - calendar.year_start(year);
+        let mut month = 1;
+        loop {
+            let len = calendar.days_in_month(year, month)? as i64;
+            if day < len {
+                break;
+            }
+            day -= len;
+            month += 1;
+        }
+        Ok(HijriDate {
+            year,
+            month,
+            day: day as u32 + 1,
+        })
+    }
+
+    pub fn to_naive_date(&self) -> IkigaiResult<NaiveDate> {
+        self.to_naive_date_in(HijriCalendar::default())
+    }
+
+    pub fn to_naive_date_in(&self, calendar: HijriCalendar) -> IkigaiResult<NaiveDate> {
+        Self::new_in(self.year, self.month, self.day, calendar)?;
+        let mut jdn = calendar.year_start(self.year) + self.day as i64 - 1;
+        for month in 1..self.month {
+            jdn += calendar.days_in_month(self.year, month)? as i64;
+        }
+        from_jdn(jdn)
+    }
+
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn hijri_month(&self) -> HijriMonth {
+        HijriMonth::ALL[self.month as usize - 1]
+    }
+}
+
+// 形如 `1 Ramadan 1445 AH`
+impl fmt::Display for HijriDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} AH", self.day, self.hijri_month(), self.year)
+    }
+}
+
+impl TryFrom<NaiveDate> for HijriDate {
+    type Error = IkigaiError;
+
+    fn try_from(date: NaiveDate) -> IkigaiResult<Self> {
+        HijriDate::from_naive_date(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_tabular() {
+        let tests = vec![
+            ("epoch", (1, 1, 1), date(622, 7, 19)),
+            ("y1000", (1000, 1, 1), date(1591, 10, 19)),
+            ("ramadan_1445", (1445, 9, 1), date(2024, 3, 11)),
+            ("muharram_1446", (1446, 1, 1), date(2024, 7, 8)),
+            ("leap_day_1445", (1445, 12, 30), date(2024, 7, 7)),
+        ];
+        for (name, (y, m, d), want) in tests {
+            let hijri = HijriDate::new(y, m, d).unwrap();
+            assert_eq!(hijri.to_naive_date(), Ok(want), "{} failed", name);
+            assert_eq!(
+                HijriDate::from_naive_date(want),
+                Ok(hijri),
+                "{} failed",
+                name
+            );
+        }
+
+        // 与 Kuwaiti 算法的通项公式逐日比对
+        let calendar = HijriCalendar::default();
+        for year in 1300..1600 {
+            for month in 1..=12 {
+                let want = 1
+                    + (29 * (month - 1) + month / 2)
+                    + (year - 1) * 354
+                    + (3 + 11 * year) / 30
+                    + 1948439;
+                let got = HijriDate::new(year, month as u32, 1)
+                    .unwrap()
+                    .to_naive_date()
+                    .unwrap();
+                assert_eq!(jdn(got), want);
+            }
+            let leap = (14 + 11 * year) % 30 < 11;
+            assert_eq!(LeapPattern::Standard.is_leap_year(year), leap);
+            assert_eq!(calendar.days_in_year(year), Ok(354 + leap as u32));
+        }
+    }
+
+    #[test]
+    fn test_leap_patterns() {
+        let tests = vec![
+            (LeapPattern::Standard, 15, false),
+            (LeapPattern::Standard, 16, true),
+            (LeapPattern::Kushyar, 15, true),
+            (LeapPattern::Kushyar, 16, false),
+            (LeapPattern::Fatimid, 8, true),
+            (LeapPattern::HabashAlHasib, 30, true),
+            (LeapPattern::HabashAlHasib, 29, false),
+        ];
+        for (pattern, year, want) in tests {
+            assert_eq!(pattern.is_leap_year(year), want, "{:?} {}", pattern, year);
+            // 周期位置相同的年份同为闰年
+            assert_eq!(pattern.is_leap_year(year + 30 * 47), want);
+        }
+        // 第 15 年与第 16 年之间两种方案相差一天
+        let (standard, kushyar) = (
+            HijriCalendar::Tabular(LeapPattern::Standard),
+            HijriCalendar::Tabular(LeapPattern::Kushyar),
+        );
+        let new_year = |calendar, year| {
+            HijriDate::new_in(year, 1, 1, calendar)
+                .unwrap()
+                .to_naive_date_in(calendar)
+                .unwrap()
+        };
+        assert_eq!(new_year(standard, 1425), new_year(kushyar, 1425));
+        assert_eq!(
+            new_year(standard, 1426) - new_year(kushyar, 1426),
+            chrono::Duration::days(-1)
+        );
+        assert_eq!(new_year(standard, 1427), new_year(kushyar, 1427));
+    }
+
+    #[test]
+    fn test_umm_al_qura() {
+        let calendar = HijriCalendar::UmmAlQura;
+        let tests = vec![
+            ("muharram_1423", (1423, 1, 1), date(2002, 3, 15)),
+            ("muharram_1430", (1430, 1, 1), date(2008, 12, 29)),
+            ("muharram_1440", (1440, 1, 1), date(2018, 9, 11)),
+            ("ramadan_1444", (1444, 9, 1), date(2023, 3, 23)),
+            ("ramadan_1445", (1445, 9, 1), date(2024, 3, 11)),
+            ("shawwal_1445", (1445, 10, 1), date(2024, 4, 10)),
+            ("dhu_al_hijjah_1445", (1445, 12, 1), date(2024, 6, 7)),
+            ("muharram_1446", (1446, 1, 1), date(2024, 7, 7)),
+            ("ramadan_1446", (1446, 9, 1), date(2025, 3, 1)),
+            ("muharram_1447", (1447, 1, 1), date(2025, 6, 26)),
+        ];
+        for (name, (y, m, d), want) in tests {
+            let hijri = HijriDate::new_in(y, m, d, calendar).unwrap();
+            assert_eq!(
+                hijri.to_naive_date_in(calendar),
+                Ok(want),
+                "{} failed",
+                name
+            );
+            assert_eq!(
+                HijriDate::from_naive_date_in(want, calendar),
+                Ok(hijri),
+                "{} failed",
+                name
+            );
+        }
+
+        // 全表往返换算
+        let (first, last) = (date(2002, 3, 15), date(2077, 11, 16));
+        let mut day = first;
+        let mut prev = HijriDate::from_naive_date_in(day, calendar).unwrap();
+        while day < last {
+            day = day.succ_opt().unwrap();
+            let hijri = HijriDate::from_naive_date_in(day, calendar).unwrap();
+            assert!(hijri > prev);
+            assert_eq!(hijri.to_naive_date_in(calendar), Ok(day));
+            prev = hijri;
+        }
+        assert_eq!(prev, HijriDate::new_in(1500, 12, 30, calendar).unwrap());
+        assert!(HijriDate::from_naive_date_in(date(2002, 3, 14), calendar).is_err());
+        assert!(HijriDate::from_naive_date_in(date(2077, 11, 17), calendar).is_err());
+        assert!(HijriDate::new_in(1422, 1, 1, calendar).is_err());
+        // 官方历某月可能只有 29 天
+        assert_eq!(calendar.days_in_month(1445, 9), Ok(30));
+        assert!(HijriDate::new_in(1445, 10, 30, calendar).is_err());
+    }
+
+    #[test]
+    fn test_invalid_date() {
+        let tests = vec![
+            ("month_zero", 1445, 0, 1),
+            ("month_13", 1445, 13, 1),
+            ("day_zero", 1445, 1, 0),
+            ("short_month", 1445, 2, 30),
+            ("common_year", 1444, 12, 30),
+            ("year_zero", 0, 1, 1),
+        ];
+        for (name, y, m, d) in tests {
+            assert!(HijriDate::new(y, m, d).is_err(), "{} failed", name);
+        }
+        assert!(HijriDate::from_naive_date(date(622, 7, 18)).is_err());
+    }
+
+    #[test]
+    fn test_month_names() {
+        let hijri = HijriDate::new(1445, 9, 1).unwrap();
+        assert_eq!(hijri.hijri_month(), HijriMonth::Ramadan);
+        assert_eq!(hijri.hijri_month().arabic(), "رمضان");
+        assert_eq!(hijri.hijri_month().alias(), "赖买丹月");
+        assert_eq!(hijri.to_string(), "1 Ramadan 1445 AH");
+        assert_eq!(HijriMonth::from_number(12), Some(HijriMonth::DhuAlHijjah));
+        assert_eq!(HijriMonth::from_number(0), None);
+        assert_eq!(HijriMonth::DhuAlQadah.number(), 11);
+    }
+}
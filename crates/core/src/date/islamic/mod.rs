@@ -0,0 +1,2 @@
+pub mod hijri;
+mod umm_al_qura;
@@ -0,0 +1,43 @@
+// 沙特官方的乌姆古拉历(Umm al-Qura), 回历 1423 至 1500 年
+//
+// 自 1423 年起的规则: 若当月二十九日麦加日落前已合朔且月落晚于日落,
+// 次日即为下月初一, 否则当月为 30 天. 表中各年按此规则逐月推算.
+
+pub(super) const FROM_YEAR: i64 = 1423;
+pub(super) const TO_YEAR: i64 = 1500;
+
+// 1423 年穆哈兰姆月初一(2002-03-15)的儒略日数
+pub(super) const FIRST_JDN: i64 = 2452349;
+
+// 每年各月的大小, 第 i 位为 1 表示第 i+1 月为 30 天
+pub(super) const MONTH_LENGTHS: [u16; (TO_YEAR - FROM_YEAR + 1) as usize] = [
+    0xa95, 0x52d, 0x5ad, 0xb6a, 0x6e4, 0xdc9, 0xd92, 0xaa6, // 1423
+    0x956, 0x2ae, 0x56d, 0x36a, 0xb55, 0xaaa, 0x94d, 0x49d, // 1431
+    0x95d, 0x2ba, 0x5b5, 0x5aa, 0xd55, 0xa9a, 0x92e, 0x25e, // 1439
+    0x55d, 0xada, 0x6d4, 0x6a5, 0x54b, 0xa97, 0x54e, 0xaae, // 1447
+    0x5ac, 0xba9, 0xd92, 0xb25, 0x64b, 0xcab, 0x55a, 0xb55, // 1455
+    0x6d2, 0xea5, 0xe4a, 0xa95, 0x52d, 0xaad, 0x36c, 0x759, // 1463
+    0x6d2, 0x695, 0x52d, 0xa5b, 0x2ba, 0x9ba, 0x3b4, 0xb69, // 1471
+    0xb52, 0xaa6, 0x4b6, 0x96d, 0x2ec, 0x6d9, 0xdb2, 0xd54, // 1479
+    0xd2a, 0xa56, 0x4ae, 0x96d, 0xd6a, 0xb54, 0xb29, 0xa93, // 1487
+    0x52b, 0xa57, 0x536, 0xab5, 0x6aa, 0xe93, // 1495
+];
+
+// 某月的天数, 年份须在表内
+pub(super) fn days_in_month(year: i64, month: u32) -> u32 {
+    let mask = MONTH_LENGTHS[(year - FROM_YEAR) as usize];
+    if mask >> (month - 1) & 1 == 1 {
+        30
+    } else {
+        29
+    }
+}
+
+// 某年正月初一的儒略日数, 年份可取 TO_YEAR + 1 表示表的终点
+pub(super) fn year_start(year: i64) -> i64 {
+    MONTH_LENGTHS[..(year - FROM_YEAR) as usize]
+        .iter()
+        .map(|mask| 29 * 12 + mask.count_ones() as i64)
+        .sum::<i64>()
+        + FIRST_JDN
+}
@@ -0,0 +1,318 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 日本元号
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Era {
+    name: &'static str,
+    romaji: &'static str,
+    // 改元当日(公历)
+    start: (i32, u32, u32),
+}
+
+// 明治改元为旧历庆应四年九月八日, 即公历 1868-10-23;
+// 1873 年改用公历以前的日期亦按公历推算
+const ERAS: [Era; 5] = [
+    Era {
+        name: "明治",
+        romaji: "Meiji",
+        start: (1868, 10, 23),
+    },
+    Era {
+        name: "大正",
+        romaji: "Taisho",
+        start: (1912, 7, 30),
+    },
+    Era {
+        name: "昭和",
+        romaji: "Showa",
+        start: (1926, 12, 25),
+    },
+    Era {
+        name: "平成",
+        romaji: "Heisei",
+        start: (1989, 1, 8),
+    },
+    Era {
+        name: "令和",
+        romaji: "Reiwa",
+        start: (2019, 5, 1),
+    },
+];
+
+impl Era {
+    // 明治以来的全部元号
+    pub fn all() -> &'static [Era] {
+        &ERAS
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn romaji(&self) -> &'static str {
+        self.romaji
+    }
+
+    // 英文缩写(M、T、S、H、R)
+    pub fn abbr(&self) -> char {
+        self.romaji.chars().next().unwrap()
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        let (y, m, d) = self.start;
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    // 最后一天, 当前元号返回 None
+    pub fn end(&self) -> Option<NaiveDate> {
+        let i = ERAS.iter().position(|era| era == self)?;
+        ERAS.get(i + 1).and_then(|next| next.start().pred_opt())
+    }
+
+    // 公历日期所在的元号
+    pub fn of(date: NaiveDate) -> Option<Era> {
+        ERAS.iter().rev().find(|era| era.start() <= date).copied()
+    }
+
+    fn contains(&self, date: NaiveDate) -> bool {
+        self.start() <= date && self.end().is_none_or(|end| date <= end)
+    }
+}
+
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl FromStr for Era {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        ERAS.into_iter()
+            .find(|era| era.name == s || era.romaji.eq_ignore_ascii_case(s))
+            .ok_or_else(|| IkigaiError::ParseError {
+                input: s.to_string(),
+                kind: "Era",
+            })
+    }
+}
+
+// 和历日期, 如令和6年5月1日
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JapaneseDate {
+    era: Era,
+    year: i64,
+    month: u32,
+    day: u32,
+}
+
+impl JapaneseDate {
+    // 创建并校验和历日期, 日期须落在该元号之内
+    pub fn new(era: Era, year: i64, month: u32, day: u32) -> IkigaiResult<Self> {
+        let date = JapaneseDate {
+            era,
+            year,
+            month,
+            day,
+        };
+        date.to_naive_date()?;
+        Ok(date)
+    }
+
+    pub fn from_naive_date(date: NaiveDate) -> IkigaiResult<Self> {
+        let era = Era::of(date).ok_or_else(|| IkigaiError::InvalidEraDate(date.to_string()))?;
+        Ok(JapaneseDate {
+            era,
+            year: (date.year() - era.start.0) as i64 + 1,
+            month: date.month(),
+            day: date.day(),
+        })
+    }
+
+    pub fn to_naive_date(&self) -> IkigaiResult<NaiveDate> {
+        let err = || IkigaiError::InvalidEraDate(self.to_string());
+        let year = i32::try_from(self.year - 1 + self.era.start.0 as i64).map_err(|_| err())?;
+        let date = NaiveDate::from_ymd_opt(year, self.month, self.day).ok_or_else(err)?;
+        if !self.era.contains(date) {
+            return Err(err());
+        }
+        Ok(date)
+    }
+
+    pub fn era(&self) -> Era {
+        self.era
+    }
+
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    // 缩写形式, 如 R6.05.01
+    pub fn abbr(&self) -> String {
+        format!(
+            "{}{}.{:02}.{:02}",
+            self.era.abbr(),
+            self.year,
+            self.month,
+            self.day
+        )
+    }
+
+    // 解析 `R6.5.1` 形式的缩写
+    fn parse_abbr(s: &str) -> Option<(Era, i64, u32, u32)> {
+        let mut chars = s.chars();
+        let abbr = chars.next()?.to_ascii_uppercase();
+        let era = ERAS.into_iter().find(|era| era.abbr() == abbr)?;
+        let mut parts = chars.as_str().split('.');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some((era, year, month, day))
+    }
+
+    // 解析 `令和6年5月1日` 形式, 首年可写作元年
+    fn parse_kanji(s: &str) -> Option<(Era, i64, u32, u32)> {
+        let era = ERAS.into_iter().find(|era| s.starts_with(era.name))?;
+        let rest = &s[era.name.len()..];
+        let (year, rest) = rest.split_once('年')?;
+        let year = match year {
+            "元" => 1,
+            _ => year.parse().ok()?,
+        };
+        let (month, rest) = rest.split_once('月')?;
+        let day = rest.strip_suffix('日')?;
+        Some((era, year, month.parse().ok()?, day.parse().ok()?))
+    }
+}
+
+// 形如 `令和6年5月1日`, 首年写作 `令和元年`
+impl fmt::Display for JapaneseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.year == 1 {
+            write!(f, "{}元年", self.era)?;
+        } else {
+            write!(f, "{}{}年", self.era, self.year)?;
+        }
+        write!(f, "{}月{}日", self.month, self.day)
+    }
+}
+
+impl FromStr for JapaneseDate {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        let (era, year, month, day) = Self::parse_kanji(s)
+            .or_else(|| Self::parse_abbr(s))
+            .ok_or_else(|| IkigaiError::ParseError {
+                input: s.to_string(),
+                kind: "JapaneseDate",
+            })?;
+        JapaneseDate::new(era, year, month, day)
+    }
+}
+
+impl TryFrom<NaiveDate> for JapaneseDate {
+    type Error = IkigaiError;
+
+    fn try_from(date: NaiveDate) -> IkigaiResult<Self> {
+        JapaneseDate::from_naive_date(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_from_naive_date() {
+        let tests = vec![
+            ("reiwa", date(2024, 6, 1), "令和6年6月1日"),
+            ("reiwa_first_day", date(2019, 5, 1), "令和元年5月1日"),
+            ("heisei_last_day", date(2019, 4, 30), "平成31年4月30日"),
+            ("heisei_first_day", date(1989, 1, 8), "平成元年1月8日"),
+            ("showa_last_day", date(1989, 1, 7), "昭和64年1月7日"),
+            ("showa_first_day", date(1926, 12, 25), "昭和元年12月25日"),
+            ("taisho_last_day", date(1926, 12, 24), "大正15年12月24日"),
+            ("taisho_first_day", date(1912, 7, 30), "大正元年7月30日"),
+            ("meiji_last_day", date(1912, 7, 29), "明治45年7月29日"),
+            ("meiji_first_day", date(1868, 10, 23), "明治元年10月23日"),
+        ];
+        for (name, solar, want) in tests {
+            let got = JapaneseDate::from_naive_date(solar).unwrap();
+            assert_eq!(got.to_string(), want, "{} failed", name);
+            assert_eq!(got.to_naive_date().unwrap(), solar, "{} failed", name);
+        }
+        assert!(JapaneseDate::from_naive_date(date(1868, 10, 22)).is_err());
+    }
+
+    #[test]
+    fn test_japanese_date_from_str() {
+        let reiwa = Era::from_str("令和").unwrap();
+        let tests = vec![
+            ("kanji", "令和6年5月1日", (reiwa, 6)),
+            ("gannen", "令和元年5月1日", (reiwa, 1)),
+            ("first_year", "令和1年5月1日", (reiwa, 1)),
+            ("abbr", "R6.5.1", (reiwa, 6)),
+            ("abbr_lower", "r6.05.01", (reiwa, 6)),
+        ];
+        for (name, s, (era, year)) in tests {
+            let got = s.parse::<JapaneseDate>().unwrap();
+            assert_eq!((got.era(), got.year()), (era, year), "{} failed", name);
+            assert_eq!((got.month(), got.day()), (5, 1), "{} failed", name);
+        }
+        assert_eq!(
+            "H31.4.30".parse::<JapaneseDate>().unwrap().to_naive_date(),
+            Ok(date(2019, 4, 30))
+        );
+        assert_eq!(
+            JapaneseDate::from_naive_date(date(2024, 5, 1))
+                .unwrap()
+                .abbr(),
+            "R6.05.01"
+        );
+
+        let errors = vec![
+            ("after_era_end", "平成31年5月1日"),
+            ("before_era_start", "令和元年4月30日"),
+            ("bad_day", "令和6年2月30日"),
+            ("unknown_era", "天平1年1月1日"),
+            ("no_day", "令和6年5月"),
+            ("bad_abbr", "X6.5.1"),
+        ];
+        for (name, s) in errors {
+            assert!(s.parse::<JapaneseDate>().is_err(), "{} should fail", name);
+        }
+    }
+
+    #[test]
+    fn test_era() {
+        assert_eq!(Era::all().len(), 5);
+        let heisei: Era = "Heisei".parse().unwrap();
+        assert_eq!(heisei.name(), "平成");
+        assert_eq!(heisei.abbr(), 'H');
+        assert_eq!(heisei.end(), Some(date(2019, 4, 30)));
+        assert_eq!("令和".parse::<Era>().unwrap().end(), None);
+        assert_eq!(Era::of(date(1950, 1, 1)).unwrap().name(), "昭和");
+        assert_eq!(Era::of(date(1800, 1, 1)), None);
+    }
+}
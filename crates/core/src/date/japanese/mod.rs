@@ -0,0 +1,2 @@
+pub mod era;
+pub mod rokuyo;
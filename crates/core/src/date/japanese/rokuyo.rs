@@ -0,0 +1,131 @@
+use std::{fmt, str::FromStr};
+
+use crate::ikigai_error::{IkigaiError, IkigaiResult};
+
+// 六曜, 由旧历的月与日推算, 闰月按本月计
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rokuyo {
+    Sensho,
+    Tomobiki,
+    Senbu,
+    Butsumetsu,
+    Taian,
+    Shakko,
+}
+
+// 与 (月 + 日) % 6 的余数对应
+const ROKUYO_ORDER: [Rokuyo; 6] = [
+    Rokuyo::Taian,
+    Rokuyo::Shakko,
+    Rokuyo::Sensho,
+    Rokuyo::Tomobiki,
+    Rokuyo::Senbu,
+    Rokuyo::Butsumetsu,
+];
+
+impl Rokuyo {
+    pub fn from_lunar(month: i64, day: i64) -> Rokuyo {
+        ROKUYO_ORDER[(month + day).rem_euclid(6) as usize]
+    }
+
+    pub fn alias(&self) -> &'static str {
+        match self {
+            Rokuyo::Sensho => "先勝",
+            Rokuyo::Tomobiki => "友引",
+            Rokuyo::Senbu => "先負",
+            Rokuyo::Butsumetsu => "仏滅",
+            Rokuyo::Taian => "大安",
+            Rokuyo::Shakko => "赤口",
+        }
+    }
+
+    // 读音(せんしょう...)
+    pub fn reading(&self) -> &'static str {
+        match self {
+            Rokuyo::Sensho => "せんしょう",
+            Rokuyo::Tomobiki => "ともびき",
+            Rokuyo::Senbu => "せんぶ",
+            Rokuyo::Butsumetsu => "ぶつめつ",
+            Rokuyo::Taian => "たいあん",
+            Rokuyo::Shakko => "しゃっこう",
+        }
+    }
+}
+
+impl fmt::Display for Rokuyo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.alias())
+    }
+}
+
+impl FromStr for Rokuyo {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> IkigaiResult<Self> {
+        ROKUYO_ORDER
+            .into_iter()
+            .find(|r| r.alias() == s || r.reading() == s)
+            .ok_or_else(|| IkigaiError::ParseError {
+                input: s.to_string(),
+                kind: "Rokuyo",
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::date::chinese::{lunar::Lunar, variant::CalendarVariant};
+
+    #[test]
+    fn test_rokuyo_from_lunar() {
+        let tests = vec![
+            ("first_month", 1, 1, Rokuyo::Sensho),
+            ("second_month", 2, 1, Rokuyo::Tomobiki),
+            ("sixth_month", 6, 1, Rokuyo::Shakko),
+            ("taian", 1, 5, Rokuyo::Taian),
+            ("last_day", 12, 30, Rokuyo::Taian),
+        ];
+        for (name, month, day, want) in tests {
+            assert_eq!(Rokuyo::from_lunar(month, day), want, "{} failed", name);
+        }
+    }
+
+    #[test]
+    fn test_lunar_rokuyo() {
+        let jp = CalendarVariant::Japanese;
+        let tests = vec![
+            // 2024-02-10 为旧历正月初一
+            ("shogatsu", (2024, 2, 10), "先勝"),
+            ("next_day", (2024, 2, 11), "友引"),
+            ("butsumetsu", (2024, 2, 13), "仏滅"),
+            ("taian", (2024, 2, 14), "大安"),
+            // 旧历十一月二十
+            ("new_year", (2024, 1, 1), "赤口"),
+        ];
+        for (name, (y, m, d), want) in tests {
+            let t = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+            let lunar = Lunar::new_in(t, jp).unwrap();
+            assert_eq!(lunar.rokuyo().unwrap().alias(), want, "{} failed", name);
+        }
+
+        // 2012-04-21 为中国农历四月初一, 日本旧历闰三月初一; 六曜总按旧历推算
+        let t = Utc.with_ymd_and_hms(2012, 4, 21, 0, 0, 0).unwrap();
+        let chinese = Lunar::new(t).unwrap();
+        assert_eq!(chinese.get_month(), 4);
+        assert_eq!(chinese.rokuyo().unwrap(), Rokuyo::Senbu);
+        let vietnamese = Lunar::new_in(t, CalendarVariant::Vietnamese).unwrap();
+        assert_eq!(vietnamese.rokuyo().unwrap(), Rokuyo::Senbu);
+    }
+
+    #[test]
+    fn test_rokuyo_from_str() {
+        for rokuyo in ROKUYO_ORDER {
+            assert_eq!(rokuyo.to_string().parse::<Rokuyo>(), Ok(rokuyo));
+            assert_eq!(rokuyo.reading().parse::<Rokuyo>(), Ok(rokuyo));
+        }
+        assert!("吉日".parse::<Rokuyo>().is_err());
+    }
+}
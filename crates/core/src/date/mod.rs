@@ -1 +1,6 @@
+pub mod astro;
 pub mod chinese;
+pub mod christian;
+pub mod hebrew;
+pub mod islamic;
+pub mod japanese;
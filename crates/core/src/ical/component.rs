@@ -0,0 +1,201 @@
+use std::fmt;
+
+// iCalendar 内容行, 形如 `NAME;PARAM=VALUE:value`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub name: String,
+    pub params: Vec<(String, String)>,
+    pub value: String,
+}
+
+// iCalendar 组件, 如 VCALENDAR、VEVENT, 可以嵌套
+#[derive(Debug, Clone, PartialEq)]
+pub struct Component {
+    pub name: String,
+    pub properties: Vec<Property>,
+    pub components: Vec<Component>,
+}
+
+// RFC 5545 建议每行不超过 75 个字节
+const MAX_LINE_OCTETS: usize = 75;
+
+impl Property {
+    pub fn new(name: &str, value: &str) -> Self {
+        Property {
+            name: name.to_ascii_uppercase(),
+            params: Vec::new(),
+            value: value.to_string(),
+        }
+    }
+
+    // 创建 TEXT 类型的属性, 自动转义 `\` `;` `,` 和换行
+    pub fn text(name: &str, value: &str) -> Self {
+        Self::new(name, &escape_text(value))
+    }
+
+    pub fn with_param(mut self, name: &str, value: &str) -> Self {
+        self.params
+            .push((name.to_ascii_uppercase(), value.to_string()));
+        self
+    }
+
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl fmt::Display for Property {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        for (k, v) in &self.params {
+            write!(f, ";{}={}", k, v)?;
+        }
+        write!(f, ":{}", self.value)
+    }
+}
+
+impl Component {
+    pub fn new(name: &str) -> Self {
+        Component {
+            name: name.to_ascii_uppercase(),
+            properties: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, property: Property) {
+        self.properties.push(property);
+    }
+
+    // 返回第一个同名属性
+    pub fn property(&self, name: &str) -> Option<&Property> {
+        self.properties
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    // 序列化为 iCalendar 文本, 使用 CRLF 换行并折叠长行
+    pub fn to_ics(&self) -> String {
+        let mut out = String::new();
+        self.write_to(&mut out);
+        out
+    }
+
+    fn write_to(&self, out: &mut String) {
+        write_line(out, &format!("BEGIN:{}", self.name));
+        for property in &self.properties {
+            write_line(out, &property.to_string());
+        }
+        for component in &self.components {
+            component.write_to(out);
+        }
+        write_line(out, &format!("END:{}", self.name));
+    }
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_ics())
+    }
+}
+
+// 按字节折叠长行, 续行以一个空格开头, 不会截断多字节字符
+fn write_line(out: &mut String, line: &str) {
+    let mut limit = MAX_LINE_OCTETS;
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > limit {
+            out.push_str("\r\n ");
+            limit = MAX_LINE_OCTETS - 1;
+            len = 0;
+        }
+        out.push(c);
+        len += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+pub fn escape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+pub fn unescape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_component_to_ics() {
+        let mut event = Component::new("vevent");
+        event.push(Property::new("UID", "test@ikigai"));
+        event.push(Property::new("DTSTART", "20240210").with_param("VALUE", "DATE"));
+        event.push(Property::text("SUMMARY", "春节, 正月初一"));
+        let mut calendar = Component::new("VCALENDAR");
+        calendar.components.push(event);
+
+        assert_eq!(
+            calendar.to_ics(),
+            "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:test@ikigai\r\n\
+             DTSTART;VALUE=DATE:20240210\r\nSUMMARY:春节\\, 正月初一\r\n\
+             END:VEVENT\r\nEND:VCALENDAR\r\n"
+        );
+    }
+
+    #[test]
+    fn test_fold_long_line() {
+        let mut out = String::new();
+        write_line(&mut out, &format!("DESCRIPTION:{}", "节气".repeat(30)));
+        for line in out.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS, "line too long: {}", line);
+        }
+        assert_eq!(
+            out.replace("\r\n ", "").trim_end(),
+            format!("DESCRIPTION:{}", "节气".repeat(30))
+        );
+    }
+
+    #[test]
+    fn test_escape_text() {
+        let tests = vec![
+            ("plain", "清明", "清明"),
+            ("comma", "a,b", "a\\,b"),
+            ("semicolon", "a;b", "a\\;b"),
+            ("backslash", "a\\b", "a\\\\b"),
+            ("newline", "a\nb", "a\\nb"),
+        ];
+        for (name, raw, escaped) in tests {
+            assert_eq!(escape_text(raw), escaped, "{} failed", name);
+            assert_eq!(unescape_text(escaped), raw, "{} failed", name);
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+
+use super::component::{Component, Property};
+use crate::{
+    date::chinese::{
+        festival::Festival,
+        lunar::{Lunar, LUNAR_TO_YEAR},
+        solarterm::{Solarterm, SOLARTERM_FROM_YEAR},
+    },
+    ikigai_error::{IkigaiError, IkigaiResult},
+};
+
+pub const PRODID: &str = "-//ikigai//ikigai_core//ZH";
+
+const UID_DOMAIN: &str = "ikigai";
+
+// 节气表中的时间为北京时间
+const CHINA_UTC_OFFSET: i64 = 8 * 3600;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportOptions {
+    pub solarterms: bool,
+    pub lunar_days: bool,
+    pub festivals: bool,
+    pub calendar_name: Option<String>,
+    // 写入 DTSTAMP 的时间, 默认为导出时刻
+    pub dtstamp: Option<DateTime<Utc>>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            solarterms: true,
+            lunar_days: true,
+            festivals: true,
+            calendar_name: None,
+            dtstamp: None,
+        }
+    }
+}
+
+// 导出 [from_year, to_year] 公历年份内的节气、农历初一十五和节日
+pub fn export(from_year: i32, to_year: i32, options: &ExportOptions) -> IkigaiResult<String> {
+    Ok(calendar(from_year, to_year, options)?.to_ics())
+}
+
+pub fn calendar(from_year: i32, to_year: i32, options: &ExportOptions) -> IkigaiResult<Component> {
+    if from_year > to_year {
+        return Err(IkigaiError::InvalidDate);
+    }
+    for year in [from_year as i64, to_year as i64] {
+        if !(SOLARTERM_FROM_YEAR..=LUNAR_TO_YEAR).contains(&year) {
+            return Err(IkigaiError::YearOutOfRange {
+                year,
+                min: SOLARTERM_FROM_YEAR,
+                max: LUNAR_TO_YEAR,
+            });
+        }
+    }
+    let dtstamp = format_utc(&options.dtstamp.unwrap_or_else(Utc::now));
+
+    let mut calendar = Component::new("VCALENDAR");
+    calendar.push(Property::new("VERSION", "2.0"));
+    calendar.push(Property::new("PRODID", PRODID));
+    calendar.push(Property::new("CALSCALE", "GREGORIAN"));
+    if let Some(name) = &options.calendar_name {
+        calendar.push(Property::text("X-WR-CALNAME", name));
+    }
+
+    if options.solarterms {
+        calendar
+            .components
+            .extend(solarterm_events(from_year, to_year, &dtstamp)?);
+    }
+    if options.lunar_days || options.festivals {
+        let mut date = NaiveDate::from_ymd_opt(from_year, 1, 1).ok_or(IkigaiError::InvalidDate)?;
+        let end = NaiveDate::from_ymd_opt(to_year, 12, 31).ok_or(IkigaiError::InvalidDate)?;
+        while date <= end {
+            let t = Utc.from_utc_datetime(&date.and_time(Default::default()));
+            if options.lunar_days {
+                calendar.components.extend(lunar_day_event(&t, &dtstamp));
+            }
+            if options.festivals {
+                calendar.components.extend(festival_events(&t, &dtstamp));
+            }
+            date += Duration::days(1);
+        }
+    }
+    Ok(calendar)
+}
+
+fn solarterm_events(from_year: i32, to_year: i32, dtstamp: &str) -> IkigaiResult<Vec<Component>> {
+    let first = 24 * (from_year as i64 - SOLARTERM_FROM_YEAR);
+    let last = 24 * (to_year as i64 - SOLARTERM_FROM_YEAR + 1);
+    let mut events = Vec::new();
+    for index in first..last {
+        let Ok(term) = Solarterm::new(index) else {
+            break;
+        };
+        let local = term.time()?;
+        let instant = local - Duration::seconds(CHINA_UTC_OFFSET);
+        let year = SOLARTERM_FROM_YEAR + index / 24;
+
+        let mut event = Component::new("VEVENT");
+        event.push(Property::new(
+            "UID",
+            &format!("solarterm-{}-{:02}@{}", year, index % 24, UID_DOMAIN),
+        ));
+        event.push(Property::new("DTSTAMP", dtstamp));
+        event.push(Property::new("DTSTART", &format_utc(&instant)));
+        event.push(Property::text("SUMMARY", term.alias()));
+        event.push(Property::text(
+            "DESCRIPTION",
+            &format!(
+                "{} 北京时间 {}",
+                term.alias(),
+                local.format("%Y-%m-%d %H:%M:%S")
+            ),
+        ));
+        event.push(Property::text("CATEGORIES", "节气"));
+        event.push(Property::new("TRANSP", "TRANSPARENT"));
+        events.push(event);
+    }
+    Ok(events)
+}
+
+// 仅为农历初一和十五生成全天事件
+fn lunar_day_event(t: &DateTime<Utc>, dtstamp: &str) -> Option<Component> {
+    let lunar = Lunar::new(*t).ok()?;
+    if lunar.get_day() != 1 && lunar.get_day() != 15 {
+        return None;
+    }
+    let uid = format!(
+        "lunar-{}-{:02}{}-{:02}@{}",
+        lunar.get_year(),
+        lunar.get_month(),
+        if lunar.is_leap_month() { "L" } else { "" },
+        lunar.get_day(),
+        UID_DOMAIN
+    );
+    let summary = format!("{}{}", lunar.month_alias(), lunar.day_alias());
+    Some(all_day_event(t, &uid, &summary, "农历", dtstamp))
+}
+
+// 同一农历节日可能在一个公历年内出现两次(如 2022 年的腊八), UID 取完整日期
+fn festival_events(t: &DateTime<Utc>, dtstamp: &str) -> Vec<Component> {
+    Festival::from_solar(t)
+        .iter()
+        .map(|festival| {
+            let uid = format!(
+                "festival-{}-{}@{}",
+                format_date(t),
+                festival.id(),
+                UID_DOMAIN
+            );
+            all_day_event(t, &uid, festival.alias(), "节日", dtstamp)
+        })
+        .collect()
+}
+
+fn all_day_event(
+    t: &DateTime<Utc>,
+    uid: &str,
+    summary: &str,
+    category: &str,
+    dtstamp: &str,
+) -> Component {
+    let mut event = Component::new("VEVENT");
+    event.push(Property::new("UID", uid));
+    event.push(Property::new("DTSTAMP", dtstamp));
+    event.push(Property::new("DTSTART", &format_date(t)).with_param("VALUE", "DATE"));
+    event.push(
+        Property::new("DTEND", &format_date(&(*t + Duration::days(1)))).with_param("VALUE", "DATE"),
+    );
+    event.push(Property::text("SUMMARY", summary));
+    event.push(Property::text("CATEGORIES", category));
+    event.push(Property::new("TRANSP", "TRANSPARENT"));
+    event
+}
+
+fn format_utc(t: &DateTime<Utc>) -> String {
+    t.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn format_date(t: &DateTime<Utc>) -> String {
+    t.format("%Y%m%d").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> ExportOptions {
+        ExportOptions {
+            dtstamp: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+            ..Default::default()
+        }
+    }
+
+    fn events_with_uid<'a>(calendar: &'a Component, prefix: &str) -> Vec<&'a Component> {
+        calendar
+            .components
+            .iter()
+            .filter(|e| e.property("UID").unwrap().value.starts_with(prefix))
+            .collect()
+    }
+
+    #[test]
+    fn test_export_solarterms() {
+        let calendar = calendar(2018, 2018, &options()).unwrap();
+        let terms = events_with_uid(&calendar, "solarterm-");
+        assert_eq!(terms.len(), 24);
+
+        // 2018 年春分: 北京时间 2018-03-21 00:15:28
+        let chunfen = terms[5];
+        assert_eq!(chunfen.property("SUMMARY").unwrap().value, "春分");
+        assert_eq!(
+            chunfen.property("UID").unwrap().value,
+            "solarterm-2018-05@ikigai"
+        );
+        assert_eq!(
+            chunfen.property("DTSTART").unwrap().value,
+            "20180320T161528Z"
+        );
+    }
+
+    #[test]
+    fn test_export_lunar_days_and_festivals() {
+        let calendar = calendar(2024, 2024, &options()).unwrap();
+
+        let lunar_days = events_with_uid(&calendar, "lunar-");
+        assert_eq!(lunar_days.len(), 25);
+        let chuyi = lunar_days
+            .iter()
+            .find(|e| e.property("UID").unwrap().value == "lunar-2024-01-01@ikigai")
+            .unwrap();
+        assert_eq!(chuyi.property("SUMMARY").unwrap().value, "正月初一");
+        assert_eq!(chuyi.property("DTSTART").unwrap().value, "20240210");
+        assert_eq!(chuyi.property("DTEND").unwrap().value, "20240211");
+        assert_eq!(
+            chuyi.property("DTSTART").unwrap().param("VALUE"),
+            Some("DATE")
+        );
+
+        let festivals = events_with_uid(&calendar, "festival-");
+        let chunjie = festivals
+            .iter()
+            .find(|e| e.property("UID").unwrap().value == "festival-20240210-lunar-0101@ikigai")
+            .unwrap();
+        assert_eq!(chunjie.property("SUMMARY").unwrap().value, "春节");
+    }
+
+    #[test]
+    fn test_export_stable_uids() {
+        let first = export(2024, 2024, &options()).unwrap();
+        let second = export(2024, 2024, &options()).unwrap();
+        assert_eq!(first, second);
+
+        let calendar = calendar(2023, 2024, &options()).unwrap();
+        let mut uids: Vec<&str> = calendar
+            .components
+            .iter()
+            .map(|e| e.property("UID").unwrap().value.as_str())
+            .collect();
+        let len = uids.len();
+        uids.sort();
+        uids.dedup();
+        assert_eq!(uids.len(), len);
+    }
+
+    #[test]
+    fn test_export_festival_uids() {
+        // 2022 年有两个腊八: 2022-01-10 与 2022-12-30
+        let calendar = calendar(2022, 2022, &options()).unwrap();
+        let laba: Vec<&str> = events_with_uid(&calendar, "festival-")
+            .iter()
+            .filter(|e| e.property("SUMMARY").unwrap().value == "腊八节")
+            .map(|e| e.property("UID").unwrap().value.as_str())
+            .collect();
+        assert_eq!(
+            laba,
+            vec![
+                "festival-20220110-lunar-1208@ikigai",
+                "festival-20221230-lunar-1208@ikigai"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_export_options() {
+        let opts = ExportOptions {
+            lunar_days: false,
+            festivals: false,
+            calendar_name: Some("节气".to_string()),
+            ..options()
+        };
+        let ics = export(2024, 2024, &opts).unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.contains("X-WR-CALNAME:节气\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 24);
+    }
+
+    #[test]
+    fn test_export_invalid_range() {
+        assert!(export(2024, 2023, &options()).is_err());
+        assert!(export(1800, 2023, &options()).is_err());
+        assert!(export(2024, 2200, &options()).is_err());
+    }
+}
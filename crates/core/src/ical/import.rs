@@ -0,0 +1,579 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+use super::component::{escape_text, unescape_text, Component, Property};
+use crate::{
+    date::chinese::{gan_zhi::Ganzhi, lunar::Lunar, solarterm::Solarterm},
+    ikigai_error::{IkigaiError, IkigaiResult},
+};
+
+pub const X_LUNAR_DATE: &str = "X-IKIGAI-LUNAR-DATE";
+pub const X_LUNAR_LABEL: &str = "X-IKIGAI-LUNAR-LABEL";
+pub const X_DAY_GANZHI: &str = "X-IKIGAI-DAY-GANZHI";
+pub const X_SOLARTERM: &str = "X-IKIGAI-SOLARTERM";
+
+// 节气表中的时间为北京时间
+const CHINA_UTC_OFFSET: i32 = 8 * 3600;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotateOptions {
+    // 事件的当地时区, 默认为北京时间; 用于换算 UTC 时间(以 Z 结尾)和查节气
+    pub utc_offset: FixedOffset,
+    // 写入 X-IKIGAI-* 属性
+    pub properties: bool,
+    // 在 DESCRIPTION 末尾追加一行农历信息
+    pub description: bool,
+}
+
+impl Default for AnnotateOptions {
+    fn default() -> Self {
+        AnnotateOptions {
+            utc_offset: FixedOffset::east_opt(CHINA_UTC_OFFSET).unwrap(),
+            properties: true,
+            description: true,
+        }
+    }
+}
+
+// 某个事件对应的农历信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    pub lunar_date: String,
+    pub lunar_label: String,
+    pub day_ganzhi: Option<String>,
+    pub solarterm: Option<String>,
+}
+
+impl Annotation {
+    // t 为事件开始的北京时间
+    pub fn new(t: &DateTime<Utc>) -> IkigaiResult<Self> {
+        Self::new_in(t, &FixedOffset::east_opt(CHINA_UTC_OFFSET).unwrap())
+    }
+
+    // t 为事件开始的当地时间, offset 为当地时区
+    pub fn new_in(t: &DateTime<Utc>, offset: &FixedOffset) -> IkigaiResult<Self> {
+        let lunar = Lunar::new(*t)?;
+        let lunar_date = format!(
+            "{}-{:02}{}-{:02}",
+            lunar.get_year(),
+            lunar.get_month(),
+            if lunar.is_leap_month() { "L" } else { "" },
+            lunar.get_day()
+        );
+        let lunar_label = format!("{}{}", lunar.month_alias(), lunar.day_alias());
+        Ok(Annotation {
+            lunar_date,
+            lunar_label,
+            day_ganzhi: Ganzhi::new(*t).ok().map(|gz| gz.day_ganzhi_alias()),
+            solarterm: containing_solarterm(t, offset)
+                .ok()
+                .map(|term| term.alias().to_string()),
+        })
+    }
+
+    // DESCRIPTION 中追加的文本, 如 `农历正月初一 甲辰日 立春`
+    pub fn summary(&self) -> String {
+        let mut s = format!("农历{}", self.lunar_label);
+        if let Some(gz) = &self.day_ganzhi {
+            s += &format!(" {}日", gz);
+        }
+        if let Some(term) = &self.solarterm {
+            s += &format!(" {}", term);
+        }
+        s
+    }
+}
+
+// 解析 iCalendar 文本, 返回最外层组件(通常为 VCALENDAR)
+pub fn parse(ics: &str) -> IkigaiResult<Component> {
+    let mut stack: Vec<Component> = Vec::new();
+    let mut root = None;
+    for line in unfold(ics) {
+        if line.is_empty() {
+            continue;
+        }
+        let property = parse_line(&line)?;
+        if property.name == "BEGIN" {
+            stack.push(Component::new(&property.value));
+        } else if property.name == "END" {
+            let component = stack
+                .pop()
+                .ok_or_else(|| invalid(format!("unexpected `{}`", line)))?;
+            if !component.name.eq_ignore_ascii_case(&property.value) {
+                return Err(invalid(format!(
+                    "`{}` does not close BEGIN:{}",
+                    line, component.name
+                )));
+            }
+            match stack.last_mut() {
+                Some(parent) => parent.components.push(component),
+                None if root.is_none() => root = Some(component),
+                None => return Err(invalid("multiple top-level components".to_string())),
+            }
+        } else {
+            stack
+                .last_mut()
+                .ok_or_else(|| invalid(format!("property outside of a component: `{}`", line)))?
+                .push(property);
+        }
+    }
+    if let Some(open) = stack.last() {
+        return Err(invalid(format!("missing END:{}", open.name)));
+    }
+    root.ok_or_else(|| invalid("no component found".to_string()))
+}
+
+// 为日历中的每个 VEVENT 添加农历、日干支和节气信息, 返回成功标注的事件数;
+// DTSTART 带 TZID 时须有同名且偏移固定的 VTIMEZONE, 否则无法确定时刻, 不予标注
+pub fn annotate(calendar: &mut Component, options: &AnnotateOptions) -> usize {
+    let zones = fixed_timezones(calendar);
+    annotate_in(calendar, &zones, options)
+}
+
+fn annotate_in(
+    calendar: &mut Component,
+    zones: &HashMap<String, FixedOffset>,
+    options: &AnnotateOptions,
+) -> usize {
+    let mut count = 0;
+    for component in calendar.components.iter_mut() {
+        if component.name == "VEVENT" {
+            if annotate_event(component, zones, options) {
+                count += 1;
+            }
+        } else {
+            count += annotate_in(component, zones, options);
+        }
+    }
+    count
+}
+
+// 读取 iCalendar 文本, 标注后重新序列化
+pub fn annotate_ics(ics: &str, options: &AnnotateOptions) -> IkigaiResult<String> {
+    let mut calendar = parse(ics)?;
+    annotate(&mut calendar, options);
+    Ok(calendar.to_ics())
+}
+
+fn annotate_event(
+    event: &mut Component,
+    zones: &HashMap<String, FixedOffset>,
+    options: &AnnotateOptions,
+) -> bool {
+    let Some(t) = event
+        .property("DTSTART")
+        .and_then(|p| local_time(p, zones, &options.utc_offset))
+    else {
+        return false;
+    };
+    let Ok(annotation) = Annotation::new_in(&t, &options.utc_offset) else {
+        return false;
+    };
+
+    if options.description {
+        let line = annotation.summary();
+        match event
+            .properties
+            .iter_mut()
+            .find(|p| p.name == "DESCRIPTION")
+        {
+            Some(p) => {
+                let text = unescape_text(&p.value);
+                if !text.lines().any(|l| l == line) {
+                    p.value = escape_text(&format!("{}\n{}", text, line));
+                }
+            }
+            None => event.push(Property::text("DESCRIPTION", &line)),
+        }
+    }
+    if options.properties {
+        event
+            .properties
+            .retain(|p| !p.name.starts_with("X-IKIGAI-"));
+        event.push(Property::new(X_LUNAR_DATE, &annotation.lunar_date));
+        event.push(Property::text(X_LUNAR_LABEL, &annotation.lunar_label));
+        if let Some(gz) = &annotation.day_ganzhi {
+            event.push(Property::text(X_DAY_GANZHI, gz));
+        }
+        if let Some(term) = &annotation.solarterm {
+            event.push(Property::text(X_SOLARTERM, term));
+        }
+    }
+    true
+}
+
+// 返回事件开始的当地时间; UTC 时间和带 TZID 的时间换算到 offset, 日期和浮动时间直接使用其字面值
+fn local_time(
+    property: &Property,
+    zones: &HashMap<String, FixedOffset>,
+    offset: &FixedOffset,
+) -> Option<DateTime<Utc>> {
+    let value = property.value.trim();
+    let is_date = property
+        .param("VALUE")
+        .is_some_and(|v| v.eq_ignore_ascii_case("DATE"))
+        || value.len() == 8;
+    let naive = if is_date {
+        NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()?
+            .and_hms_opt(0, 0, 0)?
+    } else if let Some(utc) = value.strip_suffix('Z') {
+        let utc = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        utc + *offset
+    } else if let Some(tzid) = property.param("TZID") {
+        let zone = zones.get(tzid.trim_matches('"'))?;
+        let local = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+        local - *zone + *offset
+    } else {
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?
+    };
+    Some(Utc.from_utc_datetime(&naive))
+}
+
+// 收集日历中偏移固定的 VTIMEZONE; 含夏令时等多个偏移的时区无法仅凭偏移换算, 不予收录
+fn fixed_timezones(calendar: &Component) -> HashMap<String, FixedOffset> {
+    let mut zones = HashMap::new();
+    for zone in calendar.components.iter().filter(|c| c.name == "VTIMEZONE") {
+        let Some(tzid) = zone.property("TZID") else {
+            continue;
+        };
+        let offsets: Option<Vec<FixedOffset>> = zone
+            .components
+            .iter()
+            .map(|rule| {
+                rule.property("TZOFFSETTO")
+                    .and_then(|p| utc_offset(&p.value))
+            })
+            .collect();
+        if let Some([first, rest @ ..]) = offsets.as_deref() {
+            if rest.iter().all(|o| o == first) {
+                zones.insert(tzid.value.clone(), *first);
+            }
+        }
+    }
+    zones
+}
+
+// 解析 UTC-OFFSET, 如 `+0800`、`-0500`、`+053000`
+fn utc_offset(value: &str) -> Option<FixedOffset> {
+    let value = value.trim();
+    let sign = match value.get(..1)? {
+        "+" => 1,
+        "-" => -1,
+        _ => return None,
+    };
+    let digits = &value[1..];
+    if !(digits.len() == 4 || digits.len() == 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let field = |i: usize| digits.get(i..i + 2).map_or(Ok(0), str::parse::<i32>);
+    let seconds = field(0).ok()? * 3600 + field(2).ok()? * 60 + field(4).ok()?;
+    FixedOffset::east_opt(sign * seconds)
+}
+
+// 日期所在的节气区间, 节气交接时刻本身属于新节气; 先将当地时间换算为北京时间再查表
+fn containing_solarterm(t: &DateTime<Utc>, offset: &FixedOffset) -> IkigaiResult<Solarterm> {
+    let beijing = *t + Duration::seconds((CHINA_UTC_OFFSET - offset.local_minus_utc()) as i64);
+    Ok(Solarterm::calc_solarterm(&beijing)?.0)
+}
+
+// 展开折叠行(以空格或制表符开头的行接续上一行)
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in ics.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+fn parse_line(line: &str) -> IkigaiResult<Property> {
+    let mut in_quotes = false;
+    let mut colon = None;
+    let mut separators = Vec::new();
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => separators.push(i),
+            ':' if !in_quotes => {
+                colon = Some(i);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let colon = colon.ok_or_else(|| invalid(format!("missing `:` in `{}`", line)))?;
+    let head = &line[..colon];
+    let name_end = separators.first().copied().unwrap_or(colon);
+    let name = &head[..name_end];
+    if name.is_empty() {
+        return Err(invalid(format!("missing property name in `{}`", line)));
+    }
+
+    let mut property = Property::new(name, &line[colon + 1..]);
+    let mut bounds = separators.clone();
+    bounds.push(colon);
+    for pair in bounds.windows(2) {
+        let param = &line[pair[0] + 1..pair[1]];
+        let (k, v) = param
+            .split_once('=')
+            .ok_or_else(|| invalid(format!("invalid parameter `{}`", param)))?;
+        property = property.with_param(k, v);
+    }
+    Ok(property)
+}
+
+fn invalid(msg: String) -> IkigaiError {
+    IkigaiError::InvalidCalendar(msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ICS: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//test//test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:1@test\r\n\
+DTSTART;VALUE=DATE:20240210\r\n\
+SUMMARY:Team dinner\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:2@test\r\n\
+DTSTART:20240404T010000Z\r\n\
+SUMMARY:Standup\r\n\
+DESCRIPTION:Daily sync\\, room 1\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:3@test\r\n\
+DTSTART;TZID=\"America/New_York\":20240203T230000\r\n\
+SUMMARY:Late call with a very long summary that must be folded when it is wri\r\n \
+tten back\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn test_parse() {
+        let calendar = parse(ICS).unwrap();
+        assert_eq!(calendar.name, "VCALENDAR");
+        assert_eq!(calendar.components.len(), 3);
+
+        let third = &calendar.components[2];
+        let dtstart = third.property("DTSTART").unwrap();
+        assert_eq!(dtstart.param("TZID"), Some("\"America/New_York\""));
+        assert_eq!(dtstart.value, "20240203T230000");
+        assert!(third
+            .property("SUMMARY")
+            .unwrap()
+            .value
+            .ends_with("written back"));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let tests = vec![
+            ("no_component", "VERSION:2.0\r\n"),
+            (
+                "missing_end",
+                "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nEND:VCALENDAR\r\n",
+            ),
+            ("unexpected_end", "END:VEVENT\r\n"),
+            (
+                "missing_colon",
+                "BEGIN:VCALENDAR\r\nSUMMARY\r\nEND:VCALENDAR\r\n",
+            ),
+            ("empty", ""),
+        ];
+        for (name, ics) in tests {
+            assert!(parse(ics).is_err(), "{} should fail", name);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let calendar = parse(ICS).unwrap();
+        assert_eq!(parse(&calendar.to_ics()).unwrap(), calendar);
+    }
+
+    #[test]
+    fn test_annotate() {
+        let mut calendar = parse(ICS).unwrap();
+        assert_eq!(annotate(&mut calendar, &AnnotateOptions::default()), 2);
+
+        let tests = vec![
+            // 2024-02-10 为春节, 已过立春
+            (
+                0,
+                "2024-01-01",
+                "正月初一",
+                "甲辰",
+                "立春",
+                "农历正月初一 甲辰日 立春",
+            ),
+            // 09:00 北京时间, 清明于当日 15:02 交节, 仍属春分
+            (
+                1,
+                "2024-02-26",
+                "二月廿六",
+                "戊戌",
+                "春分",
+                "Daily sync, room 1\n农历二月廿六 戊戌日 春分",
+            ),
+        ];
+        for (i, date, label, gz, term, description) in tests {
+            let event = &calendar.components[i];
+            assert_eq!(event.property(X_LUNAR_DATE).unwrap().value, date, "{}", i);
+            assert_eq!(event.property(X_LUNAR_LABEL).unwrap().value, label, "{}", i);
+            assert_eq!(event.property(X_DAY_GANZHI).unwrap().value, gz, "{}", i);
+            assert_eq!(event.property(X_SOLARTERM).unwrap().value, term, "{}", i);
+            assert_eq!(
+                unescape_text(&event.property("DESCRIPTION").unwrap().value),
+                description,
+                "{}",
+                i
+            );
+        }
+        // 缺少 VTIMEZONE 的 TZID 无法确定时刻, 不予标注
+        let event = &calendar.components[2];
+        assert!(event.property(X_LUNAR_DATE).is_none());
+        assert!(event.property("DESCRIPTION").is_none());
+    }
+
+    #[test]
+    fn test_annotate_tzid() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Asia/Tokyo\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19700101T000000\r\n\
+TZOFFSETFROM:+0900\r\n\
+TZOFFSETTO:+0900\r\n\
+END:STANDARD\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:America/New_York\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701101T020000\r\n\
+TZOFFSETFROM:-0400\r\n\
+TZOFFSETTO:-0500\r\n\
+END:STANDARD\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:19700308T020000\r\n\
+TZOFFSETFROM:-0500\r\n\
+TZOFFSETTO:-0400\r\n\
+END:DAYLIGHT\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:1@test\r\n\
+DTSTART;TZID=Asia/Tokyo:20240204T170000\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:2@test\r\n\
+DTSTART;TZID=\"Asia/Tokyo\":20240204T173000\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:3@test\r\n\
+DTSTART;TZID=America/New_York:20240404T030000\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let mut calendar = parse(ics).unwrap();
+        // 含夏令时的时区偏移不固定, 不予标注
+        assert_eq!(annotate(&mut calendar, &AnnotateOptions::default()), 2);
+
+        // 东京时间 17:00 即北京时间 16:00, 尚未立春(16:27); 17:30 即 16:30, 已立春
+        let tests = vec![(2, "2023-12-25", "大寒"), (3, "2023-12-25", "立春")];
+        for (i, date, term) in tests {
+            let event = &calendar.components[i];
+            assert_eq!(event.property(X_LUNAR_DATE).unwrap().value, date, "{}", i);
+            assert_eq!(event.property(X_SOLARTERM).unwrap().value, term, "{}", i);
+        }
+        assert!(calendar.components[4].property(X_LUNAR_DATE).is_none());
+
+        // 当地时区为 UTC-5 时, 东京 17:00 换算为当地 03:00, 交节判断不变
+        let options = AnnotateOptions {
+            utc_offset: FixedOffset::west_opt(5 * 3600).unwrap(),
+            ..Default::default()
+        };
+        let mut calendar = parse(ics).unwrap();
+        annotate(&mut calendar, &options);
+        let event = &calendar.components[2];
+        assert_eq!(event.property(X_LUNAR_DATE).unwrap().value, "2023-12-25");
+        assert_eq!(event.property(X_SOLARTERM).unwrap().value, "大寒");
+    }
+
+    #[test]
+    fn test_utc_offset() {
+        let tests = vec![
+            ("+0800", Some(8 * 3600)),
+            ("-0500", Some(-5 * 3600)),
+            ("+053000", Some(5 * 3600 + 30 * 60)),
+            ("0800", None),
+            ("+08", None),
+            ("+08:00", None),
+        ];
+        for (value, want) in tests {
+            let got = utc_offset(value).map(|o| o.local_minus_utc());
+            assert_eq!(got, want, "{} failed", value);
+        }
+    }
+
+    #[test]
+    fn test_annotate_utc_offset() {
+        // 纽约时间 2024-04-04 03:00, 即北京时间 16:00, 已过清明(15:02)
+        let ics = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+UID:1@test\r\n\
+DTSTART:20240404T080000Z\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let options = AnnotateOptions {
+            utc_offset: FixedOffset::west_opt(5 * 3600).unwrap(),
+            ..Default::default()
+        };
+        let calendar = parse(&annotate_ics(ics, &options).unwrap()).unwrap();
+        let event = &calendar.components[0];
+        assert_eq!(event.property(X_LUNAR_DATE).unwrap().value, "2024-02-26");
+        assert_eq!(event.property(X_SOLARTERM).unwrap().value, "清明");
+
+        let t = Utc.with_ymd_and_hms(2024, 4, 4, 3, 0, 0).unwrap();
+        assert_eq!(Annotation::new(&t).unwrap().solarterm.unwrap(), "春分");
+        assert_eq!(
+            Annotation::new_in(&t, &options.utc_offset)
+                .unwrap()
+                .solarterm
+                .unwrap(),
+            "清明"
+        );
+    }
+
+    #[test]
+    fn test_annotate_is_idempotent() {
+        let once = annotate_ics(ICS, &AnnotateOptions::default()).unwrap();
+        let twice = annotate_ics(&once, &AnnotateOptions::default()).unwrap();
+        assert_eq!(once, twice);
+    }
+
+    #[test]
+    fn test_annotate_options() {
+        let options = AnnotateOptions {
+            description: false,
+            ..Default::default()
+        };
+        let ics = annotate_ics(ICS, &options).unwrap();
+        assert_eq!(ics.matches(X_LUNAR_DATE).count(), 2);
+        assert_eq!(ics.matches("DESCRIPTION").count(), 1);
+
+        let options = AnnotateOptions {
+            properties: false,
+            ..Default::default()
+        };
+        let ics = annotate_ics(ICS, &options).unwrap();
+        assert!(!ics.contains("X-IKIGAI-"));
+        assert_eq!(ics.matches("DESCRIPTION").count(), 2);
+    }
+}
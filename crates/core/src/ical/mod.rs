@@ -0,0 +1,4 @@
+pub mod component;
+pub mod export;
+pub mod import;
+pub mod rrule;
@@ -0,0 +1,567 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+use crate::{
+    date::chinese::{
+        lunar::{Lunar, LUNAR_FROM_YEAR, LUNAR_TO_YEAR},
+        lunar_date::LunarDate,
+    },
+    ikigai_error::{IkigaiError, IkigaiResult},
+};
+
+// 重复频率(仅支持按农历年、农历月重复)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Yearly,
+    Monthly,
+}
+
+// RFC 7529 中的 SKIP 取值, 决定无效日期(如不存在的闰月、三十日)如何处理
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Skip {
+    #[default]
+    Omit,
+    Backward,
+    Forward,
+}
+
+// BYMONTH 中的一项, 例如 `5` 表示五月, `5L` 表示闰五月
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleMonth {
+    pub month: i64,
+    pub is_leap: bool,
+}
+
+// RSCALE=CHINESE 的重复规则
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    pub freq: Frequency,
+    pub interval: i64,
+    pub count: Option<usize>,
+    pub until: Option<DateTime<Utc>>,
+    pub by_month: Vec<RuleMonth>,
+    pub by_month_day: Vec<i64>,
+    pub skip: Skip,
+}
+
+// 农历中的某一个月: (年, 月, 是否闰月)
+type MonthKey = (i64, i64, bool);
+
+impl RecurrenceRule {
+    // 解析 `RRULE:RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=5L;BYMONTHDAY=5` 形式的规则
+    pub fn parse(s: &str) -> IkigaiResult<Self> {
+        let s = s.trim();
+        let value = match s.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &s[6..],
+            _ => s,
+        };
+
+        let mut rscale = None;
+        let mut freq = None;
+        let mut interval = 1;
+        let mut count = None;
+        let mut until = None;
+        let mut by_month = Vec::new();
+        let mut by_month_day = Vec::new();
+        let mut skip = None;
+
+        for part in value.split(';').filter(|p| !p.is_empty()) {
+            let (key, val) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("missing value in `{}`", part)))?;
+            match key.to_ascii_uppercase().as_str() {
+                "RSCALE" => rscale = Some(val.to_ascii_uppercase()),
+                "FREQ" => {
+                    freq = Some(match val.to_ascii_uppercase().as_str() {
+                        "YEARLY" => Frequency::Yearly,
+                        "MONTHLY" => Frequency::Monthly,
+                        _ => return Err(invalid(format!("unsupported FREQ `{}`", val))),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = parse_number(key, val)?;
+                    if interval < 1 {
+                        return Err(invalid("INTERVAL must be positive".to_string()));
+                    }
+                }
+                "COUNT" => {
+                    let n = parse_number(key, val)?;
+                    if n < 1 {
+                        return Err(invalid("COUNT must be positive".to_string()));
+                    }
+                    count = Some(n as usize);
+                }
+                "UNTIL" => until = Some(parse_until(val)?),
+                "BYMONTH" => {
+                    for item in val.split(',') {
+                        by_month.push(parse_rule_month(item)?);
+                    }
+                }
+                "BYMONTHDAY" => {
+                    for item in val.split(',') {
+                        let day = parse_number(key, item)?;
+                        if day == 0 || !(-30..=30).contains(&day) {
+                            return Err(invalid(format!("invalid BYMONTHDAY `{}`", item)));
+                        }
+                        by_month_day.push(day);
+                    }
+                }
+                "SKIP" => {
+                    skip = Some(match val.to_ascii_uppercase().as_str() {
+                        "OMIT" => Skip::Omit,
+                        "BACKWARD" => Skip::Backward,
+                        "FORWARD" => Skip::Forward,
+                        _ => return Err(invalid(format!("invalid SKIP `{}`", val))),
+                    })
+                }
+                "WKST" => {}
+                _ => return Err(invalid(format!("unsupported rule part `{}`", key))),
+            }
+        }
+
+        match rscale.as_deref() {
+            Some("CHINESE") => {}
+            Some(other) => return Err(invalid(format!("unsupported RSCALE `{}`", other))),
+            None => return Err(invalid("RSCALE=CHINESE is required".to_string())),
+        }
+        if count.is_some() && until.is_some() {
+            return Err(invalid(
+                "COUNT and UNTIL are mutually exclusive".to_string(),
+            ));
+        }
+
+        Ok(RecurrenceRule {
+            freq: freq.ok_or_else(|| invalid("FREQ is required".to_string()))?,
+            interval,
+            count,
+            until,
+            by_month,
+            by_month_day,
+            skip: skip.unwrap_or_default(),
+        })
+    }
+
+    // 从 dtstart 开始计算全部重复日期(公历), 无 COUNT/UNTIL 时截止到农历表的末年
+    pub fn occurrences(&self, dtstart: DateTime<Utc>) -> IkigaiResult<Vec<DateTime<Utc>>> {
+        self.occurrences_between(dtstart, dtstart, None)
+    }
+
+    // 计算落在 [after, before) 区间内的重复日期, COUNT 仍从 dtstart 起算
+    pub fn occurrences_between(
+        &self,
+        dtstart: DateTime<Utc>,
+        after: DateTime<Utc>,
+        before: Option<DateTime<Utc>>,
+    ) -> IkigaiResult<Vec<DateTime<Utc>>> {
+        let start = Lunar::new(dtstart)?;
+        let start_month = (start.get_year(), start.get_month(), start.is_leap_month());
+        let days = if self.by_month_day.is_empty() {
+            vec![start.get_day()]
+        } else {
+            self.by_month_day.clone()
+        };
+
+        let mut result = Vec::new();
+        let mut emitted = 0;
+        let mut key = start_month;
+        while key.0 <= LUNAR_TO_YEAR {
+            let months = match self.freq {
+                Frequency::Yearly => {
+                    let months = if self.by_month.is_empty() {
+                        vec![RuleMonth {
+                            month: start_month.1,
+                            is_leap: start_month.2,
+                        }]
+                    } else {
+                        self.by_month.clone()
+                    };
+                    months
+                        .iter()
+                        .filter_map(|m| self.resolve_month(key.0, m))
+                        .collect()
+                }
+                Frequency::Monthly => {
+                    let matched = self.by_month.is_empty()
+                        || self
+                            .by_month
+                            .iter()
+                            .any(|m| m.month == key.1 && m.is_leap == key.2);
+                    if matched {
+                        vec![key]
+                    } else {
+                        vec![]
+                    }
+                }
+            };
+
+            let mut set: Vec<DateTime<Utc>> = months
+                .iter()
+                .flat_map(|m| days.iter().filter_map(|d| self.resolve_day(*m, *d)))
+                .filter_map(|(m, d)| to_datetime(m, d, &dtstart))
+                .collect();
+            set.sort();
+            set.dedup();
+
+            for t in set {
+                if t < dtstart {
+                    continue;
+                }
+                if self.until.is_some_and(|until| t > until)
+                    || self.count.is_some_and(|count| emitted >= count)
+                    || before.is_some_and(|before| t >= before)
+                {
+                    return Ok(result);
+                }
+                emitted += 1;
+                if t >= after {
+                    result.push(t);
+                }
+            }
+
+            key = match self.freq {
+                Frequency::Yearly => (key.0 + self.interval, key.1, key.2),
+                Frequency::Monthly => (0..self.interval).fold(key, |k, _| next_month(k)),
+            };
+        }
+        Ok(result)
+    }
+
+    // 处理 BYMONTH 中当年不存在的闰月
+    fn resolve_month(&self, year: i64, month: &RuleMonth) -> Option<MonthKey> {
+        if !month.is_leap || Lunar::leap_month(year) == month.month {
+            return Some((year, month.month, month.is_leap));
+        }
+        match self.skip {
+            Skip::Omit => None,
+            Skip::Backward => Some((year, month.month, false)),
+            Skip::Forward => Some(next_month((year, month.month, false))),
+        }
+    }
+
+    // 处理月份中不存在的日期(如小月的三十)
+    fn resolve_day(&self, month: MonthKey, day: i64) -> Option<(MonthKey, i64)> {
+        let len = month_days(month)?;
+        let d = if day > 0 { day } else { len + day + 1 };
+        if (1..=len).contains(&d) {
+            return Some((month, d));
+        }
+        match (self.skip, day > 0) {
+            (Skip::Omit, _) => None,
+            (Skip::Backward, true) => Some((month, len)),
+            (Skip::Backward, false) => {
+                let prev = prev_month(month);
+                Some((prev, month_days(prev)?))
+            }
+            (Skip::Forward, true) => Some((next_month(month), 1)),
+            (Skip::Forward, false) => Some((month, 1)),
+        }
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = IkigaiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let freq = match self.freq {
+            Frequency::Yearly => "YEARLY",
+            Frequency::Monthly => "MONTHLY",
+        };
+        write!(f, "RSCALE=CHINESE;FREQ={}", freq)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+        if !self.by_month.is_empty() {
+            let months: Vec<String> = self.by_month.iter().map(|m| m.to_string()).collect();
+            write!(f, ";BYMONTH={}", months.join(","))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(|d| d.to_string()).collect();
+            write!(f, ";BYMONTHDAY={}", days.join(","))?;
+        }
+        match self.skip {
+            Skip::Omit => Ok(()),
+            Skip::Backward => write!(f, ";SKIP=BACKWARD"),
+            Skip::Forward => write!(f, ";SKIP=FORWARD"),
+        }
+    }
+}
+
+impl fmt::Display for RuleMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.month, if self.is_leap { "L" } else { "" })
+    }
+}
+
+fn invalid(msg: String) -> IkigaiError {
+    IkigaiError::InvalidRecurrenceRule(msg)
+}
+
+fn parse_number(key: &str, val: &str) -> IkigaiResult<i64> {
+    val.trim()
+        .parse()
+        .map_err(|_| invalid(format!("invalid {} `{}`", key, val)))
+}
+
+fn parse_rule_month(item: &str) -> IkigaiResult<RuleMonth> {
+    let item = item.trim();
+    let (num, is_leap) = match item.strip_suffix(['L', 'l']) {
+        Some(num) => (num, true),
+        None => (item, false),
+    };
+    let month = parse_number("BYMONTH", num)?;
+    if !(1..=12).contains(&month) {
+        return Err(invalid(format!("invalid BYMONTH `{}`", item)));
+    }
+    Ok(RuleMonth { month, is_leap })
+}
+
+// UNTIL 支持 `20240101` 与 `20240101T000000Z` 两种形式, 纯日期视为当天结束
+fn parse_until(val: &str) -> IkigaiResult<DateTime<Utc>> {
+    let err = || invalid(format!("invalid UNTIL `{}`", val));
+    let date =
+        NaiveDate::parse_from_str(val.get(..8).ok_or_else(err)?, "%Y%m%d").map_err(|_| err())?;
+    let time = match val.get(8..) {
+        Some("") => date.and_hms_opt(23, 59, 59),
+        Some(rest) => {
+            let rest = rest.strip_prefix('T').ok_or_else(err)?;
+            let rest = rest.strip_suffix('Z').unwrap_or(rest);
+            let t = chrono::NaiveTime::parse_from_str(rest, "%H%M%S").map_err(|_| err())?;
+            Some(date.and_time(t))
+        }
+        None => None,
+    };
+    Ok(Utc.from_utc_datetime(&time.ok_or_else(err)?))
+}
+
+fn month_days((year, month, is_leap): MonthKey) -> Option<i64> {
+    if !(LUNAR_FROM_YEAR..=LUNAR_TO_YEAR).contains(&year) {
+        return None;
+    }
+    if is_leap {
+        Some(Lunar::leap_days(year))
+    } else {
+        Some(Lunar::lunar_days(year, month))
+    }
+}
+
+fn next_month((year, month, is_leap): MonthKey) -> MonthKey {
+    if !is_leap
+        && (LUNAR_FROM_YEAR..=LUNAR_TO_YEAR).contains(&year)
+        && Lunar::leap_month(year) == month
+    {
+        (year, month, true)
+    } else if month == 12 {
+        (year + 1, 1, false)
+    } else {
+        (year, month + 1, false)
+    }
+}
+
+fn prev_month((year, month, is_leap): MonthKey) -> MonthKey {
+    let (y, m) = match (is_leap, month) {
+        (true, _) => return (year, month, false),
+        (false, 1) => (year - 1, 12),
+        (false, _) => (year, month - 1),
+    };
+    let leap = (LUNAR_FROM_YEAR..=LUNAR_TO_YEAR).contains(&y) && Lunar::leap_month(y) == m;
+    (y, m, leap)
+}
+
+// 农历日期对应的公历日期, 沿用 dtstart 的时刻
+fn to_datetime(
+    (year, month, is_leap): MonthKey,
+    day: i64,
+    dtstart: &DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let date = LunarDate {
+        year,
+        month,
+        day,
+        is_leap,
+    }
+    .to_naive_date()
+    .ok()?;
+    Some(Utc.from_utc_datetime(&date.and_time(dtstart.time())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        let rule: RecurrenceRule = "RRULE:RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=5L;BYMONTHDAY=5"
+            .parse()
+            .unwrap();
+        assert_eq!(rule.freq, Frequency::Yearly);
+        assert_eq!(rule.interval, 1);
+        assert_eq!(
+            rule.by_month,
+            vec![RuleMonth {
+                month: 5,
+                is_leap: true
+            }]
+        );
+        assert_eq!(rule.by_month_day, vec![5]);
+        assert_eq!(rule.skip, Skip::Omit);
+
+        let tests = vec![
+            ("missing_rscale", "FREQ=YEARLY"),
+            ("gregorian", "RSCALE=GREGORIAN;FREQ=YEARLY"),
+            ("missing_freq", "RSCALE=CHINESE;BYMONTH=1"),
+            ("weekly", "RSCALE=CHINESE;FREQ=WEEKLY"),
+            ("bad_month", "RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=13"),
+            ("bad_day", "RSCALE=CHINESE;FREQ=YEARLY;BYMONTHDAY=31"),
+            ("bad_skip", "RSCALE=CHINESE;FREQ=YEARLY;SKIP=SIDEWAYS"),
+            ("byday", "RSCALE=CHINESE;FREQ=YEARLY;BYDAY=MO"),
+            ("zero_count", "RSCALE=CHINESE;FREQ=YEARLY;COUNT=0"),
+            ("negative_count", "RSCALE=CHINESE;FREQ=YEARLY;COUNT=-1"),
+            (
+                "count_and_until",
+                "RSCALE=CHINESE;FREQ=YEARLY;COUNT=2;UNTIL=20300101",
+            ),
+        ];
+        for (name, s) in tests {
+            assert!(RecurrenceRule::parse(s).is_err(), "{} should fail", name);
+        }
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let tests = vec![
+            "RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=5L;BYMONTHDAY=5",
+            "RSCALE=CHINESE;FREQ=MONTHLY;INTERVAL=2;COUNT=4;BYMONTHDAY=-1;SKIP=BACKWARD",
+            "RSCALE=CHINESE;FREQ=YEARLY;UNTIL=20300101T000000Z;BYMONTH=1,12;SKIP=FORWARD",
+        ];
+        for s in tests {
+            let rule = RecurrenceRule::parse(s).unwrap();
+            assert_eq!(rule.to_string(), s);
+            assert_eq!(RecurrenceRule::parse(&rule.to_string()).unwrap(), rule);
+        }
+    }
+
+    #[test]
+    fn test_occurrences() {
+        let tests = vec![
+            (
+                "dragon_boat",
+                "RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=5;BYMONTHDAY=5;COUNT=3",
+                date(2024, 1, 1),
+                vec![date(2024, 6, 10), date(2025, 5, 31), date(2026, 6, 19)],
+            ),
+            (
+                "from_dtstart",
+                "RSCALE=CHINESE;FREQ=YEARLY;COUNT=2",
+                date(2024, 9, 17),
+                vec![date(2024, 9, 17), date(2025, 10, 6)],
+            ),
+            (
+                "new_years_eve",
+                "RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=12;BYMONTHDAY=-1;COUNT=3",
+                date(2024, 1, 1),
+                vec![date(2024, 2, 9), date(2025, 1, 28), date(2026, 2, 16)],
+            ),
+            (
+                "until",
+                "RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=1;BYMONTHDAY=1;UNTIL=20260217",
+                date(2024, 1, 1),
+                vec![date(2024, 2, 10), date(2025, 1, 29), date(2026, 2, 17)],
+            ),
+            (
+                "interval",
+                "RSCALE=CHINESE;FREQ=YEARLY;INTERVAL=2;BYMONTH=1;BYMONTHDAY=1;COUNT=2",
+                date(2024, 2, 10),
+                vec![date(2024, 2, 10), date(2026, 2, 17)],
+            ),
+            (
+                "monthly_first_day",
+                "RSCALE=CHINESE;FREQ=MONTHLY;BYMONTHDAY=1;COUNT=3",
+                date(2023, 3, 1),
+                vec![date(2023, 3, 22), date(2023, 4, 20), date(2023, 5, 19)],
+            ),
+        ];
+        for (name, s, dtstart, want) in tests {
+            let got = RecurrenceRule::parse(s)
+                .unwrap()
+                .occurrences(dtstart)
+                .unwrap();
+            assert_eq!(got, want, "{} failed", name);
+        }
+    }
+
+    #[test]
+    fn test_occurrences_leap_month_skip() {
+        let tests = vec![
+            (
+                "omit",
+                "RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=5L;BYMONTHDAY=5;COUNT=2",
+                vec![date(2009, 6, 27), date(2028, 6, 27)],
+            ),
+            (
+                "backward",
+                "RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=5L;BYMONTHDAY=5;COUNT=2;SKIP=BACKWARD",
+                vec![date(2009, 6, 27), date(2010, 6, 16)],
+            ),
+            (
+                "forward",
+                "RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=5L;BYMONTHDAY=5;COUNT=2;SKIP=FORWARD",
+                vec![date(2009, 6, 27), date(2010, 7, 16)],
+            ),
+        ];
+        for (name, s, want) in tests {
+            let got = RecurrenceRule::parse(s)
+                .unwrap()
+                .occurrences(date(2009, 1, 1))
+                .unwrap();
+            assert_eq!(got, want, "{} failed", name);
+        }
+    }
+
+    #[test]
+    fn test_occurrences_invalid_day_skip() {
+        // 2024 年农历正月为小月(29 天), 二月为大月(30 天)
+        let tests = vec![
+            ("", vec![date(2024, 4, 8)]),
+            (";SKIP=BACKWARD", vec![date(2024, 3, 9), date(2024, 4, 8)]),
+            (";SKIP=FORWARD", vec![date(2024, 3, 10), date(2024, 4, 8)]),
+        ];
+        for (skip, want) in tests {
+            let s = format!(
+                "RSCALE=CHINESE;FREQ=MONTHLY;BYMONTHDAY=30;UNTIL=20240501{}",
+                skip
+            );
+            let got = RecurrenceRule::parse(&s)
+                .unwrap()
+                .occurrences(date(2024, 2, 10))
+                .unwrap();
+            assert_eq!(got, want, "{} failed", s);
+        }
+    }
+
+    #[test]
+    fn test_occurrences_between() {
+        let rule =
+            RecurrenceRule::parse("RSCALE=CHINESE;FREQ=YEARLY;BYMONTH=8;BYMONTHDAY=15").unwrap();
+        let got = rule
+            .occurrences_between(date(2000, 9, 12), date(2023, 1, 1), Some(date(2025, 1, 1)))
+            .unwrap();
+        assert_eq!(got, vec![date(2023, 9, 29), date(2024, 9, 17)]);
+    }
+}
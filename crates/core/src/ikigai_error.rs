@@ -1,9 +1,50 @@
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq)]
 pub enum IkigaiError {
     #[error("Invalid date")]
     InvalidDate,
+    #[error("Year {year} out of range [{min}, {max}]")]
+    YearOutOfRange { year: i64, min: i64, max: i64 },
+    #[error("Invalid lunar month {month} in year {year}")]
+    InvalidLunarMonth { year: i64, month: i64 },
+    #[error("Invalid lunar day {day} in {year}-{month}, the month has {max} days")]
+    InvalidLunarDay {
+        year: i64,
+        month: i64,
+        day: i64,
+        max: i64,
+    },
+    #[error("Lunar year {year} has no leap month {month}")]
+    LeapMonthNotInYear { year: i64, month: i64 },
+    #[error("Year {year} has no month {month}")]
+    MonthNotInYear { year: i64, month: &'static str },
+    #[error("Solarterm index {index} out of range [{min}, {max}]")]
+    SolartermIndexOutOfRange { index: i64, min: i64, max: i64 },
+    #[error("{name} order {order} out of range [1, {max}]")]
+    OrderOutOfRange {
+        name: &'static str,
+        order: i64,
+        max: i64,
+    },
+    #[error("Invalid ganzhi pillar: {0}")]
+    InvalidPillar(String),
+    #[error("Invalid format string: {0}")]
+    InvalidFormat(String),
+    #[error("Cannot parse {input:?} as {kind}")]
+    ParseError { input: String, kind: &'static str },
+    #[error("Cannot parse date text {input:?}: {reason}")]
+    InvalidDateText { input: String, reason: String },
+    #[error("{name} does not fall in year {year}")]
+    HolidayNotInYear { name: &'static str, year: i64 },
+    #[error("Invalid Japanese era date: {0}")]
+    InvalidEraDate(String),
+    #[error("Invalid location: latitude {latitude}, longitude {longitude}")]
+    InvalidLocation { latitude: f64, longitude: f64 },
+    #[error("Invalid recurrence rule: {0}")]
+    InvalidRecurrenceRule(String),
+    #[error("Invalid iCalendar data: {0}")]
+    InvalidCalendar(String),
 }
 
 pub type IkigaiResult<T> = Result<T, IkigaiError>;
@@ -1,2 +1,4 @@
 pub mod date;
+pub mod ical;
 pub mod ikigai_error;
+pub mod render;
@@ -0,0 +1,171 @@
+use chrono::{Datelike, Weekday};
+
+use super::{cell_notes, escape, Note, Theme, WEEK_ALIAS};
+use crate::date::chinese::month_grid::MonthGrid;
+
+// 单月表格片段, 样式由 `style` 或调用方的 CSS 提供
+pub fn month_table(grid: &MonthGrid) -> String {
+    let mut out = String::from("<table class=\"ikigai-month\">");
+    out += &format!(
+        "<caption>{}年{}月</caption><thead><tr>",
+        grid.year(),
+        grid.month()
+    );
+    for weekday in grid.weekdays() {
+        let class = if matches!(weekday, Weekday::Sat | Weekday::Sun) {
+            " class=\"weekend\""
+        } else {
+            ""
+        };
+        out += &format!(
+            "<th{}>{}</th>",
+            class,
+            WEEK_ALIAS[weekday.num_days_from_sunday() as usize]
+        );
+    }
+    out += "</tr></thead><tbody>";
+
+    for week in grid.weeks() {
+        out += "<tr>";
+        for cell in week {
+            if !cell.in_month {
+                out += "<td class=\"empty\"></td>";
+                continue;
+            }
+            let class = if cell.is_weekend {
+                " class=\"weekend\""
+            } else {
+                ""
+            };
+            let (lunar, badges) = cell_notes(cell);
+            out += &format!(
+                "<td{} data-date=\"{}\"><span class=\"day\">{}</span>{}",
+                class,
+                cell.date,
+                cell.date.day(),
+                span(lunar, Note::Lunar)
+            );
+            if !badges.is_empty() {
+                out += "<span class=\"badges\">";
+                for (badge, kind) in badges {
+                    out += &span(badge, kind);
+                }
+                out += "</span>";
+            }
+            out += "</td>";
+        }
+        out += "</tr>";
+    }
+    out += "</tbody></table>";
+    out
+}
+
+fn span(content: &str, kind: Note) -> String {
+    format!(
+        "<span class=\"{}\">{}</span>",
+        kind.class(),
+        escape(content)
+    )
+}
+
+// 表格使用的 CSS
+pub fn style(theme: &Theme) -> String {
+    format!(
+        "body{{background:{bg};color:{text};font-family:{font};}}\
+.ikigai-month{{border-collapse:collapse;margin:1em;display:inline-table;}}\
+.ikigai-month caption{{color:{title};font-size:1.4em;font-weight:bold;padding:.3em;}}\
+.ikigai-month th,.ikigai-month td{{border:1px solid {grid};width:{w}px;text-align:center;}}\
+.ikigai-month td{{height:{h}px;}}\
+.ikigai-month .weekend{{color:{weekend};}}\
+.ikigai-month .day{{display:block;font-size:1.6em;font-weight:bold;}}\
+.ikigai-month .lunar,.ikigai-month .badges{{display:block;}}\
+.ikigai-month .lunar{{color:{lunar};font-size:.8em;}}\
+.ikigai-month .solarterm{{color:{solarterm};font-size:.8em;}}\
+.ikigai-month .festival{{color:{festival};font-size:.8em;}}",
+        bg = css(&theme.background),
+        text = css(&theme.text),
+        font = css(&theme.font_family),
+        title = css(&theme.title),
+        grid = css(&theme.grid),
+        w = theme.cell_width,
+        h = theme.cell_height,
+        weekend = css(&theme.weekend),
+        lunar = css(&theme.lunar),
+        solarterm = css(&theme.solarterm),
+        festival = css(&theme.festival),
+    )
+}
+
+// 完整的 HTML 页面, 样式内嵌, 不依赖外部资源
+pub fn document(grids: &[MonthGrid], theme: &Theme) -> String {
+    let title = match (grids.first(), grids.len()) {
+        (Some(grid), 1) => format!("{}年{}月", grid.year(), grid.month()),
+        (Some(grid), _) => format!("{}年", grid.year()),
+        (None, _) => String::new(),
+    };
+    let tables: String = grids.iter().map(month_table).collect();
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"zh-CN\"><head><meta charset=\"utf-8\"><title>{}</title><style>{}</style></head><body>{}</body></html>\n",
+        escape(&title),
+        style(theme),
+        tables
+    )
+}
+
+// 主题中的值写入 <style>, 去掉可能提前结束样式块的字符
+fn css(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !matches!(c, '<' | '>' | '{' | '}' | ';'))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_table() {
+        let grid = MonthGrid::new(2024, 2, Weekday::Sun).unwrap();
+        let html = month_table(&grid);
+
+        assert!(html.starts_with("<table class=\"ikigai-month\"><caption>2024年2月</caption>"));
+        assert!(html.contains("<th class=\"weekend\">日</th><th>一</th>"));
+        assert_eq!(html.matches("<tr>").count(), 1 + 5);
+        assert_eq!(html.matches("<td").count(), 35);
+        assert_eq!(html.matches("class=\"empty\"").count(), 6);
+        assert!(html.contains(
+            "<td class=\"weekend\" data-date=\"2024-02-10\"><span class=\"day\">10</span><span class=\"lunar\">正月</span>\
+<span class=\"badges\"><span class=\"festival\">春节</span></span></td>"
+        ));
+        assert!(html.contains(
+            "<span class=\"lunar\">初十</span><span class=\"badges\"><span class=\"solarterm\">雨水</span></span>"
+        ));
+        // 每天都有农历日期
+        assert_eq!(html.matches("<span class=\"lunar\">").count(), 29);
+        assert!(html.contains(
+            "<td data-date=\"2024-02-01\"><span class=\"day\">1</span><span class=\"lunar\">廿二</span></td>"
+        ));
+    }
+
+    #[test]
+    fn test_document() {
+        let theme = Theme {
+            festival: "#ff0000".to_string(),
+            font_family: "serif;}</style><script>".to_string(),
+            ..Default::default()
+        };
+        let grids = MonthGrid::year_grids(2024, Weekday::Mon).unwrap();
+        let html = document(&grids, &theme);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>2024年</title>"));
+        assert_eq!(html.matches("<table").count(), 12);
+        assert!(html.contains(".ikigai-month .festival{color:#ff0000;"));
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<link"));
+
+        let single = document(&grids[1..2], &theme);
+        assert!(single.contains("<title>2024年2月</title>"));
+    }
+}
@@ -0,0 +1,119 @@
+pub mod html;
+pub mod svg;
+
+use crate::date::chinese::month_grid::DayCell;
+
+// 星期的简称, 下标为 num_days_from_sunday
+pub const WEEK_ALIAS: [&str; 7] = ["日", "一", "二", "三", "四", "五", "六"];
+
+// 渲染月历所用的字体、颜色和尺寸
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub font_family: String,
+    pub background: String,
+    pub text: String,
+    pub weekend: String,
+    pub lunar: String,
+    pub solarterm: String,
+    pub festival: String,
+    pub grid: String,
+    pub title: String,
+    // 单元格宽高(SVG 中的像素)
+    pub cell_width: f64,
+    pub cell_height: f64,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            font_family: "'Noto Sans CJK SC', 'PingFang SC', 'Microsoft YaHei', sans-serif"
+                .to_string(),
+            background: "#ffffff".to_string(),
+            text: "#222222".to_string(),
+            weekend: "#c0392b".to_string(),
+            lunar: "#888888".to_string(),
+            solarterm: "#27ae60".to_string(),
+            festival: "#d35400".to_string(),
+            grid: "#dddddd".to_string(),
+            title: "#222222".to_string(),
+            cell_width: 80.0,
+            cell_height: 64.0,
+        }
+    }
+}
+
+// 格子中小字的种类
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Note {
+    Festival,
+    Solarterm,
+    Lunar,
+}
+
+impl Note {
+    fn color(self, theme: &Theme) -> &str {
+        match self {
+            Note::Festival => &theme.festival,
+            Note::Solarterm => &theme.solarterm,
+            Note::Lunar => &theme.lunar,
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Note::Festival => "festival",
+            Note::Solarterm => "solarterm",
+            Note::Lunar => "lunar",
+        }
+    }
+}
+
+// 格子中的小字: 首行总是农历日期, 当天有节日或节气时另起一行标出
+fn cell_notes(cell: &DayCell) -> (&str, Vec<(&str, Note)>) {
+    let festival = cell.festivals.first().map(|f| (f.alias(), Note::Festival));
+    let term = cell
+        .solarterm
+        .as_ref()
+        .map(|t| (t.alias(), Note::Solarterm));
+    (
+        &cell.lunar_label,
+        festival.into_iter().chain(term).collect(),
+    )
+}
+
+// 转义 XML/HTML 文本及属性值
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        let tests = vec![
+            ("plain", "春节", "春节"),
+            ("font", "'Noto Sans', serif", "&#39;Noto Sans&#39;, serif"),
+            (
+                "markup",
+                "<a href=\"x\">&</a>",
+                "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;",
+            ),
+        ];
+        for (name, raw, want) in tests {
+            assert_eq!(escape(raw), want, "{} failed", name);
+        }
+    }
+}
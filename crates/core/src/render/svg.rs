@@ -0,0 +1,231 @@
+use chrono::{Datelike, Weekday};
+
+use super::{cell_notes, escape, Theme, WEEK_ALIAS};
+use crate::date::chinese::month_grid::MonthGrid;
+
+const MARGIN: f64 = 16.0;
+
+// 年历海报每行排列的月份数
+const POSTER_COLUMNS: usize = 3;
+
+// 月标题、星期表头高度与单元格高度之比
+const TITLE_RATIO: f64 = 0.75;
+const HEADER_RATIO: f64 = 0.5;
+
+// 单月 SVG, 高度随周数变化
+pub fn month(grid: &MonthGrid, theme: &Theme) -> String {
+    let width = block_width(theme) + 2.0 * MARGIN;
+    let height = block_height(grid.weeks().len(), theme) + 2.0 * MARGIN;
+    document(
+        width,
+        height,
+        theme,
+        &month_group(grid, theme, MARGIN, MARGIN),
+    )
+}
+
+// 年历海报, 每行 3 个月; 每个月按 6 周留出高度以便对齐
+pub fn year(grids: &[MonthGrid], theme: &Theme) -> String {
+    let title_height = theme.cell_height;
+    let block_w = block_width(theme);
+    let block_h = block_height(6, theme);
+    let rows = grids.len().div_ceil(POSTER_COLUMNS);
+    let width = POSTER_COLUMNS as f64 * (block_w + MARGIN) + MARGIN;
+    let height = title_height + rows as f64 * (block_h + MARGIN) + MARGIN;
+
+    let mut body = String::new();
+    if let Some(first) = grids.first() {
+        body += &text(
+            width / 2.0,
+            title_height * 0.7,
+            theme.cell_height * 0.6,
+            &theme.title,
+            &format!("{}年", first.year()),
+            true,
+        );
+    }
+    for (i, grid) in grids.iter().enumerate() {
+        let x = MARGIN + (i % POSTER_COLUMNS) as f64 * (block_w + MARGIN);
+        let y = title_height + (i / POSTER_COLUMNS) as f64 * (block_h + MARGIN);
+        body += &month_group(grid, theme, x, y);
+    }
+    document(width, height, theme, &body)
+}
+
+fn document(width: f64, height: f64, theme: &Theme, body: &str) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"{font}\">\
+<rect width=\"{w}\" height=\"{h}\" fill=\"{bg}\"/>{body}</svg>\n",
+        w = width,
+        h = height,
+        font = escape(&theme.font_family),
+        bg = escape(&theme.background),
+        body = body
+    )
+}
+
+fn month_group(grid: &MonthGrid, theme: &Theme, x: f64, y: f64) -> String {
+    let cw = theme.cell_width;
+    let ch = theme.cell_height;
+    let title_h = ch * TITLE_RATIO;
+    let header_h = ch * HEADER_RATIO;
+
+    let mut out = format!("<g transform=\"translate({},{})\">", x, y);
+    out += &text(
+        block_width(theme) / 2.0,
+        title_h * 0.7,
+        title_h * 0.5,
+        &theme.title,
+        &format!("{}年{}月", grid.year(), grid.month()),
+        true,
+    );
+    for (col, weekday) in grid.weekdays().iter().enumerate() {
+        let color = if matches!(weekday, Weekday::Sat | Weekday::Sun) {
+            &theme.weekend
+        } else {
+            &theme.text
+        };
+        out += &text(
+            (col as f64 + 0.5) * cw,
+            title_h + header_h * 0.7,
+            header_h * 0.5,
+            color,
+            WEEK_ALIAS[weekday.num_days_from_sunday() as usize],
+            false,
+        );
+    }
+
+    let top = title_h + header_h;
+    for (row, week) in grid.weeks().iter().enumerate() {
+        for (col, cell) in week.iter().enumerate() {
+            let left = col as f64 * cw;
+            let cell_top = top + row as f64 * ch;
+            out += &format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"{}\"/>",
+                left,
+                cell_top,
+                cw,
+                ch,
+                escape(&theme.grid)
+            );
+            if !cell.in_month {
+                continue;
+            }
+            let color = if cell.is_weekend {
+                &theme.weekend
+            } else {
+                &theme.text
+            };
+            out += &text(
+                left + cw / 2.0,
+                cell_top + ch * 0.42,
+                ch * 0.34,
+                color,
+                &cell.date.day().to_string(),
+                true,
+            );
+            let (lunar, badges) = cell_notes(cell);
+            out += &text(
+                left + cw / 2.0,
+                cell_top + ch * 0.68,
+                ch * 0.18,
+                &theme.lunar,
+                lunar,
+                false,
+            );
+            // 节日和节气排在农历日期下方的同一行, 各用自己的颜色
+            if !badges.is_empty() {
+                let spans: Vec<String> = badges
+                    .iter()
+                    .map(|(badge, kind)| {
+                        format!(
+                            "<tspan fill=\"{}\">{}</tspan>",
+                            escape(kind.color(theme)),
+                            escape(badge)
+                        )
+                    })
+                    .collect();
+                out += &format!(
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" text-anchor=\"middle\">{}</text>",
+                    left + cw / 2.0,
+                    cell_top + ch * 0.9,
+                    ch * 0.18,
+                    spans.join(" ")
+                );
+            }
+        }
+    }
+    out += "</g>";
+    out
+}
+
+fn text(x: f64, y: f64, size: f64, color: &str, content: &str, bold: bool) -> String {
+    format!(
+        "<text x=\"{}\" y=\"{}\" font-size=\"{}\" fill=\"{}\" text-anchor=\"middle\"{}>{}</text>",
+        x,
+        y,
+        size,
+        escape(color),
+        if bold { " font-weight=\"bold\"" } else { "" },
+        escape(content)
+    )
+}
+
+fn block_width(theme: &Theme) -> f64 {
+    7.0 * theme.cell_width
+}
+
+fn block_height(weeks: usize, theme: &Theme) -> f64 {
+    theme.cell_height * (TITLE_RATIO + HEADER_RATIO + weeks as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_svg() {
+        let grid = MonthGrid::new(2024, 2, Weekday::Sun).unwrap();
+        let svg = month(&grid, &Theme::default());
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"592\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains(">2024年2月</text>"));
+        // 29 天, 每天一个日期和一行农历; 另有 5 个节日和 2 个节气各占一行
+        assert_eq!(svg.matches("<text").count(), 1 + 7 + 29 * 2 + 7);
+        assert_eq!(svg.matches("<rect").count(), 1 + 35);
+        assert!(svg.contains("fill=\"#888888\" text-anchor=\"middle\">正月</text>"));
+        assert!(svg.contains("<tspan fill=\"#d35400\">春节</tspan></text>"));
+        assert!(svg.contains("<tspan fill=\"#27ae60\">立春</tspan></text>"));
+        assert!(svg.contains("fill=\"#888888\" text-anchor=\"middle\">初二</text>"));
+        assert!(!svg.contains("http://fonts"));
+    }
+
+    #[test]
+    fn test_month_svg_theme() {
+        let theme = Theme {
+            font_family: "'Kaiti', serif".to_string(),
+            weekend: "#0000ff".to_string(),
+            cell_width: 100.0,
+            ..Default::default()
+        };
+        let grid = MonthGrid::new(2024, 2, Weekday::Mon).unwrap();
+        let svg = month(&grid, &theme);
+        assert!(svg.contains("font-family=\"&#39;Kaiti&#39;, serif\""));
+        assert!(svg.contains("width=\"732\""));
+        assert!(
+            svg.contains("fill=\"#0000ff\" text-anchor=\"middle\" font-weight=\"bold\">10</text>")
+        );
+    }
+
+    #[test]
+    fn test_year_svg() {
+        let grids = MonthGrid::year_grids(2024, Weekday::Sun).unwrap();
+        let svg = year(&grids, &Theme::default());
+        assert!(svg.contains(">2024年</text>"));
+        assert_eq!(svg.matches("<g transform").count(), 12);
+        for month in 1..=12 {
+            assert!(svg.contains(&format!(">2024年{}月</text>", month)));
+        }
+    }
+}
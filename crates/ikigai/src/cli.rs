@@ -0,0 +1,583 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Utc, Weekday};
+use ikigai_core::{
+    date::chinese::{
+        gan_zhi::Ganzhi,
+        lunar::Lunar,
+        lunar_date::LunarDate,
+        month_grid::{DayCell, MonthGrid},
+        solar::Solar,
+        solarterm::{self, Solarterm, SOLARTERM_FROM_YEAR},
+    },
+    ikigai_error::IkigaiError,
+    render::WEEK_ALIAS,
+};
+use thiserror::Error;
+
+pub const USAGE: &str = "\
+Usage: ikigai [--tz <offset>] [--json] <command>
+
+Commands:
+  today                        solar, lunar, ganzhi, solar term, animal and constellation
+  convert <yyyy-mm-dd>         solar date to lunar date
+  convert --lunar <yyyy-mm-dd> [--leap]
+                               lunar date to solar date, --leap for a leap month
+  terms <year>                 the 24 solar terms of a year
+  cal <year> <month>           month grid with lunar days
+
+Options:
+  --tz <offset>   local time zone as a UTC offset, e.g. +08:00, -05:00 or UTC (default +08:00)
+  --json          print JSON instead of text
+  -h, --help      print this help";
+
+// 节气表中的时间为北京时间
+const CHINA_UTC_OFFSET: i32 = 8 * 3600;
+
+#[derive(Error, Debug)]
+pub enum CliError {
+    #[error("{0}\n\n{USAGE}")]
+    Usage(String),
+    #[error("{0}")]
+    Core(#[from] IkigaiError),
+}
+
+pub type CliResult<T> = Result<T, CliError>;
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Help,
+    Today,
+    Convert {
+        date: NaiveDate,
+    },
+    ConvertLunar {
+        year: i64,
+        month: i64,
+        day: i64,
+        is_leap: bool,
+    },
+    Terms {
+        year: i64,
+    },
+    Cal {
+        year: i32,
+        month: u32,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    command: Command,
+    tz: FixedOffset,
+    json: bool,
+}
+
+// 执行命令行参数(不含程序名), now 为当前时刻
+pub fn run(args: &[String], now: DateTime<Utc>) -> CliResult<String> {
+    let options = parse_args(args)?;
+    let tz = options.tz;
+    match options.command {
+        Command::Help => Ok(USAGE.to_string()),
+        Command::Today => today(&now.with_timezone(&tz), options.json),
+        Command::Convert { date } => convert(date, options.json),
+        Command::ConvertLunar {
+            year,
+            month,
+            day,
+            is_leap,
+        } => convert_lunar(year, month, day, is_leap, options.json),
+        Command::Terms { year } => terms(year, &tz, options.json),
+        Command::Cal { year, month } => cal(year, month, options.json),
+    }
+}
+
+fn parse_args(args: &[String]) -> CliResult<Options> {
+    let mut tz = FixedOffset::east_opt(CHINA_UTC_OFFSET).unwrap();
+    let mut json = false;
+    let mut lunar = false;
+    let mut leap = false;
+    let mut help = false;
+    let mut positional = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--lunar" => lunar = true,
+            "--leap" => leap = true,
+            "-h" | "--help" => help = true,
+            "--tz" => {
+                let value = iter.next().ok_or_else(|| usage("--tz requires a value"))?;
+                tz = parse_tz(value)?;
+            }
+            _ if arg.starts_with("--tz=") => tz = parse_tz(&arg[5..])?,
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                return Err(usage(&format!("unknown option `{}`", arg)))
+            }
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    let command = match positional.as_slice() {
+        _ if help => Command::Help,
+        [] => return Err(usage("missing command")),
+        ["today"] => Command::Today,
+        ["convert", date] if lunar => {
+            let (year, month, day) = parse_ymd(date)?;
+            Command::ConvertLunar {
+                year,
+                month,
+                day,
+                is_leap: leap,
+            }
+        }
+        ["convert", date] => {
+            let (year, month, day) = parse_ymd(date)?;
+            let date = i32::try_from(year)
+                .ok()
+                .zip(u32::try_from(month).ok())
+                .zip(u32::try_from(day).ok())
+                .and_then(|((year, month), day)| NaiveDate::from_ymd_opt(year, month, day))
+                .ok_or_else(|| usage(&format!("invalid date `{}`", date)))?;
+            Command::Convert { date }
+        }
+        ["terms", year] => Command::Terms {
+            year: parse_number(year)?,
+        },
+        ["cal", year, month] => {
+            let month = parse_number(month)?;
+            if !(1..=12).contains(&month) {
+                return Err(usage(&format!("invalid month `{}`", month)));
+            }
+            let year = i32::try_from(parse_number(year)?)
+                .map_err(|_| usage(&format!("invalid year `{}`", year)))?;
+            Command::Cal {
+                year,
+                month: month as u32,
+            }
+        }
+        [command, ..] => {
+            return Err(usage(&format!(
+                "invalid arguments for command `{}`",
+                command
+            )))
+        }
+    };
+    if (lunar || leap) && !matches!(command, Command::ConvertLunar { .. } | Command::Help) {
+        return Err(usage("--lunar and --leap are only valid for `convert`"));
+    }
+    Ok(Options { command, tz, json })
+}
+
+fn today(now: &DateTime<FixedOffset>, json: bool) -> CliResult<String> {
+    // 与 ikigai_core 保持一致: 以 Utc 类型承载当地时间
+    let t = Utc.from_utc_datetime(&now.naive_local());
+    let solar = Solar::new(t)?;
+    let lunar = lunar_of(&t)?;
+    let ganzhi = Ganzhi::new(t)?;
+    // 节气表为北京时间, 先换算到东八区再查节气
+    let china = FixedOffset::east_opt(CHINA_UTC_OFFSET).unwrap();
+    let beijing = Solar::new(Utc.from_utc_datetime(&now.with_timezone(&china).naive_local()))?;
+    let term = beijing
+        .current_solarterm()
+        .unwrap_or_else(|| beijing.prev_solarterm());
+    let constellation = solar.constellation().alias();
+
+    if json {
+        return Ok(format!(
+            "{{\"solar\":{},\"week\":{},\"lunar\":{},\"ganzhi\":{{\"year\":{},\"month\":{},\"day\":{},\"hour\":{}}},\"solarterm\":{},\"solarterm_today\":{},\"animal\":{},\"constellation\":{}}}",
+            json_string(&now.to_rfc3339()),
+            json_string(solar.week_alias()),
+            lunar_json(&lunar),
+            json_string(&ganzhi.year_ganzhi_alias()),
+            json_string(&ganzhi.month_ganzhi_alias()),
+            json_string(&ganzhi.day_ganzhi_alias()),
+            json_string(&ganzhi.hour_ganzhi_alias()),
+            json_string(term.alias()),
+            beijing.current_solarterm().is_some(),
+            json_string(lunar.animal().alias()),
+            json_string(constellation),
+        ));
+    }
+    let mut lines = vec![
+        format!(
+            "公历: {} 星期{}",
+            now.format("%Y-%m-%d %H:%M:%S %:z"),
+            solar.week_alias()
+        ),
+        format!("农历: {}", lunar_label(&lunar)),
+        format!(
+            "干支: {}年 {}月 {}日 {}时",
+            ganzhi.year_ganzhi_alias(),
+            ganzhi.month_ganzhi_alias(),
+            ganzhi.day_ganzhi_alias(),
+            ganzhi.hour_ganzhi_alias()
+        ),
+    ];
+    if beijing.current_solarterm().is_some() {
+        lines.push(format!("节气: {} (今日交节)", term.alias()));
+    } else {
+        lines.push(format!("节气: {}", term.alias()));
+    }
+    lines.push(format!("生肖: {}", lunar.animal().alias()));
+    lines.push(format!("星座: {}座", constellation));
+    Ok(lines.join("\n"))
+}
+
+fn convert(date: NaiveDate, json: bool) -> CliResult<String> {
+    let t = Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap());
+    let lunar = lunar_of(&t)?;
+    if json {
+        return Ok(format!(
+            "{{\"solar\":{},\"lunar\":{}}}",
+            json_string(&date.to_string()),
+            lunar_json(&lunar)
+        ));
+    }
+    Ok(format!("{} => 农历{}", date, lunar_label(&lunar)))
+}
+
+fn convert_lunar(year: i64, month: i64, day: i64, is_leap: bool, json: bool) -> CliResult<String> {
+    let date = LunarDate::new(year, month, day, is_leap)?.to_naive_date()?;
+    let lunar = lunar_of(&Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap()))?;
+    if json {
+        return Ok(format!(
+            "{{\"solar\":{},\"lunar\":{}}}",
+            json_string(&date.to_string()),
+            lunar_json(&lunar)
+        ));
+    }
+    Ok(format!("农历{} => {}", lunar_label(&lunar), date))
+}
+
+fn terms(year: i64, tz: &FixedOffset, json: bool) -> CliResult<String> {
+    solarterm::check_year(year)?;
+    let mut rows = Vec::new();
+    for index in 24 * (year - SOLARTERM_FROM_YEAR)..24 * (year - SOLARTERM_FROM_YEAR + 1) {
+        let term = Solarterm::new(index)?;
+        let beijing = term.time()?;
+        let instant = beijing - Duration::seconds(CHINA_UTC_OFFSET as i64);
+        rows.push((term.alias().to_string(), instant.with_timezone(tz)));
+    }
+    if json {
+        let items: Vec<String> = rows
+            .iter()
+            .map(|(name, t)| {
+                format!(
+                    "{{\"name\":{},\"time\":{}}}",
+                    json_string(name),
+                    json_string(&t.to_rfc3339())
+                )
+            })
+            .collect();
+        return Ok(format!("[{}]", items.join(",")));
+    }
+    Ok(rows
+        .iter()
+        .map(|(name, t)| format!("{}  {}", name, t.format("%Y-%m-%d %H:%M:%S %:z")))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+fn cal(year: i32, month: u32, json: bool) -> CliResult<String> {
+    // 以周日为一周的第一天
+    let grid = MonthGrid::new(year, month, Weekday::Sun)?;
+
+    if json {
+        let weeks: Vec<String> = grid
+            .weeks()
+            .iter()
+            .map(|week| {
+                let cells: Vec<String> = week
+                    .iter()
+                    .map(|cell| {
+                        if !cell.in_month {
+                            return "null".to_string();
+                        }
+                        let festivals: Vec<String> = cell
+                            .festivals
+                            .iter()
+                            .map(|f| json_string(f.alias()))
+                            .collect();
+                        format!(
+                            "{{\"date\":{},\"day\":{},\"lunar\":{},\"solarterm\":{},\"festivals\":[{}],\"weekend\":{}}}",
+                            json_string(&cell.date.to_string()),
+                            cell.date.day(),
+                            json_string(&cell.lunar_label),
+                            cell.solarterm
+                                .as_ref()
+                                .map_or("null".to_string(), |t| json_string(t.alias())),
+                            festivals.join(","),
+                            cell.is_weekend
+                        )
+                    })
+                    .collect();
+                format!("[{}]", cells.join(","))
+            })
+            .collect();
+        return Ok(format!(
+            "{{\"year\":{},\"month\":{},\"weeks\":[{}]}}",
+            year,
+            month,
+            weeks.join(",")
+        ));
+    }
+
+    // 每格宽 6 列, 汉字按 2 列计
+    let mut lines = vec![format!("{:>21}年{}月", year, month)];
+    lines.push(
+        grid.weekdays()
+            .iter()
+            .map(|w| format!("    {}", WEEK_ALIAS[w.num_days_from_sunday() as usize]))
+            .collect::<String>(),
+    );
+    for week in grid.weeks() {
+        let mut days = String::new();
+        let mut labels = String::new();
+        for cell in week {
+            if cell.in_month {
+                days += &format!("{:>6}", cell.date.day());
+                labels += &format!("  {}", cell_label(cell));
+            } else {
+                days += "      ";
+                labels += "      ";
+            }
+        }
+        lines.push(days.trim_end().to_string());
+        lines.push(labels.trim_end().to_string());
+    }
+    Ok(lines.join("\n"))
+}
+
+// 月历格子中的农历标签: 节气优先, 其余取农历标签的前两个字
+fn cell_label(cell: &DayCell) -> String {
+    match &cell.solarterm {
+        Some(term) => term.alias().to_string(),
+        None => cell.lunar_label.chars().take(2).collect(),
+    }
+}
+
+fn lunar_of(t: &DateTime<Utc>) -> CliResult<Lunar> {
+    Ok(Lunar::new(*t)?)
+}
+
+fn lunar_label(lunar: &Lunar) -> String {
+    format!(
+        "{}年{}{}",
+        lunar.year_alias(),
+        lunar.month_alias(),
+        lunar.day_alias()
+    )
+}
+
+fn lunar_json(lunar: &Lunar) -> String {
+    format!(
+        "{{\"year\":{},\"month\":{},\"day\":{},\"leap\":{},\"label\":{}}}",
+        lunar.get_year(),
+        lunar.get_month(),
+        lunar.get_day(),
+        lunar.is_leap_month(),
+        json_string(&format!("{}{}", lunar.month_alias(), lunar.day_alias()))
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn parse_tz(value: &str) -> CliResult<FixedOffset> {
+    let err = || usage(&format!("invalid time zone `{}`", value));
+    if value.eq_ignore_ascii_case("UTC") || value == "Z" {
+        return Ok(FixedOffset::east_opt(0).unwrap());
+    }
+    let (sign, rest) = match value.as_bytes().first() {
+        Some(b'+') => (1, &value[1..]),
+        Some(b'-') => (-1, &value[1..]),
+        _ => return Err(err()),
+    };
+    let (h, m) = rest.split_once(':').unwrap_or((rest, "0"));
+    let h: i32 = h.parse().map_err(|_| err())?;
+    let m: i32 = m.parse().map_err(|_| err())?;
+    if !(0..60).contains(&m) {
+        return Err(err());
+    }
+    FixedOffset::east_opt(sign * (h * 3600 + m * 60)).ok_or_else(err)
+}
+
+fn parse_ymd(value: &str) -> CliResult<(i64, i64, i64)> {
+    let parts: Vec<&str> = value.split('-').collect();
+    match parts.as_slice() {
+        [y, m, d] => Ok((parse_number(y)?, parse_number(m)?, parse_number(d)?)),
+        _ => Err(usage(&format!(
+            "invalid date `{}`, expected yyyy-mm-dd",
+            value
+        ))),
+    }
+}
+
+fn parse_number(value: &str) -> CliResult<i64> {
+    value
+        .parse()
+        .map_err(|_| usage(&format!("invalid number `{}`", value)))
+}
+
+fn usage(msg: &str) -> CliError {
+    CliError::Usage(msg.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ikigai_core::date::chinese::solarterm::SOLARTERM_TO_YEAR;
+
+    fn run_str(args: &str) -> CliResult<String> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        // 北京时间 2024-02-10 10:00:00
+        let now = Utc.with_ymd_and_hms(2024, 2, 10, 2, 0, 0).unwrap();
+        run(&args, now)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let tests = vec![
+            ("missing", ""),
+            ("unknown_command", "foo"),
+            ("unknown_option", "today --bar"),
+            ("bad_tz", "today --tz Asia/Shanghai"),
+            ("bad_date", "convert 2024-02-30"),
+            ("bad_month", "cal 2024 13"),
+            ("leap_without_convert", "today --leap"),
+            ("terms_without_year", "terms"),
+        ];
+        for (name, args) in tests {
+            assert!(run_str(args).is_err(), "{} should fail", name);
+        }
+        // 超出 i32/u32 的数值不能截断成合法日期
+        let tests = vec![
+            ("huge_year", "convert 4294969321-01-01"),
+            ("huge_month", "convert 2024-4294967297-01"),
+            ("huge_day", "convert 2024-01-4294967297"),
+            ("huge_cal_year", "cal 4294969320 2"),
+        ];
+        for (name, args) in tests {
+            assert!(
+                matches!(run_str(args), Err(CliError::Usage(_))),
+                "{} should fail",
+                name
+            );
+        }
+        assert_eq!(run_str("--help").unwrap(), USAGE);
+    }
+
+    #[test]
+    fn test_today() {
+        let got = run_str("today").unwrap();
+        assert_eq!(
+            got,
+            "公历: 2024-02-10 10:00:00 +08:00 星期六\n\
+             农历: 二零二四年正月初一\n\
+             干支: 甲辰年 丙寅月 甲辰日 己巳时\n\
+             节气: 立春\n\
+             生肖: 龙\n\
+             星座: 水瓶座"
+        );
+
+        let got = run_str("today --tz -05:00").unwrap();
+        assert!(
+            got.starts_with("公历: 2024-02-09 21:00:00 -05:00 星期五\n农历: 二零二三年腊月三十")
+        );
+
+        // 北京时间已过春分, 纽约当地日期仍为 3 月 20 日
+        let args: Vec<String> = ["today", "--tz", "-05:00"].map(String::from).to_vec();
+        let now = Utc.with_ymd_and_hms(2018, 3, 20, 17, 0, 0).unwrap();
+        let got = run(&args, now).unwrap();
+        assert!(got.starts_with("公历: 2018-03-20 12:00:00 -05:00 星期二\n"));
+        assert!(got.contains("节气: 春分 (今日交节)"));
+
+        let got = run_str("--json today").unwrap();
+        assert!(got.starts_with("{\"solar\":\"2024-02-10T10:00:00+08:00\",\"week\":\"六\","));
+        assert!(got.contains(
+            "\"lunar\":{\"year\":2024,\"month\":1,\"day\":1,\"leap\":false,\"label\":\"正月初一\"}"
+        ));
+    }
+
+    #[test]
+    fn test_convert() {
+        let tests = vec![
+            ("convert 2024-02-10", "2024-02-10 => 农历二零二四年正月初一"),
+            (
+                "convert 2023-03-22",
+                "2023-03-22 => 农历二零二三年闰二月初一",
+            ),
+            (
+                "convert --lunar 2023-02-01 --leap",
+                "农历二零二三年闰二月初一 => 2023-03-22",
+            ),
+            (
+                "convert --lunar 2023-02-01",
+                "农历二零二三年二月初一 => 2023-02-20",
+            ),
+        ];
+        for (args, want) in tests {
+            assert_eq!(run_str(args).unwrap(), want, "{} failed", args);
+        }
+        assert!(run_str("convert --lunar 2024-02-01 --leap").is_err());
+        assert_eq!(
+            run_str("convert 2024-02-10 --json").unwrap(),
+            "{\"solar\":\"2024-02-10\",\"lunar\":{\"year\":2024,\"month\":1,\"day\":1,\"leap\":false,\"label\":\"正月初一\"}}"
+        );
+    }
+
+    #[test]
+    fn test_terms() {
+        let got = run_str("terms 2018").unwrap();
+        let lines: Vec<&str> = got.lines().collect();
+        assert_eq!(lines.len(), 24);
+        assert_eq!(lines[5], "春分  2018-03-21 00:15:28 +08:00");
+
+        let got = run_str("terms 2018 --tz UTC --json").unwrap();
+        assert!(got.contains("{\"name\":\"春分\",\"time\":\"2018-03-20T16:15:28+00:00\"}"));
+        assert!(run_str("terms 1800").is_err());
+
+        // 与 ikigai_core 的节气表范围一致
+        let got = run_str(&format!("terms {}", SOLARTERM_TO_YEAR)).unwrap();
+        assert_eq!(got.lines().count(), 24);
+        assert!(got.lines().last().unwrap().starts_with("冬至  3000-12-"));
+        assert!(matches!(
+            run_str(&format!("terms {}", SOLARTERM_TO_YEAR + 1)),
+            Err(CliError::Core(IkigaiError::YearOutOfRange {
+                min: SOLARTERM_FROM_YEAR,
+                max: SOLARTERM_TO_YEAR,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_cal() {
+        let got = run_str("cal 2024 2").unwrap();
+        let lines: Vec<&str> = got.lines().collect();
+        assert_eq!(lines[0], "                 2024年2月");
+        assert_eq!(lines[1], "    日    一    二    三    四    五    六");
+        assert_eq!(lines[2], "                             1     2     3");
+        assert_eq!(lines[3], "                          廿二  廿三  廿四");
+        // 2 月 4 日立春, 2 月 10 日为正月初一
+        assert_eq!(lines[4], "     4     5     6     7     8     9    10");
+        assert_eq!(lines[5], "  立春  廿六  廿七  廿八  廿九  三十  正月");
+        assert_eq!(lines.len(), 12);
+
+        let got = run_str("cal 2024 2 --json").unwrap();
+        assert!(got.starts_with("{\"year\":2024,\"month\":2,\"weeks\":[[null,null,null,null,{\"date\":\"2024-02-01\",\"day\":1,\"lunar\":\"廿二\",\"solarterm\":null,\"festivals\":[],\"weekend\":false}"));
+        assert!(got.contains("{\"date\":\"2024-02-10\",\"day\":10,\"lunar\":\"正月\",\"solarterm\":null,\"festivals\":[\"春节\"],\"weekend\":true}"));
+    }
+}
@@ -0,0 +1,22 @@
+use std::{
+    io::{self, Write},
+    process::ExitCode,
+};
+
+use chrono::Utc;
+use ikigai::cli;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match cli::run(&args, Utc::now()) {
+        Ok(output) => {
+            // 忽略管道关闭等写入错误, 如 `ikigai terms 2024 | head`
+            let _ = writeln!(io::stdout(), "{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}